@@ -26,8 +26,11 @@ fn main() {
         .minimize(&euclidean, &initial_point, cost)
         .unwrap();
 
-    println!("\nOptimized point: ({:.3}, {:.3})", result[0], result[1]);
-    println!("Final cost: {:.6}", cost(&result));
+    println!(
+        "\nOptimized point: ({:.3}, {:.3})",
+        result.point[0], result.point[1]
+    );
+    println!("Final cost: {:.6}", cost(&result.point));
     println!("Expected minimum: (1.000, 2.000)\n");
 
     // Example 2: Rosenbrock function
@@ -48,7 +51,10 @@ fn main() {
         .minimize(&euclidean, &initial_point, rosenbrock)
         .unwrap();
 
-    println!("\nOptimized point: ({:.3}, {:.3})", result[0], result[1]);
-    println!("Final cost: {:.6}", rosenbrock(&result));
+    println!(
+        "\nOptimized point: ({:.3}, {:.3})",
+        result.point[0], result.point[1]
+    );
+    println!("Final cost: {:.6}", rosenbrock(&result.point));
     println!("Expected minimum: (1.000, 1.000)");
 }
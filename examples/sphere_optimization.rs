@@ -28,13 +28,13 @@ fn main() {
 
     println!(
         "\nOptimized point: ({:.3}, {:.3}, {:.3})",
-        result[0], result[1], result[2]
+        result.point[0], result.point[1], result.point[2]
     );
-    println!("Final cost: {:.6}", cost(&result));
+    println!("Final cost: {:.6}", cost(&result.point));
 
-    let norm: f64 = result.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm: f64 = result.point.iter().map(|x| x * x).sum::<f64>().sqrt();
     println!("Point norm (should be 1.0): {:.6}", norm);
 
-    let dist = sphere.distance(&initial_point, &result).unwrap();
+    let dist = sphere.distance(&initial_point, &result.point).unwrap();
     println!("Geodesic distance traveled: {:.6}", dist);
 }
@@ -0,0 +1,87 @@
+use poly_manifold_autodiff::{GradientDescent, IterativeOptimizer};
+use poly_manifold_core::Manifold;
+use poly_manifold_spaces::{Euclidean, Sphere};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::time::Instant;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+fn measure<M, F>(name: &str, manifold: &M, initial_point: &[f64], cost: F, iterations: usize)
+where
+    M: Manifold<Scalar = f64>,
+    F: Fn(&[f64]) -> f64 + Copy,
+{
+    let optimizer = GradientDescent::new(1e-3, iterations, 0.0);
+    let mut state = optimizer.init(manifold, initial_point, cost).unwrap();
+    optimizer.step(manifold, &mut state, cost).unwrap();
+
+    let before = allocations();
+    let start = Instant::now();
+    for _ in 0..iterations {
+        optimizer.step(manifold, &mut state, cost).unwrap();
+    }
+    let elapsed = start.elapsed();
+    let per_iteration = (allocations() - before) as f64 / iterations as f64;
+
+    println!(
+        "{name}: {per_iteration:.2} allocations/iteration, {:.2} µs/iteration",
+        elapsed.as_secs_f64() * 1e6 / iterations as f64
+    );
+    assert!(
+        per_iteration < 0.01,
+        "{name} allocated {per_iteration} times per iteration"
+    );
+}
+
+fn main() {
+    let dimension = 64;
+
+    let euclidean = Euclidean::new(dimension);
+    let start = vec![1.0; dimension];
+    measure(
+        "euclidean",
+        &euclidean,
+        &start,
+        |p: &[f64]| p.iter().map(|x| x * x).sum(),
+        10_000,
+    );
+
+    let sphere = Sphere::new(dimension - 1);
+    let mut start = vec![0.0; dimension];
+    start[0] = 1.0;
+    measure(
+        "sphere",
+        &sphere,
+        &start,
+        |p: &[f64]| p.iter().enumerate().map(|(i, x)| i as f64 * x * x).sum(),
+        10_000,
+    );
+}
@@ -0,0 +1,165 @@
+use crate::optimizer::RiemannianOptimizer;
+use crate::prox::ProximalOperator;
+use crate::result::{OptimizationResult, Termination};
+use poly_manifold_core::profiling::snapshot;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Manifold, ManifoldError, Result};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RiemannianAdmm<O> {
+    pub inner: O,
+    pub penalty: f64,
+    pub max_iterations: usize,
+    pub tolerance: f64,
+}
+
+impl<O: RiemannianOptimizer> RiemannianAdmm<O> {
+    pub fn new(inner: O, penalty: f64, max_iterations: usize, tolerance: f64) -> Self {
+        Self {
+            inner,
+            penalty,
+            max_iterations,
+            tolerance,
+        }
+    }
+
+    pub fn minimize_composite<M, F, G>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        smooth: F,
+        nonsmooth: &G,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+        G: ProximalOperator + ?Sized,
+    {
+        if self.penalty <= 0.0 {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "ADMM penalty must be positive, got {}",
+                self.penalty
+            )));
+        }
+        validate_entry_point(manifold, initial_point)?;
+        let start = snapshot();
+        let finish =
+            |point: Vec<f64>, iterations: usize, termination: Termination| OptimizationResult {
+                cost: smooth(&point) + nonsmooth.value(&point),
+                point,
+                iterations,
+                termination,
+                operation_counts: snapshot().since(&start),
+            };
+
+        let n = initial_point.len();
+        let mut x = initial_point.to_vec();
+        let mut z = x.clone();
+        let mut u = vec![0.0; n];
+
+        for iteration in 1..=self.max_iterations {
+            let augmented = |p: &[f64]| {
+                let penalty_term: f64 = (0..n).map(|i| (p[i] - z[i] + u[i]).powi(2)).sum();
+                smooth(p) + 0.5 * self.penalty * penalty_term
+            };
+            let inner = self.inner.minimize(manifold, &x, augmented)?;
+            x = inner.point;
+            if matches!(
+                inner.termination,
+                Termination::Cancelled | Termination::LineSearchFailed
+            ) {
+                return Ok(finish(x, iteration, inner.termination));
+            }
+
+            let shifted: Vec<f64> = (0..n).map(|i| x[i] + u[i]).collect();
+            let z_prev = std::mem::replace(&mut z, nonsmooth.prox(&shifted, 1.0 / self.penalty));
+
+            for i in 0..n {
+                u[i] += x[i] - z[i];
+            }
+
+            let primal_residual = (0..n).map(|i| (x[i] - z[i]).powi(2)).sum::<f64>().sqrt();
+            let dual_residual = self.penalty
+                * (0..n)
+                    .map(|i| (z[i] - z_prev[i]).powi(2))
+                    .sum::<f64>()
+                    .sqrt();
+
+            if primal_residual < self.tolerance && dual_residual < self.tolerance {
+                return Ok(finish(x, iteration, Termination::Converged));
+            }
+        }
+
+        Ok(finish(x, self.max_iterations, Termination::MaxIterations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::GradientDescent;
+    use crate::prox::{L1Norm, ZeroFunction};
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    #[test]
+    fn test_admm_lasso_scalar() {
+        let euclidean = Euclidean::new(1);
+        let smooth = |p: &[f64]| (p[0] - 3.0).powi(2);
+
+        let solver = RiemannianAdmm::new(GradientDescent::new(0.1, 1000, 1e-14), 1.0, 500, 1e-5);
+        let result = solver
+            .minimize_composite(&euclidean, &[0.0], smooth, &L1Norm::new(2.0))
+            .unwrap()
+            .into_converged()
+            .unwrap();
+
+        assert_relative_eq!(result[0], 2.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_admm_sparse_point_on_sphere() {
+        let sphere = Sphere::new(2);
+        let target = [0.9, 0.3, 0.1];
+        let smooth = |p: &[f64]| {
+            (p[0] - target[0]).powi(2) + (p[1] - target[1]).powi(2) + (p[2] - target[2]).powi(2)
+        };
+
+        let solver = RiemannianAdmm::new(GradientDescent::new(0.05, 2000, 1e-14), 2.0, 500, 1e-5);
+        let result = solver
+            .minimize_composite(&sphere, &[1.0, 0.0, 0.0], smooth, &L1Norm::new(0.4))
+            .unwrap()
+            .into_converged()
+            .unwrap();
+
+        assert!(sphere.check_point(&result).is_ok());
+        let norm = (0.7f64 * 0.7 + 0.1 * 0.1).sqrt();
+        assert_relative_eq!(result[0], 0.7 / norm, epsilon = 1e-3);
+        assert_relative_eq!(result[1], 0.1 / norm, epsilon = 1e-3);
+        assert_relative_eq!(result[2], 0.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_admm_with_zero_nonsmooth_term() {
+        let euclidean = Euclidean::new(2);
+        let smooth = |p: &[f64]| (p[0] - 1.0).powi(2) + (p[1] + 1.0).powi(2);
+
+        let solver = RiemannianAdmm::new(GradientDescent::new(0.1, 1000, 1e-14), 1.0, 200, 1e-5);
+        let result = solver
+            .minimize_composite(&euclidean, &[0.0, 0.0], smooth, &ZeroFunction)
+            .unwrap()
+            .into_converged()
+            .unwrap();
+
+        assert_relative_eq!(result[0], 1.0, epsilon = 1e-3);
+        assert_relative_eq!(result[1], -1.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_admm_rejects_non_positive_penalty() {
+        let euclidean = Euclidean::new(1);
+        let solver = RiemannianAdmm::new(GradientDescent::new(0.1, 10, 1e-8), 0.0, 10, 1e-5);
+        let result = solver.minimize_composite(&euclidean, &[0.0], |p: &[f64]| p[0], &ZeroFunction);
+        assert!(matches!(result, Err(ManifoldError::InvalidParameter(_))));
+    }
+}
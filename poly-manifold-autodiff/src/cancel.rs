@@ -0,0 +1,143 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    flag: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Relaxed)
+    }
+}
+
+impl From<Arc<AtomicBool>> for CancellationToken {
+    fn from(flag: Arc<AtomicBool>) -> Self {
+        Self { flag }
+    }
+}
+
+pub(crate) fn is_cancelled(token: &Option<CancellationToken>) -> bool {
+    token.as_ref().is_some_and(CancellationToken::is_cancelled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::admm::RiemannianAdmm;
+    use crate::constrained::{AugmentedLagrangian, Constraints};
+    use crate::multistart::MultiStart;
+    use crate::optimizer::{GradientDescent, RiemannianOptimizer};
+    use crate::prox::{L1Norm, ZeroFunction};
+    use crate::proximal::ProximalGradient;
+    use crate::result::Termination;
+    use poly_manifold_core::{Manifold, ManifoldError};
+    use poly_manifold_spaces::Sphere;
+    use std::sync::atomic::AtomicUsize;
+
+    fn cancelling_cost<'a>(
+        token: &'a CancellationToken,
+        evaluations: &'a AtomicUsize,
+        limit: usize,
+    ) -> impl Fn(&[f64]) -> f64 + 'a {
+        move |p: &[f64]| {
+            if evaluations.fetch_add(1, Ordering::Relaxed) + 1 == limit {
+                token.cancel();
+            }
+            -p[2]
+        }
+    }
+
+    #[test]
+    fn test_cancelled_runs_return_partial_results() {
+        let sphere = Sphere::new(2);
+        let start = [1.0, 0.0, 0.0];
+
+        let token = CancellationToken::new();
+        let evaluations = AtomicUsize::new(0);
+        let result = GradientDescent::new(0.01, 10_000, 1e-12)
+            .with_cancellation(token.clone())
+            .minimize(&sphere, &start, cancelling_cost(&token, &evaluations, 50))
+            .unwrap();
+        assert_eq!(result.termination, Termination::Cancelled);
+        assert!(result.iterations > 0 && result.iterations < 50);
+        assert!(sphere.check_point(&result.point).is_ok());
+        assert!(result.cost < 0.0);
+        assert!(matches!(
+            result.into_converged(),
+            Err(ManifoldError::ConvergenceError { .. })
+        ));
+
+        let token = CancellationToken::new();
+        let evaluations = AtomicUsize::new(0);
+        let result = ProximalGradient::new(ZeroFunction, 0.01, 10_000, 1e-12)
+            .with_cancellation(token.clone())
+            .minimize(&sphere, &start, cancelling_cost(&token, &evaluations, 50))
+            .unwrap();
+        assert_eq!(result.termination, Termination::Cancelled);
+        assert!(result.iterations > 0 && result.iterations < 50);
+    }
+
+    #[test]
+    fn test_cancellation_before_the_first_iteration() {
+        let sphere = Sphere::new(2);
+        let flag = Arc::new(AtomicBool::new(true));
+        let optimizer = GradientDescent::builder()
+            .cancellation(flag.clone().into())
+            .build()
+            .unwrap();
+
+        let result = optimizer
+            .minimize(&sphere, &[1.0, 0.0, 0.0], |p: &[f64]| -p[2])
+            .unwrap();
+        assert_eq!(result.termination, Termination::Cancelled);
+        assert_eq!(result.iterations, 0);
+        assert_eq!(result.point, vec![1.0, 0.0, 0.0]);
+
+        let result = MultiStart::new(optimizer, vec![vec![0.0, 0.0, 1.0]])
+            .minimize(&sphere, &[1.0, 0.0, 0.0], |p: &[f64]| -p[2])
+            .unwrap();
+        assert_eq!(result.termination, Termination::Cancelled);
+        assert_eq!(result.cost, -1.0);
+
+        flag.store(false, Ordering::Relaxed);
+        let result = GradientDescent::new(0.2, 500, 1e-10)
+            .with_cancellation(flag.into())
+            .minimize(&sphere, &[0.6, 0.0, 0.8], |p: &[f64]| -p[2])
+            .unwrap();
+        assert_eq!(result.termination, Termination::Converged);
+    }
+
+    #[test]
+    fn test_outer_solvers_stop_when_the_inner_run_is_cancelled() {
+        let sphere = Sphere::new(2);
+        let start = [0.6, 0.0, 0.8];
+        let token = CancellationToken::new();
+        token.cancel();
+        let inner = || GradientDescent::new(0.05, 2000, 1e-14).with_cancellation(token.clone());
+
+        let constraints = Constraints::new().with_inequality(|p: &[f64]| -p[0]);
+        let result = AugmentedLagrangian::new(inner(), 50, 1e-12)
+            .minimize_constrained(&sphere, &start, |p: &[f64]| -p[1], &constraints)
+            .unwrap();
+        assert_eq!(result.termination, Termination::Cancelled);
+        assert_eq!(result.iterations, 1);
+        assert_eq!(result.point, start.to_vec());
+
+        let result = RiemannianAdmm::new(inner(), 1.0, 50, 1e-12)
+            .minimize_composite(&sphere, &start, |p: &[f64]| -p[1], &L1Norm::new(0.1))
+            .unwrap();
+        assert_eq!(result.termination, Termination::Cancelled);
+        assert_eq!(result.iterations, 1);
+        assert_eq!(result.point, start.to_vec());
+    }
+}
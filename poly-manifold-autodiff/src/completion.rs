@@ -0,0 +1,195 @@
+use crate::trust_region::RiemannianTrustRegion;
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+use poly_manifold_spaces::FixedRank;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompletedMatrix {
+    pub left: DMatrix<f64>,
+    pub right: DMatrix<f64>,
+}
+
+impl CompletedMatrix {
+    pub fn matrix(&self) -> DMatrix<f64> {
+        &self.left * self.right.transpose()
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatrixCompletion {
+    pub rows: usize,
+    pub cols: usize,
+    pub rank: usize,
+    pub entries: Vec<(usize, usize, f64)>,
+    pub regularization: f64,
+}
+
+impl MatrixCompletion {
+    pub fn new(
+        rows: usize,
+        cols: usize,
+        rank: usize,
+        entries: Vec<(usize, usize, f64)>,
+    ) -> Result<Self> {
+        if rank == 0 || rank > rows.min(cols) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Rank must be between 1 and {}, got {}",
+                rows.min(cols),
+                rank
+            )));
+        }
+        if let Some((i, j, _)) = entries.iter().find(|(i, j, _)| *i >= rows || *j >= cols) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Observed entry ({}, {}) is outside a {}x{} matrix",
+                i, j, rows, cols
+            )));
+        }
+        if entries.is_empty() {
+            return Err(ManifoldError::InvalidParameter(
+                "Matrix completion needs at least one observed entry".to_string(),
+            ));
+        }
+        Ok(Self {
+            rows,
+            cols,
+            rank,
+            entries,
+            regularization: 0.0,
+        })
+    }
+
+    pub fn with_regularization(mut self, regularization: f64) -> Self {
+        self.regularization = regularization;
+        self
+    }
+
+    pub fn manifold(&self) -> Result<FixedRank> {
+        FixedRank::new(self.rows, self.cols, self.rank)
+    }
+
+    pub fn cost(&self, point: &[f64]) -> f64 {
+        let residual: f64 = self
+            .entries
+            .iter()
+            .map(|&(i, j, value)| (point[i * self.cols + j] - value).powi(2))
+            .sum();
+        let penalty: f64 = point.iter().map(|x| x * x).sum();
+        0.5 * residual + 0.5 * self.regularization * penalty
+    }
+
+    pub fn initial_point(&self) -> Result<Vec<f64>> {
+        let scale = (self.rows * self.cols) as f64 / self.entries.len() as f64;
+        let mut observed = vec![0.0; self.rows * self.cols];
+        for &(i, j, value) in &self.entries {
+            observed[i * self.cols + j] = value * scale;
+        }
+        self.manifold()?.project_to_manifold(&observed)
+    }
+
+    pub fn solve(&self, solver: &RiemannianTrustRegion) -> Result<CompletedMatrix> {
+        let manifold = self.manifold()?;
+        let gradient = |x: &[f64]| {
+            let mut gradient = DVector::from_column_slice(x) * self.regularization;
+            for &(i, j, value) in &self.entries {
+                gradient[i * self.cols + j] += x[i * self.cols + j] - value;
+            }
+            TangentVector::new(gradient)
+        };
+        let hessian = |x: &[f64], v: &TangentVector<f64>| {
+            let mut product = &v.components * self.regularization;
+            for &(i, j, _) in &self.entries {
+                product[i * self.cols + j] += v.components[i * self.cols + j];
+            }
+            manifold.euclidean_to_riemannian_hessian(
+                x,
+                &gradient(x),
+                &TangentVector::new(product),
+                v,
+            )
+        };
+
+        let point = solver
+            .minimize(
+                &manifold,
+                &self.initial_point()?,
+                |x| self.cost(x),
+                |x| Ok(gradient(x)),
+                hessian,
+            )?
+            .into_converged()?;
+        let (u, s, v) = manifold.factors(&point)?;
+        let root = DMatrix::from_diagonal(&s.map(f64::sqrt));
+        Ok(CompletedMatrix {
+            left: u * &root,
+            right: v * root,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn rank_one_ground_truth() -> DMatrix<f64> {
+        let u = [1.0, 2.0, 3.0, 4.0];
+        let v = [1.0, -1.0, 2.0, 0.5];
+        DMatrix::from_fn(4, 4, |i, j| u[i] * v[j])
+    }
+
+    #[test]
+    fn test_matrix_completion_rejects_invalid_inputs() {
+        assert!(MatrixCompletion::new(3, 3, 0, vec![(0, 0, 1.0)]).is_err());
+        assert!(MatrixCompletion::new(3, 3, 4, vec![(0, 0, 1.0)]).is_err());
+        assert!(MatrixCompletion::new(3, 3, 1, vec![(3, 0, 1.0)]).is_err());
+        assert!(MatrixCompletion::new(3, 3, 1, vec![]).is_err());
+    }
+
+    #[test]
+    fn test_matrix_completion_cost_zero_at_truth() {
+        let problem = MatrixCompletion::new(2, 2, 1, vec![(0, 0, 2.0), (1, 1, 3.0)]).unwrap();
+        let point = [2.0, 1.0, 6.0, 3.0];
+        assert_relative_eq!(problem.cost(&point), 0.0, epsilon = 1e-12);
+        assert!(problem.manifold().unwrap().check_point(&point).is_ok());
+    }
+
+    #[test]
+    fn test_matrix_completion_recovers_rank_one() {
+        let truth = rank_one_ground_truth();
+        let hidden = [(0, 3), (1, 1), (2, 2), (3, 0)];
+        let entries: Vec<(usize, usize, f64)> = (0..4)
+            .flat_map(|i| (0..4).map(move |j| (i, j)))
+            .filter(|idx| !hidden.contains(idx))
+            .map(|(i, j)| (i, j, truth[(i, j)]))
+            .collect();
+
+        let problem = MatrixCompletion::new(4, 4, 1, entries).unwrap();
+        let solver = RiemannianTrustRegion::new(200, 1e-10);
+        let completed = problem.solve(&solver).unwrap().matrix();
+
+        for &(i, j) in &hidden {
+            assert_relative_eq!(completed[(i, j)], truth[(i, j)], epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_matrix_completion_recovers_rank_two() {
+        let left = DMatrix::from_fn(12, 2, |i, j| ((i * 2 + j + 1) as f64).sin());
+        let right = DMatrix::from_fn(10, 2, |i, j| ((i * 3 + j + 2) as f64).cos());
+        let truth = &left * right.transpose();
+        let entries: Vec<(usize, usize, f64)> = (0..12)
+            .flat_map(|i| (0..10).map(move |j| (i, j)))
+            .filter(|(i, j)| (i * 10 + j) * 37 % 11 >= 3)
+            .map(|(i, j)| (i, j, truth[(i, j)]))
+            .collect();
+
+        let problem = MatrixCompletion::new(12, 10, 2, entries).unwrap();
+        let solver = RiemannianTrustRegion::new(50, 1e-10);
+        let completed = problem.solve(&solver).unwrap();
+        assert_eq!(completed.left.shape(), (12, 2));
+        assert_eq!(completed.right.shape(), (10, 2));
+        assert!((completed.matrix() - truth).amax() < 1e-6);
+    }
+}
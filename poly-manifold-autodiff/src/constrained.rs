@@ -0,0 +1,236 @@
+use crate::optimizer::RiemannianOptimizer;
+use crate::result::{OptimizationResult, Termination};
+use poly_manifold_core::profiling::snapshot;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Manifold, ManifoldError, Result};
+
+type ConstraintFn<'a> = Box<dyn Fn(&[f64]) -> f64 + 'a>;
+
+#[derive(Default)]
+pub struct Constraints<'a> {
+    pub equality: Vec<ConstraintFn<'a>>,
+    pub inequality: Vec<ConstraintFn<'a>>,
+}
+
+impl<'a> Constraints<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_equality<F>(mut self, constraint: F) -> Self
+    where
+        F: Fn(&[f64]) -> f64 + 'a,
+    {
+        self.equality.push(Box::new(constraint));
+        self
+    }
+
+    pub fn with_inequality<F>(mut self, constraint: F) -> Self
+    where
+        F: Fn(&[f64]) -> f64 + 'a,
+    {
+        self.inequality.push(Box::new(constraint));
+        self
+    }
+
+    pub fn max_violation(&self, point: &[f64]) -> f64 {
+        let equality = self.equality.iter().map(|h| h(point).abs());
+        let inequality = self.inequality.iter().map(|g| g(point).max(0.0));
+        equality.chain(inequality).fold(0.0, f64::max)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AugmentedLagrangian<O> {
+    pub inner: O,
+    pub initial_penalty: f64,
+    pub penalty_growth: f64,
+    pub max_penalty: f64,
+    pub max_outer_iterations: usize,
+    pub constraint_tolerance: f64,
+}
+
+impl<O: RiemannianOptimizer> AugmentedLagrangian<O> {
+    pub fn new(inner: O, max_outer_iterations: usize, constraint_tolerance: f64) -> Self {
+        Self {
+            inner,
+            initial_penalty: 1.0,
+            penalty_growth: 10.0,
+            max_penalty: 1e6,
+            max_outer_iterations,
+            constraint_tolerance,
+        }
+    }
+
+    pub fn minimize_constrained<M, F>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+        constraints: &Constraints,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+    {
+        if self.initial_penalty <= 0.0 || self.penalty_growth < 1.0 {
+            return Err(ManifoldError::InvalidParameter(
+                "Penalty must be positive and growth factor at least 1".to_string(),
+            ));
+        }
+        validate_entry_point(manifold, initial_point)?;
+        let start = snapshot();
+        let finish =
+            |point: Vec<f64>, iterations: usize, termination: Termination| OptimizationResult {
+                cost: cost_function(&point),
+                point,
+                iterations,
+                termination,
+                operation_counts: snapshot().since(&start),
+            };
+
+        let mut point = initial_point.to_vec();
+        let mut lambda = vec![0.0; constraints.equality.len()];
+        let mut mu = vec![0.0; constraints.inequality.len()];
+        let mut penalty = self.initial_penalty;
+        let mut prev_violation = constraints.max_violation(&point);
+
+        for outer in 1..=self.max_outer_iterations {
+            let lagrangian = |p: &[f64]| {
+                let mut value = cost_function(p);
+                for (h, l) in constraints.equality.iter().zip(lambda.iter()) {
+                    let h_val = h(p);
+                    value += l * h_val + 0.5 * penalty * h_val * h_val;
+                }
+                for (g, m) in constraints.inequality.iter().zip(mu.iter()) {
+                    let shifted = (m + penalty * g(p)).max(0.0);
+                    value += (shifted * shifted - m * m) / (2.0 * penalty);
+                }
+                value
+            };
+
+            let inner = self.inner.minimize(manifold, &point, lagrangian)?;
+            point = inner.point;
+            if matches!(
+                inner.termination,
+                Termination::Cancelled | Termination::LineSearchFailed
+            ) {
+                return Ok(finish(point, outer, inner.termination));
+            }
+
+            for (h, l) in constraints.equality.iter().zip(lambda.iter_mut()) {
+                *l += penalty * h(&point);
+            }
+            for (g, m) in constraints.inequality.iter().zip(mu.iter_mut()) {
+                *m = (*m + penalty * g(&point)).max(0.0);
+            }
+
+            let violation = constraints.max_violation(&point);
+            if violation < self.constraint_tolerance {
+                return Ok(finish(point, outer, Termination::Converged));
+            }
+
+            if violation > 0.25 * prev_violation {
+                penalty = (penalty * self.penalty_growth).min(self.max_penalty);
+            }
+            prev_violation = violation;
+        }
+
+        Ok(finish(
+            point,
+            self.max_outer_iterations,
+            Termination::MaxIterations,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::GradientDescent;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    #[test]
+    fn test_equality_constrained_quadratic() {
+        let euclidean = Euclidean::new(2);
+        let cost = |p: &[f64]| p[0] * p[0] + p[1] * p[1];
+        let constraints = Constraints::new().with_equality(|p: &[f64]| p[0] + p[1] - 1.0);
+
+        let solver = AugmentedLagrangian::new(GradientDescent::new(0.05, 2000, 1e-14), 50, 1e-5);
+        let result = solver
+            .minimize_constrained(&euclidean, &[0.0, 0.0], cost, &constraints)
+            .unwrap()
+            .into_converged()
+            .unwrap();
+
+        assert_relative_eq!(result[0], 0.5, epsilon = 1e-3);
+        assert_relative_eq!(result[1], 0.5, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_sphere_with_nonnegativity() {
+        let sphere = Sphere::new(2);
+        let target = [-0.6, 0.8, 0.0];
+        let cost = |p: &[f64]| {
+            (p[0] - target[0]).powi(2) + (p[1] - target[1]).powi(2) + (p[2] - target[2]).powi(2)
+        };
+        let constraints = Constraints::new().with_inequality(|p: &[f64]| -p[0]);
+
+        let solver = AugmentedLagrangian::new(GradientDescent::new(0.05, 2000, 1e-14), 50, 1e-5);
+        let result = solver
+            .minimize_constrained(&sphere, &[0.6, 0.0, 0.8], cost, &constraints)
+            .unwrap()
+            .into_converged()
+            .unwrap();
+
+        assert!(sphere.check_point(&result).is_ok());
+        assert!(result[0] > -1e-5);
+        assert_relative_eq!(result[0], 0.0, epsilon = 1e-3);
+        assert_relative_eq!(result[1], 1.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_inactive_inequality_returns_unconstrained_minimum() {
+        let euclidean = Euclidean::new(1);
+        let cost = |p: &[f64]| (p[0] - 2.0).powi(2);
+        let constraints = Constraints::new().with_inequality(|p: &[f64]| p[0] - 5.0);
+
+        let solver = AugmentedLagrangian::new(GradientDescent::new(0.1, 1000, 1e-14), 10, 1e-6);
+        let result = solver
+            .minimize_constrained(&euclidean, &[0.0], cost, &constraints)
+            .unwrap()
+            .into_converged()
+            .unwrap();
+
+        assert_relative_eq!(result[0], 2.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_max_violation() {
+        let constraints = Constraints::new()
+            .with_equality(|p: &[f64]| p[0] - 1.0)
+            .with_inequality(|p: &[f64]| p[1]);
+        assert_relative_eq!(constraints.max_violation(&[3.0, 0.5]), 2.0);
+        assert_relative_eq!(constraints.max_violation(&[1.0, -4.0]), 0.0);
+    }
+
+    #[test]
+    fn test_infeasible_problem_reports_convergence_error() {
+        let euclidean = Euclidean::new(1);
+        let cost = |p: &[f64]| p[0] * p[0];
+        let constraints = Constraints::new()
+            .with_equality(|p: &[f64]| p[0] - 1.0)
+            .with_equality(|p: &[f64]| p[0] + 1.0);
+
+        let solver = AugmentedLagrangian::new(GradientDescent::new(0.01, 200, 1e-12), 3, 1e-6);
+        let result = solver
+            .minimize_constrained(&euclidean, &[0.0], cost, &constraints)
+            .unwrap();
+        assert_eq!(result.termination, Termination::MaxIterations);
+        assert!(matches!(
+            result.into_converged(),
+            Err(ManifoldError::ConvergenceError { iterations: 3 })
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::optimizer::RiemannianOptimizer;
+use crate::result::OptimizationResult;
+use poly_manifold_core::{Manifold, Result};
+
+pub type CostFunction<'a> = Box<dyn Fn(&[f64]) -> f64 + Send + Sync + 'a>;
+
+pub type BoxedOptimizer = Box<dyn DynOptimizer>;
+
+pub trait DynOptimizer: Send + Sync {
+    fn minimize_dyn(
+        &self,
+        manifold: &dyn Manifold<Scalar = f64>,
+        initial_point: &[f64],
+        cost_function: &dyn Fn(&[f64]) -> f64,
+    ) -> Result<OptimizationResult>;
+}
+
+impl<O: RiemannianOptimizer + Send + Sync> DynOptimizer for O {
+    fn minimize_dyn(
+        &self,
+        manifold: &dyn Manifold<Scalar = f64>,
+        initial_point: &[f64],
+        cost_function: &dyn Fn(&[f64]) -> f64,
+    ) -> Result<OptimizationResult> {
+        self.minimize(&manifold, initial_point, cost_function)
+    }
+}
+
+impl<O: DynOptimizer + ?Sized> RiemannianOptimizer for Box<O> {
+    fn minimize<M, F>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+    {
+        (**self).minimize_dyn(manifold, initial_point, &cost_function)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multistart::MultiStart;
+    use crate::optimizer::GradientDescent;
+    use crate::prox::ZeroFunction;
+    use crate::proximal::ProximalGradient;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    fn select(name: &str) -> BoxedOptimizer {
+        let descent = GradientDescent::new(0.1, 2000, 1e-10);
+        match name {
+            "proximal" => Box::new(ProximalGradient::new(ZeroFunction, 0.1, 2000, 1e-10)),
+            "multistart" => Box::new(MultiStart::new(descent, vec![vec![0.0, 1.0, 0.0]])),
+            _ => Box::new(descent),
+        }
+    }
+
+    #[test]
+    fn test_runtime_selected_optimizers_share_one_handle() {
+        let sphere = Sphere::new(2);
+        let cost: CostFunction = Box::new(|p| -p[2]);
+        for name in ["gradient_descent", "proximal", "multistart"] {
+            let optimizer = select(name);
+            let result = optimizer
+                .minimize(&sphere, &[1.0, 0.0, 0.0], &cost)
+                .unwrap();
+            assert_relative_eq!(result.cost, -1.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_boxed_optimizer_with_dyn_manifold_and_wrappers() {
+        let manifolds: Vec<Box<dyn Manifold<Scalar = f64> + Send + Sync>> =
+            vec![Box::new(Euclidean::new(3)), Box::new(Sphere::new(2))];
+        let target = [0.0, 0.6, 0.8];
+        let cost: CostFunction =
+            Box::new(move |p| p.iter().zip(target).map(|(x, t)| (x - t) * (x - t)).sum());
+
+        for manifold in &manifolds {
+            let result = select("gradient_descent")
+                .minimize_dyn(manifold.as_ref(), &[1.0, 0.0, 0.0], &cost)
+                .unwrap();
+            assert_relative_eq!(result.cost, 0.0, epsilon = 1e-8);
+        }
+
+        let wrapped = MultiStart::new(select("proximal"), Vec::new());
+        let result = wrapped
+            .minimize(&Euclidean::new(3), &[0.0; 3], &cost)
+            .unwrap();
+        assert_relative_eq!(result.point[2], 0.8, epsilon = 1e-4);
+    }
+}
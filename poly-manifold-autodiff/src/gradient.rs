@@ -1,5 +1,6 @@
-use poly_manifold_core::{Manifold, Result, TangentVector};
 use nalgebra::DVector;
+use poly_manifold_core::validation::{validate_entry_point, validate_entry_tangent};
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
 
 pub fn numerical_gradient<M, F>(
     manifold: &M,
@@ -11,23 +12,62 @@ where
     M: Manifold<Scalar = f64>,
     F: Fn(&[f64]) -> f64,
 {
-    manifold.check_point(point)?;
+    validate_entry_point(manifold, point)?;
+    numerical_gradient_unchecked(manifold, point, cost_function, epsilon)
+}
 
+pub(crate) fn numerical_gradient_unchecked<M, F>(
+    manifold: &M,
+    point: &[f64],
+    cost_function: F,
+    epsilon: f64,
+) -> Result<TangentVector<f64>>
+where
+    M: Manifold<Scalar = f64>,
+    F: Fn(&[f64]) -> f64,
+{
+    let mut probe = Vec::with_capacity(point.len());
+    let mut gradient = TangentVector::zero(point.len());
+    numerical_gradient_into(
+        manifold,
+        point,
+        cost_function,
+        epsilon,
+        &mut probe,
+        &mut gradient,
+    )?;
+    Ok(gradient)
+}
+
+pub fn numerical_gradient_into<M, F>(
+    manifold: &M,
+    point: &[f64],
+    cost_function: F,
+    epsilon: f64,
+    probe: &mut Vec<f64>,
+    gradient: &mut TangentVector<f64>,
+) -> Result<()>
+where
+    M: Manifold<Scalar = f64>,
+    F: Fn(&[f64]) -> f64,
+{
     let n = point.len();
-    let mut gradient = vec![0.0; n];
+    probe.clear();
+    probe.extend_from_slice(point);
+    if gradient.dim() != n {
+        gradient.components = DVector::zeros(n);
+    }
 
     let f0 = cost_function(point);
 
     for i in 0..n {
-        let mut point_plus = point.to_vec();
-        point_plus[i] += epsilon;
-
-        let f_plus = cost_function(&point_plus);
-        gradient[i] = (f_plus - f0) / epsilon;
+        probe[i] += epsilon;
+        let f_plus = cost_function(probe);
+        gradient.components[i] = (f_plus - f0) / epsilon;
+        probe[i] = point[i];
     }
 
-    let grad_tangent = TangentVector::new(DVector::from_vec(gradient));
-    manifold.project_to_tangent_space(point, &grad_tangent)
+    manifold.euclidean_to_riemannian_gradient_in_place(point, gradient)
 }
 
 pub fn riemannian_gradient<M, F>(
@@ -39,7 +79,34 @@ where
     M: Manifold<Scalar = f64>,
     F: Fn(&[f64]) -> f64,
 {
-    manifold.project_to_tangent_space(point, euclidean_gradient)
+    manifold.euclidean_to_riemannian_gradient(point, euclidean_gradient)
+}
+
+pub fn clip_tangent<M>(
+    manifold: &M,
+    point: &[f64],
+    tangent: TangentVector<f64>,
+    max_norm: f64,
+) -> Result<TangentVector<f64>>
+where
+    M: Manifold<Scalar = f64>,
+{
+    check_clip_bound("Clipping bound", max_norm)?;
+    let norm = manifold.norm(point, &tangent)?;
+    if norm > max_norm {
+        Ok(tangent * (max_norm / norm))
+    } else {
+        Ok(tangent)
+    }
+}
+
+pub(crate) fn check_clip_bound(name: &str, bound: f64) -> Result<()> {
+    if !(bound.is_finite() && bound > 0.0) {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "{name} must be positive and finite, got {bound}"
+        )));
+    }
+    Ok(())
 }
 
 pub fn finite_difference_gradient<M, F>(
@@ -53,9 +120,9 @@ where
     M: Manifold<Scalar = f64>,
     F: Fn(&[f64]) -> f64,
 {
-    manifold.check_tangent_vector(point, direction)?;
+    validate_entry_tangent(manifold, point, direction)?;
 
-    let scaled_direction = direction.clone() * epsilon;
+    let scaled_direction = direction * epsilon;
     let point_plus = manifold.exp(point, &scaled_direction)?;
 
     let f0 = cost_function(point);
@@ -97,6 +164,29 @@ mod tests {
         assert_relative_eq!(directional_derivative, 2.0, epsilon = 1e-5);
     }
 
+    #[test]
+    fn test_clip_tangent() {
+        let euclidean = Euclidean::new(2);
+        let point = vec![0.0, 0.0];
+
+        let long = TangentVector::new(DVector::from_vec(vec![3.0, 4.0]));
+        let clipped = clip_tangent(&euclidean, &point, long, 1.0).unwrap();
+        assert_relative_eq!(clipped.norm(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(clipped.components[0], 0.6, epsilon = 1e-12);
+
+        let short = TangentVector::new(DVector::from_vec(vec![0.3, 0.4]));
+        let unchanged = clip_tangent(&euclidean, &point, short, 1.0).unwrap();
+        assert_relative_eq!(unchanged.norm(), 0.5, epsilon = 1e-12);
+
+        for bound in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let tangent = TangentVector::new(DVector::from_vec(vec![3.0, 4.0]));
+            assert!(matches!(
+                clip_tangent(&euclidean, &point, tangent, bound),
+                Err(ManifoldError::InvalidParameter(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_riemannian_gradient_euclidean() {
         let euclidean = Euclidean::new(3);
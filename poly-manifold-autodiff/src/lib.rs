@@ -1,7 +1,92 @@
+pub mod admm;
+pub mod cancel;
+pub mod completion;
+pub mod constrained;
 pub mod dual;
+pub mod dynamic;
 pub mod gradient;
+pub mod line_search;
+pub mod multistart;
 pub mod optimizer;
+pub mod preconditioner;
+pub mod prelude;
+#[cfg(feature = "config")]
+pub mod problem;
+pub mod progress;
+pub mod prox;
+pub mod proximal;
+pub mod rayleigh;
+pub mod result;
+pub mod schedule;
+pub mod trace;
+pub mod trust_region;
 
+pub use admm::RiemannianAdmm;
+pub use cancel::CancellationToken;
+pub use completion::{CompletedMatrix, MatrixCompletion};
+pub use constrained::{AugmentedLagrangian, Constraints};
 pub use dual::Dual;
-pub use gradient::{numerical_gradient, riemannian_gradient};
-pub use optimizer::{GradientDescent, RiemannianOptimizer};
+pub use dynamic::{BoxedOptimizer, CostFunction, DynOptimizer};
+pub use gradient::{numerical_gradient, numerical_gradient_into, riemannian_gradient};
+pub use line_search::{LineSearch, LineSearchResult};
+pub use multistart::MultiStart;
+pub use optimizer::{
+    GradientDescent, GradientDescentBuilder, GradientDescentState, IterativeOptimizer,
+    RiemannianOptimizer, StepBuffers, StepInfo,
+};
+pub use preconditioner::{DiagonalPreconditioner, IdentityPreconditioner, Preconditioner};
+#[cfg(feature = "config")]
+pub use problem::{run_problem, ManifoldSpec, OptimizerSpec, Problem, StoppingSpec};
+pub use progress::{ProgressEvent, ProgressSink};
+pub use prox::{L1Norm, NonNegative, ProximalOperator, ZeroFunction};
+pub use proximal::ProximalGradient;
+pub use rayleigh::{EigenPair, RayleighQuotient};
+pub use result::{OptimizationResult, Termination};
+pub use schedule::Schedule;
+#[cfg(feature = "arrow")]
+pub use trace::trace_to_record_batch;
+pub use trace::{read_trace_csv, write_trace_csv};
+pub use trust_region::{exact_trust_region, truncated_cg, RiemannianTrustRegion, TrustRegionStep};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_optimizers_and_results_are_send_and_sync() {
+        assert_send_sync::<GradientDescent>();
+        assert_send_sync::<GradientDescent<DiagonalPreconditioner>>();
+        assert_send_sync::<GradientDescentBuilder>();
+        assert_send_sync::<GradientDescentState>();
+        assert_send_sync::<StepBuffers>();
+        assert_send_sync::<ProximalGradient<L1Norm>>();
+        assert_send_sync::<MultiStart<GradientDescent>>();
+        assert_send_sync::<AugmentedLagrangian<GradientDescent>>();
+        assert_send_sync::<RiemannianAdmm<GradientDescent>>();
+        assert_send_sync::<MatrixCompletion>();
+        assert_send_sync::<RayleighQuotient>();
+        assert_send_sync::<LineSearch>();
+        assert_send_sync::<Schedule>();
+        assert_send_sync::<OptimizationResult>();
+        assert_send_sync::<Termination>();
+        assert_send_sync::<CancellationToken>();
+        assert_send_sync::<StepInfo>();
+        assert_send_sync::<ProgressEvent>();
+        assert_send_sync::<LineSearchResult>();
+        assert_send_sync::<TrustRegionStep>();
+        assert_send_sync::<RiemannianTrustRegion>();
+        assert_send_sync::<CompletedMatrix>();
+        assert_send_sync::<EigenPair>();
+        assert_send_sync::<Dual>();
+        assert_send_sync::<BoxedOptimizer>();
+        assert_send_sync::<CostFunction<'_>>();
+        #[cfg(feature = "config")]
+        {
+            assert_send_sync::<Problem>();
+            fn assert_value_send_sync<T: Send + Sync + ?Sized>(_: &T) {}
+            assert_value_send_sync(&*ManifoldSpec::So3.build().unwrap());
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineSearch {
+    pub contraction: f64,
+    pub sufficient_decrease: f64,
+    pub max_trials: usize,
+    pub memory: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct LineSearchResult {
+    pub step_size: f64,
+    pub point: Vec<f64>,
+    pub cost: f64,
+    pub trials: usize,
+}
+
+impl Default for LineSearch {
+    fn default() -> Self {
+        Self::armijo()
+    }
+}
+
+impl LineSearch {
+    pub fn armijo() -> Self {
+        Self {
+            contraction: 0.5,
+            sufficient_decrease: 1e-4,
+            max_trials: 30,
+            memory: 1,
+        }
+    }
+
+    pub fn non_monotone(memory: usize) -> Self {
+        Self {
+            memory: memory.max(1),
+            ..Self::armijo()
+        }
+    }
+
+    pub fn reference_cost(&self, current_cost: f64, recent_costs: &[f64]) -> f64 {
+        let window = self.memory.saturating_sub(1).min(recent_costs.len());
+        recent_costs[recent_costs.len() - window..]
+            .iter()
+            .copied()
+            .fold(current_cost, f64::max)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn search<M, F>(
+        &self,
+        manifold: &M,
+        point: &[f64],
+        direction: &TangentVector<f64>,
+        slope: f64,
+        reference_cost: f64,
+        initial_step: f64,
+        cost_function: F,
+    ) -> Result<Option<LineSearchResult>>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+    {
+        if !(self.contraction > 0.0 && self.contraction < 1.0) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Line search contraction must lie in (0, 1), got {}",
+                self.contraction
+            )));
+        }
+        if slope >= 0.0 {
+            return Ok(None);
+        }
+
+        let mut step_size = initial_step;
+        for trial in 1..=self.max_trials {
+            let candidate = manifold.exp_unchecked(point, &(direction * step_size))?;
+            let cost = cost_function(&candidate);
+            if cost <= reference_cost + self.sufficient_decrease * step_size * slope {
+                return Ok(Some(LineSearchResult {
+                    step_size,
+                    point: candidate,
+                    cost,
+                    trials: trial,
+                }));
+            }
+            step_size *= self.contraction;
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::DVector;
+    use poly_manifold_spaces::Euclidean;
+
+    #[test]
+    fn test_reference_cost_window() {
+        let monotone = LineSearch::armijo();
+        assert_relative_eq!(monotone.reference_cost(1.0, &[5.0, 3.0]), 1.0);
+
+        let non_monotone = LineSearch::non_monotone(2);
+        assert_relative_eq!(non_monotone.reference_cost(1.0, &[5.0, 3.0]), 3.0);
+
+        let long_memory = LineSearch::non_monotone(10);
+        assert_relative_eq!(long_memory.reference_cost(1.0, &[5.0, 3.0]), 5.0);
+    }
+
+    #[test]
+    fn test_armijo_backtracks_to_sufficient_decrease() {
+        let euclidean = Euclidean::new(1);
+        let cost = |p: &[f64]| p[0] * p[0];
+        let direction = TangentVector::new(DVector::from_vec(vec![-2.0]));
+
+        let result = LineSearch::armijo()
+            .search(&euclidean, &[1.0], &direction, -4.0, 1.0, 4.0, cost)
+            .unwrap()
+            .unwrap();
+
+        assert_relative_eq!(result.step_size, 0.5);
+        assert_relative_eq!(result.point[0], 0.0, epsilon = 1e-12);
+        assert_eq!(result.trials, 4);
+    }
+
+    #[test]
+    fn test_non_monotone_accepts_temporary_increase() {
+        let euclidean = Euclidean::new(1);
+        let cost = |p: &[f64]| (p[0] - 3.0).powi(2);
+        let direction = TangentVector::new(DVector::from_vec(vec![1.0]));
+        let point = [0.9];
+        let current = cost(&point);
+        let slope = -1e-3;
+
+        let monotone = LineSearch::armijo();
+        let strict = monotone
+            .search(&euclidean, &point, &direction, slope, current, 6.0, cost)
+            .unwrap()
+            .unwrap();
+        assert!(strict.cost <= current);
+        assert_relative_eq!(strict.step_size, 3.0);
+
+        let relaxed = LineSearch::non_monotone(3);
+        let reference = relaxed.reference_cost(current, &[20.0, 10.0]);
+        let result = relaxed
+            .search(&euclidean, &point, &direction, slope, reference, 6.0, cost)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(result.step_size, 6.0);
+        assert!(result.cost > current);
+    }
+
+    #[test]
+    fn test_line_search_rejects_ascent_direction() {
+        let euclidean = Euclidean::new(1);
+        let direction = TangentVector::new(DVector::from_vec(vec![1.0]));
+        let result = LineSearch::armijo()
+            .search(
+                &euclidean,
+                &[1.0],
+                &direction,
+                2.0,
+                1.0,
+                1.0,
+                |p: &[f64]| p[0] * p[0],
+            )
+            .unwrap();
+        assert!(result.is_none());
+    }
+}
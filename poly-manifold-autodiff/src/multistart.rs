@@ -0,0 +1,271 @@
+use crate::optimizer::RiemannianOptimizer;
+use crate::result::{OptimizationResult, Termination};
+use poly_manifold_core::random::random_tangent;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Context, Manifold, RandomSource, Result, SeededRng};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiStart<O> {
+    pub inner: O,
+    pub starts: Vec<Vec<f64>>,
+    pub random_starts: usize,
+    pub random_radius: f64,
+    pub seed: u64,
+}
+
+impl<O: RiemannianOptimizer> MultiStart<O> {
+    pub fn new(inner: O, starts: Vec<Vec<f64>>) -> Self {
+        Self {
+            inner,
+            starts,
+            random_starts: 0,
+            random_radius: 1.0,
+            seed: Context::current().seed,
+        }
+    }
+
+    pub fn with_random_starts(mut self, count: usize, radius: f64, seed: u64) -> Self {
+        self.random_starts = count;
+        self.random_radius = radius;
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_context(mut self, context: &Context) -> Self {
+        self.seed = context.seed;
+        self
+    }
+
+    fn candidates<M>(&self, manifold: &M, initial_point: &[f64]) -> Result<Vec<Vec<f64>>>
+    where
+        M: Manifold<Scalar = f64>,
+    {
+        let mut candidates = Vec::with_capacity(1 + self.starts.len() + self.random_starts);
+        candidates.push(initial_point.to_vec());
+        candidates.extend(self.starts.iter().cloned());
+
+        if self.random_starts > 0 {
+            validate_entry_point(manifold, initial_point)?;
+            let mut rng = SeededRng::seed_from_u64(self.seed);
+            for _ in 0..self.random_starts {
+                let direction = random_tangent(manifold, initial_point, &mut rng)?;
+                let norm = manifold.norm(initial_point, &direction)?;
+                let scale = if norm > 0.0 {
+                    self.random_radius * rng.next_f64() / norm
+                } else {
+                    0.0
+                };
+                candidates.push(manifold.exp(initial_point, &(direction * scale))?);
+            }
+        }
+
+        Ok(candidates)
+    }
+}
+
+fn best_of(results: Vec<OptimizationResult>) -> OptimizationResult {
+    let cancelled = results
+        .iter()
+        .any(|result| result.termination == Termination::Cancelled);
+    let operation_counts = results.iter().map(|result| result.operation_counts).sum();
+    let mut best = results
+        .into_iter()
+        .min_by(|a, b| a.cost.total_cmp(&b.cost))
+        .expect("multi-start always runs at least the initial point");
+    if cancelled {
+        best.termination = Termination::Cancelled;
+    }
+    best.operation_counts = operation_counts;
+    best
+}
+
+impl<O: RiemannianOptimizer> RiemannianOptimizer for MultiStart<O> {
+    fn minimize<M, F>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+    {
+        let results = self
+            .candidates(manifold, initial_point)?
+            .iter()
+            .map(|start| self.inner.minimize(manifold, start, &cost_function))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(best_of(results))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<O: RiemannianOptimizer + Sync> MultiStart<O> {
+    pub fn par_minimize<M, F>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64> + Sync,
+        F: Fn(&[f64]) -> f64 + Sync,
+    {
+        use poly_manifold_core::profiling::{absorb, snapshot};
+        use poly_manifold_core::ManifoldError;
+        use rayon::prelude::*;
+
+        let context = Context::current();
+        let candidates = self.candidates(manifold, initial_point)?;
+        let before = snapshot();
+        let run = || {
+            candidates
+                .par_iter()
+                .map(|start| context.scope(|| self.inner.minimize(manifold, start, &cost_function)))
+                .collect::<Result<Vec<_>>>()
+        };
+        let results = match context.threads {
+            Some(threads) => rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .map_err(|e| {
+                    ManifoldError::InvalidParameter(format!("Cannot build thread pool: {e}"))
+                })?
+                .install(run)?,
+            None => run()?,
+        };
+        absorb(
+            &before,
+            results.iter().map(|result| result.operation_counts).sum(),
+        );
+        Ok(best_of(results))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::GradientDescent;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    fn double_well(p: &[f64]) -> f64 {
+        (p[0] * p[0] - 4.0).powi(2) + p[0]
+    }
+
+    #[test]
+    fn test_multistart_finds_global_minimum() {
+        let euclidean = Euclidean::new(1);
+        let optimizer = MultiStart::new(
+            GradientDescent::new(0.01, 5000, 1e-12),
+            vec![vec![-3.0], vec![0.5]],
+        );
+        let result = optimizer.minimize(&euclidean, &[3.0], double_well).unwrap();
+        assert!(result.point[0] < -1.9 && result.point[0] > -2.1);
+    }
+
+    #[test]
+    fn test_multistart_without_extra_starts_matches_inner() {
+        let euclidean = Euclidean::new(1);
+        let inner = GradientDescent::new(0.01, 5000, 1e-12);
+        let expected = inner.minimize(&euclidean, &[3.0], double_well).unwrap();
+
+        let optimizer = MultiStart::new(inner, Vec::new());
+        let result = optimizer.minimize(&euclidean, &[3.0], double_well).unwrap();
+        assert_relative_eq!(result.point[0], expected.point[0], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_multistart_propagates_invalid_start() {
+        let sphere = Sphere::new(2);
+        let optimizer = MultiStart::new(
+            GradientDescent::new(0.1, 10, 1e-8),
+            vec![vec![2.0, 0.0, 0.0]],
+        );
+        let result = optimizer.minimize(&sphere, &[1.0, 0.0, 0.0], |p: &[f64]| p[0]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_multistart_random_starts_are_reproducible() {
+        let sphere = Sphere::new(2);
+        let optimizer = MultiStart::new(GradientDescent::new(0.1, 10, 1e-8), Vec::new())
+            .with_random_starts(5, 1.0, 11);
+
+        let first = optimizer.candidates(&sphere, &[1.0, 0.0, 0.0]).unwrap();
+        let second = optimizer.candidates(&sphere, &[1.0, 0.0, 0.0]).unwrap();
+        assert_eq!(first.len(), 6);
+        assert_eq!(first, second);
+        for candidate in &first {
+            assert!(sphere.check_point(candidate).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_multistart_random_starts_escape_local_minimum() {
+        let euclidean = Euclidean::new(1);
+        let optimizer = MultiStart::new(GradientDescent::new(0.01, 5000, 1e-12), Vec::new())
+            .with_random_starts(20, 6.0, 5);
+        let result = optimizer.minimize(&euclidean, &[2.0], double_well).unwrap();
+        assert!(result.point[0] < -1.9 && result.point[0] > -2.1);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_multistart_matches_sequential() {
+        let euclidean = Euclidean::new(1);
+        let starts = (0..16).map(|i| vec![-4.0 + 0.5 * i as f64]).collect();
+        let optimizer = MultiStart::new(GradientDescent::new(0.01, 5000, 1e-12), starts);
+
+        let sequential = optimizer.minimize(&euclidean, &[3.0], double_well).unwrap();
+        let parallel = optimizer
+            .par_minimize(&euclidean, &[3.0], double_well)
+            .unwrap();
+        assert_relative_eq!(sequential.point[0], parallel.point[0], epsilon = 1e-12);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_multistart_uses_context_threads_and_validation() {
+        use poly_manifold_core::{validation_level, ValidationLevel};
+
+        let euclidean = Euclidean::new(1);
+        let optimizer = MultiStart::new(GradientDescent::new(0.01, 5000, 1e-12), Vec::new())
+            .with_random_starts(8, 6.0, 0)
+            .with_context(&Context::new().with_seed(5));
+        assert_eq!(optimizer.seed, 5);
+
+        let context = Context::new()
+            .with_threads(2)
+            .with_validation(ValidationLevel::OnEntry);
+        let result = context
+            .scope(|| {
+                optimizer.par_minimize(&euclidean, &[2.0], |p: &[f64]| {
+                    assert_eq!(rayon::current_num_threads(), 2);
+                    assert_eq!(validation_level(), ValidationLevel::OnEntry);
+                    double_well(p)
+                })
+            })
+            .unwrap();
+        assert!(result.point[0] < -1.9 && result.point[0] > -2.1);
+    }
+
+    #[cfg(all(feature = "rayon", feature = "profiling"))]
+    #[test]
+    fn test_par_multistart_counts_work_on_every_worker() {
+        use poly_manifold_core::profiling::snapshot;
+
+        let euclidean = Euclidean::new(1);
+        let starts = (0..8).map(|i| vec![-4.0 + i as f64]).collect();
+        let optimizer = MultiStart::new(GradientDescent::new(0.01, 200, 1e-12), starts);
+
+        let sequential = optimizer.minimize(&euclidean, &[3.0], double_well).unwrap();
+        let before = snapshot();
+        let parallel = optimizer
+            .par_minimize(&euclidean, &[3.0], double_well)
+            .unwrap();
+        assert_eq!(parallel.operation_counts, sequential.operation_counts);
+        assert_eq!(snapshot().since(&before), parallel.operation_counts);
+        assert!(parallel.operation_counts.exp > 200);
+    }
+}
@@ -1,5 +1,28 @@
-use crate::gradient::numerical_gradient;
-use poly_manifold_core::{Manifold, Result};
+use crate::cancel::{is_cancelled, CancellationToken};
+use crate::gradient::{check_clip_bound, numerical_gradient_into};
+use crate::line_search::LineSearch;
+use crate::preconditioner::{IdentityPreconditioner, Preconditioner};
+use crate::progress::{ProgressReporter, ProgressSink};
+use crate::result::{OptimizationResult, Termination};
+use crate::schedule::Schedule;
+use nalgebra::DVector;
+use poly_manifold_core::precision::promote;
+use poly_manifold_core::profiling::snapshot;
+#[cfg(feature = "profiling")]
+use poly_manifold_core::profiling::{counted_cost, Counted};
+use poly_manifold_core::reproject::reproject_in_place;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Contextual, Manifold, ManifoldError, Result, TangentVector, Widened};
+
+pub(crate) fn check_finite_step(values: &[f64], operation: &str, iteration: usize) -> Result<()> {
+    match values.iter().position(|value| !value.is_finite()) {
+        Some(index) => Err(ManifoldError::NumericalError(format!(
+            "{operation} produced non-finite value {} at index {index} in iteration {iteration}",
+            values[index]
+        ))),
+        None => Ok(()),
+    }
+}
 
 pub trait RiemannianOptimizer {
     fn minimize<M, F>(
@@ -7,69 +30,632 @@ pub trait RiemannianOptimizer {
         manifold: &M,
         initial_point: &[f64],
         cost_function: F,
-    ) -> Result<Vec<f64>>
+    ) -> Result<OptimizationResult>
     where
         M: Manifold<Scalar = f64>,
         F: Fn(&[f64]) -> f64;
+
+    fn minimize_f32<M, F>(
+        &self,
+        manifold: &M,
+        initial_point: &[f32],
+        cost_function: F,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f32>,
+        F: Fn(&[f64]) -> f64,
+    {
+        self.minimize(
+            &Widened::new(manifold),
+            &promote(initial_point),
+            cost_function,
+        )
+    }
+}
+
+impl<O: RiemannianOptimizer> RiemannianOptimizer for Contextual<O> {
+    fn minimize<M, F>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+    {
+        self.context
+            .scope(|| self.inner.minimize(manifold, initial_point, cost_function))
+    }
 }
 
-pub struct GradientDescent {
+pub trait IterativeOptimizer {
+    type State;
+
+    fn init<M, F>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+    ) -> Result<Self::State>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64;
+
+    fn step<M, F>(
+        &self,
+        manifold: &M,
+        state: &mut Self::State,
+        cost_function: F,
+    ) -> Result<StepInfo>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StepInfo {
+    pub iteration: usize,
+    pub cost: f64,
+    pub cost_change: f64,
+    pub gradient_norm: f64,
+    pub step_size: f64,
+    pub step_length: f64,
+    pub converged: bool,
+    pub line_search_failed: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StepBuffers {
+    gradient: Vec<f64>,
+    direction: Vec<f64>,
+    probe: Vec<f64>,
+    candidate: Vec<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GradientDescentState {
+    pub point: Vec<f64>,
+    pub cost: f64,
+    pub iteration: usize,
+    pub recent_costs: Vec<f64>,
+    pub buffers: StepBuffers,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GradientDescent<P = IdentityPreconditioner> {
     pub learning_rate: f64,
     pub max_iterations: usize,
     pub tolerance: f64,
+    pub schedule: Schedule,
+    pub max_gradient_norm: Option<f64>,
+    pub max_step_length: Option<f64>,
+    pub line_search: Option<LineSearch>,
+    pub preconditioner: P,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub finite_checks: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reproject: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub cancellation: Option<CancellationToken>,
 }
 
 impl GradientDescent {
+    pub const DEFAULT_LEARNING_RATE: f64 = 0.1;
+    pub const DEFAULT_MAX_ITERATIONS: usize = 1000;
+    pub const DEFAULT_TOLERANCE: f64 = 1e-6;
+
     pub fn new(learning_rate: f64, max_iterations: usize, tolerance: f64) -> Self {
         Self {
             learning_rate,
             max_iterations,
             tolerance,
+            schedule: Schedule::Constant,
+            max_gradient_norm: None,
+            max_step_length: None,
+            line_search: None,
+            preconditioner: IdentityPreconditioner,
+            finite_checks: false,
+            reproject: false,
+            cancellation: None,
         }
     }
+
+    pub fn builder() -> GradientDescentBuilder {
+        GradientDescentBuilder {
+            inner: Self::default(),
+        }
+    }
+}
+
+impl Default for GradientDescent {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_LEARNING_RATE,
+            Self::DEFAULT_MAX_ITERATIONS,
+            Self::DEFAULT_TOLERANCE,
+        )
+    }
 }
 
-impl RiemannianOptimizer for GradientDescent {
+pub struct GradientDescentBuilder<P = IdentityPreconditioner> {
+    inner: GradientDescent<P>,
+}
+
+impl<P> GradientDescentBuilder<P> {
+    pub fn learning_rate(mut self, learning_rate: f64) -> Self {
+        self.inner.learning_rate = learning_rate;
+        self
+    }
+
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.inner.max_iterations = max_iterations;
+        self
+    }
+
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.inner.tolerance = tolerance;
+        self
+    }
+
+    pub fn schedule(mut self, schedule: Schedule) -> Self {
+        self.inner.schedule = schedule;
+        self
+    }
+
+    pub fn max_gradient_norm(mut self, max_gradient_norm: f64) -> Self {
+        self.inner.max_gradient_norm = Some(max_gradient_norm);
+        self
+    }
+
+    pub fn max_step_length(mut self, max_step_length: f64) -> Self {
+        self.inner.max_step_length = Some(max_step_length);
+        self
+    }
+
+    pub fn line_search(mut self, line_search: LineSearch) -> Self {
+        self.inner.line_search = Some(line_search);
+        self
+    }
+
+    pub fn finite_checks(mut self, enabled: bool) -> Self {
+        self.inner.finite_checks = enabled;
+        self
+    }
+
+    pub fn reproject(mut self, enabled: bool) -> Self {
+        self.inner.reproject = enabled;
+        self
+    }
+
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.inner.cancellation = Some(token);
+        self
+    }
+
+    pub fn preconditioner<Q: Preconditioner>(self, preconditioner: Q) -> GradientDescentBuilder<Q> {
+        GradientDescentBuilder {
+            inner: self.inner.with_preconditioner(preconditioner),
+        }
+    }
+
+    pub fn build(self) -> Result<GradientDescent<P>> {
+        let optimizer = self.inner;
+        if !(optimizer.learning_rate.is_finite() && optimizer.learning_rate > 0.0) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Learning rate must be positive and finite, got {}",
+                optimizer.learning_rate
+            )));
+        }
+        if !(optimizer.tolerance.is_finite() && optimizer.tolerance >= 0.0) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Tolerance must be non-negative and finite, got {}",
+                optimizer.tolerance
+            )));
+        }
+        if optimizer.max_iterations == 0 {
+            return Err(ManifoldError::InvalidParameter(
+                "Maximum iterations must be at least 1".to_string(),
+            ));
+        }
+        optimizer.check_clipping()?;
+        Ok(optimizer)
+    }
+}
+
+impl<P> GradientDescent<P> {
+    fn check_clipping(&self) -> Result<()> {
+        if let Some(max_norm) = self.max_gradient_norm {
+            check_clip_bound("Gradient norm bound", max_norm)?;
+        }
+        if let Some(max_length) = self.max_step_length {
+            check_clip_bound("Maximum step length", max_length)?;
+        }
+        Ok(())
+    }
+
+    pub fn with_schedule(mut self, schedule: Schedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    pub fn with_gradient_clipping(mut self, max_gradient_norm: f64) -> Self {
+        self.max_gradient_norm = Some(max_gradient_norm);
+        self
+    }
+
+    pub fn with_max_step_length(mut self, max_step_length: f64) -> Self {
+        self.max_step_length = Some(max_step_length);
+        self
+    }
+
+    pub fn with_line_search(mut self, line_search: LineSearch) -> Self {
+        self.line_search = Some(line_search);
+        self
+    }
+
+    pub fn with_finite_checks(mut self) -> Self {
+        self.finite_checks = true;
+        self
+    }
+
+    pub fn with_reprojection(mut self) -> Self {
+        self.reproject = true;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    pub fn with_preconditioner<Q: Preconditioner>(self, preconditioner: Q) -> GradientDescent<Q> {
+        GradientDescent {
+            learning_rate: self.learning_rate,
+            max_iterations: self.max_iterations,
+            tolerance: self.tolerance,
+            schedule: self.schedule,
+            max_gradient_norm: self.max_gradient_norm,
+            max_step_length: self.max_step_length,
+            line_search: self.line_search,
+            preconditioner,
+            finite_checks: self.finite_checks,
+            reproject: self.reproject,
+            cancellation: self.cancellation,
+        }
+    }
+}
+
+impl<P: Preconditioner> RiemannianOptimizer for GradientDescent<P> {
     fn minimize<M, F>(
         &self,
         manifold: &M,
         initial_point: &[f64],
         cost_function: F,
-    ) -> Result<Vec<f64>>
+    ) -> Result<OptimizationResult>
     where
         M: Manifold<Scalar = f64>,
         F: Fn(&[f64]) -> f64,
     {
-        manifold.check_point(initial_point)?;
+        let mut state = self.init(manifold, initial_point, &cost_function)?;
+        self.minimize_from(manifold, &mut state, cost_function)
+    }
+}
 
-        let mut point = initial_point.to_vec();
-        let mut prev_cost = cost_function(&point);
+impl<P: Preconditioner> GradientDescent<P> {
+    pub fn minimize_from<M, F>(
+        &self,
+        manifold: &M,
+        state: &mut GradientDescentState,
+        cost_function: F,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+    {
+        self.run(manifold, state, cost_function, |_| {})
+    }
 
-        for _iter in 0..self.max_iterations {
-            let gradient = numerical_gradient(manifold, &point, &cost_function, 1e-7)?;
+    pub fn minimize_traced<M, F>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+    ) -> Result<(OptimizationResult, Vec<StepInfo>)>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+    {
+        let mut state = self.init(manifold, initial_point, &cost_function)?;
+        let mut trace = Vec::new();
+        let result = self.run(manifold, &mut state, cost_function, |info| {
+            trace.push(*info)
+        })?;
+        Ok((result, trace))
+    }
 
-            let descent_direction = gradient * (-self.learning_rate);
+    pub fn minimize_with_progress<M, F, S>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+        progress: &S,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+        S: ProgressSink + ?Sized,
+    {
+        let mut state = self.init(manifold, initial_point, &cost_function)?;
+        let mut reporter = ProgressReporter::new(progress, self.max_iterations);
+        let result = self.run(manifold, &mut state, cost_function, |info| {
+            reporter.step(info)
+        })?;
+        reporter.finish(&result);
+        Ok(result)
+    }
 
-            point = manifold.exp(&point, &descent_direction)?;
+    fn run<M, F, C>(
+        &self,
+        manifold: &M,
+        state: &mut GradientDescentState,
+        cost_function: F,
+        mut on_step: C,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+        C: FnMut(&StepInfo),
+    {
+        let start = snapshot();
+        #[cfg(feature = "profiling")]
+        let manifold = &Counted::new(manifold);
+        #[cfg(feature = "profiling")]
+        let cost_function = counted_cost(cost_function);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "minimize",
+            optimizer = "gradient_descent",
+            ambient_dim = manifold.ambient_dim(),
+            max_iterations = self.max_iterations
+        )
+        .entered();
 
-            let current_cost = cost_function(&point);
+        validate_entry_point(manifold, &state.point)?;
+        state.cost = cost_function(&state.point);
+        if self.finite_checks {
+            check_finite_step(&[state.cost], "cost", state.iteration)?;
+        }
 
-            if (prev_cost - current_cost).abs() < self.tolerance {
+        let mut iterations = 0;
+        let mut termination = Termination::MaxIterations;
+        while iterations < self.max_iterations {
+            if is_cancelled(&self.cancellation) {
+                termination = Termination::Cancelled;
+                break;
+            }
+            let info = self.step(manifold, state, &cost_function)?;
+            on_step(&info);
+            iterations += 1;
+            if info.converged {
+                termination = Termination::Converged;
                 break;
             }
+            if info.line_search_failed {
+                termination = Termination::LineSearchFailed;
+                break;
+            }
+        }
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            iterations,
+            cost = state.cost,
+            termination = ?termination,
+            "minimize finished"
+        );
+
+        Ok(OptimizationResult {
+            point: state.point.clone(),
+            cost: state.cost,
+            iterations,
+            termination,
+            operation_counts: snapshot().since(&start),
+        })
+    }
+}
+
+impl<P: Preconditioner> IterativeOptimizer for GradientDescent<P> {
+    type State = GradientDescentState;
+
+    fn init<M, F>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+    ) -> Result<Self::State>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+    {
+        validate_entry_point(manifold, initial_point)?;
+
+        Ok(GradientDescentState {
+            point: initial_point.to_vec(),
+            cost: cost_function(initial_point),
+            iteration: 0,
+            recent_costs: Vec::new(),
+            buffers: StepBuffers::default(),
+        })
+    }
+
+    fn step<M, F>(
+        &self,
+        manifold: &M,
+        state: &mut Self::State,
+        cost_function: F,
+    ) -> Result<StepInfo>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+    {
+        self.check_clipping()?;
+        let buffers = &mut state.buffers;
+        let point = &state.point;
+        let iteration = state.iteration + 1;
+
+        let mut gradient =
+            TangentVector::new(DVector::from_vec(std::mem::take(&mut buffers.gradient)));
+        numerical_gradient_into(
+            manifold,
+            point,
+            &cost_function,
+            1e-7,
+            &mut buffers.probe,
+            &mut gradient,
+        )?;
+        if self.finite_checks {
+            check_finite_step(gradient.as_slice(), "gradient", iteration)?;
+        }
+        let gradient_norm = manifold.norm(point, &gradient)?;
+        if let Some(max_norm) = self.max_gradient_norm {
+            if gradient_norm > max_norm {
+                gradient.components *= max_norm / gradient_norm;
+            }
+        }
 
-            prev_cost = current_cost;
+        let mut direction =
+            TangentVector::new(DVector::from_vec(std::mem::take(&mut buffers.direction)));
+        if direction.dim() == gradient.dim() {
+            direction.components.copy_from(&gradient.components);
+        } else {
+            direction.components = gradient.components.clone();
+        }
+        self.preconditioner.apply_in_place(point, &mut direction)?;
+        manifold.project_to_tangent_space_in_place(point, &mut direction)?;
+        if self.finite_checks {
+            check_finite_step(direction.as_slice(), "search direction", iteration)?;
         }
 
-        Ok(point)
+        let scheduled = self
+            .schedule
+            .learning_rate(self.learning_rate, state.iteration);
+
+        let mut line_search_failed = false;
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        let (new_cost, step_size, step_length, line_search_trials) = match &self.line_search {
+            None => {
+                direction.components *= -scheduled;
+                let mut step_length = manifold.norm(point, &direction)?;
+                if let Some(max_length) = self.max_step_length {
+                    if step_length > max_length {
+                        direction.components *= max_length / step_length;
+                        step_length = manifold.norm(point, &direction)?;
+                    }
+                }
+                manifold.exp_unchecked_into(point, &direction, &mut buffers.candidate)?;
+                if self.reproject {
+                    reproject_in_place(manifold, &mut buffers.candidate)?;
+                }
+                let new_cost = cost_function(&buffers.candidate);
+                (new_cost, scheduled, step_length, 0)
+            }
+            Some(line_search) => {
+                direction.components *= -1.0;
+                let direction_norm = manifold.norm(point, &direction)?;
+                let initial_step = match self.max_step_length {
+                    Some(max_length) if scheduled * direction_norm > max_length => {
+                        max_length / direction_norm
+                    }
+                    _ => scheduled,
+                };
+                let slope = manifold.inner_product_unchecked(point, &gradient, &direction)?;
+                let reference = line_search.reference_cost(state.cost, &state.recent_costs);
+
+                match line_search.search(
+                    manifold,
+                    point,
+                    &direction,
+                    slope,
+                    reference,
+                    initial_step,
+                    &cost_function,
+                )? {
+                    Some(accepted) => {
+                        buffers.candidate = accepted.point;
+                        let new_cost = if self.reproject {
+                            reproject_in_place(manifold, &mut buffers.candidate)?;
+                            cost_function(&buffers.candidate)
+                        } else {
+                            accepted.cost
+                        };
+                        (
+                            new_cost,
+                            accepted.step_size,
+                            accepted.step_size * direction_norm,
+                            accepted.trials,
+                        )
+                    }
+                    None => {
+                        line_search_failed = gradient_norm > 0.0;
+                        buffers.candidate.clear();
+                        buffers.candidate.extend_from_slice(point);
+                        (state.cost, 0.0, 0.0, line_search.max_trials)
+                    }
+                }
+            }
+        };
+        if self.finite_checks {
+            check_finite_step(&buffers.candidate, "retraction", iteration)?;
+            check_finite_step(&[new_cost], "cost", iteration)?;
+        }
+        buffers.gradient = gradient.components.data.into();
+        buffers.direction = direction.components.data.into();
+        let cost_change = new_cost - state.cost;
+
+        if let Some(line_search) = &self.line_search {
+            state.recent_costs.push(state.cost);
+            let excess = state.recent_costs.len().saturating_sub(line_search.memory);
+            state.recent_costs.drain(..excess);
+        }
+        std::mem::swap(&mut state.point, &mut state.buffers.candidate);
+        state.cost = new_cost;
+        state.iteration += 1;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            iteration = state.iteration,
+            cost = new_cost,
+            cost_change,
+            gradient_norm,
+            step_size,
+            step_length,
+            line_search_trials,
+            line_search_failed,
+            "gradient descent step"
+        );
+
+        Ok(StepInfo {
+            iteration: state.iteration,
+            cost: new_cost,
+            cost_change,
+            gradient_norm,
+            step_size,
+            step_length,
+            converged: !line_search_failed && cost_change.abs() < self.tolerance,
+            line_search_failed,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::line_search::LineSearch;
+    use crate::preconditioner::DiagonalPreconditioner;
+    use crate::schedule::Schedule;
     use approx::assert_relative_eq;
-    use poly_manifold_spaces::Euclidean;
+    use poly_manifold_core::ManifoldError;
+    use poly_manifold_spaces::{Euclidean, Sphere};
 
     #[test]
     fn test_gradient_descent_quadratic() {
@@ -83,8 +669,138 @@ mod tests {
             .minimize(&euclidean, &initial_point, cost)
             .unwrap();
 
-        assert_relative_eq!(result[0], 1.0, epsilon = 1e-2);
-        assert_relative_eq!(result[1], 2.0, epsilon = 1e-2);
+        assert_relative_eq!(result.point[0], 1.0, epsilon = 1e-2);
+        assert_relative_eq!(result.point[1], 2.0, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_finite_checks_fail_fast_with_iteration_and_operation() {
+        let euclidean = Euclidean::new(1);
+        let cost = |p: &[f64]| {
+            if p[0] > 1.5 {
+                f64::NAN
+            } else {
+                (p[0] - 3.0).powi(2)
+            }
+        };
+
+        let unchecked = GradientDescent::new(0.1, 50, 1e-12);
+        let result = unchecked.minimize(&euclidean, &[0.0], cost).unwrap();
+        assert!(result.cost.is_nan());
+
+        let error = unchecked
+            .with_finite_checks()
+            .minimize(&euclidean, &[0.0], cost)
+            .unwrap_err();
+        assert!(matches!(error, ManifoldError::NumericalError(_)));
+        assert_eq!(
+            error.to_string(),
+            "Numerical error: cost produced non-finite value NaN at index 0 in iteration 4"
+        );
+
+        let checked = GradientDescent::builder()
+            .finite_checks(true)
+            .build()
+            .unwrap();
+        let error = checked
+            .minimize(
+                &euclidean,
+                &[0.0],
+                |p: &[f64]| {
+                    if p[0] == 0.0 {
+                        0.0
+                    } else {
+                        f64::NAN
+                    }
+                },
+            )
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("gradient produced non-finite value"));
+        assert!(error.to_string().ends_with("in iteration 1"));
+        assert!(matches!(
+            checked.minimize(&euclidean, &[0.0], |_: &[f64]| f64::INFINITY),
+            Err(ManifoldError::NumericalError(message)) if message.contains("iteration 0")
+        ));
+    }
+
+    struct FirstOrderSphere(Sphere);
+
+    impl Manifold for FirstOrderSphere {
+        type Scalar = f64;
+
+        fn dim(&self) -> usize {
+            self.0.dim()
+        }
+
+        fn check_point(&self, point: &[f64]) -> Result<()> {
+            self.0.check_point(point)
+        }
+
+        fn check_tangent_vector(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<()> {
+            self.0.check_tangent_vector(point, tangent)
+        }
+
+        fn project_to_manifold(&self, point: &[f64]) -> Result<Vec<f64>> {
+            self.0.project_to_manifold(point)
+        }
+
+        fn project_to_tangent_space(
+            &self,
+            point: &[f64],
+            vector: &TangentVector<f64>,
+        ) -> Result<TangentVector<f64>> {
+            self.0.project_to_tangent_space(point, vector)
+        }
+
+        fn exp(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<Vec<f64>> {
+            Ok(point
+                .iter()
+                .zip(tangent.iter())
+                .map(|(p, v)| p + v)
+                .collect())
+        }
+
+        fn log(&self, point: &[f64], other: &[f64]) -> Result<TangentVector<f64>> {
+            self.0.log(point, other)
+        }
+
+        fn inner_product(
+            &self,
+            point: &[f64],
+            v1: &TangentVector<f64>,
+            v2: &TangentVector<f64>,
+        ) -> Result<f64> {
+            self.0.inner_product(point, v1, v2)
+        }
+    }
+
+    #[test]
+    fn test_reprojection_keeps_drifting_steps_on_the_manifold() {
+        let manifold = FirstOrderSphere(Sphere::new(2));
+        let cost = |p: &[f64]| -p[2];
+        let optimizer = GradientDescent::new(0.1, 500, 1e-10);
+
+        assert!(optimizer
+            .minimize(&manifold, &[1.0, 0.0, 0.0], cost)
+            .is_err());
+
+        for optimizer in [
+            optimizer.with_reprojection(),
+            GradientDescent::builder()
+                .reproject(true)
+                .tolerance(1e-10)
+                .line_search(LineSearch::armijo())
+                .build()
+                .unwrap(),
+        ] {
+            let result = optimizer
+                .minimize(&manifold, &[1.0, 0.0, 0.0], cost)
+                .unwrap();
+            assert!(manifold.check_point(&result.point).is_ok());
+            assert_relative_eq!(result.cost, -1.0, epsilon = 1e-6);
+        }
     }
 
     #[test]
@@ -103,7 +819,471 @@ mod tests {
             .minimize(&euclidean, &initial_point, cost)
             .unwrap();
 
-        assert_relative_eq!(result[0], 1.0, epsilon = 1e-1);
-        assert_relative_eq!(result[1], 1.0, epsilon = 1e-1);
+        assert_relative_eq!(result.point[0], 1.0, epsilon = 1e-1);
+        assert_relative_eq!(result.point[1], 1.0, epsilon = 1e-1);
+    }
+
+    #[test]
+    fn test_gradient_descent_diagonal_preconditioner() {
+        let euclidean = Euclidean::new(2);
+        let initial_point = vec![1.0, 1.0];
+
+        let cost = |p: &[f64]| 100.0 * p[0] * p[0] + p[1] * p[1];
+
+        let preconditioner = DiagonalPreconditioner::new(vec![1.0 / 200.0, 1.0 / 2.0]).unwrap();
+        let optimizer = GradientDescent::new(1.0, 5, 1e-12).with_preconditioner(preconditioner);
+        let result = optimizer
+            .minimize(&euclidean, &initial_point, cost)
+            .unwrap();
+
+        assert_relative_eq!(result.point[0], 0.0, epsilon = 1e-4);
+        assert_relative_eq!(result.point[1], 0.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_gradient_descent_preconditioner_stays_on_sphere() {
+        let sphere = Sphere::new(2);
+        let initial_point = vec![1.0, 0.0, 0.0];
+        let target = [0.0, 0.6, 0.8];
+
+        let cost = |p: &[f64]| {
+            (p[0] - target[0]).powi(2) + (p[1] - target[1]).powi(2) + (p[2] - target[2]).powi(2)
+        };
+
+        let preconditioner = DiagonalPreconditioner::new(vec![1.0, 2.0, 0.5]).unwrap();
+        let optimizer = GradientDescent::new(0.1, 500, 1e-10).with_preconditioner(preconditioner);
+        let result = optimizer.minimize(&sphere, &initial_point, cost).unwrap();
+
+        assert!(sphere.check_point(&result.point).is_ok());
+        assert_relative_eq!(result.point[1], 0.6, epsilon = 1e-3);
+        assert_relative_eq!(result.point[2], 0.8, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_gradient_descent_with_schedule() {
+        let euclidean = Euclidean::new(2);
+        let initial_point = vec![5.0, 5.0];
+
+        let cost = |p: &[f64]| (p[0] - 1.0).powi(2) + (p[1] - 2.0).powi(2);
+
+        let optimizer = GradientDescent::new(0.4, 1000, 1e-12).with_schedule(Schedule::warmup(
+            5,
+            Schedule::Cosine {
+                period: 200,
+                min_factor: 0.1,
+            },
+        ));
+        let result = optimizer
+            .minimize(&euclidean, &initial_point, cost)
+            .unwrap();
+
+        assert_relative_eq!(result.point[0], 1.0, epsilon = 1e-3);
+        assert_relative_eq!(result.point[1], 2.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_gradient_descent_reports_termination() {
+        let euclidean = Euclidean::new(1);
+        let cost = |p: &[f64]| (p[0] - 1.0).powi(2);
+
+        let result = GradientDescent::new(0.1, 1000, 1e-10)
+            .minimize(&euclidean, &[5.0], cost)
+            .unwrap();
+        assert_eq!(result.termination, Termination::Converged);
+        assert!(result.iterations < 1000);
+        assert_relative_eq!(result.cost, cost(&result.point), epsilon = 1e-12);
+
+        let result = GradientDescent::new(0.01, 5, 1e-10)
+            .minimize(&euclidean, &[5.0], cost)
+            .unwrap();
+        assert_eq!(result.termination, Termination::MaxIterations);
+        assert_eq!(result.iterations, 5);
+        assert!(matches!(
+            result.into_converged(),
+            Err(ManifoldError::ConvergenceError { iterations: 5 })
+        ));
+    }
+
+    #[test]
+    fn test_gradient_descent_max_step_length() {
+        let euclidean = Euclidean::new(1);
+        let cost = |p: &[f64]| (p[0] - 10.0).powi(2);
+
+        let optimizer = GradientDescent::new(0.25, 3, 0.0).with_max_step_length(0.5);
+        let result = optimizer.minimize(&euclidean, &[0.0], cost).unwrap();
+
+        assert_relative_eq!(result.point[0], 1.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_gradient_descent_gradient_clipping() {
+        let euclidean = Euclidean::new(1);
+        let cost = |p: &[f64]| (p[0] - 10.0).powi(2);
+
+        let optimizer = GradientDescent::new(0.1, 1, 0.0).with_gradient_clipping(2.0);
+        let result = optimizer.minimize(&euclidean, &[0.0], cost).unwrap();
+
+        assert_relative_eq!(result.point[0], 0.2, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_gradient_descent_rejects_invalid_clipping_bounds() {
+        let euclidean = Euclidean::new(1);
+        let cost = |p: &[f64]| (p[0] - 10.0).powi(2);
+
+        for bound in [0.0, -2.0, f64::NAN] {
+            for optimizer in [
+                GradientDescent::new(0.1, 5, 0.0).with_gradient_clipping(bound),
+                GradientDescent::new(0.1, 5, 0.0).with_max_step_length(bound),
+            ] {
+                assert!(matches!(
+                    optimizer.minimize(&euclidean, &[0.0], cost),
+                    Err(ManifoldError::InvalidParameter(_))
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_gradient_descent_step_api() {
+        let euclidean = Euclidean::new(2);
+        let cost = |p: &[f64]| (p[0] - 1.0).powi(2) + (p[1] - 2.0).powi(2);
+
+        let optimizer = GradientDescent::new(0.1, 1000, 1e-10);
+        let mut state = optimizer.init(&euclidean, &[5.0, 5.0], cost).unwrap();
+        assert_eq!(state.iteration, 0);
+        assert_relative_eq!(state.cost, 25.0, epsilon = 1e-12);
+
+        let info = optimizer.step(&euclidean, &mut state, cost).unwrap();
+        assert_eq!(info.iteration, 1);
+        assert_eq!(state.iteration, 1);
+        assert!(info.cost_change < 0.0);
+        assert_relative_eq!(info.gradient_norm, 10.0, epsilon = 1e-5);
+        assert_relative_eq!(info.step_length, 1.0, epsilon = 1e-5);
+        assert_relative_eq!(state.point[0], 4.2, epsilon = 1e-5);
+
+        let mut converged = false;
+        for _ in 0..1000 {
+            if optimizer
+                .step(&euclidean, &mut state, cost)
+                .unwrap()
+                .converged
+            {
+                converged = true;
+                break;
+            }
+        }
+        assert!(converged);
+        assert_relative_eq!(state.point[0], 1.0, epsilon = 1e-3);
+        assert_relative_eq!(state.point[1], 2.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_gradient_descent_step_api_invalid_initial_point() {
+        let sphere = Sphere::new(2);
+        let optimizer = GradientDescent::new(0.1, 10, 1e-8);
+        assert!(optimizer
+            .init(&sphere, &[1.0, 1.0, 0.0], |p: &[f64]| p[0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_gradient_descent_warm_start_continuation() {
+        let euclidean = Euclidean::new(1);
+
+        let mut state = GradientDescent::new(0.1, 10, 1e-12)
+            .init(&euclidean, &[0.0], |p: &[f64]| p[0] * p[0])
+            .unwrap();
+
+        for (regularization, learning_rate) in [(1.0, 0.2), (0.1, 0.3), (0.0, 0.4)] {
+            let cost = move |p: &[f64]| (p[0] - 3.0).powi(2) + regularization * p[0] * p[0];
+            let optimizer = GradientDescent::new(learning_rate, 1000, 1e-12);
+            let previous_iterations = state.iteration;
+            optimizer
+                .minimize_from(&euclidean, &mut state, cost)
+                .unwrap();
+
+            assert!(state.iteration > previous_iterations);
+            assert_relative_eq!(state.point[0], 3.0 / (1.0 + regularization), epsilon = 1e-4);
+            assert_relative_eq!(state.cost, cost(&state.point), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_gradient_descent_warm_start_rejects_invalid_state() {
+        let sphere = Sphere::new(2);
+        let mut state = GradientDescentState {
+            point: vec![2.0, 0.0, 0.0],
+            cost: 0.0,
+            iteration: 0,
+            recent_costs: Vec::new(),
+            buffers: StepBuffers::default(),
+        };
+        let optimizer = GradientDescent::new(0.1, 10, 1e-8);
+        assert!(optimizer
+            .minimize_from(&sphere, &mut state, |p: &[f64]| p[0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_gradient_descent_line_search_handles_large_step() {
+        let euclidean = Euclidean::new(2);
+        let cost = |p: &[f64]| 50.0 * p[0] * p[0] + p[1] * p[1];
+
+        let optimizer =
+            GradientDescent::new(1.0, 2000, 1e-14).with_line_search(LineSearch::armijo());
+        let result = optimizer.minimize(&euclidean, &[1.0, 1.0], cost).unwrap();
+
+        assert_relative_eq!(result.point[0], 0.0, epsilon = 1e-3);
+        assert_relative_eq!(result.point[1], 0.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_line_search_failure_stops_the_run() {
+        let euclidean = Euclidean::new(1);
+        let cost = |p: &[f64]| p[0] + if p[0] == 1.0 { 0.0 } else { 1.0 };
+
+        for tolerance in [1e-6, 0.0] {
+            let result = GradientDescent::new(0.1, 100, tolerance)
+                .with_line_search(LineSearch::armijo())
+                .minimize(&euclidean, &[1.0], cost)
+                .unwrap();
+            assert_eq!(result.termination, Termination::LineSearchFailed);
+            assert_eq!(result.iterations, 1);
+            assert_eq!(result.point, vec![1.0]);
+            assert!(matches!(
+                result.into_converged(),
+                Err(ManifoldError::NumericalError(message)) if message.contains("iteration 1")
+            ));
+        }
+    }
+
+    #[test]
+    fn test_gradient_descent_non_monotone_line_search() {
+        let euclidean = Euclidean::new(2);
+        let rosenbrock = |p: &[f64]| (1.0 - p[0]).powi(2) + 100.0 * (p[1] - p[0].powi(2)).powi(2);
+
+        let optimizer =
+            GradientDescent::new(0.01, 20000, 1e-14).with_line_search(LineSearch::non_monotone(5));
+        let mut state = optimizer
+            .init(&euclidean, &[-1.0, 1.0], rosenbrock)
+            .unwrap();
+        for _ in 0..20000 {
+            let info = optimizer.step(&euclidean, &mut state, rosenbrock).unwrap();
+            assert!(state.recent_costs.len() <= 5);
+            if info.converged {
+                break;
+            }
+        }
+
+        assert_relative_eq!(state.point[0], 1.0, epsilon = 5e-2);
+        assert_relative_eq!(state.point[1], 1.0, epsilon = 1e-1);
+    }
+
+    #[test]
+    fn test_gradient_descent_on_f32_sphere() {
+        let sphere = Sphere::<f32>::with_scalar(2);
+        let target = [0.0, 0.6, 0.8];
+        let cost = |p: &[f64]| -p.iter().zip(target.iter()).map(|(a, b)| a * b).sum::<f64>();
+
+        let optimizer = GradientDescent::new(0.2, 500, 1e-9);
+        let result = optimizer
+            .minimize_f32(&sphere, &[1.0, 0.0, 0.0], cost)
+            .unwrap();
+
+        let point: Vec<f32> = result.point.iter().map(|x| *x as f32).collect();
+        assert!(sphere.check_point(&point).is_ok());
+        for (a, b) in point.iter().zip(target.iter()) {
+            assert_relative_eq!(*a as f64, *b, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_validation_level_controls_entry_checks() {
+        use poly_manifold_core::validation::with_validation_level;
+        use poly_manifold_core::ValidationLevel;
+
+        let sphere = Sphere::new(2);
+        let cost = |p: &[f64]| p[0];
+        let optimizer = GradientDescent::new(0.1, 3, 1e-12);
+        let invalid = [0.0, 0.0, 2.0];
+
+        with_validation_level(ValidationLevel::OnEntry, || {
+            assert!(optimizer.minimize(&sphere, &invalid, cost).is_err());
+            assert!(optimizer.minimize(&sphere, &[0.0, 0.0, 1.0], cost).is_ok());
+        });
+        with_validation_level(ValidationLevel::Off, || {
+            assert!(optimizer.minimize(&sphere, &invalid, cost).is_ok());
+        });
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn test_gradient_descent_reports_operation_counts() {
+        use poly_manifold_spaces::SPD;
+
+        let spd = SPD::new(2);
+        let target = [2.0, 0.3, 0.3, 1.0];
+        let cost = |p: &[f64]| p.iter().zip(&target).map(|(a, b)| (a - b).powi(2)).sum();
+        let result = GradientDescent::new(0.1, 5, 1e-12)
+            .minimize(&spd, &[1.0, 0.0, 0.0, 1.0], cost)
+            .unwrap();
+
+        let counts = result.operation_counts;
+        assert_eq!(counts.exp, result.iterations as u64);
+        assert!(counts.cost_evaluations > counts.exp);
+        assert!(counts.cholesky + counts.eigendecompositions >= counts.exp);
+        assert_eq!(counts.retractions, 0);
+    }
+
+    #[test]
+    fn test_contextual_optimizer_runs_in_its_context() {
+        use poly_manifold_core::{validation_level, Context, ValidationLevel};
+
+        let sphere = Sphere::new(2);
+        let off_sphere = [2.0, 0.0, 0.0];
+        let solver = GradientDescent::new(0.1, 5, 0.0);
+        assert!(solver.minimize(&sphere, &off_sphere, |p| -p[2]).is_err());
+
+        let relaxed = Context::new()
+            .with_validation(ValidationLevel::Off)
+            .wrap(solver)
+            .unwrap();
+        let result = relaxed
+            .minimize(&sphere, &off_sphere, |p| {
+                assert_eq!(validation_level(), ValidationLevel::Off);
+                -p[2]
+            })
+            .unwrap();
+        assert_eq!(result.iterations, 5);
+        assert_eq!(validation_level(), ValidationLevel::Strict);
+    }
+
+    #[test]
+    fn test_builder_defaults_and_setters() {
+        let defaults = GradientDescent::builder().build().unwrap();
+        assert_eq!(
+            defaults.learning_rate,
+            GradientDescent::DEFAULT_LEARNING_RATE
+        );
+        assert_eq!(
+            defaults.max_iterations,
+            GradientDescent::DEFAULT_MAX_ITERATIONS
+        );
+        assert_eq!(defaults.tolerance, GradientDescent::DEFAULT_TOLERANCE);
+        assert!(defaults.line_search.is_none());
+
+        let solver = GradientDescent::builder()
+            .learning_rate(0.2)
+            .max_iterations(500)
+            .tolerance(1e-10)
+            .schedule(Schedule::Exponential { gamma: 0.99 })
+            .max_gradient_norm(5.0)
+            .max_step_length(1.0)
+            .line_search(LineSearch::armijo())
+            .preconditioner(DiagonalPreconditioner::new(vec![1.0, 2.0, 1.0]).unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(solver.learning_rate, 0.2);
+        assert_eq!(solver.max_gradient_norm, Some(5.0));
+        assert_eq!(solver.max_step_length, Some(1.0));
+
+        let result = solver
+            .minimize(&Sphere::new(2), &[1.0, 0.0, 0.0], |p: &[f64]| -p[2])
+            .unwrap();
+        assert!(result.converged());
+        assert_relative_eq!(result.point[2], 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_settings() {
+        for builder in [
+            GradientDescent::builder().learning_rate(0.0),
+            GradientDescent::builder().learning_rate(-0.1),
+            GradientDescent::builder().learning_rate(f64::NAN),
+            GradientDescent::builder().tolerance(-1e-6),
+            GradientDescent::builder().max_iterations(0),
+            GradientDescent::builder().max_gradient_norm(0.0),
+            GradientDescent::builder().max_step_length(f64::INFINITY),
+        ] {
+            assert!(matches!(
+                builder.build(),
+                Err(ManifoldError::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_minimize_emits_tracing_events() {
+        use std::io::Write;
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Buffer {
+            fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(bytes);
+                Ok(bytes.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+
+        let solver = GradientDescent::new(0.1, 3, 0.0).with_line_search(LineSearch::armijo());
+        tracing::subscriber::with_default(subscriber, || {
+            solver
+                .minimize(&Sphere::new(2), &[1.0, 0.0, 0.0], |p: &[f64]| -p[2])
+                .unwrap();
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output.matches("gradient descent step").count(), 3);
+        assert!(output.contains("minimize{optimizer=\"gradient_descent\""));
+        assert!(output.contains("line_search_trials=1"));
+        assert!(output.contains("iterations=3"));
+        assert!(output.contains("termination=MaxIterations"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_experiment_serde_round_trip() {
+        use crate::result::OptimizationResult;
+
+        let sphere = Sphere::new(2);
+        let solver = GradientDescent::new(0.1, 500, 1e-10)
+            .with_schedule(Schedule::warmup(5, Schedule::Exponential { gamma: 0.99 }))
+            .with_line_search(LineSearch::armijo())
+            .with_preconditioner(DiagonalPreconditioner::new(vec![1.0, 2.0, 1.0]).unwrap());
+        let cost = |p: &[f64]| -p[2];
+        let initial_point = vec![1.0, 0.0, 0.0];
+        let result = solver.minimize(&sphere, &initial_point, cost).unwrap();
+
+        let json = serde_json::to_string(&(&sphere, &solver, &result)).unwrap();
+        let (sphere, solver, recorded): (
+            Sphere,
+            GradientDescent<DiagonalPreconditioner>,
+            OptimizationResult,
+        ) = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            solver.schedule,
+            Schedule::warmup(5, Schedule::Exponential { gamma: 0.99 })
+        );
+        assert_eq!(recorded.termination, result.termination);
+        assert_eq!(recorded.iterations, result.iterations);
+        let rerun = solver.minimize(&sphere, &initial_point, cost).unwrap();
+        assert_eq!(rerun.point, recorded.point);
     }
 }
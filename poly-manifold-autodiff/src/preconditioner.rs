@@ -0,0 +1,120 @@
+use nalgebra::DVector;
+use poly_manifold_core::{ManifoldError, Result, TangentVector};
+
+pub trait Preconditioner {
+    fn apply(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<TangentVector<f64>>;
+
+    fn apply_in_place(&self, point: &[f64], tangent: &mut TangentVector<f64>) -> Result<()> {
+        *tangent = self.apply(point, tangent)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdentityPreconditioner;
+
+impl Preconditioner for IdentityPreconditioner {
+    fn apply(&self, _point: &[f64], tangent: &TangentVector<f64>) -> Result<TangentVector<f64>> {
+        Ok(tangent.clone())
+    }
+
+    fn apply_in_place(&self, _point: &[f64], _tangent: &mut TangentVector<f64>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiagonalPreconditioner {
+    pub diagonal: DVector<f64>,
+}
+
+impl DiagonalPreconditioner {
+    pub fn new(diagonal: Vec<f64>) -> Result<Self> {
+        if let Some(d) = diagonal.iter().find(|d| !(**d > 0.0 && d.is_finite())) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Diagonal preconditioner entries must be positive and finite, got {}",
+                d
+            )));
+        }
+        Ok(Self {
+            diagonal: DVector::from_vec(diagonal),
+        })
+    }
+}
+
+impl Preconditioner for DiagonalPreconditioner {
+    fn apply(&self, _point: &[f64], tangent: &TangentVector<f64>) -> Result<TangentVector<f64>> {
+        if tangent.dim() != self.diagonal.len() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.diagonal.len(),
+                got: tangent.dim(),
+            });
+        }
+        Ok(TangentVector::new(
+            tangent.components.component_mul(&self.diagonal),
+        ))
+    }
+
+    fn apply_in_place(&self, _point: &[f64], tangent: &mut TangentVector<f64>) -> Result<()> {
+        if tangent.dim() != self.diagonal.len() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.diagonal.len(),
+                got: tangent.dim(),
+            });
+        }
+        tangent.components.component_mul_assign(&self.diagonal);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_identity_preconditioner() {
+        let v = TangentVector::new(DVector::from_vec(vec![1.0, -2.0]));
+        let result = IdentityPreconditioner.apply(&[0.0, 0.0], &v).unwrap();
+        assert_eq!(result.components, v.components);
+    }
+
+    #[test]
+    fn test_diagonal_preconditioner_scales_components() {
+        let preconditioner = DiagonalPreconditioner::new(vec![0.5, 2.0]).unwrap();
+        let v = TangentVector::new(DVector::from_vec(vec![4.0, 3.0]));
+        let result = preconditioner.apply(&[0.0, 0.0], &v).unwrap();
+        assert_relative_eq!(result.components[0], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(result.components[1], 6.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_diagonal_preconditioner_rejects_non_positive_entries() {
+        assert!(DiagonalPreconditioner::new(vec![1.0, 0.0]).is_err());
+        assert!(DiagonalPreconditioner::new(vec![-1.0]).is_err());
+        assert!(DiagonalPreconditioner::new(vec![f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_diagonal_preconditioner_dimension_mismatch() {
+        let preconditioner = DiagonalPreconditioner::new(vec![1.0, 1.0, 1.0]).unwrap();
+        let v = TangentVector::new(DVector::from_vec(vec![1.0, 1.0]));
+        assert!(preconditioner.apply(&[0.0, 0.0], &v).is_err());
+    }
+
+    #[test]
+    fn test_apply_in_place_matches_apply() {
+        let preconditioner = DiagonalPreconditioner::new(vec![0.5, 2.0]).unwrap();
+        let mut v = TangentVector::new(DVector::from_vec(vec![4.0, 3.0]));
+        let expected = preconditioner.apply(&[0.0, 0.0], &v).unwrap();
+        preconditioner.apply_in_place(&[0.0, 0.0], &mut v).unwrap();
+        assert_eq!(v.components, expected.components);
+
+        IdentityPreconditioner
+            .apply_in_place(&[0.0, 0.0], &mut v)
+            .unwrap();
+        assert_eq!(v.components, expected.components);
+    }
+}
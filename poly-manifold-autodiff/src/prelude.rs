@@ -0,0 +1,34 @@
+pub use crate::dynamic::{BoxedOptimizer, CostFunction, DynOptimizer};
+pub use crate::line_search::LineSearch;
+pub use crate::optimizer::{GradientDescent, IterativeOptimizer, RiemannianOptimizer};
+pub use crate::result::{OptimizationResult, Termination};
+pub use crate::schedule::Schedule;
+pub use poly_manifold_spaces::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_prelude_covers_a_typical_program() {
+        let sphere = Sphere::new(2);
+        let start = sphere.point(vec![1.0, 0.0, 0.0]).unwrap();
+        let solver = GradientDescent::builder()
+            .learning_rate(0.2)
+            .line_search(LineSearch::armijo())
+            .build()
+            .unwrap();
+        let result: OptimizationResult = solver.minimize(&sphere, &start, |p| -p[2]).unwrap();
+        assert_eq!(result.termination, Termination::Converged);
+        assert_relative_eq!(result.point[2], 1.0, epsilon = 1e-4);
+
+        let north = sphere.point(result.point).unwrap();
+        let back: Result<TangentVector<f64>> = sphere.log(&north, &start);
+        assert_relative_eq!(
+            back.unwrap().norm(),
+            sphere.typed_distance(&north, &start).unwrap(),
+            epsilon = 1e-9
+        );
+    }
+}
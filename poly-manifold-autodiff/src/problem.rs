@@ -0,0 +1,275 @@
+use crate::line_search::LineSearch;
+use crate::optimizer::{GradientDescent, RiemannianOptimizer};
+use crate::result::OptimizationResult;
+use crate::schedule::Schedule;
+use poly_manifold_core::{Manifold, ManifoldError, Result};
+use poly_manifold_spaces::{
+    Euclidean, PoincareBall, PowerManifold, Sphere, Stiefel, SE3, SO3, SPD,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ManifoldSpec {
+    Euclidean {
+        dimension: usize,
+    },
+    Sphere {
+        dimension: usize,
+    },
+    Spd {
+        dimension: usize,
+    },
+    PoincareBall {
+        dimension: usize,
+    },
+    So3,
+    Se3,
+    Stiefel {
+        n: usize,
+        p: usize,
+    },
+    Power {
+        base: Box<ManifoldSpec>,
+        count: usize,
+    },
+}
+
+impl ManifoldSpec {
+    pub fn build(&self) -> Result<Box<dyn Manifold<Scalar = f64> + Send + Sync>> {
+        Ok(match self {
+            ManifoldSpec::Euclidean { dimension } => Box::new(Euclidean::try_new(*dimension)?),
+            ManifoldSpec::Sphere { dimension } => Box::new(Sphere::try_new(*dimension)?),
+            ManifoldSpec::Spd { dimension } => Box::new(SPD::try_new(*dimension)?),
+            ManifoldSpec::PoincareBall { dimension } => {
+                Box::new(PoincareBall::try_new(*dimension)?)
+            }
+            ManifoldSpec::So3 => Box::new(SO3::new()),
+            ManifoldSpec::Se3 => Box::new(SE3::new()),
+            ManifoldSpec::Stiefel { n, p } => Box::new(Stiefel::new(*n, *p)?),
+            ManifoldSpec::Power { base, count } => {
+                Box::new(PowerManifold::new(base.build()?, *count))
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptimizerSpec {
+    pub learning_rate: f64,
+    #[serde(default)]
+    pub schedule: Schedule,
+    #[serde(default)]
+    pub line_search: Option<LineSearch>,
+    #[serde(default)]
+    pub max_gradient_norm: Option<f64>,
+    #[serde(default)]
+    pub max_step_length: Option<f64>,
+    #[serde(default)]
+    pub finite_checks: bool,
+    #[serde(default)]
+    pub reproject: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StoppingSpec {
+    pub max_iterations: usize,
+    pub tolerance: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Problem {
+    pub manifold: ManifoldSpec,
+    pub optimizer: OptimizerSpec,
+    pub stopping: StoppingSpec,
+    pub initial_point: Vec<f64>,
+}
+
+impl Problem {
+    pub fn from_json(source: &str) -> Result<Self> {
+        serde_json::from_str(source).map_err(|e| {
+            ManifoldError::InvalidParameter(format!("Invalid JSON problem description: {e}"))
+        })
+    }
+
+    pub fn from_toml(source: &str) -> Result<Self> {
+        toml::from_str(source).map_err(|e| {
+            ManifoldError::InvalidParameter(format!("Invalid TOML problem description: {e}"))
+        })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let parse = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json,
+            Some("toml") => Self::from_toml,
+            _ => {
+                return Err(ManifoldError::InvalidParameter(format!(
+                    "Cannot infer problem format of {}; expected a .json or .toml file",
+                    path.display()
+                )))
+            }
+        };
+        parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| ManifoldError::InvalidParameter(e.to_string()))
+    }
+
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string(self).map_err(|e| ManifoldError::InvalidParameter(e.to_string()))
+    }
+
+    pub fn optimizer(&self) -> GradientDescent {
+        let spec = &self.optimizer;
+        let mut optimizer = GradientDescent::new(
+            spec.learning_rate,
+            self.stopping.max_iterations,
+            self.stopping.tolerance,
+        )
+        .with_schedule(spec.schedule.clone());
+        optimizer.line_search = spec.line_search.clone();
+        optimizer.max_gradient_norm = spec.max_gradient_norm;
+        optimizer.max_step_length = spec.max_step_length;
+        optimizer.finite_checks = spec.finite_checks;
+        optimizer.reproject = spec.reproject;
+        optimizer
+    }
+}
+
+pub fn run_problem<F>(problem: &Problem, cost_function: F) -> Result<OptimizationResult>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let manifold = problem.manifold.build()?;
+    if problem.initial_point.len() != manifold.ambient_dim() {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: manifold.ambient_dim(),
+            got: problem.initial_point.len(),
+        });
+    }
+    problem
+        .optimizer()
+        .minimize(&manifold, &problem.initial_point, cost_function)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    const SPHERE_TOML: &str = r#"
+initial_point = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0]
+
+[manifold]
+type = "power"
+count = 2
+base = { type = "sphere", dimension = 2 }
+
+[optimizer]
+learning_rate = 0.2
+schedule = { Exponential = { gamma = 0.999 } }
+
+[stopping]
+max_iterations = 2000
+tolerance = 1e-10
+"#;
+
+    #[test]
+    fn test_toml_power_sphere_problem() {
+        let problem = Problem::from_toml(SPHERE_TOML).unwrap();
+        assert_eq!(
+            problem.manifold,
+            ManifoldSpec::Power {
+                base: Box::new(ManifoldSpec::Sphere { dimension: 2 }),
+                count: 2,
+            }
+        );
+        assert_eq!(
+            problem.optimizer().schedule,
+            Schedule::Exponential { gamma: 0.999 }
+        );
+        assert_eq!(problem.manifold.build().unwrap().ambient_dim(), 6);
+
+        let result = run_problem(&problem, |x| -x[2] + x[3]).unwrap();
+        assert!(result.converged());
+        assert_relative_eq!(result.cost, -2.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_json_roundtrip_and_defaults() {
+        let problem = Problem::from_json(
+            r#"{
+                "manifold": {"type": "so3"},
+                "optimizer": {"learning_rate": 0.1, "max_gradient_norm": 5.0},
+                "stopping": {"max_iterations": 10, "tolerance": 1e-6},
+                "initial_point": [1, 0, 0, 0, 1, 0, 0, 0, 1]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(problem.optimizer.schedule, Schedule::Constant);
+        assert_eq!(problem.optimizer().max_gradient_norm, Some(5.0));
+        assert!(problem.optimizer.line_search.is_none());
+        assert!(!problem.optimizer().finite_checks);
+        assert!(!problem.optimizer().reproject);
+
+        assert_eq!(
+            Problem::from_json(&problem.to_json().unwrap()).unwrap(),
+            problem
+        );
+        assert_eq!(
+            Problem::from_toml(&problem.to_toml().unwrap()).unwrap(),
+            problem
+        );
+    }
+
+    #[test]
+    fn test_run_problem_rejects_mismatched_initial_point() {
+        let problem = Problem {
+            manifold: ManifoldSpec::Stiefel { n: 3, p: 2 },
+            optimizer: OptimizerSpec {
+                learning_rate: 0.1,
+                schedule: Schedule::Constant,
+                line_search: None,
+                max_gradient_norm: None,
+                max_step_length: None,
+                finite_checks: false,
+                reproject: false,
+            },
+            stopping: StoppingSpec {
+                max_iterations: 5,
+                tolerance: 1e-6,
+            },
+            initial_point: vec![1.0, 0.0, 0.0],
+        };
+        assert!(matches!(
+            run_problem(&problem, |x| x[0]),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 6,
+                got: 3
+            })
+        ));
+        assert!(Problem::from_toml("manifold = 3").is_err());
+        assert!(matches!(
+            Problem::load("problem.yaml"),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_rejects_invalid_specs() {
+        assert!(matches!(
+            ManifoldSpec::Stiefel { n: 2, p: 3 }.build(),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        assert!(ManifoldSpec::Sphere { dimension: 0 }.build().is_err());
+        let power = ManifoldSpec::Power {
+            base: Box::new(ManifoldSpec::Stiefel { n: 1, p: 2 }),
+            count: 3,
+        };
+        assert!(power.build().is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use crate::optimizer::StepInfo;
+use crate::result::{OptimizationResult, Termination};
+use std::sync::mpsc::{Sender, SyncSender};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProgressEvent {
+    Iteration {
+        info: StepInfo,
+        max_iterations: usize,
+        elapsed: Duration,
+        eta: Duration,
+    },
+    Finished {
+        iterations: usize,
+        cost: f64,
+        termination: Termination,
+        elapsed: Duration,
+    },
+}
+
+pub trait ProgressSink {
+    fn report(&self, event: ProgressEvent);
+}
+
+impl ProgressSink for Sender<ProgressEvent> {
+    fn report(&self, event: ProgressEvent) {
+        let _ = self.send(event);
+    }
+}
+
+// Iteration events are dropped while the queue is full, but the terminal
+// `Finished` event waits for room so a receiver always sees how the run ended.
+impl ProgressSink for SyncSender<ProgressEvent> {
+    fn report(&self, event: ProgressEvent) {
+        match event {
+            ProgressEvent::Iteration { .. } => {
+                let _ = self.try_send(event);
+            }
+            ProgressEvent::Finished { .. } => {
+                let _ = self.send(event);
+            }
+        }
+    }
+}
+
+pub(crate) struct ProgressReporter<'a, S: ?Sized> {
+    sink: &'a S,
+    max_iterations: usize,
+    completed: usize,
+    start: Instant,
+}
+
+impl<'a, S: ProgressSink + ?Sized> ProgressReporter<'a, S> {
+    pub(crate) fn new(sink: &'a S, max_iterations: usize) -> Self {
+        Self {
+            sink,
+            max_iterations,
+            completed: 0,
+            start: Instant::now(),
+        }
+    }
+
+    pub(crate) fn step(&mut self, info: &StepInfo) {
+        self.completed += 1;
+        let elapsed = self.start.elapsed();
+        let remaining = self.max_iterations.saturating_sub(self.completed);
+        let eta = if info.converged || info.line_search_failed {
+            Duration::ZERO
+        } else {
+            elapsed.mul_f64(remaining as f64 / self.completed as f64)
+        };
+        self.sink.report(ProgressEvent::Iteration {
+            info: *info,
+            max_iterations: self.max_iterations,
+            elapsed,
+            eta,
+        });
+    }
+
+    pub(crate) fn finish(&self, result: &OptimizationResult) {
+        self.sink.report(ProgressEvent::Finished {
+            iterations: result.iterations,
+            cost: result.cost,
+            termination: result.termination,
+            elapsed: self.start.elapsed(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::GradientDescent;
+    use crate::prox::L1Norm;
+    use crate::proximal::ProximalGradient;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+    use std::sync::mpsc;
+    use std::thread;
+
+    #[test]
+    fn test_progress_streams_from_the_solver_thread() {
+        let (sender, receiver) = mpsc::channel();
+        let solver = thread::spawn(move || {
+            GradientDescent::new(0.2, 500, 1e-10).minimize_with_progress(
+                &Sphere::new(2),
+                &[0.6, 0.0, 0.8],
+                |p: &[f64]| -p[2],
+                &sender,
+            )
+        });
+        let events: Vec<ProgressEvent> = receiver.iter().collect();
+        let result = solver.join().unwrap().unwrap();
+
+        assert_eq!(events.len(), result.iterations + 1);
+        for (k, event) in events[..result.iterations].iter().enumerate() {
+            let ProgressEvent::Iteration {
+                info,
+                max_iterations,
+                ..
+            } = event
+            else {
+                panic!("expected an iteration event, got {event:?}");
+            };
+            assert_eq!(info.iteration, k + 1);
+            assert_eq!(*max_iterations, 500);
+        }
+        assert!(matches!(
+            events[result.iterations - 1],
+            ProgressEvent::Iteration { info, eta, .. } if info.converged && eta == Duration::ZERO
+        ));
+        assert!(matches!(
+            events[result.iterations],
+            ProgressEvent::Finished { iterations, cost, termination: Termination::Converged, .. }
+                if iterations == result.iterations && cost == result.cost
+        ));
+    }
+
+    #[test]
+    fn test_bounded_channel_never_blocks_the_solver() {
+        let (sender, receiver) = mpsc::sync_channel(2);
+        let solver = thread::spawn(move || {
+            ProximalGradient::new(L1Norm::new(0.5), 0.1, 50, 0.0).minimize_with_progress(
+                &Euclidean::new(2),
+                &[1.0, 2.0],
+                |p: &[f64]| p[0] * p[0] + p[1] * p[1],
+                &sender,
+            )
+        });
+        let first: Vec<ProgressEvent> = receiver.iter().take(2).collect();
+        assert!(matches!(
+            first[1],
+            ProgressEvent::Iteration { info, max_iterations: 50, elapsed, eta }
+                if info.iteration == 2 && eta >= elapsed
+        ));
+
+        // Only the final event may have waited for room in the queue.
+        let rest: Vec<ProgressEvent> = receiver.iter().collect();
+        let result = solver.join().unwrap().unwrap();
+        assert_eq!(result.iterations, 50);
+        assert!(rest.len() <= 49);
+        assert!(matches!(
+            rest.last(),
+            Some(ProgressEvent::Finished { iterations: 50, .. })
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+pub trait ProximalOperator {
+    fn value(&self, point: &[f64]) -> f64;
+
+    fn prox(&self, point: &[f64], step: f64) -> Vec<f64>;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZeroFunction;
+
+impl ProximalOperator for ZeroFunction {
+    fn value(&self, _point: &[f64]) -> f64 {
+        0.0
+    }
+
+    fn prox(&self, point: &[f64], _step: f64) -> Vec<f64> {
+        point.to_vec()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct L1Norm {
+    pub weight: f64,
+}
+
+impl L1Norm {
+    pub fn new(weight: f64) -> Self {
+        Self { weight }
+    }
+}
+
+impl ProximalOperator for L1Norm {
+    fn value(&self, point: &[f64]) -> f64 {
+        self.weight * point.iter().map(|x| x.abs()).sum::<f64>()
+    }
+
+    fn prox(&self, point: &[f64], step: f64) -> Vec<f64> {
+        let threshold = self.weight * step;
+        point
+            .iter()
+            .map(|x| x.signum() * (x.abs() - threshold).max(0.0))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NonNegative;
+
+impl ProximalOperator for NonNegative {
+    fn value(&self, point: &[f64]) -> f64 {
+        if point.iter().all(|x| *x >= 0.0) {
+            0.0
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn prox(&self, point: &[f64], _step: f64) -> Vec<f64> {
+        point.iter().map(|x| x.max(0.0)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_zero_function_prox_is_identity() {
+        assert_eq!(ZeroFunction.prox(&[1.0, -2.0], 0.5), vec![1.0, -2.0]);
+        assert_eq!(ZeroFunction.value(&[1.0, -2.0]), 0.0);
+    }
+
+    #[test]
+    fn test_l1_soft_thresholding() {
+        let l1 = L1Norm::new(2.0);
+        let result = l1.prox(&[3.0, -0.5, -4.0], 0.5);
+        assert_relative_eq!(result[0], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(result[1], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(result[2], -3.0, epsilon = 1e-12);
+        assert_relative_eq!(l1.value(&[3.0, -0.5, -4.0]), 15.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_nonnegative_projection() {
+        assert_eq!(NonNegative.prox(&[1.0, -2.0], 1.0), vec![1.0, 0.0]);
+        assert!(NonNegative.value(&[1.0, -2.0]).is_infinite());
+        assert_eq!(NonNegative.value(&[1.0, 2.0]), 0.0);
+    }
+}
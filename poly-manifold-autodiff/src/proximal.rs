@@ -0,0 +1,351 @@
+use crate::cancel::{is_cancelled, CancellationToken};
+use crate::gradient::{check_clip_bound, clip_tangent, numerical_gradient_unchecked};
+use crate::optimizer::{check_finite_step, RiemannianOptimizer, StepInfo};
+use crate::progress::{ProgressReporter, ProgressSink};
+use crate::prox::ProximalOperator;
+use crate::result::{OptimizationResult, Termination};
+use nalgebra::DVector;
+use poly_manifold_core::profiling::snapshot;
+#[cfg(feature = "profiling")]
+use poly_manifold_core::profiling::{counted_cost, Counted};
+use poly_manifold_core::reproject::reproject_in_place;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Manifold, Result, TangentVector};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProximalGradient<G> {
+    pub learning_rate: f64,
+    pub max_iterations: usize,
+    pub tolerance: f64,
+    pub max_gradient_norm: Option<f64>,
+    pub max_step_length: Option<f64>,
+    pub nonsmooth: G,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub finite_checks: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reproject: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub cancellation: Option<CancellationToken>,
+}
+
+impl<G: ProximalOperator> ProximalGradient<G> {
+    pub fn new(nonsmooth: G, learning_rate: f64, max_iterations: usize, tolerance: f64) -> Self {
+        Self {
+            learning_rate,
+            max_iterations,
+            tolerance,
+            max_gradient_norm: None,
+            max_step_length: None,
+            nonsmooth,
+            finite_checks: false,
+            reproject: false,
+            cancellation: None,
+        }
+    }
+
+    pub fn with_gradient_clipping(mut self, max_gradient_norm: f64) -> Self {
+        self.max_gradient_norm = Some(max_gradient_norm);
+        self
+    }
+
+    pub fn with_max_step_length(mut self, max_step_length: f64) -> Self {
+        self.max_step_length = Some(max_step_length);
+        self
+    }
+
+    pub fn with_finite_checks(mut self) -> Self {
+        self.finite_checks = true;
+        self
+    }
+
+    pub fn with_reprojection(mut self) -> Self {
+        self.reproject = true;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    pub fn objective<F>(&self, point: &[f64], smooth: F) -> f64
+    where
+        F: Fn(&[f64]) -> f64,
+    {
+        smooth(point) + self.nonsmooth.value(point)
+    }
+}
+
+impl<G: ProximalOperator> RiemannianOptimizer for ProximalGradient<G> {
+    fn minimize<M, F>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+    {
+        self.run(manifold, initial_point, cost_function, |_| {})
+    }
+}
+
+impl<G: ProximalOperator> ProximalGradient<G> {
+    pub fn minimize_with_progress<M, F, S>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+        progress: &S,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+        S: ProgressSink + ?Sized,
+    {
+        let mut reporter = ProgressReporter::new(progress, self.max_iterations);
+        let result = self.run(manifold, initial_point, cost_function, |info| {
+            reporter.step(info)
+        })?;
+        reporter.finish(&result);
+        Ok(result)
+    }
+
+    fn run<M, F, C>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+        mut on_step: C,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+        C: FnMut(&StepInfo),
+    {
+        let start = snapshot();
+        #[cfg(feature = "profiling")]
+        let manifold = &Counted::new(manifold);
+        #[cfg(feature = "profiling")]
+        let cost_function = counted_cost(cost_function);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "minimize",
+            optimizer = "proximal_gradient",
+            ambient_dim = manifold.ambient_dim(),
+            max_iterations = self.max_iterations
+        )
+        .entered();
+
+        validate_entry_point(manifold, initial_point)?;
+        if let Some(max_norm) = self.max_gradient_norm {
+            check_clip_bound("Gradient norm bound", max_norm)?;
+        }
+        if let Some(max_length) = self.max_step_length {
+            check_clip_bound("Maximum step length", max_length)?;
+        }
+
+        let mut point = initial_point.to_vec();
+        let mut prev_objective = self.objective(&point, &cost_function);
+        if self.finite_checks {
+            check_finite_step(&[prev_objective], "cost", 0)?;
+        }
+        let mut iterations = 0;
+        let mut termination = Termination::MaxIterations;
+
+        while iterations < self.max_iterations {
+            if is_cancelled(&self.cancellation) {
+                termination = Termination::Cancelled;
+                break;
+            }
+            let mut gradient =
+                numerical_gradient_unchecked(manifold, &point, &cost_function, 1e-7)?;
+            if self.finite_checks {
+                check_finite_step(gradient.as_slice(), "gradient", iterations + 1)?;
+            }
+            let gradient_norm = manifold.norm(&point, &gradient)?;
+            if let Some(max_norm) = self.max_gradient_norm {
+                gradient = clip_tangent(manifold, &point, gradient, max_norm)?;
+            }
+
+            let forward: Vec<f64> = point
+                .iter()
+                .zip(gradient.components.iter())
+                .map(|(x, g)| x - self.learning_rate * g)
+                .collect();
+            let backward = self.nonsmooth.prox(&forward, self.learning_rate);
+
+            let displacement: Vec<f64> = backward
+                .iter()
+                .zip(point.iter())
+                .map(|(z, x)| z - x)
+                .collect();
+            let mut direction = manifold.project_to_tangent_space(
+                &point,
+                &TangentVector::new(DVector::from_vec(displacement)),
+            )?;
+            if let Some(max_length) = self.max_step_length {
+                direction = clip_tangent(manifold, &point, direction, max_length)?;
+            }
+            if self.finite_checks {
+                check_finite_step(direction.as_slice(), "search direction", iterations + 1)?;
+            }
+            let step_length = manifold.norm(&point, &direction)?;
+
+            point = manifold.retraction(&point, &direction)?;
+            if self.reproject {
+                reproject_in_place(manifold, &mut point)?;
+            }
+            iterations += 1;
+
+            let current_objective = self.objective(&point, &cost_function);
+            if self.finite_checks {
+                check_finite_step(&point, "retraction", iterations)?;
+                check_finite_step(&[current_objective], "cost", iterations)?;
+            }
+            let change = prev_objective - current_objective;
+            prev_objective = current_objective;
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                iteration = iterations,
+                cost = current_objective,
+                cost_change = -change,
+                gradient_norm,
+                step_size = self.learning_rate,
+                step_length,
+                "proximal gradient step"
+            );
+            let converged = step_length < self.tolerance
+                || (change.is_finite() && change.abs() < self.tolerance);
+            on_step(&StepInfo {
+                iteration: iterations,
+                cost: current_objective,
+                cost_change: -change,
+                gradient_norm,
+                step_size: self.learning_rate,
+                step_length,
+                converged,
+                line_search_failed: false,
+            });
+            if converged {
+                termination = Termination::Converged;
+                break;
+            }
+        }
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            iterations,
+            cost = prev_objective,
+            termination = ?termination,
+            "minimize finished"
+        );
+
+        Ok(OptimizationResult {
+            point,
+            cost: prev_objective,
+            iterations,
+            termination,
+            operation_counts: snapshot().since(&start),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prox::{L1Norm, NonNegative};
+    use approx::assert_relative_eq;
+    use poly_manifold_core::ManifoldError;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    #[test]
+    fn test_proximal_gradient_lasso() {
+        let euclidean = Euclidean::new(2);
+        let smooth = |p: &[f64]| (p[0] - 3.0).powi(2) + (p[1] - 0.5).powi(2);
+
+        let optimizer = ProximalGradient::new(L1Norm::new(2.0), 0.1, 1000, 1e-12);
+        let result = optimizer.minimize(&euclidean, &[0.0, 0.0], smooth).unwrap();
+
+        assert_relative_eq!(result.point[0], 2.0, epsilon = 1e-4);
+        assert_eq!(result.point[1], 0.0);
+    }
+
+    #[test]
+    fn test_proximal_gradient_finite_checks() {
+        let euclidean = Euclidean::new(1);
+        let smooth = |p: &[f64]| if p[0] > 1.0 { f64::NAN } else { -p[0] };
+        let error = ProximalGradient::new(L1Norm::new(0.0), 0.4, 100, 1e-12)
+            .with_finite_checks()
+            .minimize(&euclidean, &[0.0], smooth)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Numerical error: cost produced non-finite value NaN at index 0 in iteration 3"
+        );
+    }
+
+    #[test]
+    fn test_proximal_gradient_sparse_point_on_sphere() {
+        let sphere = Sphere::new(2);
+        let target = [0.9, 0.3, 0.1];
+        let smooth = |p: &[f64]| {
+            (p[0] - target[0]).powi(2) + (p[1] - target[1]).powi(2) + (p[2] - target[2]).powi(2)
+        };
+
+        let optimizer = ProximalGradient::new(L1Norm::new(0.4), 0.1, 5000, 1e-12);
+        let result = optimizer
+            .minimize(&sphere, &[0.0, 0.0, 1.0], smooth)
+            .unwrap();
+
+        assert!(sphere.check_point(&result.point).is_ok());
+        let norm = (0.7f64 * 0.7 + 0.1 * 0.1).sqrt();
+        assert_relative_eq!(result.point[0], 0.7 / norm, epsilon = 1e-3);
+        assert_relative_eq!(result.point[1], 0.1 / norm, epsilon = 1e-3);
+        assert_relative_eq!(result.point[2], 0.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_proximal_gradient_projected_nonnegative() {
+        let euclidean = Euclidean::new(2);
+        let smooth = |p: &[f64]| (p[0] + 1.0).powi(2) + (p[1] - 2.0).powi(2);
+
+        let optimizer = ProximalGradient::new(NonNegative, 0.1, 1000, 1e-12);
+        let result = optimizer.minimize(&euclidean, &[1.0, 1.0], smooth).unwrap();
+
+        assert_eq!(result.point[0], 0.0);
+        assert_relative_eq!(result.point[1], 2.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_proximal_gradient_max_step_length() {
+        let euclidean = Euclidean::new(1);
+        let smooth = |p: &[f64]| (p[0] - 10.0).powi(2);
+
+        let optimizer =
+            ProximalGradient::new(L1Norm::new(0.0), 0.25, 2, 0.0).with_max_step_length(0.5);
+        let result = optimizer.minimize(&euclidean, &[0.0], smooth).unwrap();
+
+        assert_relative_eq!(result.point[0], 1.0, epsilon = 1e-6);
+        assert_eq!(result.termination, Termination::MaxIterations);
+        assert_eq!(result.iterations, 2);
+    }
+
+    #[test]
+    fn test_proximal_gradient_rejects_invalid_clipping_bounds() {
+        let euclidean = Euclidean::new(1);
+        let smooth = |p: &[f64]| (p[0] - 10.0).powi(2);
+
+        for bound in [0.0, -0.5, f64::NAN] {
+            for optimizer in [
+                ProximalGradient::new(L1Norm::new(0.0), 0.25, 2, 0.0).with_gradient_clipping(bound),
+                ProximalGradient::new(L1Norm::new(0.0), 0.25, 2, 0.0).with_max_step_length(bound),
+            ] {
+                assert!(matches!(
+                    optimizer.minimize(&euclidean, &[0.0], smooth),
+                    Err(ManifoldError::InvalidParameter(_))
+                ));
+            }
+        }
+    }
+}
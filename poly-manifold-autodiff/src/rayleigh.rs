@@ -0,0 +1,319 @@
+use crate::optimizer::RiemannianOptimizer;
+use crate::trust_region::RiemannianTrustRegion;
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::precision::tolerance;
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+use poly_manifold_spaces::{Grassmann, Sphere};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EigenPair {
+    pub value: f64,
+    pub vector: Vec<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RayleighQuotient {
+    matrix: DMatrix<f64>,
+}
+
+impl RayleighQuotient {
+    pub fn new(matrix: DMatrix<f64>) -> Result<Self> {
+        if !matrix.is_square() || matrix.nrows() < 2 {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Rayleigh quotient needs a square matrix of size at least 2, got {}x{}",
+                matrix.nrows(),
+                matrix.ncols()
+            )));
+        }
+        let n = matrix.nrows();
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if (matrix[(i, j)] - matrix[(j, i)]).abs() > tolerance(1e-10) {
+                    return Err(ManifoldError::InvalidParameter(
+                        "Rayleigh quotient matrix must be symmetric".to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(Self { matrix })
+    }
+
+    pub fn matrix(&self) -> &DMatrix<f64> {
+        &self.matrix
+    }
+
+    pub fn manifold(&self) -> Sphere {
+        Sphere::new(self.matrix.nrows() - 1)
+    }
+
+    pub fn grassmann(&self, p: usize) -> Result<Grassmann> {
+        Grassmann::new(self.matrix.nrows(), p)
+    }
+
+    pub fn cost(&self, x: &[f64]) -> f64 {
+        let v = DVector::from_column_slice(x);
+        v.dot(&(&self.matrix * &v))
+    }
+
+    pub fn initial_guess(&self) -> Vec<f64> {
+        let n = self.matrix.nrows();
+        let norm = ((n * (n + 1) * (2 * n + 1)) as f64 / 6.0).sqrt();
+        (1..=n).map(|i| i as f64 / norm).collect()
+    }
+
+    pub fn smallest<O: RiemannianOptimizer>(
+        &self,
+        optimizer: &O,
+        initial_point: &[f64],
+    ) -> Result<EigenPair> {
+        let sphere = self.manifold();
+        let initial_point = sphere.project_to_manifold(initial_point)?;
+        let vector = optimizer
+            .minimize(&sphere, &initial_point, |x| self.cost(x))?
+            .point;
+        Ok(EigenPair {
+            value: self.cost(&vector),
+            vector,
+        })
+    }
+
+    pub fn largest<O: RiemannianOptimizer>(
+        &self,
+        optimizer: &O,
+        initial_point: &[f64],
+    ) -> Result<EigenPair> {
+        let sphere = self.manifold();
+        let initial_point = sphere.project_to_manifold(initial_point)?;
+        let vector = optimizer
+            .minimize(&sphere, &initial_point, |x| -self.cost(x))?
+            .point;
+        Ok(EigenPair {
+            value: self.cost(&vector),
+            vector,
+        })
+    }
+
+    pub fn smallest_k<O: RiemannianOptimizer>(
+        &self,
+        optimizer: &O,
+        k: usize,
+    ) -> Result<Vec<EigenPair>> {
+        let n = self.matrix.nrows();
+        if k > n {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Cannot compute {} eigenpairs of a {}x{} matrix",
+                k, n, n
+            )));
+        }
+
+        let shift = 2.0 * self.gershgorin_radius() + 1.0;
+        let mut deflated = self.matrix.clone();
+        let mut pairs: Vec<EigenPair> = Vec::with_capacity(k);
+
+        for _ in 0..k {
+            let mut start = DVector::from_vec(self.initial_guess());
+            for pair in &pairs {
+                let v = DVector::from_column_slice(&pair.vector);
+                start -= &v * v.dot(&start);
+            }
+
+            let problem = Self {
+                matrix: deflated.clone(),
+            };
+            let found = problem.smallest(optimizer, start.as_slice())?;
+
+            let v = DVector::from_column_slice(&found.vector);
+            deflated += &v * v.transpose() * shift;
+            pairs.push(EigenPair {
+                value: self.cost(&found.vector),
+                vector: found.vector,
+            });
+        }
+
+        Ok(pairs)
+    }
+
+    pub fn smallest_subspace(
+        &self,
+        solver: &RiemannianTrustRegion,
+        p: usize,
+    ) -> Result<Vec<EigenPair>> {
+        self.extreme_subspace(solver, p, 1.0)
+    }
+
+    pub fn largest_subspace(
+        &self,
+        solver: &RiemannianTrustRegion,
+        p: usize,
+    ) -> Result<Vec<EigenPair>> {
+        self.extreme_subspace(solver, p, -1.0)
+    }
+
+    // Minimizes sign · tr(XᵀAX) over orthonormal n×p frames X; the optimal span
+    // is the invariant subspace of the p extreme eigenvalues, which the final
+    // Ritz step splits into eigenpairs.
+    fn extreme_subspace(
+        &self,
+        solver: &RiemannianTrustRegion,
+        p: usize,
+        sign: f64,
+    ) -> Result<Vec<EigenPair>> {
+        let grassmann = self.grassmann(p)?;
+        let n = self.matrix.nrows();
+        let a = &self.matrix * sign;
+        let frame = |x: &[f64]| DMatrix::from_row_slice(n, p, x);
+        let flat =
+            |m: DMatrix<f64>| TangentVector::new(DVector::from_vec(grassmann.from_matrix(&m)));
+
+        let cost = |x: &[f64]| {
+            let x = frame(x);
+            (x.transpose() * &a * &x).trace()
+        };
+        let gradient = |x: &[f64]| Ok(flat(&a * frame(x) * 2.0));
+        let hessian = |x: &[f64], v: &TangentVector<f64>| {
+            let x = frame(x);
+            let z = frame(v.components.as_slice());
+            Ok(flat((&a * &z - &z * (x.transpose() * &a * &x)) * 2.0))
+        };
+
+        let start: Vec<f64> = (0..n * p).map(|k| (k as f64 + 1.0).sin()).collect();
+        let start = grassmann.project_to_manifold(&start)?;
+        let basis = frame(
+            &solver
+                .minimize(&grassmann, &start, cost, gradient, hessian)?
+                .into_converged()?,
+        );
+
+        let ritz = (basis.transpose() * &self.matrix * &basis).symmetric_eigen();
+        let mut pairs: Vec<EigenPair> = (0..p)
+            .map(|k| EigenPair {
+                value: ritz.eigenvalues[k],
+                vector: (&basis * ritz.eigenvectors.column(k)).as_slice().to_vec(),
+            })
+            .collect();
+        pairs.sort_by(|a, b| (sign * a.value).total_cmp(&(sign * b.value)));
+        Ok(pairs)
+    }
+
+    fn gershgorin_radius(&self) -> f64 {
+        self.matrix
+            .row_iter()
+            .map(|row| row.iter().map(|x| x.abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::GradientDescent;
+    use approx::assert_relative_eq;
+
+    fn test_matrix() -> DMatrix<f64> {
+        DMatrix::from_row_slice(3, 3, &[2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 5.0])
+    }
+
+    #[test]
+    fn test_rayleigh_rejects_non_symmetric() {
+        let matrix = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 0.0, 1.0]);
+        assert!(RayleighQuotient::new(matrix).is_err());
+        assert!(RayleighQuotient::new(DMatrix::zeros(2, 3)).is_err());
+
+        let nearly = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 2.0 + 1e-9, 1.0]);
+        assert!(RayleighQuotient::new(nearly.clone()).is_err());
+        let loose = poly_manifold_core::Context::new().with_tolerance_scale(100.0);
+        assert!(loose.scope(|| RayleighQuotient::new(nearly)).is_ok());
+    }
+
+    #[test]
+    fn test_rayleigh_smallest_eigenpair() {
+        let problem = RayleighQuotient::new(test_matrix()).unwrap();
+        let optimizer = GradientDescent::new(0.1, 5000, 1e-14);
+        let pair = problem
+            .smallest(&optimizer, &problem.initial_guess())
+            .unwrap();
+
+        assert_relative_eq!(pair.value, 1.0, epsilon = 1e-5);
+        let alignment = (pair.vector[0] - pair.vector[1]).abs() / 2.0f64.sqrt();
+        assert_relative_eq!(alignment, 1.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_rayleigh_largest_eigenpair() {
+        let problem = RayleighQuotient::new(test_matrix()).unwrap();
+        let optimizer = GradientDescent::new(0.1, 5000, 1e-14);
+        let pair = problem
+            .largest(&optimizer, &problem.initial_guess())
+            .unwrap();
+
+        assert_relative_eq!(pair.value, 5.0, epsilon = 1e-5);
+        assert_relative_eq!(pair.vector[2].abs(), 1.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_rayleigh_grassmann_subspaces_match_symmetric_eigen() {
+        let matrix = DMatrix::from_row_slice(
+            5,
+            5,
+            &[
+                4.0, 1.0, 0.0, 0.5, 0.2, 1.0, 3.0, 0.2, 0.0, 0.1, 0.0, 0.2, 2.0, 0.3, 0.0, 0.5,
+                0.0, 0.3, 1.0, 0.4, 0.2, 0.1, 0.0, 0.4, -1.0,
+            ],
+        );
+        let mut expected: Vec<f64> = matrix.symmetric_eigenvalues().iter().copied().collect();
+        expected.sort_by(|a, b| a.total_cmp(b));
+
+        let problem = RayleighQuotient::new(matrix.clone()).unwrap();
+        let solver = RiemannianTrustRegion::new(200, 1e-10);
+        let smallest = problem.smallest_subspace(&solver, 2).unwrap();
+        let largest = problem.largest_subspace(&solver, 3).unwrap();
+
+        for (pair, value) in smallest.iter().zip(&expected[..2]) {
+            assert_relative_eq!(pair.value, *value, epsilon = 1e-9);
+        }
+        for (pair, value) in largest.iter().zip(expected.iter().rev()) {
+            assert_relative_eq!(pair.value, *value, epsilon = 1e-9);
+        }
+        for pair in smallest.iter().chain(&largest) {
+            let v = DVector::from_column_slice(&pair.vector);
+            assert_relative_eq!(v.norm(), 1.0, epsilon = 1e-10);
+            assert!((&matrix * &v - &v * pair.value).norm() < 1e-6);
+        }
+        assert!(problem.smallest_subspace(&solver, 6).is_err());
+    }
+
+    #[test]
+    fn test_rayleigh_smallest_k_matches_symmetric_eigen() {
+        let matrix = DMatrix::from_row_slice(
+            4,
+            4,
+            &[
+                4.0, 1.0, 0.0, 0.5, 1.0, 3.0, 0.2, 0.0, 0.0, 0.2, 2.0, 0.3, 0.5, 0.0, 0.3, 1.0,
+            ],
+        );
+        let mut expected: Vec<f64> = matrix
+            .clone()
+            .symmetric_eigen()
+            .eigenvalues
+            .iter()
+            .copied()
+            .collect();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let problem = RayleighQuotient::new(matrix).unwrap();
+        let optimizer = GradientDescent::new(0.02, 20000, 1e-15);
+        let pairs = problem.smallest_k(&optimizer, 2).unwrap();
+
+        assert_eq!(pairs.len(), 2);
+        assert_relative_eq!(pairs[0].value, expected[0], epsilon = 1e-4);
+        assert_relative_eq!(pairs[1].value, expected[1], epsilon = 1e-4);
+        let overlap: f64 = pairs[0]
+            .vector
+            .iter()
+            .zip(pairs[1].vector.iter())
+            .map(|(a, b)| a * b)
+            .sum();
+        assert_relative_eq!(overlap, 0.0, epsilon = 1e-3);
+    }
+}
@@ -0,0 +1,77 @@
+use poly_manifold_core::{ManifoldError, OperationCounts, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Termination {
+    Converged,
+    MaxIterations,
+    LineSearchFailed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptimizationResult {
+    pub point: Vec<f64>,
+    pub cost: f64,
+    pub iterations: usize,
+    pub termination: Termination,
+    /// Primitives evaluated during the run, including work done on rayon
+    /// workers; all zero unless the `profiling` feature is enabled.
+    pub operation_counts: OperationCounts,
+}
+
+impl OptimizationResult {
+    pub fn converged(&self) -> bool {
+        self.termination == Termination::Converged
+    }
+
+    pub fn into_converged(self) -> Result<Vec<f64>> {
+        match self.termination {
+            Termination::Converged => Ok(self.point),
+            Termination::MaxIterations | Termination::Cancelled => {
+                Err(ManifoldError::ConvergenceError {
+                    iterations: self.iterations,
+                })
+            }
+            Termination::LineSearchFailed => Err(ManifoldError::NumericalError(format!(
+                "Line search found no sufficient decrease in iteration {}",
+                self.iterations
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_converged() {
+        let result = OptimizationResult {
+            point: vec![1.0],
+            cost: 0.0,
+            iterations: 3,
+            termination: Termination::Converged,
+            operation_counts: OperationCounts::default(),
+        };
+        assert!(result.converged());
+        assert_eq!(result.into_converged().unwrap(), vec![1.0]);
+    }
+
+    #[test]
+    fn test_into_converged_reports_budget_exhaustion() {
+        let result = OptimizationResult {
+            point: vec![1.0],
+            cost: 0.0,
+            iterations: 7,
+            termination: Termination::MaxIterations,
+            operation_counts: OperationCounts::default(),
+        };
+        assert!(!result.converged());
+        assert!(matches!(
+            result.into_converged(),
+            Err(ManifoldError::ConvergenceError { iterations: 7 })
+        ));
+    }
+}
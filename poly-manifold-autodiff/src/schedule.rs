@@ -0,0 +1,108 @@
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Schedule {
+    #[default]
+    Constant,
+    StepDecay {
+        step_size: usize,
+        gamma: f64,
+    },
+    Exponential {
+        gamma: f64,
+    },
+    Cosine {
+        period: usize,
+        min_factor: f64,
+    },
+    Warmup {
+        steps: usize,
+        then: Box<Schedule>,
+    },
+}
+
+impl Schedule {
+    pub fn warmup(steps: usize, then: Schedule) -> Self {
+        Schedule::Warmup {
+            steps,
+            then: Box::new(then),
+        }
+    }
+
+    pub fn factor(&self, iteration: usize) -> f64 {
+        match self {
+            Schedule::Constant => 1.0,
+            Schedule::StepDecay { step_size, gamma } => {
+                gamma.powi((iteration / (*step_size).max(1)) as i32)
+            }
+            Schedule::Exponential { gamma } => gamma.powi(iteration as i32),
+            Schedule::Cosine { period, min_factor } => {
+                let period = (*period).max(1);
+                let progress = iteration.min(period) as f64 / period as f64;
+                min_factor + (1.0 - min_factor) * 0.5 * (1.0 + (PI * progress).cos())
+            }
+            Schedule::Warmup { steps, then } => {
+                if iteration < *steps {
+                    (iteration + 1) as f64 / *steps as f64
+                } else {
+                    then.factor(iteration - steps)
+                }
+            }
+        }
+    }
+
+    pub fn learning_rate(&self, base: f64, iteration: usize) -> f64 {
+        base * self.factor(iteration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_constant_schedule() {
+        assert_relative_eq!(Schedule::Constant.learning_rate(0.1, 1000), 0.1);
+    }
+
+    #[test]
+    fn test_step_decay_schedule() {
+        let schedule = Schedule::StepDecay {
+            step_size: 10,
+            gamma: 0.5,
+        };
+        assert_relative_eq!(schedule.factor(0), 1.0);
+        assert_relative_eq!(schedule.factor(9), 1.0);
+        assert_relative_eq!(schedule.factor(10), 0.5);
+        assert_relative_eq!(schedule.factor(25), 0.25);
+    }
+
+    #[test]
+    fn test_exponential_schedule() {
+        let schedule = Schedule::Exponential { gamma: 0.9 };
+        assert_relative_eq!(schedule.factor(2), 0.81, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_cosine_schedule() {
+        let schedule = Schedule::Cosine {
+            period: 100,
+            min_factor: 0.1,
+        };
+        assert_relative_eq!(schedule.factor(0), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(schedule.factor(50), 0.55, epsilon = 1e-12);
+        assert_relative_eq!(schedule.factor(100), 0.1, epsilon = 1e-12);
+        assert_relative_eq!(schedule.factor(500), 0.1, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_warmup_schedule() {
+        let schedule = Schedule::warmup(4, Schedule::Exponential { gamma: 0.5 });
+        assert_relative_eq!(schedule.factor(0), 0.25);
+        assert_relative_eq!(schedule.factor(3), 1.0);
+        assert_relative_eq!(schedule.factor(4), 1.0);
+        assert_relative_eq!(schedule.factor(5), 0.5);
+    }
+}
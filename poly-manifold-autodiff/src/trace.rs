@@ -0,0 +1,219 @@
+use crate::optimizer::StepInfo;
+#[cfg(feature = "arrow")]
+use arrow::array::{BooleanArray, Float64Array, UInt64Array};
+#[cfg(feature = "arrow")]
+use arrow::datatypes::{DataType, Field, Schema};
+#[cfg(feature = "arrow")]
+use arrow::record_batch::RecordBatch;
+#[cfg(feature = "arrow")]
+use poly_manifold_core::columnar::arrow_error;
+use poly_manifold_core::csv::{parse_field, read_records, write_record};
+use poly_manifold_core::{ManifoldError, Result};
+use std::io::{BufRead, Write};
+#[cfg(feature = "arrow")]
+use std::sync::Arc;
+
+pub const TRACE_COLUMNS: [&str; 8] = [
+    "iteration",
+    "cost",
+    "cost_change",
+    "gradient_norm",
+    "step_size",
+    "step_length",
+    "converged",
+    "line_search_failed",
+];
+
+pub fn write_trace_csv<W: Write>(mut writer: W, trace: &[StepInfo]) -> Result<()> {
+    write_record(&mut writer, TRACE_COLUMNS)?;
+    for info in trace {
+        write_record(
+            &mut writer,
+            [
+                info.iteration.to_string(),
+                info.cost.to_string(),
+                info.cost_change.to_string(),
+                info.gradient_norm.to_string(),
+                info.step_size.to_string(),
+                info.step_length.to_string(),
+                info.converged.to_string(),
+                info.line_search_failed.to_string(),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+pub fn read_trace_csv<R: BufRead>(reader: R) -> Result<Vec<StepInfo>> {
+    let mut records = read_records(reader)?.into_iter().peekable();
+    if let Some((_, header)) = records.peek() {
+        if header.split(',').next().map(str::trim) == Some(TRACE_COLUMNS[0]) {
+            records.next();
+        }
+    }
+    records
+        .map(|(line, record)| {
+            let fields: Vec<&str> = record.split(',').collect();
+            if fields.len() != TRACE_COLUMNS.len() {
+                return Err(ManifoldError::DimensionMismatch {
+                    expected: TRACE_COLUMNS.len(),
+                    got: fields.len(),
+                });
+            }
+            Ok(StepInfo {
+                iteration: parse_field(fields[0], line)?,
+                cost: parse_field(fields[1], line)?,
+                cost_change: parse_field(fields[2], line)?,
+                gradient_norm: parse_field(fields[3], line)?,
+                step_size: parse_field(fields[4], line)?,
+                step_length: parse_field(fields[5], line)?,
+                converged: parse_flag(fields[6], line)?,
+                line_search_failed: parse_flag(fields[7], line)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "arrow")]
+pub fn trace_to_record_batch(trace: &[StepInfo]) -> Result<RecordBatch> {
+    let float_column =
+        |f: fn(&StepInfo) -> f64| Arc::new(Float64Array::from_iter_values(trace.iter().map(f)));
+    let schema = Schema::new(
+        TRACE_COLUMNS
+            .iter()
+            .map(|name| {
+                let data_type = match *name {
+                    "iteration" => DataType::UInt64,
+                    "converged" | "line_search_failed" => DataType::Boolean,
+                    _ => DataType::Float64,
+                };
+                Field::new(*name, data_type, false)
+            })
+            .collect::<Vec<_>>(),
+    );
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(UInt64Array::from_iter_values(
+                trace.iter().map(|info| info.iteration as u64),
+            )),
+            float_column(|info| info.cost),
+            float_column(|info| info.cost_change),
+            float_column(|info| info.gradient_norm),
+            float_column(|info| info.step_size),
+            float_column(|info| info.step_length),
+            Arc::new(BooleanArray::from_iter(
+                trace.iter().map(|info| Some(info.converged)),
+            )),
+            Arc::new(BooleanArray::from_iter(
+                trace.iter().map(|info| Some(info.line_search_failed)),
+            )),
+        ],
+    )
+    .map_err(arrow_error)
+}
+
+fn parse_flag(field: &str, line: usize) -> Result<bool> {
+    match field.trim().to_ascii_lowercase().as_str() {
+        "true" | "1" => Ok(true),
+        "false" | "0" => Ok(false),
+        other => Err(ManifoldError::InvalidParameter(format!(
+            "Line {}: cannot parse field '{}'",
+            line, other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::{GradientDescent, RiemannianOptimizer};
+    use poly_manifold_spaces::Sphere;
+
+    #[test]
+    fn test_trace_matches_minimize() {
+        let sphere = Sphere::new(2);
+        let cost = |p: &[f64]| -p[2];
+        let optimizer = GradientDescent::new(0.2, 500, 1e-10);
+        let initial = [0.6, 0.0, 0.8];
+        let (result, trace) = optimizer.minimize_traced(&sphere, &initial, cost).unwrap();
+        let plain = optimizer.minimize(&sphere, &initial, cost).unwrap();
+
+        assert_eq!(result.point, plain.point);
+        assert_eq!(trace.len(), result.iterations);
+        assert_eq!(trace.last().unwrap().cost, result.cost);
+        assert_eq!(trace.last().unwrap().converged, result.converged());
+        assert!(trace
+            .windows(2)
+            .all(|w| w[1].iteration == w[0].iteration + 1));
+    }
+
+    #[test]
+    fn test_trace_csv_round_trip() {
+        let sphere = Sphere::new(2);
+        let optimizer = GradientDescent::new(0.2, 50, 1e-10);
+        let (_, trace) = optimizer
+            .minimize_traced(&sphere, &[0.6, 0.0, 0.8], |p: &[f64]| -p[2])
+            .unwrap();
+
+        let mut buffer = Vec::new();
+        write_trace_csv(&mut buffer, &trace).unwrap();
+        let text = String::from_utf8(buffer.clone()).unwrap();
+        assert!(text.starts_with(
+            "iteration,cost,cost_change,gradient_norm,step_size,step_length,converged,line_search_failed\n1,"
+        ));
+        assert_eq!(read_trace_csv(buffer.as_slice()).unwrap(), trace);
+    }
+
+    #[test]
+    fn test_read_trace_csv_rejects_malformed_rows() {
+        let headerless = "3,1.5,-0.1,0.2,0.1,0.02,False,1\n";
+        let parsed = read_trace_csv(headerless.as_bytes()).unwrap();
+        assert_eq!(parsed[0].iteration, 3);
+        assert!(!parsed[0].converged);
+        assert!(parsed[0].line_search_failed);
+
+        assert!(matches!(
+            read_trace_csv("1,2,3\n".as_bytes()),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 8,
+                got: 3
+            })
+        ));
+        let error = read_trace_csv("1,1,0,0,0,0,maybe,0\n".as_bytes()).unwrap_err();
+        assert!(error.to_string().contains("Line 1"));
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_trace_record_batch_columns() {
+        let sphere = Sphere::new(2);
+        let optimizer = GradientDescent::new(0.2, 50, 1e-10);
+        let (_, trace) = optimizer
+            .minimize_traced(&sphere, &[0.6, 0.0, 0.8], |p: &[f64]| -p[2])
+            .unwrap();
+
+        let batch = trace_to_record_batch(&trace).unwrap();
+        assert_eq!(batch.num_rows(), trace.len());
+        let names: Vec<_> = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect();
+        assert_eq!(names, TRACE_COLUMNS);
+        let costs = batch
+            .column_by_name("cost")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(costs.value(trace.len() - 1), trace.last().unwrap().cost);
+
+        let mut buffer = Vec::new();
+        poly_manifold_core::columnar::write_parquet(&mut buffer, std::slice::from_ref(&batch))
+            .unwrap();
+        let read = poly_manifold_core::columnar::read_parquet(bytes::Bytes::from(buffer)).unwrap();
+        assert_eq!(read, vec![batch]);
+    }
+}
@@ -0,0 +1,532 @@
+use crate::result::{OptimizationResult, Termination};
+use nalgebra::{DMatrix, DVector};
+#[cfg(feature = "profiling")]
+use poly_manifold_core::profiling::{counted_cost, Counted};
+use poly_manifold_core::profiling::{record, snapshot, Counter};
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+
+#[derive(Clone)]
+pub struct TrustRegionStep {
+    pub step: TangentVector<f64>,
+    pub model_decrease: f64,
+    pub iterations: usize,
+    pub hit_boundary: bool,
+}
+
+fn boundary_step<M>(
+    manifold: &M,
+    point: &[f64],
+    s: &TangentVector<f64>,
+    d: &TangentVector<f64>,
+    radius: f64,
+) -> Result<f64>
+where
+    M: Manifold<Scalar = f64>,
+{
+    let dd = manifold.inner_product(point, d, d)?;
+    let sd = manifold.inner_product(point, s, d)?;
+    let ss = manifold.inner_product(point, s, s)?;
+    let discriminant = (sd * sd + dd * (radius * radius - ss)).max(0.0);
+    Ok((-sd + discriminant.sqrt()) / dd)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn truncated_cg<M, H>(
+    manifold: &M,
+    point: &[f64],
+    gradient: &TangentVector<f64>,
+    hessian: H,
+    radius: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<TrustRegionStep>
+where
+    M: Manifold<Scalar = f64>,
+    H: Fn(&TangentVector<f64>) -> Result<TangentVector<f64>>,
+{
+    if !(radius > 0.0 && radius.is_finite()) {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Trust-region radius must be positive and finite, got {}",
+            radius
+        )));
+    }
+
+    let apply = |v: &TangentVector<f64>| -> Result<TangentVector<f64>> {
+        manifold.project_to_tangent_space(point, &hessian(v)?)
+    };
+
+    let mut s = TangentVector::zero(gradient.dim());
+    let mut r = gradient.clone();
+    let mut d = -gradient;
+    let mut rr = manifold.inner_product(point, &r, &r)?;
+    let stop = tolerance * rr.sqrt();
+    let mut hit_boundary = false;
+    let mut iterations = 0;
+
+    while iterations < max_iterations && rr.sqrt() > stop {
+        iterations += 1;
+        let hd = apply(&d)?;
+        let dhd = manifold.inner_product(point, &d, &hd)?;
+
+        if dhd <= 0.0 {
+            let tau = boundary_step(manifold, point, &s, &d, radius)?;
+            s += d * tau;
+            hit_boundary = true;
+            break;
+        }
+
+        let alpha = rr / dhd;
+        let candidate = &s + &(&d * alpha);
+        if manifold.norm(point, &candidate)? >= radius {
+            let tau = boundary_step(manifold, point, &s, &d, radius)?;
+            s += d * tau;
+            hit_boundary = true;
+            break;
+        }
+
+        s = candidate;
+        r += hd * alpha;
+        let rr_new = manifold.inner_product(point, &r, &r)?;
+        d = -&r + d * (rr_new / rr);
+        rr = rr_new;
+    }
+
+    let hs = apply(&s)?;
+    let model_decrease = -(manifold.inner_product(point, gradient, &s)?
+        + 0.5 * manifold.inner_product(point, &s, &hs)?);
+
+    Ok(TrustRegionStep {
+        step: s,
+        model_decrease,
+        iterations,
+        hit_boundary,
+    })
+}
+
+pub fn exact_trust_region(
+    gradient: &DVector<f64>,
+    hessian: &DMatrix<f64>,
+    radius: f64,
+) -> Result<DVector<f64>> {
+    let n = gradient.len();
+    if hessian.nrows() != n || hessian.ncols() != n {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: n,
+            got: hessian.nrows(),
+        });
+    }
+    if n == 0 {
+        return Err(ManifoldError::InvalidParameter(
+            "Trust-region subproblem needs a non-empty gradient".to_string(),
+        ));
+    }
+    if !(radius > 0.0 && radius.is_finite()) {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Trust-region radius must be positive and finite, got {}",
+            radius
+        )));
+    }
+
+    let symmetric = (hessian + hessian.transpose()) * 0.5;
+    record(Counter::Eigendecomposition);
+    let eigen = symmetric.symmetric_eigen();
+    let q = &eigen.eigenvectors;
+    let values = &eigen.eigenvalues;
+    let g_tilde = q.transpose() * gradient;
+
+    let (min_index, lambda_min) = values
+        .iter()
+        .copied()
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("the Hessian is non-empty after the dimension check");
+
+    let step_norm = |shift: f64| -> f64 {
+        (0..n)
+            .map(|i| {
+                let denom = values[i] + shift;
+                if denom.abs() < 1e-300 {
+                    0.0
+                } else {
+                    (g_tilde[i] / denom).powi(2)
+                }
+            })
+            .sum::<f64>()
+            .sqrt()
+    };
+    let step_for = |shift: f64| -> DVector<f64> {
+        let coeffs = DVector::from_fn(n, |i, _| {
+            let denom = values[i] + shift;
+            if denom.abs() < 1e-300 {
+                0.0
+            } else {
+                -g_tilde[i] / denom
+            }
+        });
+        q * coeffs
+    };
+
+    let floor = (-lambda_min).max(0.0);
+    let scale = 1e-12 * (1.0 + values.amax());
+
+    if lambda_min > scale && step_norm(0.0) <= radius {
+        return Ok(step_for(0.0));
+    }
+
+    let degenerate = g_tilde[min_index].abs() <= 1e-12 * (1.0 + gradient.norm());
+    if degenerate && step_norm(floor + scale) <= radius {
+        let mut s = step_for(floor);
+        let remaining = (radius * radius - s.norm_squared()).max(0.0).sqrt();
+        s += q.column(min_index) * remaining;
+        return Ok(s);
+    }
+
+    let mut lower = floor + scale;
+    let mut upper = floor + gradient.norm() / radius + values.amax() + 1.0;
+    while step_norm(upper) > radius {
+        upper *= 2.0;
+    }
+    for _ in 0..200 {
+        let mid = 0.5 * (lower + upper);
+        if step_norm(mid) > radius {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+        if upper - lower <= 1e-14 * upper.max(1.0) {
+            break;
+        }
+    }
+
+    Ok(step_for(upper))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RiemannianTrustRegion {
+    pub max_iterations: usize,
+    pub tolerance: f64,
+    pub initial_radius: f64,
+    pub max_radius: f64,
+}
+
+impl Default for RiemannianTrustRegion {
+    fn default() -> Self {
+        Self::new(1000, 1e-6)
+    }
+}
+
+impl RiemannianTrustRegion {
+    pub fn new(max_iterations: usize, tolerance: f64) -> Self {
+        Self {
+            max_iterations,
+            tolerance,
+            initial_radius: 1.0,
+            max_radius: 10.0,
+        }
+    }
+
+    pub fn with_radius(mut self, initial_radius: f64, max_radius: f64) -> Self {
+        self.initial_radius = initial_radius;
+        self.max_radius = max_radius;
+        self
+    }
+
+    pub fn minimize<M, F, G, H>(
+        &self,
+        manifold: &M,
+        initial_point: &[f64],
+        cost_function: F,
+        gradient: G,
+        hessian: H,
+    ) -> Result<OptimizationResult>
+    where
+        M: Manifold<Scalar = f64>,
+        F: Fn(&[f64]) -> f64,
+        G: Fn(&[f64]) -> Result<TangentVector<f64>>,
+        H: Fn(&[f64], &TangentVector<f64>) -> Result<TangentVector<f64>>,
+    {
+        if self.tolerance.is_nan() || self.tolerance < 0.0 {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Gradient tolerance must be non-negative, got {}",
+                self.tolerance
+            )));
+        }
+        if !(self.initial_radius > 0.0
+            && self.initial_radius <= self.max_radius
+            && self.max_radius.is_finite())
+        {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Trust-region radii must satisfy 0 < initial <= max < inf, got {} and {}",
+                self.initial_radius, self.max_radius
+            )));
+        }
+        let start = snapshot();
+        #[cfg(feature = "profiling")]
+        let manifold = &Counted::new(manifold);
+        #[cfg(feature = "profiling")]
+        let cost_function = counted_cost(cost_function);
+
+        validate_entry_point(manifold, initial_point)?;
+        let mut point = initial_point.to_vec();
+        let mut cost = cost_function(&point);
+        let mut radius = self.initial_radius;
+        let mut iterations = 0;
+        let mut termination = Termination::MaxIterations;
+        while iterations < self.max_iterations {
+            let g = manifold.project_to_tangent_space(&point, &gradient(&point)?)?;
+            let g_norm = manifold.norm(&point, &g)?;
+            if g_norm <= self.tolerance {
+                termination = Termination::Converged;
+                break;
+            }
+            iterations += 1;
+
+            let step = truncated_cg(
+                manifold,
+                &point,
+                &g,
+                |v| hessian(&point, v),
+                radius,
+                g_norm.min(0.1),
+                manifold.dim().max(1),
+            )?;
+            let candidate = manifold.retraction(&point, &step.step)?;
+            let candidate_cost = cost_function(&candidate);
+            // Regularize the ratio so round-off near a minimum does not read as
+            // a failed step.
+            let slack = 1e3 * f64::EPSILON * cost.abs().max(1.0);
+            let rho = (cost - candidate_cost + slack) / (step.model_decrease + slack);
+
+            if rho < 0.25 {
+                radius *= 0.25;
+            } else if rho > 0.75 && step.hit_boundary {
+                radius = (2.0 * radius).min(self.max_radius);
+            }
+            if rho > 0.1 {
+                point = candidate;
+                cost = candidate_cost;
+            }
+            if radius <= f64::EPSILON * self.max_radius {
+                termination = Termination::LineSearchFailed;
+                break;
+            }
+        }
+
+        Ok(OptimizationResult {
+            point,
+            cost,
+            iterations,
+            termination,
+            operation_counts: snapshot().since(&start),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::Euclidean;
+
+    fn diagonal_hessian(
+        diag: Vec<f64>,
+    ) -> impl Fn(&TangentVector<f64>) -> Result<TangentVector<f64>> {
+        let diag = DVector::from_vec(diag);
+        move |v: &TangentVector<f64>| Ok(TangentVector::new(v.components.component_mul(&diag)))
+    }
+
+    #[test]
+    fn test_truncated_cg_interior_newton_step() {
+        let euclidean = Euclidean::new(2);
+        let gradient = TangentVector::new(DVector::from_vec(vec![2.0, 4.0]));
+        let result = truncated_cg(
+            &euclidean,
+            &[0.0, 0.0],
+            &gradient,
+            diagonal_hessian(vec![2.0, 8.0]),
+            10.0,
+            1e-12,
+            10,
+        )
+        .unwrap();
+
+        assert!(!result.hit_boundary);
+        assert_relative_eq!(result.step.components[0], -1.0, epsilon = 1e-10);
+        assert_relative_eq!(result.step.components[1], -0.5, epsilon = 1e-10);
+        assert_relative_eq!(result.model_decrease, 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_truncated_cg_stops_at_boundary() {
+        let euclidean = Euclidean::new(2);
+        let gradient = TangentVector::new(DVector::from_vec(vec![2.0, 4.0]));
+        let result = truncated_cg(
+            &euclidean,
+            &[0.0, 0.0],
+            &gradient,
+            diagonal_hessian(vec![2.0, 8.0]),
+            0.1,
+            1e-12,
+            10,
+        )
+        .unwrap();
+
+        assert!(result.hit_boundary);
+        assert_relative_eq!(result.step.norm(), 0.1, epsilon = 1e-10);
+        assert!(result.model_decrease > 0.0);
+    }
+
+    #[test]
+    fn test_truncated_cg_negative_curvature() {
+        let euclidean = Euclidean::new(2);
+        let gradient = TangentVector::new(DVector::from_vec(vec![1.0, 0.0]));
+        let result = truncated_cg(
+            &euclidean,
+            &[0.0, 0.0],
+            &gradient,
+            diagonal_hessian(vec![-1.0, 1.0]),
+            2.0,
+            1e-12,
+            10,
+        )
+        .unwrap();
+
+        assert!(result.hit_boundary);
+        assert_relative_eq!(result.step.components[0], -2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_exact_trust_region_interior_and_boundary() {
+        let gradient = DVector::from_vec(vec![2.0, 4.0]);
+        let hessian = DMatrix::from_diagonal(&DVector::from_vec(vec![2.0, 8.0]));
+
+        let interior = exact_trust_region(&gradient, &hessian, 10.0).unwrap();
+        assert_relative_eq!(interior[0], -1.0, epsilon = 1e-10);
+        assert_relative_eq!(interior[1], -0.5, epsilon = 1e-10);
+
+        let boundary = exact_trust_region(&gradient, &hessian, 0.5).unwrap();
+        assert_relative_eq!(boundary.norm(), 0.5, epsilon = 1e-8);
+        assert!(boundary[0] < 0.0 && boundary[1] < 0.0);
+    }
+
+    #[test]
+    fn test_exact_trust_region_hard_case() {
+        let gradient = DVector::from_vec(vec![0.0, 1.0]);
+        let hessian = DMatrix::from_diagonal(&DVector::from_vec(vec![-2.0, 1.0]));
+
+        let step = exact_trust_region(&gradient, &hessian, 1.0).unwrap();
+        assert_relative_eq!(step.norm(), 1.0, epsilon = 1e-8);
+        assert_relative_eq!(step[1], -1.0 / 3.0, epsilon = 1e-8);
+        assert!(step[0].abs() > 0.9);
+    }
+
+    #[test]
+    fn test_exact_trust_region_rejects_empty_problem() {
+        assert!(matches!(
+            exact_trust_region(&DVector::zeros(0), &DMatrix::zeros(0, 0), 1.0),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_subproblems_reject_non_finite_radius() {
+        let euclidean = Euclidean::new(2);
+        let gradient = DVector::from_vec(vec![2.0, 4.0]);
+        let hessian = DMatrix::from_diagonal(&DVector::from_vec(vec![2.0, 8.0]));
+        for radius in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                exact_trust_region(&gradient, &hessian, radius),
+                Err(ManifoldError::InvalidParameter(_))
+            ));
+            assert!(matches!(
+                truncated_cg(
+                    &euclidean,
+                    &[0.0, 0.0],
+                    &TangentVector::new(gradient.clone()),
+                    diagonal_hessian(vec![2.0, 8.0]),
+                    radius,
+                    1e-12,
+                    10,
+                ),
+                Err(ManifoldError::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_trust_region_minimizes_quadratic_on_sphere() {
+        let sphere = poly_manifold_spaces::Sphere::new(2);
+        let a = DMatrix::from_diagonal(&DVector::from_vec(vec![3.0, 1.0, 2.0]));
+        let cost = |x: &[f64]| {
+            let x = DVector::from_column_slice(x);
+            x.dot(&(&a * &x))
+        };
+        let gradient = |x: &[f64]| -> Result<TangentVector<f64>> {
+            let x = DVector::from_column_slice(x);
+            let egrad = &a * &x * 2.0;
+            Ok(TangentVector::new(&egrad - &x * x.dot(&egrad)))
+        };
+        let hessian = |x: &[f64], v: &TangentVector<f64>| -> Result<TangentVector<f64>> {
+            let x = DVector::from_column_slice(x);
+            let egrad = &a * &x * 2.0;
+            let ehess = &a * &v.components * 2.0;
+            Ok(TangentVector::new(ehess - &v.components * x.dot(&egrad)))
+        };
+
+        let start = sphere.project_to_manifold(&[1.0, 0.5, 0.8]).unwrap();
+        let result = RiemannianTrustRegion::new(100, 1e-10)
+            .minimize(&sphere, &start, cost, gradient, hessian)
+            .unwrap();
+        assert!(result.converged());
+        assert!(result.iterations < 20);
+        assert_relative_eq!(result.cost, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(result.point[1].abs(), 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_trust_region_rejects_invalid_settings() {
+        let euclidean = Euclidean::new(1);
+        let gradient = |x: &[f64]| Ok(TangentVector::new(DVector::from_column_slice(x)));
+        let hessian = |_: &[f64], v: &TangentVector<f64>| Ok(v.clone());
+        for solver in [
+            RiemannianTrustRegion::new(10, -1.0),
+            RiemannianTrustRegion::new(10, 1e-6).with_radius(0.0, 1.0),
+            RiemannianTrustRegion::new(10, 1e-6).with_radius(2.0, 1.0),
+            RiemannianTrustRegion::new(10, 1e-6).with_radius(1.0, f64::INFINITY),
+        ] {
+            assert!(matches!(
+                solver.minimize(&euclidean, &[1.0], |x| x[0] * x[0], gradient, hessian),
+                Err(ManifoldError::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_exact_matches_truncated_cg_model_decrease_bound() {
+        let gradient = DVector::from_vec(vec![1.0, -2.0, 0.5]);
+        let hessian =
+            DMatrix::from_row_slice(3, 3, &[3.0, 1.0, 0.0, 1.0, -1.0, 0.5, 0.0, 0.5, 2.0]);
+        let radius = 0.75;
+        let model = |s: &DVector<f64>| gradient.dot(s) + 0.5 * s.dot(&(&hessian * s));
+
+        let exact = exact_trust_region(&gradient, &hessian, radius).unwrap();
+        assert!(exact.norm() <= radius + 1e-8);
+
+        let euclidean = Euclidean::new(3);
+        let h = hessian.clone();
+        let tcg = truncated_cg(
+            &euclidean,
+            &[0.0, 0.0, 0.0],
+            &TangentVector::new(gradient.clone()),
+            move |v: &TangentVector<f64>| Ok(TangentVector::new(&h * &v.components)),
+            radius,
+            1e-12,
+            10,
+        )
+        .unwrap();
+
+        assert!(model(&exact) <= model(&tcg.step.components) + 1e-10);
+    }
+}
@@ -0,0 +1,113 @@
+#ifndef POLY_MANIFOLD_H
+#define POLY_MANIFOLD_H
+
+/* Generated by cbindgen from poly-manifold-capi; do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum PmStatus {
+  PM_STATUS_OK = 0,
+  PM_STATUS_NULL_POINTER = 1,
+  PM_STATUS_DIMENSION_MISMATCH = 2,
+  PM_STATUS_POINT_NOT_ON_MANIFOLD = 3,
+  PM_STATUS_INVALID_TANGENT_VECTOR = 4,
+  PM_STATUS_NUMERICAL_ERROR = 5,
+  PM_STATUS_CONVERGENCE_ERROR = 6,
+  PM_STATUS_INVALID_PARAMETER = 7,
+  PM_STATUS_LINEAR_ALGEBRA_ERROR = 8,
+  PM_STATUS_PANIC = 9,
+  PM_STATUS_BACKEND_ERROR = 10,
+  PM_STATUS_IO_ERROR = 11,
+} PmStatus;
+
+typedef struct PmManifold PmManifold;
+
+typedef struct PmOptimizer PmOptimizer;
+
+typedef double (*PmCostFn)(const double *point, size_t len, void *user_data);
+
+typedef struct PmOptimizeResult {
+  double cost;
+  size_t iterations;
+  bool converged;
+} PmOptimizeResult;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+const char *pm_last_error_message(void);
+
+struct PmManifold *pm_manifold_euclidean(size_t dimension);
+
+struct PmManifold *pm_manifold_sphere(size_t dimension);
+
+struct PmManifold *pm_manifold_spd(size_t dimension);
+
+struct PmManifold *pm_manifold_poincare_ball(size_t dimension);
+
+struct PmManifold *pm_manifold_so3(void);
+
+struct PmManifold *pm_manifold_se3(void);
+
+struct PmManifold *pm_manifold_stiefel(size_t n, size_t p);
+
+void pm_manifold_free(struct PmManifold *manifold);
+
+size_t pm_manifold_dim(const struct PmManifold *manifold);
+
+size_t pm_manifold_ambient_dim(const struct PmManifold *manifold);
+
+enum PmStatus pm_manifold_check_point(const struct PmManifold *manifold,
+                                      const double *point,
+                                      size_t len);
+
+enum PmStatus pm_manifold_project(const struct PmManifold *manifold,
+                                  const double *point,
+                                  size_t len,
+                                  double *out);
+
+enum PmStatus pm_manifold_exp(const struct PmManifold *manifold,
+                              const double *point,
+                              const double *tangent,
+                              size_t len,
+                              double *out);
+
+enum PmStatus pm_manifold_log(const struct PmManifold *manifold,
+                              const double *point,
+                              const double *other,
+                              size_t len,
+                              double *out);
+
+enum PmStatus pm_manifold_distance(const struct PmManifold *manifold,
+                                   const double *point,
+                                   const double *other,
+                                   size_t len,
+                                   double *out);
+
+struct PmOptimizer *pm_gradient_descent_new(double learning_rate,
+                                            size_t max_iterations,
+                                            double tolerance);
+
+enum PmStatus pm_gradient_descent_enable_line_search(struct PmOptimizer *optimizer);
+
+void pm_optimizer_free(struct PmOptimizer *optimizer);
+
+enum PmStatus pm_optimizer_minimize(const struct PmOptimizer *optimizer,
+                                    const struct PmManifold *manifold,
+                                    const double *initial_point,
+                                    size_t len,
+                                    PmCostFn cost,
+                                    void *user_data,
+                                    double *out_point,
+                                    struct PmOptimizeResult *out_result);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* POLY_MANIFOLD_H */
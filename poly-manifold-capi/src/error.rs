@@ -0,0 +1,167 @@
+use poly_manifold_core::{ErrorCode, ManifoldError};
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PmStatus {
+    Ok = 0,
+    NullPointer = 1,
+    DimensionMismatch = 2,
+    PointNotOnManifold = 3,
+    InvalidTangentVector = 4,
+    NumericalError = 5,
+    ConvergenceError = 6,
+    InvalidParameter = 7,
+    LinearAlgebraError = 8,
+    Panic = 9,
+    BackendError = 10,
+    IoError = 11,
+}
+
+impl From<&ManifoldError> for PmStatus {
+    fn from(error: &ManifoldError) -> Self {
+        match error.code() {
+            ErrorCode::DimensionMismatch => PmStatus::DimensionMismatch,
+            ErrorCode::PointNotOnManifold => PmStatus::PointNotOnManifold,
+            ErrorCode::InvalidTangentVector => PmStatus::InvalidTangentVector,
+            ErrorCode::Numerical => PmStatus::NumericalError,
+            ErrorCode::Convergence => PmStatus::ConvergenceError,
+            ErrorCode::InvalidParameter => PmStatus::InvalidParameter,
+            ErrorCode::LinearAlgebra => PmStatus::LinearAlgebraError,
+            ErrorCode::Backend => PmStatus::BackendError,
+            ErrorCode::Io => PmStatus::IoError,
+        }
+    }
+}
+
+pub(crate) enum Failure {
+    NullPointer(&'static str),
+    Manifold(ManifoldError),
+}
+
+impl From<ManifoldError> for Failure {
+    fn from(error: ManifoldError) -> Self {
+        Failure::Manifold(error)
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+pub(crate) fn run<F>(f: F) -> PmStatus
+where
+    F: FnOnce() -> Result<(), Failure>,
+{
+    LAST_ERROR.with(|last| last.borrow_mut().take());
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => PmStatus::Ok,
+        Ok(Err(Failure::NullPointer(name))) => {
+            set_last_error(format!("{} must not be null", name));
+            PmStatus::NullPointer
+        }
+        Ok(Err(Failure::Manifold(error))) => {
+            set_last_error(error.to_string());
+            PmStatus::from(&error)
+        }
+        Err(_) => {
+            set_last_error("Unexpected panic inside poly-manifold".to_string());
+            PmStatus::Panic
+        }
+    }
+}
+
+pub(crate) unsafe fn handle<'a, T>(ptr: *const T, name: &'static str) -> Result<&'a T, Failure> {
+    ptr.as_ref().ok_or(Failure::NullPointer(name))
+}
+
+pub(crate) unsafe fn slice<'a>(
+    ptr: *const f64,
+    len: usize,
+    name: &'static str,
+) -> Result<&'a [f64], Failure> {
+    if ptr.is_null() {
+        return Err(Failure::NullPointer(name));
+    }
+    Ok(std::slice::from_raw_parts(ptr, len))
+}
+
+pub(crate) unsafe fn slice_mut<'a>(
+    ptr: *mut f64,
+    len: usize,
+    name: &'static str,
+) -> Result<&'a mut [f64], Failure> {
+    if ptr.is_null() {
+        return Err(Failure::NullPointer(name));
+    }
+    Ok(std::slice::from_raw_parts_mut(ptr, len))
+}
+
+#[no_mangle]
+pub extern "C" fn pm_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn last_error() -> Option<String> {
+        let message = pm_last_error_message();
+        (!message.is_null()).then(|| {
+            unsafe { CStr::from_ptr(message) }
+                .to_string_lossy()
+                .into_owned()
+        })
+    }
+
+    #[test]
+    fn test_run_maps_errors_to_status() {
+        assert_eq!(run(|| Ok(())), PmStatus::Ok);
+        assert!(last_error().is_none());
+
+        let status = run(|| Err(ManifoldError::ConvergenceError { iterations: 4 }.into()));
+        assert_eq!(status, PmStatus::ConvergenceError);
+        assert_eq!(
+            last_error().unwrap(),
+            "Convergence failed after 4 iterations"
+        );
+
+        let status = run(|| {
+            Err(ManifoldError::LinearAlgebraError("singular".to_string())
+                .with_context(poly_manifold_core::ErrorContext::new("log").on("SPD(2)"))
+                .into())
+        });
+        assert_eq!(status, PmStatus::LinearAlgebraError);
+        assert_eq!(
+            last_error().unwrap(),
+            "log on SPD(2): Linear algebra error: singular"
+        );
+
+        assert_eq!(
+            run(|| Err(Failure::NullPointer("point"))),
+            PmStatus::NullPointer
+        );
+        assert_eq!(last_error().unwrap(), "point must not be null");
+    }
+
+    #[test]
+    fn test_run_catches_panics() {
+        let status = run(|| panic!("boom"));
+        assert_eq!(status, PmStatus::Panic);
+        assert!(last_error().is_some());
+    }
+}
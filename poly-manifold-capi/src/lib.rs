@@ -0,0 +1,21 @@
+#![allow(clippy::missing_safety_doc)]
+
+// Every function taking pointers expects the handles to come from the matching
+// `pm_*_new` / `pm_manifold_*` constructor and every `*const f64` / `*mut f64`
+// buffer to hold `len` values, where `len` is the manifold's ambient dimension.
+
+mod error;
+mod manifold;
+mod optimizer;
+
+pub use error::{pm_last_error_message, PmStatus};
+pub use manifold::{
+    pm_manifold_ambient_dim, pm_manifold_check_point, pm_manifold_dim, pm_manifold_distance,
+    pm_manifold_euclidean, pm_manifold_exp, pm_manifold_free, pm_manifold_log,
+    pm_manifold_poincare_ball, pm_manifold_project, pm_manifold_se3, pm_manifold_so3,
+    pm_manifold_spd, pm_manifold_sphere, pm_manifold_stiefel, PmManifold,
+};
+pub use optimizer::{
+    pm_gradient_descent_enable_line_search, pm_gradient_descent_new, pm_optimizer_free,
+    pm_optimizer_minimize, PmCostFn, PmOptimizeResult, PmOptimizer,
+};
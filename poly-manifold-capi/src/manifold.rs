@@ -0,0 +1,272 @@
+use crate::error::{handle, run, slice, slice_mut, Failure, PmStatus};
+use poly_manifold_core::{Manifold, ManifoldError, TangentVector};
+use poly_manifold_spaces::{Euclidean, PoincareBall, Sphere, Stiefel, SE3, SO3, SPD};
+use std::ptr;
+
+pub struct PmManifold {
+    pub(crate) inner: Box<dyn Manifold<Scalar = f64> + Send + Sync>,
+}
+
+impl PmManifold {
+    pub(crate) fn check_len(&self, len: usize) -> Result<(), Failure> {
+        let expected = self.inner.ambient_dim();
+        if len != expected {
+            return Err(ManifoldError::DimensionMismatch { expected, got: len }.into());
+        }
+        Ok(())
+    }
+}
+
+fn into_handle(manifold: impl Manifold<Scalar = f64> + Send + Sync + 'static) -> *mut PmManifold {
+    Box::into_raw(Box::new(PmManifold {
+        inner: Box::new(manifold),
+    }))
+}
+
+fn try_handle<M, F>(build: F) -> *mut PmManifold
+where
+    M: Manifold<Scalar = f64> + Send + Sync + 'static,
+    F: FnOnce() -> poly_manifold_core::Result<M>,
+{
+    let mut manifold = None;
+    run(|| {
+        manifold = Some(build()?);
+        Ok(())
+    });
+    manifold.map_or(ptr::null_mut(), into_handle)
+}
+
+#[no_mangle]
+pub extern "C" fn pm_manifold_euclidean(dimension: usize) -> *mut PmManifold {
+    try_handle(|| Euclidean::try_new(dimension))
+}
+
+#[no_mangle]
+pub extern "C" fn pm_manifold_sphere(dimension: usize) -> *mut PmManifold {
+    try_handle(|| Sphere::try_new(dimension))
+}
+
+#[no_mangle]
+pub extern "C" fn pm_manifold_spd(dimension: usize) -> *mut PmManifold {
+    try_handle(|| SPD::try_new(dimension))
+}
+
+#[no_mangle]
+pub extern "C" fn pm_manifold_poincare_ball(dimension: usize) -> *mut PmManifold {
+    try_handle(|| PoincareBall::try_new(dimension))
+}
+
+#[no_mangle]
+pub extern "C" fn pm_manifold_so3() -> *mut PmManifold {
+    try_handle(|| Ok(SO3::new()))
+}
+
+#[no_mangle]
+pub extern "C" fn pm_manifold_se3() -> *mut PmManifold {
+    try_handle(|| Ok(SE3::new()))
+}
+
+#[no_mangle]
+pub extern "C" fn pm_manifold_stiefel(n: usize, p: usize) -> *mut PmManifold {
+    try_handle(|| Stiefel::new(n, p))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_manifold_free(manifold: *mut PmManifold) {
+    if !manifold.is_null() {
+        drop(Box::from_raw(manifold));
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_manifold_dim(manifold: *const PmManifold) -> usize {
+    manifold.as_ref().map_or(0, |m| m.inner.dim())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_manifold_ambient_dim(manifold: *const PmManifold) -> usize {
+    manifold.as_ref().map_or(0, |m| m.inner.ambient_dim())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_manifold_check_point(
+    manifold: *const PmManifold,
+    point: *const f64,
+    len: usize,
+) -> PmStatus {
+    run(|| {
+        let manifold = handle(manifold, "manifold")?;
+        manifold.check_len(len)?;
+        manifold.inner.check_point(slice(point, len, "point")?)?;
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_manifold_project(
+    manifold: *const PmManifold,
+    point: *const f64,
+    len: usize,
+    out: *mut f64,
+) -> PmStatus {
+    run(|| {
+        let manifold = handle(manifold, "manifold")?;
+        manifold.check_len(len)?;
+        let projected = manifold
+            .inner
+            .project_to_manifold(slice(point, len, "point")?)?;
+        slice_mut(out, len, "out")?.copy_from_slice(&projected);
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_manifold_exp(
+    manifold: *const PmManifold,
+    point: *const f64,
+    tangent: *const f64,
+    len: usize,
+    out: *mut f64,
+) -> PmStatus {
+    run(|| {
+        let manifold = handle(manifold, "manifold")?;
+        manifold.check_len(len)?;
+        let tangent = TangentVector::new(slice(tangent, len, "tangent")?.to_vec().into());
+        let result = manifold.inner.exp(slice(point, len, "point")?, &tangent)?;
+        slice_mut(out, len, "out")?.copy_from_slice(&result);
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_manifold_log(
+    manifold: *const PmManifold,
+    point: *const f64,
+    other: *const f64,
+    len: usize,
+    out: *mut f64,
+) -> PmStatus {
+    run(|| {
+        let manifold = handle(manifold, "manifold")?;
+        manifold.check_len(len)?;
+        let result = manifold
+            .inner
+            .log(slice(point, len, "point")?, slice(other, len, "other")?)?;
+        slice_mut(out, len, "out")?.copy_from_slice(result.components.as_slice());
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_manifold_distance(
+    manifold: *const PmManifold,
+    point: *const f64,
+    other: *const f64,
+    len: usize,
+    out: *mut f64,
+) -> PmStatus {
+    run(|| {
+        let manifold = handle(manifold, "manifold")?;
+        manifold.check_len(len)?;
+        let distance = manifold
+            .inner
+            .distance(slice(point, len, "point")?, slice(other, len, "other")?)?;
+        *out.as_mut().ok_or(Failure::NullPointer("out"))? = distance;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::pm_last_error_message;
+    use approx::assert_relative_eq;
+    use std::f64::consts::FRAC_PI_2;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_sphere_round_trip_through_c_api() {
+        unsafe {
+            let sphere = pm_manifold_sphere(2);
+            assert_eq!(pm_manifold_dim(sphere), 2);
+            assert_eq!(pm_manifold_ambient_dim(sphere), 3);
+
+            let north = [0.0, 0.0, 1.0];
+            let east = [1.0, 0.0, 0.0];
+            let mut log = [0.0; 3];
+            let mut back = [0.0; 3];
+            let mut distance = 0.0;
+            assert_eq!(
+                pm_manifold_log(sphere, north.as_ptr(), east.as_ptr(), 3, log.as_mut_ptr()),
+                PmStatus::Ok
+            );
+            assert_eq!(
+                pm_manifold_exp(sphere, north.as_ptr(), log.as_ptr(), 3, back.as_mut_ptr()),
+                PmStatus::Ok
+            );
+            assert_eq!(
+                pm_manifold_distance(sphere, north.as_ptr(), east.as_ptr(), 3, &mut distance),
+                PmStatus::Ok
+            );
+            pm_manifold_free(sphere);
+
+            assert_relative_eq!(distance, FRAC_PI_2, epsilon = 1e-12);
+            for (a, b) in back.iter().zip(east.iter()) {
+                assert_relative_eq!(a, b, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_arguments_report_status() {
+        unsafe {
+            let spd = pm_manifold_spd(2);
+            let point = [1.0, 0.5, 0.0, 1.0];
+            assert_eq!(
+                pm_manifold_check_point(spd, point.as_ptr(), 4),
+                PmStatus::PointNotOnManifold
+            );
+            assert_eq!(
+                pm_manifold_check_point(spd, point.as_ptr(), 3),
+                PmStatus::DimensionMismatch
+            );
+            assert_eq!(
+                pm_manifold_check_point(spd, ptr::null(), 4),
+                PmStatus::NullPointer
+            );
+            assert_eq!(
+                pm_manifold_check_point(ptr::null(), point.as_ptr(), 4),
+                PmStatus::NullPointer
+            );
+            assert_eq!(pm_manifold_dim(ptr::null()), 0);
+            pm_manifold_free(spd);
+            pm_manifold_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_invalid_constructor_arguments_return_null() {
+        unsafe {
+            for manifold in [
+                pm_manifold_stiefel(2, 3),
+                pm_manifold_stiefel(3, 0),
+                pm_manifold_euclidean(0),
+                pm_manifold_sphere(0),
+                pm_manifold_spd(0),
+                pm_manifold_poincare_ball(0),
+            ] {
+                assert!(manifold.is_null());
+                assert!(!pm_last_error_message().is_null());
+            }
+            assert!(pm_manifold_stiefel(2, 3).is_null());
+            let message = CStr::from_ptr(pm_last_error_message()).to_str().unwrap();
+            assert!(message.contains("p <= n"));
+
+            let stiefel = pm_manifold_stiefel(3, 2);
+            assert!(!stiefel.is_null());
+            assert!(pm_last_error_message().is_null());
+            assert_eq!(pm_manifold_ambient_dim(stiefel), 6);
+            pm_manifold_free(stiefel);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::error::{handle, run, slice, slice_mut, Failure, PmStatus};
+use crate::manifold::PmManifold;
+use poly_manifold_autodiff::{GradientDescent, LineSearch, RiemannianOptimizer};
+use std::ffi::c_void;
+
+pub type PmCostFn =
+    Option<unsafe extern "C" fn(point: *const f64, len: usize, user_data: *mut c_void) -> f64>;
+
+pub struct PmOptimizer {
+    inner: GradientDescent,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PmOptimizeResult {
+    pub cost: f64,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+#[no_mangle]
+pub extern "C" fn pm_gradient_descent_new(
+    learning_rate: f64,
+    max_iterations: usize,
+    tolerance: f64,
+) -> *mut PmOptimizer {
+    Box::into_raw(Box::new(PmOptimizer {
+        inner: GradientDescent::new(learning_rate, max_iterations, tolerance),
+    }))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_gradient_descent_enable_line_search(
+    optimizer: *mut PmOptimizer,
+) -> PmStatus {
+    run(|| {
+        let optimizer = optimizer
+            .as_mut()
+            .ok_or(Failure::NullPointer("optimizer"))?;
+        optimizer.inner.line_search = Some(LineSearch::armijo());
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_optimizer_free(optimizer: *mut PmOptimizer) {
+    if !optimizer.is_null() {
+        drop(Box::from_raw(optimizer));
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pm_optimizer_minimize(
+    optimizer: *const PmOptimizer,
+    manifold: *const PmManifold,
+    initial_point: *const f64,
+    len: usize,
+    cost: PmCostFn,
+    user_data: *mut c_void,
+    out_point: *mut f64,
+    out_result: *mut PmOptimizeResult,
+) -> PmStatus {
+    run(|| {
+        let optimizer = handle(optimizer, "optimizer")?;
+        let manifold = handle(manifold, "manifold")?;
+        let cost = cost.ok_or(Failure::NullPointer("cost"))?;
+        manifold.check_len(len)?;
+        let initial_point = slice(initial_point, len, "initial_point")?;
+        let out_point = slice_mut(out_point, len, "out_point")?;
+
+        let cost_function = |point: &[f64]| unsafe { cost(point.as_ptr(), point.len(), user_data) };
+        let result =
+            optimizer
+                .inner
+                .minimize(&manifold.inner.as_ref(), initial_point, cost_function)?;
+
+        out_point.copy_from_slice(&result.point);
+        if let Some(out_result) = out_result.as_mut() {
+            *out_result = PmOptimizeResult {
+                cost: result.cost,
+                iterations: result.iterations,
+                converged: result.converged(),
+            };
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::{pm_manifold_free, pm_manifold_sphere};
+    use approx::assert_relative_eq;
+    use std::ptr;
+
+    unsafe extern "C" fn height(point: *const f64, len: usize, user_data: *mut c_void) -> f64 {
+        let target = &*(user_data as *const [f64; 3]);
+        let point = std::slice::from_raw_parts(point, len);
+        -point.iter().zip(target).map(|(p, t)| p * t).sum::<f64>()
+    }
+
+    #[test]
+    fn test_minimize_through_c_api() {
+        unsafe {
+            let sphere = pm_manifold_sphere(2);
+            let optimizer = pm_gradient_descent_new(0.1, 1000, 1e-10);
+            assert_eq!(
+                pm_gradient_descent_enable_line_search(optimizer),
+                PmStatus::Ok
+            );
+
+            let mut target = [0.0, 0.6, 0.8];
+            let initial = [1.0, 0.0, 0.0];
+            let mut point = [0.0; 3];
+            let mut result = PmOptimizeResult::default();
+            let status = pm_optimizer_minimize(
+                optimizer,
+                sphere,
+                initial.as_ptr(),
+                3,
+                Some(height),
+                target.as_mut_ptr() as *mut c_void,
+                point.as_mut_ptr(),
+                &mut result,
+            );
+            pm_optimizer_free(optimizer);
+            pm_manifold_free(sphere);
+
+            assert_eq!(status, PmStatus::Ok);
+            assert!(result.converged);
+            assert_relative_eq!(result.cost, -1.0, epsilon = 1e-8);
+            for (p, t) in point.iter().zip(target.iter()) {
+                assert_relative_eq!(p, t, epsilon = 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_minimize_rejects_missing_cost() {
+        unsafe {
+            let sphere = pm_manifold_sphere(2);
+            let optimizer = pm_gradient_descent_new(0.1, 10, 1e-10);
+            let initial = [1.0, 0.0, 0.0];
+            let mut point = [0.0; 3];
+            let status = pm_optimizer_minimize(
+                optimizer,
+                sphere,
+                initial.as_ptr(),
+                3,
+                None,
+                ptr::null_mut(),
+                point.as_mut_ptr(),
+                ptr::null_mut(),
+            );
+            pm_optimizer_free(optimizer);
+            pm_manifold_free(sphere);
+            assert_eq!(status, PmStatus::NullPointer);
+        }
+    }
+}
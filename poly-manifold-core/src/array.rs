@@ -0,0 +1,136 @@
+use crate::batch::batch_len;
+use crate::{ManifoldError, Result, TangentVector};
+use nalgebra::{DVector, RealField};
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2, ArrayViewMut2, Axis};
+
+fn layout_error() -> ManifoldError {
+    ManifoldError::InvalidParameter("Array must be contiguous in row-major order".to_string())
+}
+
+impl<T: RealField> TangentVector<T> {
+    pub fn view(&self) -> ArrayView1<'_, T> {
+        ArrayView1::from(self.components.as_slice())
+    }
+
+    pub fn from_array(array: Array1<T>) -> Self {
+        let values = if array.is_standard_layout() {
+            let len = array.len();
+            let (mut values, offset) = array.into_raw_vec_and_offset();
+            let offset = offset.unwrap_or(0);
+            values.truncate(offset + len);
+            values.drain(..offset);
+            values
+        } else {
+            array.into_iter().collect()
+        };
+        Self::new(DVector::from_vec(values))
+    }
+
+    pub fn into_array(self) -> Array1<T> {
+        Array1::from_vec(self.components.data.into())
+    }
+}
+
+pub fn batch_view(points: &[f64], ambient_dim: usize) -> Result<ArrayView2<'_, f64>> {
+    let count = batch_len(ambient_dim, &[points.len()])?;
+    ArrayView2::from_shape((count, ambient_dim), points).map_err(|_| layout_error())
+}
+
+pub fn batch_view_mut(points: &mut [f64], ambient_dim: usize) -> Result<ArrayViewMut2<'_, f64>> {
+    let count = batch_len(ambient_dim, &[points.len()])?;
+    ArrayViewMut2::from_shape((count, ambient_dim), points).map_err(|_| layout_error())
+}
+
+pub fn batch_slice<'a>(array: &ArrayView2<'a, f64>) -> Result<&'a [f64]> {
+    array.to_slice().ok_or_else(layout_error)
+}
+
+pub fn points_from_array(array: ArrayView2<'_, f64>) -> Vec<Vec<f64>> {
+    array.axis_iter(Axis(0)).map(|row| row.to_vec()).collect()
+}
+
+pub fn points_to_array(points: &[Vec<f64>]) -> Result<Array2<f64>> {
+    let dim = points.first().map_or(0, Vec::len);
+    if let Some(point) = points.iter().find(|p| p.len() != dim) {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: dim,
+            got: point.len(),
+        });
+    }
+    let values = points.iter().flatten().copied().collect();
+    Array2::from_shape_vec((points.len(), dim), values).map_err(|_| layout_error())
+}
+
+pub fn tangents_from_array(array: ArrayView2<'_, f64>) -> Vec<TangentVector<f64>> {
+    array
+        .axis_iter(Axis(0))
+        .map(|row| TangentVector::new(DVector::from_iterator(row.len(), row.iter().copied())))
+        .collect()
+}
+
+pub fn tangents_to_array(tangents: &[TangentVector<f64>]) -> Result<Array2<f64>> {
+    let dim = tangents.first().map_or(0, TangentVector::dim);
+    if let Some(tangent) = tangents.iter().find(|v| v.dim() != dim) {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: dim,
+            got: tangent.dim(),
+        });
+    }
+    let mut array = Array2::zeros((tangents.len(), dim));
+    for (mut row, tangent) in array.axis_iter_mut(Axis(0)).zip(tangents) {
+        row.assign(&tangent.view());
+    }
+    Ok(array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, s};
+
+    #[test]
+    fn test_tangent_vector_array_round_trip() {
+        let v = TangentVector::new(DVector::from_vec(vec![1.0, -2.0, 0.5]));
+        assert_eq!(v.view(), array![1.0, -2.0, 0.5]);
+        let array = v.clone().into_array();
+        assert_eq!(TangentVector::from_array(array).components, v.components);
+
+        let strided = array![0.0, 1.0, 2.0, 3.0, 4.0].slice_move(s![1..;2]);
+        let w = TangentVector::from_array(strided);
+        assert_eq!(w.components.as_slice(), &[1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_batch_view_shares_buffer() {
+        let mut points = vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+        {
+            let view = batch_view(&points, 3).unwrap();
+            assert_eq!(view.dim(), (2, 3));
+            assert_eq!(view[[1, 1]], 1.0);
+            assert_eq!(batch_slice(&view).unwrap().as_ptr(), points.as_ptr());
+        }
+        batch_view_mut(&mut points, 3).unwrap()[[0, 2]] = 5.0;
+        assert_eq!(points[2], 5.0);
+        assert!(batch_view(&points, 4).is_err());
+    }
+
+    #[test]
+    fn test_batch_slice_rejects_non_contiguous_views() {
+        let array = array![[1.0, 2.0], [3.0, 4.0]];
+        assert!(batch_slice(&array.view()).is_ok());
+        assert!(batch_slice(&array.t()).is_err());
+    }
+
+    #[test]
+    fn test_points_and_tangents_conversions() {
+        let points = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        let array = points_to_array(&points).unwrap();
+        assert_eq!(array, array![[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(points_from_array(array.view()), points);
+        assert!(points_to_array(&[vec![1.0], vec![1.0, 2.0]]).is_err());
+
+        let tangents = tangents_from_array(array.t());
+        assert_eq!(tangents[1].components.as_slice(), &[2.0, 4.0]);
+        assert_eq!(tangents_to_array(&tangents).unwrap(), array.t());
+    }
+}
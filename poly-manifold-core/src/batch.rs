@@ -0,0 +1,120 @@
+use crate::tangent::TangentView;
+use crate::{Manifold, ManifoldError, Result, TangentVector};
+use nalgebra::DVector;
+
+pub fn batch_len(ambient_dim: usize, lengths: &[usize]) -> Result<usize> {
+    let first = lengths.first().copied().unwrap_or(0);
+    if ambient_dim == 0 || first % ambient_dim != 0 {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: ambient_dim,
+            got: first,
+        });
+    }
+    if let Some(len) = lengths.iter().find(|len| **len != first) {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: first,
+            got: *len,
+        });
+    }
+    Ok(first / ambient_dim)
+}
+
+pub fn tangent_views(
+    tangents: &[f64],
+    ambient_dim: usize,
+) -> Result<impl ExactSizeIterator<Item = TangentView<'_, f64>>> {
+    batch_len(ambient_dim, &[tangents.len()])?;
+    Ok(tangents
+        .chunks_exact(ambient_dim)
+        .map(TangentView::from_slice))
+}
+
+pub trait BatchManifold: Manifold<Scalar = f64> {
+    fn exp_batch(&self, points: &[f64], tangents: &[f64], out: &mut [f64]) -> Result<()> {
+        let n = self.ambient_dim();
+        batch_len(n, &[points.len(), tangents.len(), out.len()])?;
+        for ((point, tangent), out) in points
+            .chunks_exact(n)
+            .zip(tangents.chunks_exact(n))
+            .zip(out.chunks_exact_mut(n))
+        {
+            let tangent = TangentVector::new(DVector::from_column_slice(tangent));
+            out.copy_from_slice(&self.exp(point, &tangent)?);
+        }
+        Ok(())
+    }
+
+    fn log_batch(&self, points: &[f64], others: &[f64], out: &mut [f64]) -> Result<()> {
+        let n = self.ambient_dim();
+        batch_len(n, &[points.len(), others.len(), out.len()])?;
+        for ((point, other), out) in points
+            .chunks_exact(n)
+            .zip(others.chunks_exact(n))
+            .zip(out.chunks_exact_mut(n))
+        {
+            out.copy_from_slice(self.log(point, other)?.components.as_slice());
+        }
+        Ok(())
+    }
+
+    fn distance_batch(&self, points: &[f64], others: &[f64], out: &mut [f64]) -> Result<()> {
+        let n = self.ambient_dim();
+        batch_len(n, &[points.len(), others.len(), n * out.len()])?;
+        for ((point, other), out) in points
+            .chunks_exact(n)
+            .zip(others.chunks_exact(n))
+            .zip(out.iter_mut())
+        {
+            *out = self.distance(point, other)?;
+        }
+        Ok(())
+    }
+
+    fn sgd_step_batch(
+        &self,
+        points: &[f64],
+        euclidean_gradients: &[f64],
+        learning_rate: f64,
+        out: &mut [f64],
+    ) -> Result<()> {
+        let n = self.ambient_dim();
+        batch_len(n, &[points.len(), euclidean_gradients.len(), out.len()])?;
+        for ((point, gradient), out) in points
+            .chunks_exact(n)
+            .zip(euclidean_gradients.chunks_exact(n))
+            .zip(out.chunks_exact_mut(n))
+        {
+            let gradient = TangentVector::new(DVector::from_column_slice(gradient));
+            let step = self.euclidean_to_riemannian_gradient(point, &gradient)? * -learning_rate;
+            out.copy_from_slice(&self.exp(point, &step)?);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_len() {
+        assert_eq!(batch_len(3, &[6, 6, 6]).unwrap(), 2);
+        assert_eq!(batch_len(3, &[0, 0]).unwrap(), 0);
+        assert!(batch_len(3, &[7, 7]).is_err());
+        assert!(batch_len(3, &[6, 9]).is_err());
+        assert!(batch_len(0, &[0]).is_err());
+    }
+
+    #[test]
+    fn test_tangent_views_borrow_rows() {
+        let tangents = [3.0, 4.0, 0.0, 1.0, 2.0, 2.0];
+        let norms: Vec<f64> = tangent_views(&tangents, 3)
+            .unwrap()
+            .map(|v| v.norm())
+            .collect();
+        assert_eq!(norms, vec![5.0, 3.0]);
+        let second = tangent_views(&tangents, 3).unwrap().nth(1).unwrap();
+        assert!(core::ptr::eq(second.as_slice(), &tangents[3..]));
+        assert!(tangent_views(&tangents, 4).is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{Manifold, Result, TangentVector};
+use alloc::vec::Vec;
+
+pub trait CachedManifold: Manifold {
+    type CachedPoint;
+
+    fn cache_point(&self, point: &[Self::Scalar]) -> Result<Self::CachedPoint>;
+
+    fn exp_cached(
+        &self,
+        point: &Self::CachedPoint,
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>>;
+
+    fn log_cached(
+        &self,
+        point: &Self::CachedPoint,
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>>;
+
+    fn inner_product_cached(
+        &self,
+        point: &Self::CachedPoint,
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar>;
+
+    fn distance_cached(&self, a: &Self::CachedPoint, b: &Self::CachedPoint)
+        -> Result<Self::Scalar>;
+}
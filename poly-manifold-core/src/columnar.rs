@@ -0,0 +1,203 @@
+use crate::validation::validate_entry_points;
+use crate::{Manifold, ManifoldError, Result};
+use arrow::array::{Array, ArrayRef, FixedSizeListArray, Float64Array, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use nalgebra::DMatrix;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::file::reader::ChunkReader;
+use std::fmt::Display;
+use std::io::Write;
+use std::sync::Arc;
+
+pub const POINT_COLUMN: &str = "point";
+
+pub fn arrow_error(error: impl Display) -> ManifoldError {
+    ManifoldError::IoError(error.to_string())
+}
+
+pub fn point_column(points: &[Vec<f64>]) -> Result<FixedSizeListArray> {
+    let width = points.first().map_or(0, Vec::len);
+    let mut values = Vec::with_capacity(points.len() * width);
+    for point in points {
+        if point.len() != width {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: width,
+                got: point.len(),
+            });
+        }
+        values.extend_from_slice(point);
+    }
+    let size = i32::try_from(width).map_err(arrow_error)?;
+    FixedSizeListArray::try_new(
+        Arc::new(Field::new("item", DataType::Float64, false)),
+        size,
+        Arc::new(Float64Array::from(values)),
+        None,
+    )
+    .map_err(arrow_error)
+}
+
+pub fn points_to_record_batch(points: &[Vec<f64>]) -> Result<RecordBatch> {
+    let column = point_column(points)?;
+    let schema = Schema::new(vec![Field::new(
+        POINT_COLUMN,
+        column.data_type().clone(),
+        false,
+    )]);
+    RecordBatch::try_new(Arc::new(schema), vec![Arc::new(column) as ArrayRef]).map_err(arrow_error)
+}
+
+pub fn points_from_record_batch(batch: &RecordBatch, column: &str) -> Result<Vec<Vec<f64>>> {
+    let list = batch
+        .column_by_name(column)
+        .and_then(|array| array.as_any().downcast_ref::<FixedSizeListArray>())
+        .ok_or_else(|| {
+            ManifoldError::InvalidParameter(format!(
+                "Expected a fixed-size list column named {column}"
+            ))
+        })?;
+    let values = list
+        .values()
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .ok_or_else(|| {
+            ManifoldError::InvalidParameter(format!("Column {column} does not hold float64 values"))
+        })?;
+    let width = list.value_length() as usize;
+    let start = list.offset() * width;
+    Ok(values.values()[start..start + list.len() * width]
+        .chunks(width.max(1))
+        .take(list.len())
+        .map(<[f64]>::to_vec)
+        .collect())
+}
+
+pub fn distances_to_record_batch(distances: &DMatrix<f64>) -> Result<RecordBatch> {
+    let (rows, cols) = distances.shape();
+    let row =
+        UInt64Array::from_iter_values((0..rows).flat_map(|i| (0..cols).map(move |_| i as u64)));
+    let col = UInt64Array::from_iter_values((0..rows).flat_map(|_| (0..cols).map(|j| j as u64)));
+    let distance = Float64Array::from_iter_values(
+        (0..rows).flat_map(|i| (0..cols).map(move |j| distances[(i, j)])),
+    );
+    let schema = Schema::new(vec![
+        Field::new("row", DataType::UInt64, false),
+        Field::new("col", DataType::UInt64, false),
+        Field::new("distance", DataType::Float64, false),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![Arc::new(row), Arc::new(col), Arc::new(distance)],
+    )
+    .map_err(arrow_error)
+}
+
+pub fn write_parquet<W: Write + Send>(writer: W, batches: &[RecordBatch]) -> Result<()> {
+    let schema = batches.first().map(RecordBatch::schema).ok_or_else(|| {
+        ManifoldError::InvalidParameter("Cannot write an empty list of record batches".to_string())
+    })?;
+    let mut writer = ArrowWriter::try_new(writer, schema, None).map_err(arrow_error)?;
+    for batch in batches {
+        writer.write(batch).map_err(arrow_error)?;
+    }
+    writer.close().map_err(arrow_error)?;
+    Ok(())
+}
+
+pub fn read_parquet<R: ChunkReader + 'static>(reader: R) -> Result<Vec<RecordBatch>> {
+    ParquetRecordBatchReaderBuilder::try_new(reader)
+        .and_then(|builder| builder.build())
+        .map_err(arrow_error)?
+        .map(|batch| batch.map_err(arrow_error))
+        .collect()
+}
+
+pub fn read_points_parquet<M, R>(manifold: &M, reader: R) -> Result<Vec<Vec<f64>>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+    R: ChunkReader + 'static,
+{
+    let mut points = Vec::new();
+    for batch in read_parquet(reader)? {
+        points.extend(points_from_record_batch(&batch, POINT_COLUMN)?);
+    }
+    if let Some(point) = points.iter().find(|p| p.len() != manifold.ambient_dim()) {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: manifold.ambient_dim(),
+            got: point.len(),
+        });
+    }
+    validate_entry_points(manifold, &points)?;
+    Ok(points)
+}
+
+pub fn write_points_parquet<W: Write + Send>(writer: W, points: &[Vec<f64>]) -> Result<()> {
+    write_parquet(writer, &[points_to_record_batch(points)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+    use bytes::Bytes;
+
+    fn points() -> Vec<Vec<f64>> {
+        vec![vec![1.0, 0.0, 2.5], vec![0.6, 0.8, -1.0]]
+    }
+
+    #[test]
+    fn test_points_parquet_round_trip() {
+        let mut buffer = Vec::new();
+        write_points_parquet(&mut buffer, &points()).unwrap();
+        assert!(buffer.starts_with(b"PAR1"));
+        let manifold = TestEuclideanManifold { dimension: 3 };
+        assert_eq!(
+            read_points_parquet(&manifold, Bytes::from(buffer.clone())).unwrap(),
+            points()
+        );
+
+        let wrong = TestEuclideanManifold { dimension: 2 };
+        assert!(matches!(
+            read_points_parquet(&wrong, Bytes::from(buffer)),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_point_column_rejects_ragged_points() {
+        assert!(point_column(&[vec![1.0, 2.0], vec![1.0]]).is_err());
+        let sliced = points_to_record_batch(&points()).unwrap().slice(1, 1);
+        assert_eq!(
+            points_from_record_batch(&sliced, POINT_COLUMN).unwrap(),
+            vec![points()[1].clone()]
+        );
+        assert!(points_from_record_batch(&sliced, "missing").is_err());
+    }
+
+    #[test]
+    fn test_distances_record_batch_is_long_format() {
+        let distances = DMatrix::from_row_slice(2, 2, &[0.0, 1.5, 1.5, 0.0]);
+        let batch = distances_to_record_batch(&distances).unwrap();
+        assert_eq!(batch.num_rows(), 4);
+        let values = batch
+            .column_by_name("distance")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(values.values().as_ref(), &[0.0, 1.5, 1.5, 0.0]);
+        let cols = batch
+            .column_by_name("col")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap();
+        assert_eq!(cols.values().as_ref(), &[0, 1, 0, 1]);
+        assert!(write_parquet(Vec::new(), &[]).is_err());
+    }
+}
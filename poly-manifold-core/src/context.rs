@@ -0,0 +1,420 @@
+use crate::random::SeededRng;
+#[cfg(feature = "std")]
+use crate::validation::with_validation_level;
+use crate::validation::{set_validation_level, validation_level, ValidationLevel};
+#[cfg(feature = "std")]
+use crate::{Manifold, TangentVector};
+use crate::{ManifoldError, Result};
+use alloc::format;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Context {
+    pub tolerance_scale: f64,
+    pub validation: ValidationLevel,
+    pub seed: u64,
+    pub threads: Option<usize>,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self {
+            tolerance_scale: 1.0,
+            validation: ValidationLevel::Strict,
+            seed: 0,
+            threads: None,
+        }
+    }
+}
+
+static GLOBAL_TOLERANCE_SCALE: AtomicU64 = AtomicU64::new(f64::to_bits(1.0));
+static GLOBAL_SEED: AtomicU64 = AtomicU64::new(0);
+static GLOBAL_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static SCOPED_CONTEXT: Cell<Option<Context>> = const { Cell::new(None) };
+}
+
+fn global_context() -> Context {
+    Context {
+        tolerance_scale: f64::from_bits(GLOBAL_TOLERANCE_SCALE.load(Ordering::Relaxed)),
+        validation: validation_level(),
+        seed: GLOBAL_SEED.load(Ordering::Relaxed),
+        threads: match GLOBAL_THREADS.load(Ordering::Relaxed) {
+            0 => None,
+            threads => Some(threads),
+        },
+    }
+}
+
+#[cfg(feature = "std")]
+fn scoped_context() -> Option<Context> {
+    SCOPED_CONTEXT.with(Cell::get)
+}
+
+#[cfg(not(feature = "std"))]
+fn scoped_context() -> Option<Context> {
+    None
+}
+
+pub fn tolerance_scale() -> f64 {
+    match scoped_context() {
+        Some(context) => context.tolerance_scale,
+        None => f64::from_bits(GLOBAL_TOLERANCE_SCALE.load(Ordering::Relaxed)),
+    }
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_tolerance_scale(mut self, scale: f64) -> Self {
+        self.tolerance_scale = scale;
+        self
+    }
+
+    pub fn with_validation(mut self, level: ValidationLevel) -> Self {
+        self.validation = level;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !self.tolerance_scale.is_finite() || self.tolerance_scale <= 0.0 {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Context tolerance scale must be positive and finite, got {}",
+                self.tolerance_scale
+            )));
+        }
+        if self.threads == Some(0) {
+            return Err(ManifoldError::InvalidParameter(
+                "Context thread count must be positive".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn rng(&self) -> SeededRng {
+        SeededRng::seed_from_u64(self.seed)
+    }
+
+    pub fn current() -> Self {
+        let mut context = scoped_context().unwrap_or_else(global_context);
+        context.validation = validation_level();
+        context
+    }
+
+    pub fn install(self) -> Result<Self> {
+        self.validate()?;
+        let previous = global_context();
+        GLOBAL_TOLERANCE_SCALE.store(self.tolerance_scale.to_bits(), Ordering::Relaxed);
+        GLOBAL_SEED.store(self.seed, Ordering::Relaxed);
+        GLOBAL_THREADS.store(self.threads.unwrap_or(0), Ordering::Relaxed);
+        set_validation_level(self.validation);
+        Ok(previous)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn scope<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        struct Restore(Option<Context>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                SCOPED_CONTEXT.with(|scoped| scoped.set(self.0));
+            }
+        }
+
+        let _restore = Restore(SCOPED_CONTEXT.with(|scoped| scoped.replace(Some(*self))));
+        with_validation_level(self.validation, f)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn wrap<T>(self, inner: T) -> Result<Contextual<T>> {
+        Contextual::new(inner, self)
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contextual<T> {
+    pub inner: T,
+    pub context: Context,
+}
+
+#[cfg(feature = "std")]
+impl<T> Contextual<T> {
+    pub fn new(inner: T, context: Context) -> Result<Self> {
+        context.validate()?;
+        Ok(Self { inner, context })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M: Manifold> Manifold for Contextual<M> {
+    type Scalar = M::Scalar;
+
+    fn dim(&self) -> usize {
+        self.inner.dim()
+    }
+
+    fn ambient_dim(&self) -> usize {
+        self.inner.ambient_dim()
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.context.scope(|| self.inner.check_point(point))
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.context
+            .scope(|| self.inner.check_tangent_vector(point, tangent))
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.context.scope(|| self.inner.project_to_manifold(point))
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.context
+            .scope(|| self.inner.project_to_tangent_space(point, vector))
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.context.scope(|| self.inner.exp(point, tangent))
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.context.scope(|| self.inner.log(point, other))
+    }
+
+    fn inner_product(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.context
+            .scope(|| self.inner.inner_product(point, v1, v2))
+    }
+
+    fn norm(
+        &self,
+        point: &[Self::Scalar],
+        v: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.context.scope(|| self.inner.norm(point, v))
+    }
+
+    fn exp_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.context
+            .scope(|| self.inner.exp_unchecked(point, tangent))
+    }
+
+    fn log_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.context
+            .scope(|| self.inner.log_unchecked(point, other))
+    }
+
+    fn inner_product_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.context
+            .scope(|| self.inner.inner_product_unchecked(point, v1, v2))
+    }
+
+    fn exp_unchecked_into(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        out: &mut Vec<Self::Scalar>,
+    ) -> Result<()> {
+        self.context
+            .scope(|| self.inner.exp_unchecked_into(point, tangent, out))
+    }
+
+    fn project_to_tangent_space_in_place(
+        &self,
+        point: &[Self::Scalar],
+        vector: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.context
+            .scope(|| self.inner.project_to_tangent_space_in_place(point, vector))
+    }
+
+    fn retraction(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.context.scope(|| self.inner.retraction(point, tangent))
+    }
+
+    fn distance(&self, point1: &[Self::Scalar], point2: &[Self::Scalar]) -> Result<Self::Scalar> {
+        self.context.scope(|| self.inner.distance(point1, point2))
+    }
+
+    fn geodesic(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        t: Self::Scalar,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.context
+            .scope(|| self.inner.geodesic(point, tangent, t))
+    }
+
+    fn parallel_transport(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        direction: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.context
+            .scope(|| self.inner.parallel_transport(point, tangent, direction))
+    }
+
+    fn euclidean_to_riemannian_gradient(
+        &self,
+        point: &[Self::Scalar],
+        euclidean_gradient: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.context.scope(|| {
+            self.inner
+                .euclidean_to_riemannian_gradient(point, euclidean_gradient)
+        })
+    }
+
+    fn euclidean_to_riemannian_gradient_in_place(
+        &self,
+        point: &[Self::Scalar],
+        gradient: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.context.scope(|| {
+            self.inner
+                .euclidean_to_riemannian_gradient_in_place(point, gradient)
+        })
+    }
+
+    fn log_volume_change(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Option<Self::Scalar> {
+        self.context
+            .scope(|| self.inner.log_volume_change(point, tangent))
+    }
+
+    fn tangent_basis(&self, point: &[Self::Scalar]) -> Result<Vec<TangentVector<Self::Scalar>>> {
+        self.context.scope(|| self.inner.tangent_basis(point))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+    use crate::precision::tolerance;
+    use crate::random::RandomSource;
+
+    #[test]
+    fn test_scope_overrides_and_restores_the_current_context() {
+        let outer = Context::current();
+        let context = Context::new()
+            .with_tolerance_scale(10.0)
+            .with_validation(ValidationLevel::Off)
+            .with_seed(7)
+            .with_threads(2);
+
+        context.scope(|| {
+            assert_eq!(Context::current(), context);
+            assert_eq!(tolerance_scale(), 10.0);
+            assert_eq!(tolerance::<f64>(1e-10), 1e-9);
+            assert_eq!(validation_level(), ValidationLevel::Off);
+            let nested = Context::new().with_validation(ValidationLevel::OnEntry);
+            nested.scope(|| assert_eq!(tolerance_scale(), 1.0));
+            assert_eq!(tolerance_scale(), 10.0);
+        });
+        assert_eq!(Context::current(), outer);
+        assert_eq!(
+            context.rng().next_u64(),
+            SeededRng::seed_from_u64(7).next_u64()
+        );
+    }
+
+    #[test]
+    fn test_invalid_contexts_are_rejected() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        assert!(matches!(
+            Context::new().with_tolerance_scale(0.0).validate(),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            Context::new().with_tolerance_scale(f64::NAN).install(),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        assert!(Context::new().with_threads(0).wrap(manifold).is_err());
+    }
+
+    #[test]
+    fn test_contextual_manifold_runs_operations_in_its_context() {
+        let manifold = Context::new()
+            .with_tolerance_scale(1e3)
+            .wrap(TestEuclideanManifold { dimension: 2 })
+            .unwrap();
+        let tangent = TangentVector::from(vec![0.5, -1.0]);
+        assert_eq!(manifold.exp(&[1.0, 2.0], &tangent).unwrap(), vec![1.5, 1.0]);
+        assert_eq!(manifold.dim(), 2);
+        assert_eq!(tolerance_scale(), 1.0);
+    }
+}
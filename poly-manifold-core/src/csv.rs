@@ -0,0 +1,127 @@
+use crate::validation::validate_entry_points;
+use crate::{Manifold, ManifoldError, Result};
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+pub fn write_record<W, T>(writer: &mut W, fields: impl IntoIterator<Item = T>) -> Result<()>
+where
+    W: Write + ?Sized,
+    T: Display,
+{
+    let mut first = true;
+    for field in fields {
+        if !first {
+            writer.write_all(b",")?;
+        }
+        write!(writer, "{}", field)?;
+        first = false;
+    }
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+pub fn parse_field<T: FromStr>(field: &str, line: usize) -> Result<T> {
+    let trimmed = field.trim().trim_matches('"');
+    trimmed.parse().map_err(|_| {
+        ManifoldError::InvalidParameter(format!("Line {}: cannot parse field '{}'", line, trimmed))
+    })
+}
+
+pub fn read_records<R: BufRead>(reader: R) -> Result<Vec<(usize, String)>> {
+    let mut records = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            records.push((index + 1, line));
+        }
+    }
+    Ok(records)
+}
+
+pub fn write_points_csv<W: Write>(mut writer: W, points: &[Vec<f64>]) -> Result<()> {
+    let dim = points.first().map_or(0, Vec::len);
+    write_record(&mut writer, (0..dim).map(|i| format!("x{}", i)))?;
+    for point in points {
+        if point.len() != dim {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: dim,
+                got: point.len(),
+            });
+        }
+        write_record(&mut writer, point)?;
+    }
+    Ok(())
+}
+
+pub fn read_points_csv<M, R>(manifold: &M, reader: R) -> Result<Vec<Vec<f64>>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+    R: BufRead,
+{
+    let mut points = Vec::new();
+    for (position, (line, record)) in read_records(reader)?.into_iter().enumerate() {
+        let point = record
+            .split(',')
+            .map(|field| parse_field(field, line))
+            .collect::<Result<Vec<f64>>>();
+        match point {
+            Ok(point) => points.push(point),
+            Err(_) if position == 0 => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    validate_entry_points(manifold, &points)?;
+    Ok(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+
+    #[test]
+    fn test_points_csv_round_trip() {
+        let points = vec![vec![1.5, -2.0, 0.1], vec![1e-300, f64::MAX, 3.0]];
+        let mut buffer = Vec::new();
+        write_points_csv(&mut buffer, &points).unwrap();
+        let text = String::from_utf8(buffer.clone()).unwrap();
+        assert!(text.starts_with("x0,x1,x2\n1.5,-2,0.1\n"));
+
+        let manifold = TestEuclideanManifold { dimension: 3 };
+        assert_eq!(
+            read_points_csv(&manifold, buffer.as_slice()).unwrap(),
+            points
+        );
+    }
+
+    #[test]
+    fn test_read_points_csv_accepts_headerless_pandas_output() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        let text = "1.0,2.0\r\n\r\n3.0 , 4.0\r\n";
+        assert_eq!(
+            read_points_csv(&manifold, text.as_bytes()).unwrap(),
+            vec![vec![1.0, 2.0], vec![3.0, 4.0]]
+        );
+        let quoted = "\"a\",\"b\"\n5,6\n";
+        assert_eq!(
+            read_points_csv(&manifold, quoted.as_bytes()).unwrap(),
+            vec![vec![5.0, 6.0]]
+        );
+    }
+
+    #[test]
+    fn test_read_points_csv_reports_bad_rows() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        let error = read_points_csv(&manifold, "x0,x1\n1,2\n3,oops\n".as_bytes()).unwrap_err();
+        assert!(error.to_string().contains("Line 3"));
+        assert!(matches!(
+            read_points_csv(&manifold, "1,2\n3,4,5\n".as_bytes()),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        ));
+        assert!(write_points_csv(Vec::new(), &[vec![1.0], vec![1.0, 2.0]]).is_err());
+    }
+}
@@ -0,0 +1,227 @@
+use crate::precision::tolerance;
+use crate::validation::check_finite;
+use crate::{ManifoldError, Result, TangentVector};
+use alloc::{format, vec::Vec};
+use nalgebra::{DVector, RealField};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+}
+
+pub trait EmbeddedSubmanifold {
+    type Scalar: RealField + Copy;
+
+    fn manifold_dim(&self) -> usize;
+
+    fn embedding_dim(&self) -> usize;
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>>;
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>>;
+
+    fn tolerance(&self) -> Self::Scalar {
+        tolerance(1e-8)
+    }
+
+    fn log_max_iterations(&self) -> usize {
+        100
+    }
+}
+
+fn check_len(expected: usize, got: usize) -> Result<()> {
+    if got != expected {
+        return Err(ManifoldError::DimensionMismatch { expected, got });
+    }
+    Ok(())
+}
+
+fn max_abs_difference<T: RealField + Copy>(a: &[T], b: &[T]) -> T {
+    a.iter()
+        .zip(b)
+        .fold(T::zero(), |max, (x, y)| max.max((*x - *y).abs()))
+}
+
+pub fn check_point<E: EmbeddedSubmanifold + ?Sized>(
+    manifold: &E,
+    point: &[E::Scalar],
+) -> Result<()> {
+    check_len(manifold.embedding_dim(), point.len())?;
+    check_finite(point, "Point")?;
+    let projected = manifold.project_to_manifold(point)?;
+    let deviation = max_abs_difference(point, &projected);
+    if deviation > manifold.tolerance() {
+        return Err(ManifoldError::PointNotOnManifold {
+            reason: format!("Point is {deviation} away from its projection onto the manifold"),
+        });
+    }
+    Ok(())
+}
+
+pub fn check_tangent_vector<E: EmbeddedSubmanifold + ?Sized>(
+    manifold: &E,
+    point: &[E::Scalar],
+    tangent: &TangentVector<E::Scalar>,
+) -> Result<()> {
+    check_len(manifold.embedding_dim(), tangent.dim())?;
+    check_finite(tangent.as_slice(), "Tangent vector")?;
+    let projected = manifold.project_to_tangent_space(point, tangent)?;
+    let deviation = max_abs_difference(tangent.as_slice(), projected.as_slice());
+    if deviation > manifold.tolerance() {
+        return Err(ManifoldError::InvalidTangentVector {
+            reason: format!(
+                "Vector is {deviation} away from its projection onto the tangent space"
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn retraction<E: EmbeddedSubmanifold + ?Sized>(
+    manifold: &E,
+    point: &[E::Scalar],
+    tangent: &TangentVector<E::Scalar>,
+) -> Result<Vec<E::Scalar>> {
+    check_len(manifold.embedding_dim(), point.len())?;
+    check_len(manifold.embedding_dim(), tangent.dim())?;
+    let stepped: Vec<_> = point
+        .iter()
+        .zip(tangent.iter())
+        .map(|(x, v)| *x + *v)
+        .collect();
+    manifold.project_to_manifold(&stepped)
+}
+
+pub fn inverse_retraction<E: EmbeddedSubmanifold + ?Sized>(
+    manifold: &E,
+    point: &[E::Scalar],
+    other: &[E::Scalar],
+) -> Result<TangentVector<E::Scalar>> {
+    check_len(manifold.embedding_dim(), point.len())?;
+    check_len(manifold.embedding_dim(), other.len())?;
+    let difference = |a: &[E::Scalar], b: &[E::Scalar]| {
+        TangentVector::new(DVector::from_iterator(
+            a.len(),
+            a.iter().zip(b).map(|(x, y)| *x - *y),
+        ))
+    };
+
+    let mut tangent = manifold.project_to_tangent_space(point, &difference(other, point))?;
+    for _ in 0..manifold.log_max_iterations() {
+        let reached = retraction(manifold, point, &tangent)?;
+        let residual = difference(other, &reached);
+        if residual.components.amax() <= manifold.tolerance() {
+            return Ok(tangent);
+        }
+        tangent += manifold.project_to_tangent_space(point, &residual)?;
+    }
+    Err(ManifoldError::ConvergenceError {
+        iterations: manifold.log_max_iterations(),
+    })
+}
+
+pub fn inner_product<T: RealField + Copy>(v1: &TangentVector<T>, v2: &TangentVector<T>) -> T {
+    v1.dot(v2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Manifold;
+    use approx::assert_relative_eq;
+    use poly_manifold_derive::EmbeddedManifold;
+
+    #[derive(EmbeddedManifold)]
+    struct Circle {
+        radius: f64,
+    }
+
+    impl EmbeddedSubmanifold for Circle {
+        type Scalar = f64;
+
+        fn manifold_dim(&self) -> usize {
+            1
+        }
+
+        fn embedding_dim(&self) -> usize {
+            2
+        }
+
+        fn project_to_manifold(&self, point: &[f64]) -> Result<Vec<f64>> {
+            let norm = point[0].hypot(point[1]);
+            if norm == 0.0 {
+                return Err(ManifoldError::NumericalError(
+                    "Cannot project the origin onto a circle".to_string(),
+                ));
+            }
+            Ok(point.iter().map(|x| x * self.radius / norm).collect())
+        }
+
+        fn project_to_tangent_space(
+            &self,
+            point: &[f64],
+            vector: &TangentVector<f64>,
+        ) -> Result<TangentVector<f64>> {
+            let normal = TangentVector::from(point.to_vec()).normalized();
+            let radial = vector.dot(&normal);
+            let mut projected = vector.clone();
+            projected.axpy(-radial, &normal);
+            Ok(projected)
+        }
+    }
+
+    #[test]
+    fn test_derived_checks_use_projections() {
+        let circle = Circle { radius: 2.0 };
+        assert_eq!(circle.dim(), 1);
+        assert_eq!(circle.ambient_dim(), 2);
+        assert!(circle.check_point(&[0.0, 2.0]).is_ok());
+        assert!(matches!(
+            circle.check_point(&[0.0, 1.0]),
+            Err(ManifoldError::PointNotOnManifold { .. })
+        ));
+        assert!(matches!(
+            circle.check_point(&[0.0, 2.0, 0.0]),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        ));
+
+        let tangent = TangentVector::from(vec![1.0, 0.0]);
+        assert!(circle.check_tangent_vector(&[0.0, 2.0], &tangent).is_ok());
+        assert!(matches!(
+            circle.check_tangent_vector(&[2.0, 0.0], &tangent),
+            Err(ManifoldError::InvalidTangentVector { .. })
+        ));
+    }
+
+    #[test]
+    fn test_derived_retraction_and_numerical_log_round_trip() {
+        let circle = Circle { radius: 1.0 };
+        let point = [1.0, 0.0];
+        let tangent = TangentVector::from(vec![0.0, 0.75]);
+
+        let other = circle.exp(&point, &tangent).unwrap();
+        assert_relative_eq!(other[0].hypot(other[1]), 1.0, epsilon = 1e-12);
+        assert_eq!(circle.retraction(&point, &tangent).unwrap(), other);
+
+        let recovered = circle.log(&point, &other).unwrap();
+        assert_relative_eq!(recovered[0], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(recovered[1], 0.75, epsilon = 1e-7);
+        assert_relative_eq!(
+            circle
+                .inner_product(&point, &recovered, &recovered)
+                .unwrap(),
+            recovered.dot(&recovered)
+        );
+        assert!(matches!(
+            circle.log(&point, &[-1.0, 0.0]),
+            Err(ManifoldError::ConvergenceError { .. })
+        ));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::Manifold;
+
+pub type PointComparison<'a, T> = dyn FnMut(&[T], &[T]) -> bool + 'a;
+
+pub trait PointEquivalence: Manifold {
+    fn points_equivalent_by(
+        &self,
+        a: &[Self::Scalar],
+        b: &[Self::Scalar],
+        eq: &mut PointComparison<Self::Scalar>,
+    ) -> bool {
+        eq(a, b)
+    }
+
+    fn points_equivalent(&self, a: &[Self::Scalar], b: &[Self::Scalar]) -> bool {
+        self.points_equivalent_by(a, b, &mut |x, y| x == y)
+    }
+
+    #[cfg(feature = "approx")]
+    fn approx_point<'a>(&'a self, point: &'a [Self::Scalar]) -> ApproxPoint<'a, Self> {
+        ApproxPoint {
+            manifold: self,
+            point,
+        }
+    }
+}
+
+impl<M: PointEquivalence + ?Sized> PointEquivalence for &M {
+    fn points_equivalent_by(
+        &self,
+        a: &[Self::Scalar],
+        b: &[Self::Scalar],
+        eq: &mut PointComparison<Self::Scalar>,
+    ) -> bool {
+        (**self).points_equivalent_by(a, b, eq)
+    }
+}
+
+#[cfg(feature = "approx")]
+pub struct ApproxPoint<'a, M: Manifold + ?Sized> {
+    pub manifold: &'a M,
+    pub point: &'a [M::Scalar],
+}
+
+#[cfg(feature = "approx")]
+impl<M: Manifold + ?Sized> core::fmt::Debug for ApproxPoint<'_, M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.point).finish()
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<M: PointEquivalence + ?Sized> PartialEq for ApproxPoint<'_, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.manifold.points_equivalent(self.point, other.point)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<M: PointEquivalence + ?Sized> approx::AbsDiffEq for ApproxPoint<'_, M> {
+    type Epsilon = M::Scalar;
+
+    fn default_epsilon() -> M::Scalar {
+        M::Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: M::Scalar) -> bool {
+        self.manifold
+            .points_equivalent_by(self.point, other.point, &mut |a, b| {
+                a.abs_diff_eq(b, epsilon.clone())
+            })
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<M: PointEquivalence + ?Sized> approx::RelativeEq for ApproxPoint<'_, M> {
+    fn default_max_relative() -> M::Scalar {
+        M::Scalar::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: M::Scalar, max_relative: M::Scalar) -> bool {
+        self.manifold
+            .points_equivalent_by(self.point, other.point, &mut |a, b| {
+                a.relative_eq(b, epsilon.clone(), max_relative.clone())
+            })
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<M: PointEquivalence + ?Sized> approx::UlpsEq for ApproxPoint<'_, M> {
+    fn default_max_ulps() -> u32 {
+        M::Scalar::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: M::Scalar, max_ulps: u32) -> bool {
+        self.manifold
+            .points_equivalent_by(self.point, other.point, &mut |a, b| {
+                a.ulps_eq(b, epsilon.clone(), max_ulps)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+    use crate::{Result, TangentVector};
+
+    const PLANE: TestEuclideanManifold = TestEuclideanManifold { dimension: 2 };
+
+    struct SignedPlane;
+
+    impl PointEquivalence for TestEuclideanManifold {}
+
+    impl PointEquivalence for SignedPlane {
+        fn points_equivalent_by(
+            &self,
+            a: &[f64],
+            b: &[f64],
+            eq: &mut PointComparison<f64>,
+        ) -> bool {
+            let negated: Vec<f64> = b.iter().map(|x| -x).collect();
+            eq(a, b) || eq(a, &negated)
+        }
+    }
+
+    impl Manifold for SignedPlane {
+        type Scalar = f64;
+
+        fn dim(&self) -> usize {
+            PLANE.dim()
+        }
+
+        fn check_point(&self, point: &[f64]) -> Result<()> {
+            PLANE.check_point(point)
+        }
+
+        fn check_tangent_vector(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<()> {
+            PLANE.check_tangent_vector(point, tangent)
+        }
+
+        fn project_to_manifold(&self, point: &[f64]) -> Result<Vec<f64>> {
+            PLANE.project_to_manifold(point)
+        }
+
+        fn project_to_tangent_space(
+            &self,
+            point: &[f64],
+            vector: &TangentVector<f64>,
+        ) -> Result<TangentVector<f64>> {
+            PLANE.project_to_tangent_space(point, vector)
+        }
+
+        fn exp(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<Vec<f64>> {
+            PLANE.exp(point, tangent)
+        }
+
+        fn log(&self, point: &[f64], other: &[f64]) -> Result<TangentVector<f64>> {
+            PLANE.log(point, other)
+        }
+
+        fn inner_product(
+            &self,
+            point: &[f64],
+            v1: &TangentVector<f64>,
+            v2: &TangentVector<f64>,
+        ) -> Result<f64> {
+            PLANE.inner_product(point, v1, v2)
+        }
+    }
+
+    #[test]
+    fn test_points_equivalent_modulo_representation() {
+        assert!(PLANE.points_equivalent(&[1.0, 2.0], &[1.0, 2.0]));
+        assert!(!PLANE.points_equivalent(&[1.0, 2.0], &[-1.0, -2.0]));
+        assert!(SignedPlane.points_equivalent(&[1.0, 2.0], &[-1.0, -2.0]));
+        assert!(!SignedPlane.points_equivalent(&[1.0, 2.0], &[-1.0, 2.0]));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx_point_macros() {
+        use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+        let a = [0.6, 0.8];
+        let b = [-0.6 - 1e-12, -0.8];
+        assert_abs_diff_eq!(
+            SignedPlane.approx_point(&a),
+            SignedPlane.approx_point(&b),
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            SignedPlane.approx_point(&a),
+            SignedPlane.approx_point(&b),
+            epsilon = 1e-10
+        );
+        assert_ulps_eq!(SignedPlane.approx_point(&a), SignedPlane.approx_point(&a));
+        assert_relative_ne!(PLANE.approx_point(&a), PLANE.approx_point(&b));
+        assert_relative_ne!(PLANE.approx_point(&a), PLANE.approx_point(&[0.6]));
+    }
+}
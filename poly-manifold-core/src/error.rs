@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+use alloc::string::String;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -22,6 +24,207 @@ pub enum ManifoldError {
 
     #[error("Linear algebra error: {0}")]
     LinearAlgebraError(String),
+
+    #[error("Backend error: {0}")]
+    BackendError(String),
+
+    #[error("I/O error: {0}")]
+    IoError(String),
+
+    #[error("{context}: {source}")]
+    WithContext {
+        context: ErrorContext,
+        #[source]
+        source: Box<ManifoldError>,
+    },
+}
+
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    DimensionMismatch = 1,
+    PointNotOnManifold = 2,
+    InvalidTangentVector = 3,
+    Numerical = 4,
+    Convergence = 5,
+    InvalidParameter = 6,
+    LinearAlgebra = 7,
+    Backend = 8,
+    Io = 9,
+}
+
+impl ErrorCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::DimensionMismatch => "dimension_mismatch",
+            ErrorCode::PointNotOnManifold => "point_not_on_manifold",
+            ErrorCode::InvalidTangentVector => "invalid_tangent_vector",
+            ErrorCode::Numerical => "numerical",
+            ErrorCode::Convergence => "convergence",
+            ErrorCode::InvalidParameter => "invalid_parameter",
+            ErrorCode::LinearAlgebra => "linear_algebra",
+            ErrorCode::Backend => "backend",
+            ErrorCode::Io => "io",
+        }
+    }
+}
+
+impl core::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub operation: String,
+    pub manifold: Option<String>,
+    pub dimensions: Option<(usize, usize)>,
+}
+
+impl ErrorContext {
+    pub fn new(operation: impl Into<String>) -> Self {
+        Self {
+            operation: operation.into(),
+            manifold: None,
+            dimensions: None,
+        }
+    }
+
+    pub fn on(mut self, manifold: impl Into<String>) -> Self {
+        self.manifold = Some(manifold.into());
+        self
+    }
+
+    pub fn with_dimensions(mut self, expected: usize, got: usize) -> Self {
+        self.dimensions = Some((expected, got));
+        self
+    }
+}
+
+impl core::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.operation)?;
+        if let Some(manifold) = &self.manifold {
+            write!(f, " on {manifold}")?;
+        }
+        if let Some((expected, got)) = self.dimensions {
+            write!(f, " (expected dimension {expected}, got {got})")?;
+        }
+        Ok(())
+    }
 }
 
-pub type Result<T> = std::result::Result<T, ManifoldError>;
+impl ManifoldError {
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        ManifoldError::WithContext {
+            context,
+            source: Box::new(self),
+        }
+    }
+
+    pub fn root_cause(&self) -> &ManifoldError {
+        match self {
+            ManifoldError::WithContext { source, .. } => source.root_cause(),
+            error => error,
+        }
+    }
+
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ManifoldError::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ManifoldError::DimensionMismatch { .. } => ErrorCode::DimensionMismatch,
+            ManifoldError::PointNotOnManifold { .. } => ErrorCode::PointNotOnManifold,
+            ManifoldError::InvalidTangentVector { .. } => ErrorCode::InvalidTangentVector,
+            ManifoldError::NumericalError(_) => ErrorCode::Numerical,
+            ManifoldError::ConvergenceError { .. } => ErrorCode::Convergence,
+            ManifoldError::InvalidParameter(_) => ErrorCode::InvalidParameter,
+            ManifoldError::LinearAlgebraError(_) => ErrorCode::LinearAlgebra,
+            ManifoldError::BackendError(_) => ErrorCode::Backend,
+            ManifoldError::IoError(_) => ErrorCode::Io,
+            ManifoldError::WithContext { source, .. } => source.code(),
+        }
+    }
+}
+
+pub type Result<T> = core::result::Result<T, ManifoldError>;
+
+pub trait ResultExt<T> {
+    fn with_context<F: FnOnce() -> ErrorContext>(self, context: F) -> Result<T>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    fn with_context<F: FnOnce() -> ErrorContext>(self, context: F) -> Result<T> {
+        self.map_err(|error| error.with_context(context()))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for ManifoldError {
+    fn from(error: std::io::Error) -> Self {
+        ManifoldError::IoError(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::error::Error;
+
+    #[test]
+    fn test_context_chains_to_source() {
+        let error = ManifoldError::LinearAlgebraError("Cholesky decomposition failed".to_string())
+            .with_context(ErrorContext::new("log").on("SPD(3)"))
+            .with_context(ErrorContext::new("frechet mean").with_dimensions(9, 4));
+
+        assert_eq!(
+            error.to_string(),
+            "frechet mean (expected dimension 9, got 4): log on SPD(3): \
+             Linear algebra error: Cholesky decomposition failed"
+        );
+        assert_eq!(error.context().unwrap().dimensions, Some((9, 4)));
+        assert_eq!(error.code(), ErrorCode::LinearAlgebra);
+        assert!(matches!(
+            error.root_cause(),
+            ManifoldError::LinearAlgebraError(_)
+        ));
+
+        let inner = error.source().unwrap();
+        assert_eq!(
+            inner.to_string(),
+            "log on SPD(3): Linear algebra error: Cholesky decomposition failed"
+        );
+        assert_eq!(
+            inner.source().unwrap().to_string(),
+            "Linear algebra error: Cholesky decomposition failed"
+        );
+        assert!(inner.source().unwrap().source().is_none());
+    }
+
+    #[test]
+    fn test_error_codes_are_stable() {
+        let error: Result<()> = Err(ManifoldError::DimensionMismatch {
+            expected: 3,
+            got: 2,
+        });
+        let error = error.with_context(|| ErrorContext::new("exp")).unwrap_err();
+        assert_eq!(error.code(), ErrorCode::DimensionMismatch);
+        assert_eq!(error.code().as_str(), "dimension_mismatch");
+        assert_eq!(ErrorCode::Io as u16, 9);
+        assert_eq!(
+            ManifoldError::ConvergenceError { iterations: 5 }
+                .code()
+                .to_string(),
+            "convergence"
+        );
+        assert!(ManifoldError::IoError("closed".to_string())
+            .context()
+            .is_none());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::validation::{validate_entry_point, validate_entry_tangent};
+use crate::{Manifold, Result, TangentVector};
+use alloc::vec::Vec;
+
+pub struct GeodesicIter<'a, M: Manifold + ?Sized> {
+    manifold: &'a M,
+    point: &'a [M::Scalar],
+    tangent: &'a TangentVector<M::Scalar>,
+    scaled: TangentVector<M::Scalar>,
+    samples: usize,
+    next: usize,
+}
+
+impl<'a, M: Manifold + ?Sized> GeodesicIter<'a, M> {
+    pub fn new(
+        manifold: &'a M,
+        point: &'a [M::Scalar],
+        tangent: &'a TangentVector<M::Scalar>,
+        samples: usize,
+    ) -> Result<Self> {
+        validate_entry_point(manifold, point)?;
+        validate_entry_tangent(manifold, point, tangent)?;
+        Ok(Self {
+            manifold,
+            point,
+            tangent,
+            scaled: TangentVector::zero(tangent.dim()),
+            samples,
+            next: 0,
+        })
+    }
+
+    pub fn time(&self, index: usize) -> M::Scalar {
+        if self.samples < 2 {
+            return nalgebra::zero();
+        }
+        nalgebra::convert(index as f64 / (self.samples - 1) as f64)
+    }
+
+    pub fn next_into(&mut self, out: &mut Vec<M::Scalar>) -> Option<Result<M::Scalar>> {
+        if self.next >= self.samples {
+            return None;
+        }
+        let t = self.time(self.next);
+        self.next += 1;
+        self.scaled.components.copy_from(&self.tangent.components);
+        self.scaled.components *= t.clone();
+        Some(
+            self.manifold
+                .exp_unchecked_into(self.point, &self.scaled, out)
+                .map(|()| t),
+        )
+    }
+}
+
+impl<M: Manifold + ?Sized> Iterator for GeodesicIter<'_, M> {
+    type Item = Result<Vec<M::Scalar>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut out = Vec::with_capacity(self.point.len());
+        self.next_into(&mut out).map(|step| step.map(|_| out))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.samples - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<M: Manifold + ?Sized> ExactSizeIterator for GeodesicIter<'_, M> {}
+
+impl<M: Manifold + ?Sized> core::iter::FusedIterator for GeodesicIter<'_, M> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+    use crate::ManifoldError;
+
+    #[test]
+    fn test_geodesic_iter_samples_endpoints() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        let point = [1.0, 0.0];
+        let tangent = TangentVector::from(vec![2.0, -4.0]);
+        let samples = manifold.geodesic_iter(&point, &tangent, 5).unwrap();
+        assert_eq!(samples.len(), 5);
+
+        let points: Vec<Vec<f64>> = samples.collect::<Result<_>>().unwrap();
+        assert_eq!(points.first().unwrap(), &vec![1.0, 0.0]);
+        assert_eq!(points[2], vec![2.0, -2.0]);
+        assert_eq!(points.last().unwrap(), &vec![3.0, -4.0]);
+        for (i, p) in points.iter().enumerate() {
+            let t = i as f64 / 4.0;
+            assert_eq!(p, &manifold.geodesic(&point, &tangent, t).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_geodesic_iter_reuses_output_buffer() {
+        let manifold = TestEuclideanManifold { dimension: 1 };
+        let tangent = TangentVector::from(vec![1.0]);
+        let mut samples = manifold.geodesic_iter(&[0.0], &tangent, 3).unwrap();
+        let mut buffer = Vec::new();
+        let mut times = Vec::new();
+        while let Some(t) = samples.next_into(&mut buffer) {
+            times.push(t.unwrap());
+            assert_eq!(buffer, vec![times[times.len() - 1]]);
+        }
+        assert_eq!(times, vec![0.0, 0.5, 1.0]);
+        assert!(samples.next().is_none());
+
+        assert_eq!(
+            manifold.geodesic_iter(&[0.0], &tangent, 0).unwrap().count(),
+            0
+        );
+        let single: Vec<_> = manifold
+            .geodesic_iter(&[2.0], &tangent, 1)
+            .unwrap()
+            .collect();
+        assert_eq!(single.len(), 1);
+        assert!(matches!(
+            manifold.geodesic_iter(&[0.0, 1.0], &tangent, 3),
+            Err(ManifoldError::DimensionMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,197 @@
+use crate::tangent::TangentView;
+use crate::typed::{Point, Tangent};
+use crate::{Manifold, Result, TangentVector};
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use nalgebra::{DVector, DVectorView, RealField};
+
+pub trait AsPoint<T> {
+    fn as_point(&self) -> &[T];
+}
+
+impl<T> AsPoint<T> for [T] {
+    fn as_point(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsPoint<T> for [T; N] {
+    fn as_point(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> AsPoint<T> for Vec<T> {
+    fn as_point(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: nalgebra::Scalar> AsPoint<T> for DVector<T> {
+    fn as_point(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: nalgebra::Scalar> AsPoint<T> for DVectorView<'_, T> {
+    fn as_point(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<M: Manifold + ?Sized> AsPoint<M::Scalar> for Point<M> {
+    fn as_point(&self) -> &[M::Scalar] {
+        self.as_slice()
+    }
+}
+
+impl<T, P: AsPoint<T> + ?Sized> AsPoint<T> for &P {
+    fn as_point(&self) -> &[T] {
+        (**self).as_point()
+    }
+}
+
+pub trait AsTangent<T: RealField> {
+    fn as_tangent(&self) -> Cow<'_, TangentVector<T>>;
+}
+
+impl<T: RealField> AsTangent<T> for TangentVector<T> {
+    fn as_tangent(&self) -> Cow<'_, TangentVector<T>> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<M: Manifold + ?Sized> AsTangent<M::Scalar> for Tangent<M> {
+    fn as_tangent(&self) -> Cow<'_, TangentVector<M::Scalar>> {
+        Cow::Borrowed(self.vector())
+    }
+}
+
+impl<T: RealField> AsTangent<T> for TangentView<'_, T> {
+    fn as_tangent(&self) -> Cow<'_, TangentVector<T>> {
+        Cow::Owned(self.clone().into_owned())
+    }
+}
+
+impl<T: RealField> AsTangent<T> for DVector<T> {
+    fn as_tangent(&self) -> Cow<'_, TangentVector<T>> {
+        Cow::Owned(TangentVector::new(self.clone()))
+    }
+}
+
+impl<T: RealField> AsTangent<T> for [T] {
+    fn as_tangent(&self) -> Cow<'_, TangentVector<T>> {
+        Cow::Owned(TangentVector::new(DVector::from_column_slice(self)))
+    }
+}
+
+impl<T: RealField, const N: usize> AsTangent<T> for [T; N] {
+    fn as_tangent(&self) -> Cow<'_, TangentVector<T>> {
+        self.as_slice().as_tangent()
+    }
+}
+
+impl<T: RealField> AsTangent<T> for Vec<T> {
+    fn as_tangent(&self) -> Cow<'_, TangentVector<T>> {
+        self.as_slice().as_tangent()
+    }
+}
+
+impl<T: RealField, V: AsTangent<T> + ?Sized> AsTangent<T> for &V {
+    fn as_tangent(&self) -> Cow<'_, TangentVector<T>> {
+        (**self).as_tangent()
+    }
+}
+
+pub trait ManifoldExt: Manifold {
+    fn check(&self, point: impl AsPoint<Self::Scalar>) -> Result<()> {
+        self.check_point(point.as_point())
+    }
+
+    fn exp_at(
+        &self,
+        point: impl AsPoint<Self::Scalar>,
+        tangent: impl AsTangent<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.exp(point.as_point(), &tangent.as_tangent())
+    }
+
+    fn log_at(
+        &self,
+        point: impl AsPoint<Self::Scalar>,
+        other: impl AsPoint<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.log(point.as_point(), other.as_point())
+    }
+
+    fn distance_between(
+        &self,
+        a: impl AsPoint<Self::Scalar>,
+        b: impl AsPoint<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.distance(a.as_point(), b.as_point())
+    }
+}
+
+impl<M: Manifold + ?Sized> ManifoldExt for M {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+    use crate::typed::TypedManifold;
+    use crate::ManifoldError;
+
+    #[test]
+    fn test_points_from_any_container() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        let vector = DVector::from_vec(vec![3.0, 4.0]);
+        let origin = [0.0, 0.0];
+
+        assert_eq!(manifold.distance_between(origin, &vector).unwrap(), 5.0);
+        assert_eq!(
+            manifold
+                .distance_between(&origin[..], vec![3.0, 4.0])
+                .unwrap(),
+            5.0
+        );
+        assert_eq!(
+            manifold
+                .distance_between(origin, vector.rows(0, 2))
+                .unwrap(),
+            5.0
+        );
+        assert_eq!(
+            manifold.log_at(origin, &vector).unwrap().as_slice(),
+            &[3.0, 4.0]
+        );
+        assert!(manifold.check(&vector).is_ok());
+        assert!(matches!(
+            manifold.check(DVector::from_vec(vec![1.0; 3])),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_tangents_from_any_container() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        let p = manifold.point(vec![1.0, 2.0]).unwrap();
+        let expected = vec![1.5, 1.0];
+
+        assert_eq!(manifold.exp_at(&p, [0.5, -1.0]).unwrap(), expected);
+        assert_eq!(manifold.exp_at(&p, vec![0.5, -1.0]).unwrap(), expected);
+        assert_eq!(
+            manifold
+                .exp_at(&p, DVector::from_vec(vec![0.5, -1.0]))
+                .unwrap(),
+            expected
+        );
+        let tangent = manifold.tangent(&p, vec![0.5, -1.0]).unwrap();
+        assert_eq!(manifold.exp_at(&p, &tangent).unwrap(), expected);
+        assert_eq!(manifold.exp_at(&p, tangent.as_view()).unwrap(), expected);
+        assert!(manifold.exp_at([1.0, 2.0], [0.5]).is_err());
+    }
+}
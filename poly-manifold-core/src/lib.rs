@@ -1,17 +1,106 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("poly-manifold-core requires the `std` or the `libm` feature");
+
+extern crate alloc;
+#[cfg(test)]
+extern crate self as poly_manifold_core;
+
+#[cfg(feature = "ndarray")]
+pub mod array;
+pub mod batch;
+pub mod cache;
+#[cfg(feature = "arrow")]
+pub mod columnar;
+pub mod context;
+#[cfg(feature = "std")]
+pub mod csv;
+pub mod embedded;
+pub mod equivalence;
 pub mod error;
+pub mod geodesic;
+pub mod input;
+pub mod lie;
+pub mod linalg;
 pub mod manifold;
+pub mod matrix;
 pub mod metric;
+#[cfg(feature = "npy")]
+pub mod npy;
+pub mod precision;
+pub mod prelude;
+pub mod profiling;
+pub mod random;
+pub mod reproject;
+pub mod surrogate;
+pub mod table;
 pub mod tangent;
+pub mod typed;
+pub mod validation;
+pub mod workspace;
 
-pub use error::{ManifoldError, Result};
+pub use batch::BatchManifold;
+pub use cache::CachedManifold;
+pub use context::Context;
+#[cfg(feature = "std")]
+pub use context::Contextual;
+pub use embedded::EmbeddedSubmanifold;
+#[cfg(feature = "approx")]
+pub use equivalence::ApproxPoint;
+pub use equivalence::{PointComparison, PointEquivalence};
+pub use error::{ErrorCode, ErrorContext, ManifoldError, Result, ResultExt};
+pub use geodesic::GeodesicIter;
+pub use input::{AsPoint, AsTangent, ManifoldExt};
+pub use lie::LieGroup;
 pub use manifold::Manifold;
+pub use matrix::{MatrixDisplay, MatrixManifold};
 pub use metric::RiemannianMetric;
-pub use tangent::TangentVector;
+#[cfg(feature = "derive")]
+pub use poly_manifold_derive::EmbeddedManifold;
+pub use precision::{Promoted, Widened};
+pub use profiling::{Counted, OperationCounts};
+#[cfg(feature = "rand")]
+pub use random::RandSource;
+pub use random::{RandomSource, SeededRng};
+pub use reproject::Reprojected;
+pub use surrogate::DistanceSurrogate;
+pub use table::PointTable;
+pub use tangent::{TangentVector, TangentView};
+pub use typed::{Point, Tangent, TypedManifold};
+pub use validation::{set_validation_level, validation_level, ValidationLevel};
+pub use workspace::{Workspace, WorkspaceManifold};
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn test_public_types_are_send_and_sync() {
+        assert_send_sync::<TangentVector<f64>>();
+        assert_send_sync::<TangentView<'_, f32>>();
+        assert_send_sync::<ManifoldError>();
+        assert_send_sync::<Point<TestEuclideanManifold>>();
+        assert_send_sync::<Tangent<TestEuclideanManifold>>();
+        assert_send_sync::<GeodesicIter<'_, TestEuclideanManifold>>();
+        assert_send_sync::<Counted<TestEuclideanManifold>>();
+        assert_send_sync::<Promoted<TestEuclideanManifold>>();
+        assert_send_sync::<Widened<TestEuclideanManifold>>();
+        assert_send_sync::<Reprojected<TestEuclideanManifold>>();
+        assert_send_sync::<Workspace>();
+        assert_send_sync::<Context>();
+        assert_send_sync::<Contextual<TestEuclideanManifold>>();
+        assert_send_sync::<PointTable>();
+        assert_send_sync::<SeededRng>();
+        assert_send_sync::<OperationCounts>();
+        assert_send_sync::<Box<dyn Manifold<Scalar = f64> + Send + Sync>>();
+    }
 }
@@ -0,0 +1,26 @@
+use crate::{Manifold, Result};
+use alloc::vec::Vec;
+
+pub trait LieGroup: Manifold {
+    fn group_identity(&self) -> Vec<Self::Scalar>;
+
+    fn compose(&self, a: &[Self::Scalar], b: &[Self::Scalar]) -> Result<Vec<Self::Scalar>>;
+
+    fn inverse(&self, a: &[Self::Scalar]) -> Result<Vec<Self::Scalar>>;
+
+    fn group_exp(&self, algebra: &[Self::Scalar]) -> Result<Vec<Self::Scalar>>;
+
+    fn group_log(&self, element: &[Self::Scalar]) -> Result<Vec<Self::Scalar>>;
+
+    fn perturb(
+        &self,
+        point: &[Self::Scalar],
+        algebra: &[Self::Scalar],
+    ) -> Result<Vec<Self::Scalar>> {
+        self.compose(point, &self.group_exp(algebra)?)
+    }
+
+    fn between(&self, a: &[Self::Scalar], b: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.group_log(&self.compose(&self.inverse(a)?, b)?)
+    }
+}
@@ -0,0 +1,373 @@
+use crate::profiling::{record, Counter};
+use crate::validation::check_finite;
+use crate::{ManifoldError, Result};
+use alloc::{format, string::ToString};
+use nalgebra::DMatrix;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+const PADE_3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const PADE_5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const PADE_7: [f64; 8] = [
+    17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+];
+const PADE_9: [f64; 10] = [
+    17643225600.0,
+    8821612800.0,
+    2075673600.0,
+    302702400.0,
+    30270240.0,
+    2162160.0,
+    110880.0,
+    3960.0,
+    90.0,
+    1.0,
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+const THETA: [(f64, &[f64]); 4] = [
+    (1.495585217958292e-2, &PADE_3),
+    (2.53939833006323e-1, &PADE_5),
+    (9.504178996162932e-1, &PADE_7),
+    (2.097847961257068, &PADE_9),
+];
+const THETA_13: f64 = 5.371920351148152;
+
+pub fn is_symmetric(mat: &DMatrix<f64>, tolerance: f64) -> bool {
+    mat.is_square()
+        && (0..mat.nrows())
+            .all(|i| (i + 1..mat.ncols()).all(|j| (mat[(i, j)] - mat[(j, i)]).abs() <= tolerance))
+}
+
+pub fn symmetric_function<F>(mat: &DMatrix<f64>, f: F) -> DMatrix<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    let symmetric = (mat + mat.transpose()) * 0.5;
+    record(Counter::Eigendecomposition);
+    let eigen = symmetric.symmetric_eigen();
+    let mapped = eigen.eigenvalues.map(f);
+    let result =
+        &eigen.eigenvectors * DMatrix::from_diagonal(&mapped) * eigen.eigenvectors.transpose();
+    (&result + result.transpose()) * 0.5
+}
+
+pub fn matrix_exp(mat: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+    check_finite(mat.as_slice(), "Matrix exponential input")?;
+    let scale = mat.amax().max(1.0);
+    if is_symmetric(mat, 1e-12 * scale) {
+        Ok(symmetric_function(mat, f64::exp))
+    } else {
+        expm(mat)
+    }
+}
+
+pub fn expm(mat: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+    if !mat.is_square() {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: mat.nrows(),
+            got: mat.ncols(),
+        });
+    }
+    check_finite(mat.as_slice(), "Matrix exponential input")?;
+    let norm = one_norm(mat);
+    if let Some((_, coefficients)) = THETA.iter().find(|(theta, _)| norm <= *theta) {
+        return pade(mat, coefficients);
+    }
+
+    let squarings = (norm / THETA_13).log2().ceil().max(0.0) as i32;
+    let scaled = mat / 2f64.powi(squarings);
+    let mut result = pade_13(&scaled)?;
+    for _ in 0..squarings {
+        result = &result * &result;
+    }
+    Ok(result)
+}
+
+pub fn matrix_log(mat: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+    let scale = mat.amax().max(1.0);
+    if !is_symmetric(mat, 1e-10 * scale) {
+        return Err(ManifoldError::LinearAlgebraError(
+            "Matrix logarithm requires a symmetric matrix".to_string(),
+        ));
+    }
+    let symmetric = (mat + mat.transpose()) * 0.5;
+    record(Counter::Eigendecomposition);
+    let eigen = symmetric.symmetric_eigen();
+    if let Some(lambda) = eigen
+        .eigenvalues
+        .iter()
+        .find(|l| !(**l > 0.0 && l.is_finite()))
+    {
+        return Err(ManifoldError::LinearAlgebraError(format!(
+            "Matrix logarithm requires positive eigenvalues, got {}",
+            lambda
+        )));
+    }
+    let mapped = eigen.eigenvalues.map(f64::ln);
+    let result =
+        &eigen.eigenvectors * DMatrix::from_diagonal(&mapped) * eigen.eigenvectors.transpose();
+    Ok((&result + result.transpose()) * 0.5)
+}
+
+pub fn denman_beavers(mat: &DMatrix<f64>) -> Result<(DMatrix<f64>, DMatrix<f64>)> {
+    if !mat.is_square() {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: mat.nrows(),
+            got: mat.ncols(),
+        });
+    }
+    let n = mat.nrows();
+    let mut y = mat.clone();
+    let mut z = DMatrix::<f64>::identity(n, n);
+    for _ in 0..100 {
+        let scale = (y.determinant() * z.determinant())
+            .abs()
+            .powf(-0.5 / n as f64);
+        if !scale.is_finite() {
+            break;
+        }
+        let (Some(y_inv), Some(z_inv)) = (y.clone().try_inverse(), z.clone().try_inverse()) else {
+            break;
+        };
+        let next_y = (&y * scale + z_inv / scale) * 0.5;
+        let next_z = (&z * scale + y_inv / scale) * 0.5;
+        let change = (&next_y - &y).norm();
+        y = next_y;
+        z = next_z;
+        if change <= 1e-14 * y.norm() {
+            if is_symmetric(mat, 1e-12 * mat.amax().max(1.0)) {
+                y = (&y + y.transpose()) * 0.5;
+                z = (&z + z.transpose()) * 0.5;
+            }
+            return Ok((y, z));
+        }
+    }
+    Err(ManifoldError::LinearAlgebraError(
+        "Denman-Beavers iteration did not converge".to_string(),
+    ))
+}
+
+pub fn matrix_sqrt(mat: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+    Ok(denman_beavers(mat)?.0)
+}
+
+pub fn matrix_inverse_sqrt(mat: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+    Ok(denman_beavers(mat)?.1)
+}
+
+fn one_norm(mat: &DMatrix<f64>) -> f64 {
+    mat.column_iter()
+        .map(|column| column.iter().map(|x| x.abs()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+fn pade(mat: &DMatrix<f64>, coefficients: &[f64]) -> Result<DMatrix<f64>> {
+    let n = mat.nrows();
+    let square = mat * mat;
+    let mut power = DMatrix::identity(n, n);
+    let mut odd = DMatrix::zeros(n, n);
+    let mut even = DMatrix::zeros(n, n);
+    for pair in coefficients.chunks(2) {
+        even += &power * pair[0];
+        odd += &power * pair[1];
+        power = &power * &square;
+    }
+    solve_pade(mat * odd, even)
+}
+
+fn pade_13(mat: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+    let b = &PADE_13;
+    let n = mat.nrows();
+    let identity = DMatrix::<f64>::identity(n, n);
+    let a2 = mat * mat;
+    let a4 = &a2 * &a2;
+    let a6 = &a4 * &a2;
+    let odd_high = &a6 * b[13] + &a4 * b[11] + &a2 * b[9];
+    let odd = &a6 * odd_high + &a6 * b[7] + &a4 * b[5] + &a2 * b[3] + &identity * b[1];
+    let even_high = &a6 * b[12] + &a4 * b[10] + &a2 * b[8];
+    let even = &a6 * even_high + &a6 * b[6] + &a4 * b[4] + &a2 * b[2] + identity * b[0];
+    solve_pade(mat * odd, even)
+}
+
+fn solve_pade(u: DMatrix<f64>, v: DMatrix<f64>) -> Result<DMatrix<f64>> {
+    (&v - &u).lu().solve(&(v + u)).ok_or_else(|| {
+        ManifoldError::LinearAlgebraError(
+            "Padé denominator of the matrix exponential is singular".to_string(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_exp_of_zero_is_identity() {
+        let result = matrix_exp(&DMatrix::zeros(3, 3)).unwrap();
+        assert_relative_eq!(
+            (result - DMatrix::identity(3, 3)).norm(),
+            0.0,
+            epsilon = 1e-14
+        );
+    }
+
+    #[test]
+    fn test_exp_of_diagonal_with_large_entries() {
+        let mat = DMatrix::from_diagonal(&nalgebra::DVector::from_vec(vec![30.0, -25.0, 0.5]));
+        let result = matrix_exp(&mat).unwrap();
+        assert_relative_eq!(result[(0, 0)], 30.0f64.exp(), max_relative = 1e-13);
+        assert_relative_eq!(result[(1, 1)], (-25.0f64).exp(), max_relative = 1e-13);
+        assert_relative_eq!(result[(2, 2)], 0.5f64.exp(), max_relative = 1e-13);
+        assert_relative_eq!(result[(0, 1)], 0.0);
+    }
+
+    #[test]
+    fn test_symmetric_exp_matches_pade() {
+        let mat = DMatrix::from_row_slice(3, 3, &[4.0, -1.5, 0.7, -1.5, 2.0, 3.1, 0.7, 3.1, -6.0]);
+        let spectral = matrix_exp(&mat).unwrap();
+        let pade = expm(&mat).unwrap();
+        assert_relative_eq!(
+            (&spectral - &pade).norm() / pade.norm(),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert!(is_symmetric(&spectral, 1e-12 * spectral.amax()));
+    }
+
+    #[test]
+    fn test_non_symmetric_input_falls_back_to_pade() {
+        let angle = 2.5f64;
+        let generator = DMatrix::from_row_slice(2, 2, &[0.0, -angle, angle, 0.0]);
+        let rotation = matrix_exp(&generator).unwrap();
+        assert_relative_eq!(rotation[(0, 0)], angle.cos(), epsilon = 1e-12);
+        assert_relative_eq!(rotation[(1, 0)], angle.sin(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_symmetric_function_square_root() {
+        let mat = DMatrix::from_row_slice(2, 2, &[5.0, 2.0, 2.0, 2.0]);
+        let root = symmetric_function(&mat, f64::sqrt);
+        assert_relative_eq!((&root * &root - mat).norm(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_log_inverts_exp_far_from_identity() {
+        let mat = DMatrix::from_row_slice(3, 3, &[4.0, -1.5, 0.7, -1.5, 2.0, 3.1, 0.7, 3.1, -6.0]);
+        let recovered = matrix_log(&matrix_exp(&mat).unwrap()).unwrap();
+        assert_relative_eq!((recovered - &mat).norm(), 0.0, epsilon = 1e-9);
+
+        let wide = DMatrix::from_diagonal(&nalgebra::DVector::from_vec(vec![1e4, 1e-4]));
+        let log = matrix_log(&wide).unwrap();
+        assert_relative_eq!(log[(0, 0)], 1e4f64.ln(), epsilon = 1e-12);
+        assert_relative_eq!(log[(1, 1)], 1e-4f64.ln(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_log_rejects_invalid_input() {
+        let indefinite = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+        assert!(matrix_log(&indefinite).is_err());
+        let asymmetric = DMatrix::from_row_slice(2, 2, &[1.0, 0.5, 0.0, 1.0]);
+        assert!(matrix_log(&asymmetric).is_err());
+    }
+
+    #[test]
+    fn test_expm_matches_reference_across_pade_orders() {
+        let base = DMatrix::from_row_slice(3, 3, &[0.2, 1.0, -0.4, -0.3, 0.1, 0.8, 0.5, -0.6, 0.0]);
+        for scale in [1e-3, 0.1, 0.5, 1.5, 4.0, 40.0] {
+            let mat = &base * scale;
+            let reference = mat.exp();
+            assert_relative_eq!(
+                (expm(&mat).unwrap() - &reference).norm() / reference.norm(),
+                0.0,
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_expm_of_nilpotent_matrix() {
+        let mat = DMatrix::from_row_slice(3, 3, &[0.0, 2.0, 3.0, 0.0, 0.0, 5.0, 0.0, 0.0, 0.0]);
+        let expected =
+            DMatrix::from_row_slice(3, 3, &[1.0, 2.0, 8.0, 0.0, 1.0, 5.0, 0.0, 0.0, 1.0]);
+        assert_relative_eq!(
+            (expm(&mat).unwrap() - expected).norm(),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_expm_rejects_invalid_input() {
+        assert!(matches!(
+            expm(&DMatrix::zeros(2, 3)),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        ));
+        for bad in [f64::NAN, f64::INFINITY] {
+            let mat = DMatrix::from_row_slice(2, 2, &[bad, 1.0, 1.0, 0.0]);
+            assert!(matches!(expm(&mat), Err(ManifoldError::NumericalError(_))));
+            assert!(matches!(
+                matrix_exp(&mat),
+                Err(ManifoldError::NumericalError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_denman_beavers_square_root() {
+        let mat = DMatrix::from_row_slice(3, 3, &[4.0, 1.0, 0.5, -1.0, 3.0, 0.2, 0.3, 0.0, 2.0]);
+        let (root, inverse_root) = denman_beavers(&mat).unwrap();
+        assert_relative_eq!((&root * &root - &mat).norm(), 0.0, epsilon = 1e-11);
+        assert_relative_eq!(
+            (&root * &inverse_root - DMatrix::identity(3, 3)).norm(),
+            0.0,
+            epsilon = 1e-11
+        );
+
+        let spd = DMatrix::from_row_slice(2, 2, &[5.0, 2.0, 2.0, 2.0]);
+        let root = matrix_sqrt(&spd).unwrap();
+        assert_relative_eq!(
+            (&root - symmetric_function(&spd, f64::sqrt)).norm(),
+            0.0,
+            epsilon = 1e-12
+        );
+        let inverse_root = matrix_inverse_sqrt(&spd).unwrap();
+        assert_relative_eq!(
+            (&inverse_root * &spd * &inverse_root - DMatrix::identity(2, 2)).norm(),
+            0.0,
+            epsilon = 1e-11
+        );
+    }
+
+    #[test]
+    fn test_square_root_rejects_invalid_input() {
+        assert!(matches!(
+            matrix_sqrt(&DMatrix::zeros(2, 3)),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        ));
+        let negative = DMatrix::from_diagonal(&nalgebra::DVector::from_vec(vec![1.0, -4.0]));
+        assert!(matrix_sqrt(&negative).is_err());
+        assert!(matrix_inverse_sqrt(&DMatrix::zeros(2, 2)).is_err());
+    }
+}
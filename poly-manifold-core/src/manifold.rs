@@ -1,4 +1,6 @@
-use crate::{Result, TangentVector};
+use crate::geodesic::GeodesicIter;
+use crate::{ManifoldError, Result, TangentVector};
+use alloc::{boxed::Box, format, vec::Vec};
 use nalgebra::{ComplexField, RealField};
 
 pub trait Manifold {
@@ -6,6 +8,10 @@ pub trait Manifold {
 
     fn dim(&self) -> usize;
 
+    fn ambient_dim(&self) -> usize {
+        self.dim()
+    }
+
     fn check_point(&self, point: &[Self::Scalar]) -> Result<()>;
 
     fn check_tangent_vector(
@@ -49,6 +55,50 @@ pub trait Manifold {
         Ok(self.inner_product(point, v, v)?.sqrt())
     }
 
+    fn exp_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.exp(point, tangent)
+    }
+
+    fn log_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.log(point, other)
+    }
+
+    fn inner_product_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.inner_product(point, v1, v2)
+    }
+
+    fn exp_unchecked_into(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        out: &mut Vec<Self::Scalar>,
+    ) -> Result<()> {
+        *out = self.exp_unchecked(point, tangent)?;
+        Ok(())
+    }
+
+    fn project_to_tangent_space_in_place(
+        &self,
+        point: &[Self::Scalar],
+        vector: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        *vector = self.project_to_tangent_space(point, vector)?;
+        Ok(())
+    }
+
     fn retraction(
         &self,
         point: &[Self::Scalar],
@@ -68,10 +118,22 @@ pub trait Manifold {
         tangent: &TangentVector<Self::Scalar>,
         t: Self::Scalar,
     ) -> Result<Vec<Self::Scalar>> {
-        let scaled_tangent = tangent.clone() * t;
+        let scaled_tangent = tangent * t;
         self.exp(point, &scaled_tangent)
     }
 
+    fn geodesic_iter<'a>(
+        &'a self,
+        point: &'a [Self::Scalar],
+        tangent: &'a TangentVector<Self::Scalar>,
+        samples: usize,
+    ) -> Result<GeodesicIter<'a, Self>>
+    where
+        Self: Sized,
+    {
+        GeodesicIter::new(self, point, tangent, samples)
+    }
+
     fn parallel_transport(
         &self,
         point: &[Self::Scalar],
@@ -81,15 +143,255 @@ pub trait Manifold {
         let new_point = self.exp(point, direction)?;
         self.project_to_tangent_space(&new_point, tangent)
     }
+
+    fn euclidean_to_riemannian_gradient(
+        &self,
+        point: &[Self::Scalar],
+        euclidean_gradient: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.project_to_tangent_space(point, euclidean_gradient)
+    }
+
+    fn euclidean_to_riemannian_gradient_in_place(
+        &self,
+        point: &[Self::Scalar],
+        gradient: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        *gradient = self.euclidean_to_riemannian_gradient(point, gradient)?;
+        Ok(())
+    }
+
+    fn log_volume_change(
+        &self,
+        _point: &[Self::Scalar],
+        _tangent: &TangentVector<Self::Scalar>,
+    ) -> Option<Self::Scalar> {
+        None
+    }
+
+    fn tangent_basis(&self, point: &[Self::Scalar]) -> Result<Vec<TangentVector<Self::Scalar>>> {
+        self.check_point(point)?;
+        let threshold: Self::Scalar = nalgebra::convert(1e-10);
+        let mut basis: Vec<TangentVector<Self::Scalar>> = Vec::with_capacity(self.dim());
+
+        for i in 0..point.len() {
+            if basis.len() == self.dim() {
+                break;
+            }
+            let mut candidate = TangentVector::zero(point.len());
+            candidate.components[i] = nalgebra::one::<Self::Scalar>();
+            candidate = self.project_to_tangent_space(point, &candidate)?;
+            for b in &basis {
+                let coefficient = self.inner_product(point, &candidate, b)?;
+                candidate -= &(b * coefficient);
+            }
+            let norm = self.norm(point, &candidate)?;
+            if norm > threshold {
+                basis.push(candidate * norm.recip());
+            }
+        }
+
+        if basis.len() < self.dim() {
+            return Err(ManifoldError::NumericalError(format!(
+                "Found {} tangent basis vectors, expected {}",
+                basis.len(),
+                self.dim()
+            )));
+        }
+        Ok(basis)
+    }
+}
+
+macro_rules! forward_manifold {
+    ($pointer:ty) => {
+        impl<M: Manifold + ?Sized> Manifold for $pointer {
+            type Scalar = M::Scalar;
+
+            fn dim(&self) -> usize {
+                (**self).dim()
+            }
+
+            fn ambient_dim(&self) -> usize {
+                (**self).ambient_dim()
+            }
+
+            fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+                (**self).check_point(point)
+            }
+
+            fn check_tangent_vector(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &TangentVector<Self::Scalar>,
+            ) -> Result<()> {
+                (**self).check_tangent_vector(point, tangent)
+            }
+
+            fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+                (**self).project_to_manifold(point)
+            }
+
+            fn project_to_tangent_space(
+                &self,
+                point: &[Self::Scalar],
+                vector: &TangentVector<Self::Scalar>,
+            ) -> Result<TangentVector<Self::Scalar>> {
+                (**self).project_to_tangent_space(point, vector)
+            }
+
+            fn exp(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &TangentVector<Self::Scalar>,
+            ) -> Result<Vec<Self::Scalar>> {
+                (**self).exp(point, tangent)
+            }
+
+            fn log(
+                &self,
+                point: &[Self::Scalar],
+                other: &[Self::Scalar],
+            ) -> Result<TangentVector<Self::Scalar>> {
+                (**self).log(point, other)
+            }
+
+            fn inner_product(
+                &self,
+                point: &[Self::Scalar],
+                v1: &TangentVector<Self::Scalar>,
+                v2: &TangentVector<Self::Scalar>,
+            ) -> Result<Self::Scalar> {
+                (**self).inner_product(point, v1, v2)
+            }
+
+            fn norm(
+                &self,
+                point: &[Self::Scalar],
+                v: &TangentVector<Self::Scalar>,
+            ) -> Result<Self::Scalar> {
+                (**self).norm(point, v)
+            }
+
+            fn exp_unchecked(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &TangentVector<Self::Scalar>,
+            ) -> Result<Vec<Self::Scalar>> {
+                (**self).exp_unchecked(point, tangent)
+            }
+
+            fn log_unchecked(
+                &self,
+                point: &[Self::Scalar],
+                other: &[Self::Scalar],
+            ) -> Result<TangentVector<Self::Scalar>> {
+                (**self).log_unchecked(point, other)
+            }
+
+            fn inner_product_unchecked(
+                &self,
+                point: &[Self::Scalar],
+                v1: &TangentVector<Self::Scalar>,
+                v2: &TangentVector<Self::Scalar>,
+            ) -> Result<Self::Scalar> {
+                (**self).inner_product_unchecked(point, v1, v2)
+            }
+
+            fn exp_unchecked_into(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &TangentVector<Self::Scalar>,
+                out: &mut Vec<Self::Scalar>,
+            ) -> Result<()> {
+                (**self).exp_unchecked_into(point, tangent, out)
+            }
+
+            fn project_to_tangent_space_in_place(
+                &self,
+                point: &[Self::Scalar],
+                vector: &mut TangentVector<Self::Scalar>,
+            ) -> Result<()> {
+                (**self).project_to_tangent_space_in_place(point, vector)
+            }
+
+            fn retraction(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &TangentVector<Self::Scalar>,
+            ) -> Result<Vec<Self::Scalar>> {
+                (**self).retraction(point, tangent)
+            }
+
+            fn distance(
+                &self,
+                point1: &[Self::Scalar],
+                point2: &[Self::Scalar],
+            ) -> Result<Self::Scalar> {
+                (**self).distance(point1, point2)
+            }
+
+            fn geodesic(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &TangentVector<Self::Scalar>,
+                t: Self::Scalar,
+            ) -> Result<Vec<Self::Scalar>> {
+                (**self).geodesic(point, tangent, t)
+            }
+
+            fn parallel_transport(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &TangentVector<Self::Scalar>,
+                direction: &TangentVector<Self::Scalar>,
+            ) -> Result<TangentVector<Self::Scalar>> {
+                (**self).parallel_transport(point, tangent, direction)
+            }
+
+            fn euclidean_to_riemannian_gradient(
+                &self,
+                point: &[Self::Scalar],
+                euclidean_gradient: &TangentVector<Self::Scalar>,
+            ) -> Result<TangentVector<Self::Scalar>> {
+                (**self).euclidean_to_riemannian_gradient(point, euclidean_gradient)
+            }
+
+            fn euclidean_to_riemannian_gradient_in_place(
+                &self,
+                point: &[Self::Scalar],
+                gradient: &mut TangentVector<Self::Scalar>,
+            ) -> Result<()> {
+                (**self).euclidean_to_riemannian_gradient_in_place(point, gradient)
+            }
+
+            fn log_volume_change(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &TangentVector<Self::Scalar>,
+            ) -> Option<Self::Scalar> {
+                (**self).log_volume_change(point, tangent)
+            }
+
+            fn tangent_basis(
+                &self,
+                point: &[Self::Scalar],
+            ) -> Result<Vec<TangentVector<Self::Scalar>>> {
+                (**self).tangent_basis(point)
+            }
+        }
+    };
 }
 
+forward_manifold!(&M);
+forward_manifold!(Box<M>);
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use nalgebra::DVector;
 
-    struct TestEuclideanManifold {
-        dimension: usize,
+    pub(crate) struct TestEuclideanManifold {
+        pub(crate) dimension: usize,
     }
 
     impl Manifold for TestEuclideanManifold {
@@ -165,7 +467,7 @@ mod tests {
             v1: &TangentVector<Self::Scalar>,
             v2: &TangentVector<Self::Scalar>,
         ) -> Result<Self::Scalar> {
-            Ok(v1.components.dot(&v2.components))
+            Ok(v1.dot(v2))
         }
     }
 
@@ -238,4 +540,21 @@ mod tests {
         use approx::assert_relative_eq;
         assert_relative_eq!(inner, 0.0, epsilon = 1e-10);
     }
+
+    #[test]
+    fn test_tangent_basis_is_orthonormal() {
+        let manifold = TestEuclideanManifold { dimension: 3 };
+        let point = vec![1.0, 2.0, 3.0];
+        let basis = manifold.tangent_basis(&point).unwrap();
+        assert_eq!(basis.len(), 3);
+
+        use approx::assert_relative_eq;
+        for (i, u) in basis.iter().enumerate() {
+            for (j, v) in basis.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                let inner = manifold.inner_product(&point, u, v).unwrap();
+                assert_relative_eq!(inner, expected, epsilon = 1e-10);
+            }
+        }
+    }
 }
@@ -0,0 +1,202 @@
+use crate::validation::check_finite;
+use crate::{Manifold, ManifoldError, Result, TangentVector};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+use nalgebra::{DMatrix, DVector, RealField};
+
+fn check_shape(expected: (usize, usize), got: (usize, usize)) -> Result<()> {
+    if got.0 != expected.0 {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: expected.0,
+            got: got.0,
+        });
+    }
+    if got.1 != expected.1 {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: expected.1,
+            got: got.1,
+        });
+    }
+    Ok(())
+}
+
+fn check_len(expected: usize, got: usize) -> Result<()> {
+    if got != expected {
+        return Err(ManifoldError::DimensionMismatch { expected, got });
+    }
+    Ok(())
+}
+
+fn row_major<T: nalgebra::Scalar>(matrix: &DMatrix<T>) -> Vec<T> {
+    matrix.transpose().as_slice().to_vec()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixDisplay<T: RealField> {
+    pub matrix: DMatrix<T>,
+    pub summary: Vec<(&'static str, T)>,
+}
+
+impl<T: RealField> fmt::Display for MatrixDisplay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+        let entries: Vec<String> = self
+            .matrix
+            .iter()
+            .map(|value| format!("{value:.precision$}"))
+            .collect();
+        let width = entries.iter().map(String::len).max().unwrap_or(0);
+        let rows = self.matrix.nrows();
+
+        for i in 0..rows {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for j in 0..self.matrix.ncols() {
+                write!(f, " {:>width$}", entries[i + j * rows])?;
+            }
+            write!(f, " ]")?;
+        }
+        for (k, (label, value)) in self.summary.iter().enumerate() {
+            let separator = if k == 0 { "\n" } else { ", " };
+            write!(f, "{separator}{label} = {value:.precision$}")?;
+        }
+        Ok(())
+    }
+}
+
+pub trait MatrixManifold: Manifold {
+    fn matrix_shape(&self) -> (usize, usize);
+
+    fn matrix_summary(&self, _matrix: &DMatrix<Self::Scalar>) -> Vec<(&'static str, Self::Scalar)> {
+        Vec::new()
+    }
+
+    fn display_point(&self, point: &[Self::Scalar]) -> Result<MatrixDisplay<Self::Scalar>> {
+        let matrix = self.matrix_from_point(point)?;
+        let summary = self.matrix_summary(&matrix);
+        Ok(MatrixDisplay { matrix, summary })
+    }
+
+    fn display_tangent(
+        &self,
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<MatrixDisplay<Self::Scalar>> {
+        Ok(MatrixDisplay {
+            matrix: self.matrix_from_tangent(tangent)?,
+            summary: Vec::new(),
+        })
+    }
+
+    fn matrix_from_point(&self, point: &[Self::Scalar]) -> Result<DMatrix<Self::Scalar>> {
+        let (rows, cols) = self.matrix_shape();
+        check_len(rows * cols, point.len())?;
+        Ok(DMatrix::from_row_slice(rows, cols, point))
+    }
+
+    fn point_from_matrix(&self, matrix: &DMatrix<Self::Scalar>) -> Result<Vec<Self::Scalar>> {
+        check_shape(self.matrix_shape(), matrix.shape())?;
+        let point = row_major(matrix);
+        check_finite(&point, "Point")?;
+        self.check_point(&point)?;
+        Ok(point)
+    }
+
+    fn matrix_from_tangent(
+        &self,
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<DMatrix<Self::Scalar>> {
+        self.matrix_from_point(tangent.as_slice())
+    }
+
+    fn tangent_from_matrix(
+        &self,
+        at: &[Self::Scalar],
+        matrix: &DMatrix<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        check_shape(self.matrix_shape(), matrix.shape())?;
+        let tangent = TangentVector::new(DVector::from_vec(row_major(matrix)));
+        check_finite(tangent.as_slice(), "Tangent vector")?;
+        self.check_tangent_vector(at, &tangent)?;
+        Ok(tangent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+
+    impl MatrixManifold for TestEuclideanManifold {
+        fn matrix_shape(&self) -> (usize, usize) {
+            (2, self.dimension / 2)
+        }
+    }
+
+    #[test]
+    fn test_points_and_tangents_flatten_row_major() {
+        let manifold = TestEuclideanManifold { dimension: 6 };
+        let matrix = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let point = manifold.point_from_matrix(&matrix).unwrap();
+        assert_eq!(point, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(manifold.matrix_from_point(&point).unwrap(), matrix);
+
+        let tangent = manifold.tangent_from_matrix(&point, &matrix).unwrap();
+        assert_eq!(tangent.as_slice(), point.as_slice());
+        assert_eq!(manifold.matrix_from_tangent(&tangent).unwrap(), matrix);
+    }
+
+    #[test]
+    fn test_display_aligns_rows_and_columns() {
+        let manifold = TestEuclideanManifold { dimension: 6 };
+        let shown = manifold
+            .display_point(&[1.0, -22.5, 3.0, 4.0, 5.0, 0.125])
+            .unwrap();
+        assert_eq!(
+            format!("{shown}"),
+            "[   1.0000 -22.5000   3.0000 ]\n[   4.0000   5.0000   0.1250 ]"
+        );
+        assert_eq!(
+            format!("{shown:.1}"),
+            "[   1.0 -22.5   3.0 ]\n[   4.0   5.0   0.1 ]"
+        );
+
+        let tangent = TangentVector::from(vec![0.0; 6]);
+        let shown = MatrixDisplay {
+            summary: vec![("trace", 0.0), ("norm", 0.0)],
+            ..manifold.display_tangent(&tangent).unwrap()
+        };
+        assert_eq!(
+            format!("{shown:.0}"),
+            "[ 0 0 0 ]\n[ 0 0 0 ]\ntrace = 0, norm = 0"
+        );
+        assert!(manifold.display_point(&[0.0; 4]).is_err());
+    }
+
+    #[test]
+    fn test_shape_and_finiteness_are_checked() {
+        let manifold = TestEuclideanManifold { dimension: 6 };
+        assert!(matches!(
+            manifold.point_from_matrix(&DMatrix::zeros(3, 2)),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        ));
+        assert!(matches!(
+            manifold.matrix_from_point(&[0.0; 4]),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 6,
+                got: 4
+            })
+        ));
+        let mut matrix = DMatrix::zeros(2, 3);
+        matrix[(1, 2)] = f64::NAN;
+        assert!(matches!(
+            manifold.tangent_from_matrix(&[0.0; 6], &matrix),
+            Err(ManifoldError::NumericalError(_))
+        ));
+    }
+}
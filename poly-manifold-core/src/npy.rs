@@ -0,0 +1,265 @@
+use crate::array::{points_from_array, points_to_array};
+use crate::validation::validate_entry_points;
+use crate::{Manifold, ManifoldError, Result};
+use ndarray::Array2;
+use ndarray_npy::{
+    NpzReader, NpzWriter, ReadNpyError, ReadNpyExt, ReadNpzError, WriteNpyExt, WriteNpzError,
+};
+use std::io::{Read, Seek, Write};
+
+fn read_error(error: ReadNpyError) -> ManifoldError {
+    match error {
+        ReadNpyError::WrongNdim(_, got) => ManifoldError::DimensionMismatch { expected: 2, got },
+        ReadNpyError::WrongDescriptor(descriptor) => ManifoldError::InvalidParameter(format!(
+            "Expected a float64 array, found descriptor {}",
+            descriptor
+        )),
+        other => ManifoldError::IoError(other.to_string()),
+    }
+}
+
+fn read_npz_error(error: ReadNpzError) -> ManifoldError {
+    match error {
+        ReadNpzError::Npy(error) => read_error(error),
+        other => ManifoldError::IoError(other.to_string()),
+    }
+}
+
+fn write_npz_error(error: WriteNpzError) -> ManifoldError {
+    ManifoldError::IoError(error.to_string())
+}
+
+pub fn validate_point_array<M>(manifold: &M, array: &Array2<f64>) -> Result<Vec<Vec<f64>>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    if array.ncols() != manifold.ambient_dim() {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: manifold.ambient_dim(),
+            got: array.ncols(),
+        });
+    }
+    let points = points_from_array(array.view());
+    validate_entry_points(manifold, &points)?;
+    Ok(points)
+}
+
+pub fn read_points_npy<M, R>(manifold: &M, reader: R) -> Result<Vec<Vec<f64>>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+    R: Read,
+{
+    let array = Array2::<f64>::read_npy(reader).map_err(read_error)?;
+    validate_point_array(manifold, &array)
+}
+
+pub fn write_points_npy<W: Write>(writer: W, points: &[Vec<f64>]) -> Result<()> {
+    points_to_array(points)?
+        .write_npy(writer)
+        .map_err(|e| ManifoldError::IoError(e.to_string()))
+}
+
+pub fn npz_names<R: Read + Seek>(reader: R) -> Result<Vec<String>> {
+    NpzReader::new(reader)
+        .and_then(|mut npz| npz.names())
+        .map_err(read_npz_error)
+}
+
+pub fn read_points_npz<M, R>(manifold: &M, reader: R, name: &str) -> Result<Vec<Vec<f64>>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+    R: Read + Seek,
+{
+    let mut npz = NpzReader::new(reader).map_err(read_npz_error)?;
+    let array: Array2<f64> = npz.by_name(name).map_err(read_npz_error)?;
+    validate_point_array(manifold, &array)
+}
+
+pub fn write_points_npz<W>(writer: W, arrays: &[(&str, &[Vec<f64>])]) -> Result<W>
+where
+    W: Write + Seek,
+{
+    let mut npz = NpzWriter::new_compressed(writer);
+    for (name, points) in arrays {
+        npz.add_array(*name, &points_to_array(points)?)
+            .map_err(write_npz_error)?;
+    }
+    npz.finish().map_err(write_npz_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tangent::TangentVector;
+    use ndarray::ShapeBuilder;
+    use std::io::Cursor;
+
+    struct UnitCircle;
+
+    impl Manifold for UnitCircle {
+        type Scalar = f64;
+
+        fn dim(&self) -> usize {
+            1
+        }
+
+        fn ambient_dim(&self) -> usize {
+            2
+        }
+
+        fn check_point(&self, point: &[f64]) -> Result<()> {
+            if point.len() != 2 {
+                return Err(ManifoldError::DimensionMismatch {
+                    expected: 2,
+                    got: point.len(),
+                });
+            }
+            if (point[0].hypot(point[1]) - 1.0).abs() > 1e-10 {
+                return Err(ManifoldError::PointNotOnManifold {
+                    reason: "Point is not on the unit circle".to_string(),
+                });
+            }
+            Ok(())
+        }
+
+        fn check_tangent_vector(
+            &self,
+            _point: &[f64],
+            _tangent: &TangentVector<f64>,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn project_to_manifold(&self, point: &[f64]) -> Result<Vec<f64>> {
+            let norm = point[0].hypot(point[1]);
+            Ok(vec![point[0] / norm, point[1] / norm])
+        }
+
+        fn project_to_tangent_space(
+            &self,
+            _point: &[f64],
+            vector: &TangentVector<f64>,
+        ) -> Result<TangentVector<f64>> {
+            Ok(vector.clone())
+        }
+
+        fn inner_product(
+            &self,
+            _point: &[f64],
+            v1: &TangentVector<f64>,
+            v2: &TangentVector<f64>,
+        ) -> Result<f64> {
+            Ok(v1.dot(v2))
+        }
+
+        fn exp(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<Vec<f64>> {
+            self.project_to_manifold(&[
+                point[0] + tangent.components[0],
+                point[1] + tangent.components[1],
+            ])
+        }
+
+        fn log(&self, point: &[f64], other: &[f64]) -> Result<TangentVector<f64>> {
+            Ok(TangentVector::new(nalgebra::DVector::from_vec(vec![
+                other[0] - point[0],
+                other[1] - point[1],
+            ])))
+        }
+    }
+
+    fn circle_points() -> Vec<Vec<f64>> {
+        vec![vec![1.0, 0.0], vec![0.6, 0.8], vec![0.0, -1.0]]
+    }
+
+    #[test]
+    fn test_npy_round_trip() {
+        let points = circle_points();
+        let mut buffer = Vec::new();
+        write_points_npy(&mut buffer, &points).unwrap();
+        assert!(buffer.starts_with(b"\x93NUMPY"));
+        assert_eq!(
+            read_points_npy(&UnitCircle, buffer.as_slice()).unwrap(),
+            points
+        );
+    }
+
+    #[test]
+    fn test_npy_rejects_wrong_shape_and_off_manifold_points() {
+        let mut buffer = Vec::new();
+        write_points_npy(&mut buffer, &[vec![1.0, 0.0, 0.0]]).unwrap();
+        assert!(matches!(
+            read_points_npy(&UnitCircle, buffer.as_slice()),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        ));
+
+        let mut buffer = Vec::new();
+        write_points_npy(&mut buffer, &[vec![2.0, 0.0]]).unwrap();
+        assert!(matches!(
+            read_points_npy(&UnitCircle, buffer.as_slice()),
+            Err(ManifoldError::PointNotOnManifold { .. })
+        ));
+
+        let mut buffer = Vec::new();
+        ndarray::array![1.0, 0.0].write_npy(&mut buffer).unwrap();
+        assert!(matches!(
+            read_points_npy(&UnitCircle, buffer.as_slice()),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: 1
+            })
+        ));
+
+        let mut buffer = Vec::new();
+        ndarray::array![[1.0f32, 0.0]]
+            .write_npy(&mut buffer)
+            .unwrap();
+        assert!(matches!(
+            read_points_npy(&UnitCircle, buffer.as_slice()),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_npz_round_trip_by_name() {
+        let train = circle_points();
+        let test = vec![vec![-1.0, 0.0]];
+        let cursor = write_points_npz(
+            Cursor::new(Vec::new()),
+            &[("train", train.as_slice()), ("test", test.as_slice())],
+        )
+        .unwrap();
+        let bytes = cursor.into_inner();
+
+        let mut names = npz_names(Cursor::new(&bytes)).unwrap();
+        names.sort();
+        assert_eq!(names, vec!["test", "train"]);
+        assert_eq!(
+            read_points_npz(&UnitCircle, Cursor::new(&bytes), "train").unwrap(),
+            train
+        );
+        assert_eq!(
+            read_points_npz(&UnitCircle, Cursor::new(&bytes), "test").unwrap(),
+            test
+        );
+        assert!(matches!(
+            read_points_npz(&UnitCircle, Cursor::new(&bytes), "missing"),
+            Err(ManifoldError::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn test_fortran_order_arrays_are_read_row_wise() {
+        let points = circle_points();
+        let column_major = Array2::from_shape_fn((3, 2).f(), |(i, j)| points[i][j]);
+        let mut buffer = Vec::new();
+        column_major.write_npy(&mut buffer).unwrap();
+        assert!(buffer.windows(20).any(|w| w == b"'fortran_order': Tru"));
+        assert_eq!(
+            read_points_npy(&UnitCircle, buffer.as_slice()).unwrap(),
+            points
+        );
+    }
+}
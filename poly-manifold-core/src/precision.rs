@@ -0,0 +1,315 @@
+use crate::context::tolerance_scale;
+use crate::{Manifold, ManifoldError, Result, TangentVector};
+use alloc::{format, vec::Vec};
+use nalgebra::{convert, convert_unchecked, RealField};
+
+pub fn tolerance<T: RealField + Copy>(value: f64) -> T {
+    let floor = T::default_epsilon() * convert(100.0);
+    convert::<f64, T>(value * tolerance_scale()).max(floor)
+}
+
+pub fn promote<T: RealField + Copy>(values: &[T]) -> Vec<f64> {
+    values.iter().map(|x| convert_unchecked(*x)).collect()
+}
+
+pub fn demote<T: RealField + Copy>(values: &[f64]) -> Vec<T> {
+    values.iter().map(|x| convert(*x)).collect()
+}
+
+pub fn promote_tangent<T: RealField + Copy>(tangent: &TangentVector<T>) -> TangentVector<f64> {
+    tangent.map(convert_unchecked)
+}
+
+pub fn demote_tangent<T: RealField + Copy>(tangent: &TangentVector<f64>) -> TangentVector<T> {
+    tangent.map(convert)
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Promoted<M> {
+    pub inner: M,
+}
+
+impl<M: Manifold<Scalar = f64>> Promoted<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+
+    fn lift_point(&self, point: &[f32]) -> Result<Vec<f64>> {
+        self.inner.project_to_manifold(&promote(point))
+    }
+
+    fn lift_tangent(
+        &self,
+        point: &[f64],
+        tangent: &TangentVector<f32>,
+    ) -> Result<TangentVector<f64>> {
+        self.inner
+            .project_to_tangent_space(point, &promote_tangent(tangent))
+    }
+}
+
+impl<M: Manifold<Scalar = f64>> Manifold for Promoted<M> {
+    type Scalar = f32;
+
+    fn dim(&self) -> usize {
+        self.inner.dim()
+    }
+
+    fn ambient_dim(&self) -> usize {
+        self.inner.ambient_dim()
+    }
+
+    fn check_point(&self, point: &[f32]) -> Result<()> {
+        let promoted = promote(point);
+        let lifted = self.inner.project_to_manifold(&promoted)?;
+        self.inner.check_point(&lifted)?;
+
+        let deviation = promoted
+            .iter()
+            .zip(lifted.iter())
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f64::max);
+        let scale = lifted.iter().map(|x| x.abs()).fold(1.0, f64::max);
+        if deviation > tolerance::<f32>(1e-10) as f64 * scale {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: format!("Point is {} away from the manifold", deviation),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_tangent_vector(&self, point: &[f32], tangent: &TangentVector<f32>) -> Result<()> {
+        self.check_point(point)?;
+        let lifted = self.lift_point(point)?;
+        let promoted = promote_tangent(tangent);
+        let projected = self.inner.project_to_tangent_space(&lifted, &promoted)?;
+        self.inner.check_tangent_vector(&lifted, &projected)?;
+
+        let deviation = (promoted - projected.clone()).norm();
+        if deviation > tolerance::<f32>(1e-10) as f64 * (1.0 + projected.norm()) {
+            return Err(ManifoldError::InvalidTangentVector {
+                reason: format!(
+                    "Tangent vector is {} away from the tangent space",
+                    deviation
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    fn project_to_manifold(&self, point: &[f32]) -> Result<Vec<f32>> {
+        Ok(demote(&self.lift_point(point)?))
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[f32],
+        vector: &TangentVector<f32>,
+    ) -> Result<TangentVector<f32>> {
+        let lifted = self.lift_point(point)?;
+        Ok(demote_tangent(&self.lift_tangent(&lifted, vector)?))
+    }
+
+    fn exp(&self, point: &[f32], tangent: &TangentVector<f32>) -> Result<Vec<f32>> {
+        self.check_tangent_vector(point, tangent)?;
+        self.exp_unchecked(point, tangent)
+    }
+
+    fn log(&self, point: &[f32], other: &[f32]) -> Result<TangentVector<f32>> {
+        self.check_point(point)?;
+        self.check_point(other)?;
+        self.log_unchecked(point, other)
+    }
+
+    fn inner_product(
+        &self,
+        point: &[f32],
+        v1: &TangentVector<f32>,
+        v2: &TangentVector<f32>,
+    ) -> Result<f32> {
+        self.check_point(point)?;
+        self.inner_product_unchecked(point, v1, v2)
+    }
+
+    fn exp_unchecked(&self, point: &[f32], tangent: &TangentVector<f32>) -> Result<Vec<f32>> {
+        let lifted = self.lift_point(point)?;
+        let tangent = self.lift_tangent(&lifted, tangent)?;
+        Ok(demote(&self.inner.exp_unchecked(&lifted, &tangent)?))
+    }
+
+    fn log_unchecked(&self, point: &[f32], other: &[f32]) -> Result<TangentVector<f32>> {
+        let lifted = self.lift_point(point)?;
+        let other = self.lift_point(other)?;
+        Ok(demote_tangent(&self.inner.log_unchecked(&lifted, &other)?))
+    }
+
+    fn inner_product_unchecked(
+        &self,
+        point: &[f32],
+        v1: &TangentVector<f32>,
+        v2: &TangentVector<f32>,
+    ) -> Result<f32> {
+        let lifted = self.lift_point(point)?;
+        let v1 = self.lift_tangent(&lifted, v1)?;
+        let v2 = self.lift_tangent(&lifted, v2)?;
+        Ok(self.inner.inner_product_unchecked(&lifted, &v1, &v2)? as f32)
+    }
+
+    fn distance(&self, point1: &[f32], point2: &[f32]) -> Result<f32> {
+        self.check_point(point1)?;
+        self.check_point(point2)?;
+        let point1 = self.lift_point(point1)?;
+        let point2 = self.lift_point(point2)?;
+        Ok(self.inner.distance(&point1, &point2)? as f32)
+    }
+
+    fn log_volume_change(&self, point: &[f32], tangent: &TangentVector<f32>) -> Option<f32> {
+        let lifted = self.lift_point(point).ok()?;
+        let tangent = self.lift_tangent(&lifted, tangent).ok()?;
+        self.inner
+            .log_volume_change(&lifted, &tangent)
+            .map(|value| value as f32)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Widened<M> {
+    pub inner: M,
+}
+
+impl<M: Manifold> Widened<M>
+where
+    M::Scalar: Copy,
+{
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<M: Manifold> Manifold for Widened<M>
+where
+    M::Scalar: Copy,
+{
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        self.inner.dim()
+    }
+
+    fn ambient_dim(&self) -> usize {
+        self.inner.ambient_dim()
+    }
+
+    fn check_point(&self, point: &[f64]) -> Result<()> {
+        self.inner.check_point(&demote(point))
+    }
+
+    fn check_tangent_vector(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<()> {
+        self.inner
+            .check_tangent_vector(&demote(point), &demote_tangent(tangent))
+    }
+
+    fn project_to_manifold(&self, point: &[f64]) -> Result<Vec<f64>> {
+        Ok(promote(&self.inner.project_to_manifold(&demote(point))?))
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[f64],
+        vector: &TangentVector<f64>,
+    ) -> Result<TangentVector<f64>> {
+        let projected = self
+            .inner
+            .project_to_tangent_space(&demote(point), &demote_tangent(vector))?;
+        Ok(promote_tangent(&projected))
+    }
+
+    fn exp(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<Vec<f64>> {
+        Ok(promote(
+            &self.inner.exp(&demote(point), &demote_tangent(tangent))?,
+        ))
+    }
+
+    fn log(&self, point: &[f64], other: &[f64]) -> Result<TangentVector<f64>> {
+        Ok(promote_tangent(
+            &self.inner.log(&demote(point), &demote(other))?,
+        ))
+    }
+
+    fn inner_product(
+        &self,
+        point: &[f64],
+        v1: &TangentVector<f64>,
+        v2: &TangentVector<f64>,
+    ) -> Result<f64> {
+        let value =
+            self.inner
+                .inner_product(&demote(point), &demote_tangent(v1), &demote_tangent(v2))?;
+        Ok(convert_unchecked(value))
+    }
+
+    fn exp_unchecked(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<Vec<f64>> {
+        Ok(promote(
+            &self
+                .inner
+                .exp_unchecked(&demote(point), &demote_tangent(tangent))?,
+        ))
+    }
+
+    fn log_unchecked(&self, point: &[f64], other: &[f64]) -> Result<TangentVector<f64>> {
+        Ok(promote_tangent(
+            &self.inner.log_unchecked(&demote(point), &demote(other))?,
+        ))
+    }
+
+    fn inner_product_unchecked(
+        &self,
+        point: &[f64],
+        v1: &TangentVector<f64>,
+        v2: &TangentVector<f64>,
+    ) -> Result<f64> {
+        let value = self.inner.inner_product_unchecked(
+            &demote(point),
+            &demote_tangent(v1),
+            &demote_tangent(v2),
+        )?;
+        Ok(convert_unchecked(value))
+    }
+
+    fn distance(&self, point1: &[f64], point2: &[f64]) -> Result<f64> {
+        let value = self.inner.distance(&demote(point1), &demote(point2))?;
+        Ok(convert_unchecked(value))
+    }
+
+    fn log_volume_change(&self, point: &[f64], tangent: &TangentVector<f64>) -> Option<f64> {
+        self.inner
+            .log_volume_change(&demote(point), &demote_tangent(tangent))
+            .map(convert_unchecked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::DVector;
+
+    #[test]
+    fn test_tolerance_is_floored_by_precision() {
+        assert_eq!(tolerance::<f64>(1e-10), 1e-10);
+        assert!(tolerance::<f32>(1e-10) > 1e-6);
+        assert_eq!(tolerance::<f32>(0.5), 0.5);
+    }
+
+    #[test]
+    fn test_promote_demote_round_trip() {
+        let values = [1.5f32, -0.25, 3.0];
+        let promoted = promote(&values);
+        assert_eq!(promoted, vec![1.5, -0.25, 3.0]);
+        assert_eq!(demote::<f32>(&promoted), values.to_vec());
+
+        let tangent = TangentVector::new(DVector::from_vec(vec![0.125f32, -2.0]));
+        let round_trip = demote_tangent::<f32>(&promote_tangent(&tangent));
+        assert_relative_eq!(round_trip.components, tangent.components);
+    }
+}
@@ -0,0 +1,9 @@
+pub use crate::context::Context;
+pub use crate::equivalence::PointEquivalence;
+pub use crate::error::{ManifoldError, Result, ResultExt};
+pub use crate::input::{AsPoint, AsTangent, ManifoldExt};
+pub use crate::lie::LieGroup;
+pub use crate::manifold::Manifold;
+pub use crate::matrix::MatrixManifold;
+pub use crate::tangent::TangentVector;
+pub use crate::typed::{Point, Tangent, TypedManifold};
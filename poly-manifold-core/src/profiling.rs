@@ -0,0 +1,412 @@
+use crate::{Manifold, PointComparison, PointEquivalence, Result, TangentVector};
+use alloc::vec::Vec;
+#[cfg(feature = "profiling")]
+use core::cell::Cell;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Counter {
+    Cholesky,
+    Eigendecomposition,
+    Exp,
+    Log,
+    Retraction,
+    CostEvaluation,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperationCounts {
+    pub cholesky: u64,
+    pub eigendecompositions: u64,
+    pub exp: u64,
+    pub log: u64,
+    pub retractions: u64,
+    pub cost_evaluations: u64,
+}
+
+impl OperationCounts {
+    pub fn get(&self, counter: Counter) -> u64 {
+        match counter {
+            Counter::Cholesky => self.cholesky,
+            Counter::Eigendecomposition => self.eigendecompositions,
+            Counter::Exp => self.exp,
+            Counter::Log => self.log,
+            Counter::Retraction => self.retractions,
+            Counter::CostEvaluation => self.cost_evaluations,
+        }
+    }
+
+    pub fn since(&self, earlier: &OperationCounts) -> OperationCounts {
+        OperationCounts {
+            cholesky: self.cholesky - earlier.cholesky,
+            eigendecompositions: self.eigendecompositions - earlier.eigendecompositions,
+            exp: self.exp - earlier.exp,
+            log: self.log - earlier.log,
+            retractions: self.retractions - earlier.retractions,
+            cost_evaluations: self.cost_evaluations - earlier.cost_evaluations,
+        }
+    }
+
+    #[cfg(feature = "profiling")]
+    fn saturating_since(&self, earlier: &OperationCounts) -> OperationCounts {
+        OperationCounts {
+            cholesky: self.cholesky.saturating_sub(earlier.cholesky),
+            eigendecompositions: self
+                .eigendecompositions
+                .saturating_sub(earlier.eigendecompositions),
+            exp: self.exp.saturating_sub(earlier.exp),
+            log: self.log.saturating_sub(earlier.log),
+            retractions: self.retractions.saturating_sub(earlier.retractions),
+            cost_evaluations: self
+                .cost_evaluations
+                .saturating_sub(earlier.cost_evaluations),
+        }
+    }
+
+    #[cfg(feature = "profiling")]
+    fn increment(&mut self, counter: Counter) {
+        let slot = match counter {
+            Counter::Cholesky => &mut self.cholesky,
+            Counter::Eigendecomposition => &mut self.eigendecompositions,
+            Counter::Exp => &mut self.exp,
+            Counter::Log => &mut self.log,
+            Counter::Retraction => &mut self.retractions,
+            Counter::CostEvaluation => &mut self.cost_evaluations,
+        };
+        *slot += 1;
+    }
+}
+
+impl core::ops::Add for OperationCounts {
+    type Output = OperationCounts;
+
+    fn add(self, other: OperationCounts) -> OperationCounts {
+        OperationCounts {
+            cholesky: self.cholesky + other.cholesky,
+            eigendecompositions: self.eigendecompositions + other.eigendecompositions,
+            exp: self.exp + other.exp,
+            log: self.log + other.log,
+            retractions: self.retractions + other.retractions,
+            cost_evaluations: self.cost_evaluations + other.cost_evaluations,
+        }
+    }
+}
+
+impl core::iter::Sum for OperationCounts {
+    fn sum<I: Iterator<Item = OperationCounts>>(iter: I) -> OperationCounts {
+        iter.fold(OperationCounts::default(), |total, counts| total + counts)
+    }
+}
+
+#[cfg(feature = "profiling")]
+thread_local! {
+    static COUNTS: Cell<OperationCounts> = Cell::new(OperationCounts::default());
+}
+
+#[cfg(feature = "profiling")]
+pub fn record(counter: Counter) {
+    COUNTS.with(|counts| {
+        let mut current = counts.get();
+        current.increment(counter);
+        counts.set(current);
+    });
+}
+
+#[cfg(not(feature = "profiling"))]
+#[inline(always)]
+pub fn record(_counter: Counter) {}
+
+#[cfg(feature = "profiling")]
+pub fn snapshot() -> OperationCounts {
+    COUNTS.with(Cell::get)
+}
+
+#[cfg(not(feature = "profiling"))]
+pub fn snapshot() -> OperationCounts {
+    OperationCounts::default()
+}
+
+/// Runs `task` and returns the operations it recorded on the current thread.
+pub fn measure<R>(task: impl FnOnce() -> R) -> (R, OperationCounts) {
+    let start = snapshot();
+    let value = task();
+    (value, snapshot().since(&start))
+}
+
+/// Credits the calling thread with work that parallel tasks measured on other
+/// threads. `before` is the caller's snapshot from just before the tasks were
+/// spawned; whatever of `tasks` already ran on this thread is not added twice.
+#[cfg(feature = "profiling")]
+pub fn absorb(before: &OperationCounts, tasks: OperationCounts) {
+    let stolen = tasks.saturating_since(&snapshot().since(before));
+    COUNTS.with(|counts| counts.set(counts.get() + stolen));
+}
+
+#[cfg(not(feature = "profiling"))]
+#[inline(always)]
+pub fn absorb(_before: &OperationCounts, _tasks: OperationCounts) {}
+
+pub fn counted_cost<T, F>(cost_function: F) -> impl Fn(&[T]) -> T
+where
+    F: Fn(&[T]) -> T,
+{
+    move |point| {
+        record(Counter::CostEvaluation);
+        cost_function(point)
+    }
+}
+
+pub struct Counted<M> {
+    pub inner: M,
+}
+
+impl<M: Manifold> Counted<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<M: Manifold> Manifold for Counted<M> {
+    type Scalar = M::Scalar;
+
+    fn dim(&self) -> usize {
+        self.inner.dim()
+    }
+
+    fn ambient_dim(&self) -> usize {
+        self.inner.ambient_dim()
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.inner.check_point(point)
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.inner.check_tangent_vector(point, tangent)
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.inner.project_to_manifold(point)
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.inner.project_to_tangent_space(point, vector)
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        record(Counter::Exp);
+        self.inner.exp(point, tangent)
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        record(Counter::Log);
+        self.inner.log(point, other)
+    }
+
+    fn inner_product(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.inner.inner_product(point, v1, v2)
+    }
+
+    fn norm(
+        &self,
+        point: &[Self::Scalar],
+        v: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.inner.norm(point, v)
+    }
+
+    fn exp_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        record(Counter::Exp);
+        self.inner.exp_unchecked(point, tangent)
+    }
+
+    fn log_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        record(Counter::Log);
+        self.inner.log_unchecked(point, other)
+    }
+
+    fn inner_product_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.inner.inner_product_unchecked(point, v1, v2)
+    }
+
+    fn exp_unchecked_into(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        out: &mut Vec<Self::Scalar>,
+    ) -> Result<()> {
+        record(Counter::Exp);
+        self.inner.exp_unchecked_into(point, tangent, out)
+    }
+
+    fn project_to_tangent_space_in_place(
+        &self,
+        point: &[Self::Scalar],
+        vector: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.inner.project_to_tangent_space_in_place(point, vector)
+    }
+
+    fn retraction(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        record(Counter::Retraction);
+        self.inner.retraction(point, tangent)
+    }
+
+    fn distance(&self, point1: &[Self::Scalar], point2: &[Self::Scalar]) -> Result<Self::Scalar> {
+        self.inner.distance(point1, point2)
+    }
+
+    fn geodesic(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        t: Self::Scalar,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.inner.geodesic(point, tangent, t)
+    }
+
+    fn parallel_transport(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        direction: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.inner.parallel_transport(point, tangent, direction)
+    }
+
+    fn euclidean_to_riemannian_gradient(
+        &self,
+        point: &[Self::Scalar],
+        euclidean_gradient: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.inner
+            .euclidean_to_riemannian_gradient(point, euclidean_gradient)
+    }
+
+    fn euclidean_to_riemannian_gradient_in_place(
+        &self,
+        point: &[Self::Scalar],
+        gradient: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.inner
+            .euclidean_to_riemannian_gradient_in_place(point, gradient)
+    }
+
+    fn log_volume_change(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Option<Self::Scalar> {
+        self.inner.log_volume_change(point, tangent)
+    }
+
+    fn tangent_basis(&self, point: &[Self::Scalar]) -> Result<Vec<TangentVector<Self::Scalar>>> {
+        self.inner.tangent_basis(point)
+    }
+}
+
+impl<M: PointEquivalence> PointEquivalence for Counted<M> {
+    fn points_equivalent_by(
+        &self,
+        a: &[Self::Scalar],
+        b: &[Self::Scalar],
+        eq: &mut PointComparison<Self::Scalar>,
+    ) -> bool {
+        self.inner.points_equivalent_by(a, b, eq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_since() {
+        let earlier = OperationCounts {
+            cholesky: 2,
+            exp: 1,
+            ..OperationCounts::default()
+        };
+        let later = OperationCounts {
+            cholesky: 5,
+            exp: 1,
+            cost_evaluations: 4,
+            ..OperationCounts::default()
+        };
+        let delta = later.since(&earlier);
+        assert_eq!(delta.get(Counter::Cholesky), 3);
+        assert_eq!(delta.get(Counter::Exp), 0);
+        assert_eq!(delta.get(Counter::CostEvaluation), 4);
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn test_record_increments_thread_local_counts() {
+        let before = snapshot();
+        record(Counter::Eigendecomposition);
+        record(Counter::Eigendecomposition);
+        record(Counter::Log);
+        let delta = snapshot().since(&before);
+        assert_eq!(delta.eigendecompositions, 2);
+        assert_eq!(delta.log, 1);
+        assert_eq!(delta.exp, 0);
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn test_absorb_credits_work_from_other_threads_once() {
+        let before = snapshot();
+        let ((), here) = measure(|| record(Counter::Log));
+        let ((), there) = std::thread::spawn(|| {
+            measure(|| {
+                record(Counter::Exp);
+                record(Counter::Exp);
+            })
+        })
+        .join()
+        .unwrap();
+
+        absorb(&before, here + there);
+        let delta = snapshot().since(&before);
+        assert_eq!(delta.log, 1);
+        assert_eq!(delta.exp, 2);
+    }
+}
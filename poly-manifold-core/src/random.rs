@@ -0,0 +1,205 @@
+use crate::{Manifold, Result, TangentVector};
+use nalgebra::DVector;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+pub trait RandomSource {
+    fn next_u64(&mut self) -> u64;
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    fn next_gaussian(&mut self) -> f64 {
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * core::f64::consts::PI * u2).cos()
+    }
+
+    /// Uniform draw from `0..bound`, or `None` for an empty range.
+    fn next_below(&mut self, bound: usize) -> Option<usize> {
+        if bound == 0 {
+            return None;
+        }
+        Some(((self.next_u64() as u128 * bound as u128) >> 64) as usize)
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T])
+    where
+        Self: Sized,
+    {
+        for i in (1..items.len()).rev() {
+            if let Some(j) = self.next_below(i + 1) {
+                items.swap(i, j);
+            }
+        }
+    }
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeededRng {
+    state: [u64; 4],
+}
+
+impl SeededRng {
+    pub fn seed_from_u64(seed: u64) -> Self {
+        let mut sm = seed;
+        let mut next = || {
+            sm = sm.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = sm;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        Self {
+            state: [next(), next(), next(), next()],
+        }
+    }
+}
+
+impl RandomSource for SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
+
+#[cfg(feature = "rand")]
+pub struct RandSource<R>(pub R);
+
+#[cfg(feature = "rand")]
+impl<R: rand::Rng> RandomSource for RandSource<R> {
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::TryRng for SeededRng {
+    type Error = core::convert::Infallible;
+
+    fn try_next_u32(&mut self) -> core::result::Result<u32, Self::Error> {
+        Ok((RandomSource::next_u64(self) >> 32) as u32)
+    }
+
+    fn try_next_u64(&mut self) -> core::result::Result<u64, Self::Error> {
+        Ok(RandomSource::next_u64(self))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> core::result::Result<(), Self::Error> {
+        for chunk in dst.chunks_mut(8) {
+            let bytes = RandomSource::next_u64(self).to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::SeedableRng for SeededRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        let state = core::array::from_fn(|i| {
+            u64::from_le_bytes(seed[8 * i..8 * i + 8].try_into().unwrap())
+        });
+        if state == [0; 4] {
+            return Self::seed_from_u64(0);
+        }
+        Self { state }
+    }
+}
+
+pub fn random_tangent<M, R>(manifold: &M, point: &[f64], rng: &mut R) -> Result<TangentVector<f64>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+    R: RandomSource + ?Sized,
+{
+    let components = DVector::from_fn(point.len(), |_, _| rng.next_gaussian());
+    manifold.project_to_tangent_space(point, &TangentVector::new(components))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_seeded_rng_is_deterministic() {
+        let mut a = SeededRng::seed_from_u64(42);
+        let mut b = SeededRng::seed_from_u64(42);
+        let mut c = SeededRng::seed_from_u64(43);
+        let xs: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        let ys: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+        let zs: Vec<u64> = (0..8).map(|_| c.next_u64()).collect();
+        assert_eq!(xs, ys);
+        assert_ne!(xs, zs);
+    }
+
+    #[test]
+    fn test_next_f64_in_unit_interval() {
+        let mut rng = SeededRng::seed_from_u64(7);
+        for _ in 0..1000 {
+            let x = rng.next_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn test_next_gaussian_moments() {
+        let mut rng = SeededRng::seed_from_u64(1);
+        let n = 20000;
+        let samples: Vec<f64> = (0..n).map(|_| rng.next_gaussian()).collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+        assert_relative_eq!(mean, 0.0, epsilon = 0.05);
+        assert_relative_eq!(var, 1.0, epsilon = 0.05);
+    }
+
+    #[test]
+    fn test_next_below_and_shuffle() {
+        let mut rng = SeededRng::seed_from_u64(3);
+        for _ in 0..1000 {
+            assert!(rng.next_below(5).unwrap() < 5);
+        }
+        assert_eq!(rng.next_below(0), None);
+        let mut items: Vec<usize> = (0..10).collect();
+        rng.shuffle(&mut items);
+        let mut sorted = items.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_seeded_rng_drives_rand_apis() {
+        use rand::{RngExt, SeedableRng};
+
+        let mut a = SeededRng::seed_from_u64(5);
+        let mut b = RandSource(SeededRng::seed_from_u64(5));
+        assert_eq!(rand::Rng::next_u64(&mut a), b.next_u64());
+        let x: f64 = a.random();
+        assert!((0.0..1.0).contains(&x));
+
+        let from_seed = <SeededRng as SeedableRng>::from_seed([0; 32]);
+        assert_eq!(from_seed, SeededRng::seed_from_u64(0));
+        let mut bytes = [0u8; 11];
+        rand::Rng::fill_bytes(&mut a, &mut bytes);
+        assert!(bytes.iter().any(|b| *b != 0));
+    }
+}
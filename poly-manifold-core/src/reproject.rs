@@ -0,0 +1,326 @@
+use crate::validation::check_finite;
+use crate::{Manifold, PointComparison, PointEquivalence, Result, TangentVector};
+use alloc::vec::Vec;
+
+pub fn reproject_in_place<M: Manifold + ?Sized>(
+    manifold: &M,
+    point: &mut Vec<M::Scalar>,
+) -> Result<()> {
+    *point = manifold.project_to_manifold(point)?;
+    check_finite(point, "Reprojected point")?;
+    manifold.check_point(point)
+}
+
+pub struct Reprojected<M> {
+    pub inner: M,
+}
+
+impl<M: Manifold> Reprojected<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+
+    fn reproject(&self, mut point: Vec<M::Scalar>) -> Result<Vec<M::Scalar>> {
+        reproject_in_place(&self.inner, &mut point)?;
+        Ok(point)
+    }
+}
+
+impl<M: Manifold> Manifold for Reprojected<M> {
+    type Scalar = M::Scalar;
+
+    fn dim(&self) -> usize {
+        self.inner.dim()
+    }
+
+    fn ambient_dim(&self) -> usize {
+        self.inner.ambient_dim()
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.inner.check_point(point)
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.inner.check_tangent_vector(point, tangent)
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.inner.project_to_manifold(point)
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.inner.project_to_tangent_space(point, vector)
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.reproject(self.inner.exp(point, tangent)?)
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.inner.log(point, other)
+    }
+
+    fn inner_product(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.inner.inner_product(point, v1, v2)
+    }
+
+    fn norm(
+        &self,
+        point: &[Self::Scalar],
+        v: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.inner.norm(point, v)
+    }
+
+    fn exp_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.reproject(self.inner.exp_unchecked(point, tangent)?)
+    }
+
+    fn log_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.inner.log_unchecked(point, other)
+    }
+
+    fn inner_product_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.inner.inner_product_unchecked(point, v1, v2)
+    }
+
+    fn exp_unchecked_into(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        out: &mut Vec<Self::Scalar>,
+    ) -> Result<()> {
+        self.inner.exp_unchecked_into(point, tangent, out)?;
+        reproject_in_place(&self.inner, out)
+    }
+
+    fn project_to_tangent_space_in_place(
+        &self,
+        point: &[Self::Scalar],
+        vector: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.inner.project_to_tangent_space_in_place(point, vector)
+    }
+
+    fn retraction(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.reproject(self.inner.retraction(point, tangent)?)
+    }
+
+    fn distance(&self, point1: &[Self::Scalar], point2: &[Self::Scalar]) -> Result<Self::Scalar> {
+        self.inner.distance(point1, point2)
+    }
+
+    fn geodesic(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        t: Self::Scalar,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.reproject(self.inner.geodesic(point, tangent, t)?)
+    }
+
+    fn parallel_transport(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        direction: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.inner.parallel_transport(point, tangent, direction)
+    }
+
+    fn euclidean_to_riemannian_gradient(
+        &self,
+        point: &[Self::Scalar],
+        euclidean_gradient: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.inner
+            .euclidean_to_riemannian_gradient(point, euclidean_gradient)
+    }
+
+    fn euclidean_to_riemannian_gradient_in_place(
+        &self,
+        point: &[Self::Scalar],
+        gradient: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.inner
+            .euclidean_to_riemannian_gradient_in_place(point, gradient)
+    }
+
+    fn log_volume_change(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Option<Self::Scalar> {
+        self.inner.log_volume_change(point, tangent)
+    }
+
+    fn tangent_basis(&self, point: &[Self::Scalar]) -> Result<Vec<TangentVector<Self::Scalar>>> {
+        self.inner.tangent_basis(point)
+    }
+}
+
+impl<M: PointEquivalence> PointEquivalence for Reprojected<M> {
+    fn points_equivalent_by(
+        &self,
+        a: &[Self::Scalar],
+        b: &[Self::Scalar],
+        eq: &mut PointComparison<Self::Scalar>,
+    ) -> bool {
+        self.inner.points_equivalent_by(a, b, eq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ManifoldError;
+    use alloc::{string::ToString, vec};
+
+    struct DriftingCircle;
+
+    impl Manifold for DriftingCircle {
+        type Scalar = f64;
+
+        fn dim(&self) -> usize {
+            1
+        }
+
+        fn ambient_dim(&self) -> usize {
+            2
+        }
+
+        fn check_point(&self, point: &[f64]) -> Result<()> {
+            if (point[0].hypot(point[1]) - 1.0).abs() > 1e-12 {
+                return Err(ManifoldError::PointNotOnManifold {
+                    reason: "point is off the unit circle".to_string(),
+                });
+            }
+            Ok(())
+        }
+
+        fn check_tangent_vector(
+            &self,
+            _point: &[f64],
+            _tangent: &TangentVector<f64>,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn project_to_manifold(&self, point: &[f64]) -> Result<Vec<f64>> {
+            let norm = point[0].hypot(point[1]);
+            Ok(vec![point[0] / norm, point[1] / norm])
+        }
+
+        fn project_to_tangent_space(
+            &self,
+            point: &[f64],
+            vector: &TangentVector<f64>,
+        ) -> Result<TangentVector<f64>> {
+            let along = vector[0] * point[0] + vector[1] * point[1];
+            Ok(TangentVector::from(vec![
+                vector[0] - along * point[0],
+                vector[1] - along * point[1],
+            ]))
+        }
+
+        fn exp(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<Vec<f64>> {
+            Ok(vec![point[0] + tangent[0], point[1] + tangent[1]])
+        }
+
+        fn log(&self, point: &[f64], other: &[f64]) -> Result<TangentVector<f64>> {
+            let chord = TangentVector::from(vec![other[0] - point[0], other[1] - point[1]]);
+            self.project_to_tangent_space(point, &chord)
+        }
+
+        fn inner_product(
+            &self,
+            _point: &[f64],
+            v1: &TangentVector<f64>,
+            v2: &TangentVector<f64>,
+        ) -> Result<f64> {
+            Ok(v1.dot(v2))
+        }
+    }
+
+    #[test]
+    fn test_steps_are_pulled_back_onto_the_manifold() {
+        let manifold = Reprojected::new(DriftingCircle);
+        let point = [1.0, 0.0];
+        let tangent = TangentVector::from(vec![0.0, 0.5]);
+
+        assert!(DriftingCircle
+            .check_point(&DriftingCircle.exp(&point, &tangent).unwrap())
+            .is_err());
+        let expected = [2.0 / 5.0_f64.sqrt(), 1.0 / 5.0_f64.sqrt()];
+        for stepped in [
+            manifold.exp(&point, &tangent).unwrap(),
+            manifold.retraction(&point, &tangent).unwrap(),
+            manifold.geodesic(&point, &tangent, 1.0).unwrap(),
+        ] {
+            assert!((stepped[0] - expected[0]).abs() < 1e-12);
+            assert!((stepped[1] - expected[1]).abs() < 1e-12);
+        }
+
+        let mut current = point.to_vec();
+        let mut out = Vec::new();
+        for _ in 0..100 {
+            let step = manifold
+                .project_to_tangent_space(&current, &tangent)
+                .unwrap();
+            manifold
+                .exp_unchecked_into(&current, &step, &mut out)
+                .unwrap();
+            core::mem::swap(&mut current, &mut out);
+        }
+        assert!(manifold.check_point(&current).is_ok());
+    }
+
+    #[test]
+    fn test_unrecoverable_steps_are_rejected() {
+        let manifold = Reprojected::new(DriftingCircle);
+        let tangent = TangentVector::from(vec![-1.0, 0.0]);
+        assert!(matches!(
+            manifold.exp(&[1.0, 0.0], &tangent),
+            Err(ManifoldError::NumericalError(_))
+        ));
+    }
+}
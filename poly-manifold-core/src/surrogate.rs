@@ -0,0 +1,49 @@
+use crate::{Manifold, ManifoldError, Result};
+use nalgebra::DVectorView;
+
+pub fn chordal_distance(a: &[f64], b: &[f64]) -> Result<f64> {
+    if a.len() != b.len() {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: a.len(),
+            got: b.len(),
+        });
+    }
+    Ok(DVectorView::from_slice(a, a.len()).metric_distance(&DVectorView::from_slice(b, b.len())))
+}
+
+pub trait DistanceSurrogate: Manifold<Scalar = f64> {
+    fn surrogate_distance(&self, a: &[f64], b: &[f64]) -> Result<f64>;
+
+    fn distance_lower_bound(&self, surrogate: f64) -> f64;
+
+    fn distance_upper_bound(&self, _surrogate: f64) -> f64 {
+        f64::INFINITY
+    }
+
+    fn bounded_distance(&self, a: &[f64], b: &[f64], threshold: f64) -> Result<(f64, f64)> {
+        let surrogate = self.surrogate_distance(a, b)?;
+        let lower = self.distance_lower_bound(surrogate);
+        let upper = self.distance_upper_bound(surrogate);
+        if lower > threshold || lower == upper {
+            return Ok((lower, upper));
+        }
+        let exact = self.distance(a, b)?;
+        Ok((exact, exact))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_chordal_distance() {
+        assert_relative_eq!(
+            chordal_distance(&[0.0, 3.0], &[4.0, 0.0]).unwrap(),
+            5.0,
+            epsilon = 1e-12
+        );
+        assert!(chordal_distance(&[0.0], &[0.0, 1.0]).is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::batch::batch_len;
+use crate::validation::validate_entry_point;
+use crate::{Manifold, ManifoldError, Result};
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PointTable {
+    ambient_dim: usize,
+    data: Vec<f64>,
+}
+
+impl PointTable {
+    pub fn new(ambient_dim: usize, data: Vec<f64>) -> Result<Self> {
+        batch_len(ambient_dim, &[data.len()])?;
+        Ok(Self { ambient_dim, data })
+    }
+
+    pub fn from_points<M>(manifold: &M, points: &[Vec<f64>]) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let ambient_dim = manifold.ambient_dim();
+        let mut data = Vec::with_capacity(points.len() * ambient_dim);
+        for point in points {
+            if point.len() != ambient_dim {
+                return Err(ManifoldError::DimensionMismatch {
+                    expected: ambient_dim,
+                    got: point.len(),
+                });
+            }
+            validate_entry_point(manifold, point)?;
+            data.extend_from_slice(point);
+        }
+        Ok(Self { ambient_dim, data })
+    }
+
+    pub fn ambient_dim(&self) -> usize {
+        self.ambient_dim
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len() / self.ambient_dim
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    pub fn row(&self, index: usize) -> &[f64] {
+        &self.data[index * self.ambient_dim..(index + 1) * self.ambient_dim]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[f64]> {
+        self.data.chunks_exact(self.ambient_dim)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+pub fn archive_error(error: rkyv::rancor::Error) -> ManifoldError {
+    ManifoldError::IoError(format!("Invalid archive: {}", error))
+}
+
+#[cfg(feature = "rkyv")]
+impl PointTable {
+    pub fn to_bytes(&self) -> Result<rkyv::util::AlignedVec> {
+        rkyv::to_bytes::<rkyv::rancor::Error>(self).map_err(archive_error)
+    }
+
+    pub fn access(bytes: &[u8]) -> Result<&ArchivedPointTable> {
+        let table = rkyv::access::<ArchivedPointTable, rkyv::rancor::Error>(bytes)
+            .map_err(archive_error)?;
+        batch_len(table.ambient_dim(), &[table.data.len()])?;
+        Ok(table)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedPointTable {
+    pub fn ambient_dim(&self) -> usize {
+        self.ambient_dim.to_native() as usize
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len() / self.ambient_dim()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn row_into(&self, index: usize, out: &mut Vec<f64>) {
+        let n = self.ambient_dim();
+        let row = &self.data[index * n..(index + 1) * n];
+        out.clear();
+        out.extend(row.iter().map(|x| x.to_native()));
+    }
+
+    pub fn distances_to<M>(&self, manifold: &M, query: &[f64]) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        validate_entry_point(manifold, query)?;
+        let mut row = Vec::with_capacity(self.ambient_dim());
+        (0..self.len())
+            .map(|i| {
+                self.row_into(i, &mut row);
+                manifold.distance(query, &row)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+
+    #[test]
+    fn test_point_table_rows() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        let table = PointTable::from_points(&manifold, &[vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.row(1), &[3.0, 4.0]);
+        assert_eq!(table.rows().count(), 2);
+        assert_eq!(table, PointTable::new(2, vec![1.0, 2.0, 3.0, 4.0]).unwrap());
+        assert!(PointTable::new(2, vec![1.0, 2.0, 3.0]).is_err());
+        assert!(PointTable::from_points(&manifold, &[vec![1.0]]).is_err());
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_point_table_archive_round_trip() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        let table = PointTable::new(2, vec![0.0, 0.0, 3.0, 4.0, -1.0, 0.5]).unwrap();
+        let bytes = table.to_bytes().unwrap();
+
+        let archived = PointTable::access(&bytes).unwrap();
+        assert_eq!(archived.len(), 3);
+        let mut row = Vec::new();
+        archived.row_into(2, &mut row);
+        assert_eq!(row, vec![-1.0, 0.5]);
+        assert_eq!(
+            archived.distances_to(&manifold, &[0.0, 0.0]).unwrap(),
+            vec![0.0, 5.0, 1.25f64.sqrt()]
+        );
+
+        let restored: PointTable =
+            rkyv::deserialize::<PointTable, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(restored, table);
+        assert!(matches!(
+            PointTable::access(&bytes[..bytes.len() - 4]),
+            Err(ManifoldError::IoError(_))
+        ));
+    }
+}
@@ -1,5 +1,7 @@
-use nalgebra::{DVector, RealField};
+use alloc::vec::Vec;
+use nalgebra::{DMatrix, DVector, DVectorView, Dim, Dyn, RealField, U1};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TangentVector<T: RealField> {
     pub components: DVector<T>,
 }
@@ -26,6 +28,68 @@ impl<T: RealField> TangentVector<T> {
     pub fn norm(&self) -> T {
         self.norm_squared().sqrt()
     }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.components.as_slice()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.components.as_mut_slice()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    pub fn as_view(&self) -> TangentView<'_, T> {
+        TangentView::new(self.components.as_view())
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.components.dot(&other.components)
+    }
+
+    pub fn normalize(&mut self) -> T {
+        let norm = self.norm();
+        if norm > T::zero() {
+            self.components /= norm.clone();
+        }
+        norm
+    }
+
+    pub fn normalized(&self) -> Self {
+        let mut unit = self.clone();
+        unit.normalize();
+        unit
+    }
+
+    pub fn scale_to_norm(&mut self, target: T) -> T {
+        let norm = self.norm();
+        if norm > T::zero() {
+            self.components *= target / norm.clone();
+        }
+        norm
+    }
+
+    pub fn axpy(&mut self, alpha: T, x: &Self) {
+        self.components.axpy(alpha, &x.components, T::one());
+    }
+
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        Self::new(self.components.lerp(&other.components, t))
+    }
+
+    pub fn map<U: RealField, F: FnMut(T) -> U>(&self, f: F) -> TangentVector<U> {
+        TangentVector::new(self.components.map(f))
+    }
+
+    pub fn map_in_place<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.as_mut_slice().iter_mut().for_each(f);
+    }
+
+    pub fn zip_map<F: FnMut(T, T) -> T>(&self, other: &Self, f: F) -> Self {
+        Self::new(self.components.zip_map(&other.components, f))
+    }
 }
 
 impl<T: RealField> Clone for TangentVector<T> {
@@ -36,7 +100,149 @@ impl<T: RealField> Clone for TangentVector<T> {
     }
 }
 
-impl<T: RealField> std::ops::Add for TangentVector<T> {
+impl<T: RealField> PartialEq for TangentVector<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dim() == other.dim() && self.components == other.components
+    }
+}
+
+impl<T: RealField> core::fmt::Debug for TangentVector<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TangentVector")
+            .field("components", &self.components.as_slice())
+            .finish()
+    }
+}
+
+impl<T: RealField> core::fmt::Display for TangentVector<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[")?;
+        for (i, value) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match f.precision() {
+                Some(precision) => write!(f, "{value:.precision$}")?,
+                None => write!(f, "{value}")?,
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T: RealField> core::ops::Index<usize> for TangentVector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.components[index]
+    }
+}
+
+impl<T: RealField> core::ops::IndexMut<usize> for TangentVector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.components[index]
+    }
+}
+
+impl<T: RealField> IntoIterator for TangentVector<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Vec::from(self).into_iter()
+    }
+}
+
+impl<'a, T: RealField> IntoIterator for &'a TangentVector<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: RealField> IntoIterator for &'a mut TangentVector<T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T: RealField> FromIterator<T> for TangentVector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T: RealField> From<Vec<T>> for TangentVector<T> {
+    fn from(components: Vec<T>) -> Self {
+        Self::new(DVector::from_vec(components))
+    }
+}
+
+impl<T: RealField> From<DVector<T>> for TangentVector<T> {
+    fn from(components: DVector<T>) -> Self {
+        Self::new(components)
+    }
+}
+
+impl<T: RealField> From<TangentVector<T>> for Vec<T> {
+    fn from(tangent: TangentVector<T>) -> Self {
+        tangent.components.data.into()
+    }
+}
+
+impl<T: RealField> From<TangentVector<T>> for DVector<T> {
+    fn from(tangent: TangentVector<T>) -> Self {
+        tangent.components
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T: RealField> approx::AbsDiffEq for TangentVector<T> {
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.dim() == other.dim() && self.components.abs_diff_eq(&other.components, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T: RealField> approx::RelativeEq for TangentVector<T> {
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.dim() == other.dim()
+            && self
+                .components
+                .relative_eq(&other.components, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T: RealField> approx::UlpsEq for TangentVector<T> {
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.dim() == other.dim()
+            && self
+                .components
+                .ulps_eq(&other.components, epsilon, max_ulps)
+    }
+}
+
+impl<T: RealField> core::ops::Add for TangentVector<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -46,7 +252,7 @@ impl<T: RealField> std::ops::Add for TangentVector<T> {
     }
 }
 
-impl<T: RealField> std::ops::Sub for TangentVector<T> {
+impl<T: RealField> core::ops::Sub for TangentVector<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -56,7 +262,7 @@ impl<T: RealField> std::ops::Sub for TangentVector<T> {
     }
 }
 
-impl<T: RealField> std::ops::Mul<T> for TangentVector<T> {
+impl<T: RealField> core::ops::Mul<T> for TangentVector<T> {
     type Output = Self;
 
     fn mul(self, scalar: T) -> Self {
@@ -66,6 +272,251 @@ impl<T: RealField> std::ops::Mul<T> for TangentVector<T> {
     }
 }
 
+impl<T: RealField> core::ops::Add<&TangentVector<T>> for TangentVector<T> {
+    type Output = Self;
+
+    fn add(mut self, other: &TangentVector<T>) -> Self {
+        self.components += &other.components;
+        self
+    }
+}
+
+impl<T: RealField> core::ops::Add for &TangentVector<T> {
+    type Output = TangentVector<T>;
+
+    fn add(self, other: Self) -> TangentVector<T> {
+        TangentVector {
+            components: &self.components + &other.components,
+        }
+    }
+}
+
+impl<T: RealField> core::ops::Sub<&TangentVector<T>> for TangentVector<T> {
+    type Output = Self;
+
+    fn sub(mut self, other: &TangentVector<T>) -> Self {
+        self.components -= &other.components;
+        self
+    }
+}
+
+impl<T: RealField> core::ops::Sub for &TangentVector<T> {
+    type Output = TangentVector<T>;
+
+    fn sub(self, other: Self) -> TangentVector<T> {
+        TangentVector {
+            components: &self.components - &other.components,
+        }
+    }
+}
+
+impl<T: RealField> core::ops::Mul<T> for &TangentVector<T> {
+    type Output = TangentVector<T>;
+
+    fn mul(self, scalar: T) -> TangentVector<T> {
+        TangentVector {
+            components: &self.components * scalar,
+        }
+    }
+}
+
+impl<T: RealField> core::ops::Div<T> for TangentVector<T> {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self {
+        Self {
+            components: self.components / scalar,
+        }
+    }
+}
+
+impl<T: RealField> core::ops::Div<T> for &TangentVector<T> {
+    type Output = TangentVector<T>;
+
+    fn div(self, scalar: T) -> TangentVector<T> {
+        TangentVector {
+            components: &self.components / scalar,
+        }
+    }
+}
+
+impl<T: RealField> core::ops::Neg for TangentVector<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            components: -self.components,
+        }
+    }
+}
+
+impl<T: RealField> core::ops::Neg for &TangentVector<T> {
+    type Output = TangentVector<T>;
+
+    fn neg(self) -> TangentVector<T> {
+        TangentVector {
+            components: -&self.components,
+        }
+    }
+}
+
+impl<T: RealField> core::ops::AddAssign for TangentVector<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.components += other.components;
+    }
+}
+
+impl<T: RealField> core::ops::AddAssign<&TangentVector<T>> for TangentVector<T> {
+    fn add_assign(&mut self, other: &TangentVector<T>) {
+        self.components += &other.components;
+    }
+}
+
+impl<T: RealField> core::ops::SubAssign for TangentVector<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.components -= other.components;
+    }
+}
+
+impl<T: RealField> core::ops::SubAssign<&TangentVector<T>> for TangentVector<T> {
+    fn sub_assign(&mut self, other: &TangentVector<T>) {
+        self.components -= &other.components;
+    }
+}
+
+impl<T: RealField> core::ops::MulAssign<T> for TangentVector<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        self.components *= scalar;
+    }
+}
+
+impl<T: RealField> core::ops::DivAssign<T> for TangentVector<T> {
+    fn div_assign(&mut self, scalar: T) {
+        self.components /= scalar;
+    }
+}
+
+impl<T: RealField, R: Dim, C: Dim> core::ops::AddAssign<TangentView<'_, T, R, C>>
+    for TangentVector<T>
+{
+    fn add_assign(&mut self, other: TangentView<'_, T, R, C>) {
+        self.components += other.components;
+    }
+}
+
+impl<T: RealField, R: Dim, C: Dim> core::ops::SubAssign<TangentView<'_, T, R, C>>
+    for TangentVector<T>
+{
+    fn sub_assign(&mut self, other: TangentView<'_, T, R, C>) {
+        self.components -= other.components;
+    }
+}
+
+impl<T: RealField, R: Dim, C: Dim> From<TangentView<'_, T, R, C>> for TangentVector<T> {
+    fn from(view: TangentView<'_, T, R, C>) -> Self {
+        view.into_owned()
+    }
+}
+
+pub struct TangentView<'a, T: RealField, RStride: Dim = U1, CStride: Dim = Dyn> {
+    pub components: DVectorView<'a, T, RStride, CStride>,
+}
+
+impl<'a, T: RealField> TangentView<'a, T> {
+    pub fn from_slice(values: &'a [T]) -> Self {
+        Self::new(DVectorView::from_slice(values, values.len()))
+    }
+
+    pub fn column(matrix: &'a DMatrix<T>, index: usize) -> Self {
+        Self::new(matrix.column(index))
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.components.as_slice()
+    }
+}
+
+impl<'a, T: RealField> TangentView<'a, T, Dyn, Dyn> {
+    pub fn row(matrix: &'a DMatrix<T>, index: usize) -> Self {
+        let (rows, cols) = matrix.shape();
+        assert!(
+            index < rows,
+            "row index {index} out of bounds for {rows} rows"
+        );
+        Self::new(DVectorView::from_slice_with_strides_generic(
+            &matrix.as_slice()[index..],
+            Dyn(cols),
+            U1,
+            Dyn(rows),
+            Dyn(rows * cols),
+        ))
+    }
+}
+
+impl<'a, T: RealField, R: Dim, C: Dim> TangentView<'a, T, R, C> {
+    pub fn new(components: DVectorView<'a, T, R, C>) -> Self {
+        Self { components }
+    }
+
+    pub fn dim(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn norm_squared(&self) -> T {
+        self.components.dot(&self.components)
+    }
+
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    pub fn dot<R2: Dim, C2: Dim>(&self, other: &TangentView<'_, T, R2, C2>) -> T {
+        self.components.dot(&other.components)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.components.iter()
+    }
+
+    pub fn into_owned(self) -> TangentVector<T> {
+        TangentVector::new(self.components.clone_owned())
+    }
+}
+
+impl<T: RealField, R: Dim, C: Dim> Clone for TangentView<'_, T, R, C> {
+    fn clone(&self) -> Self {
+        Self::new(self.components.clone())
+    }
+}
+
+impl<T: RealField, R: Dim, C: Dim> core::fmt::Debug for TangentView<'_, T, R, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TangentView")
+            .field("components", &self.iter().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<T: RealField, R: Dim, C: Dim> core::ops::Index<usize> for TangentView<'_, T, R, C> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.components[index]
+    }
+}
+
+impl<'a, T: RealField> From<&'a [T]> for TangentView<'a, T> {
+    fn from(values: &'a [T]) -> Self {
+        Self::from_slice(values)
+    }
+}
+
+impl<'a, T: RealField> From<&'a TangentVector<T>> for TangentView<'a, T> {
+    fn from(vector: &'a TangentVector<T>) -> Self {
+        vector.as_view()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,4 +557,165 @@ mod tests {
         assert_eq!(v2.components[0], 2.0);
         assert_eq!(v2.components[1], 4.0);
     }
+
+    #[test]
+    fn test_tangent_vector_reference_arithmetic() {
+        let v1 = TangentVector::new(DVector::from_vec(vec![1.0, 2.0]));
+        let v2 = TangentVector::new(DVector::from_vec(vec![3.0, -4.0]));
+        assert_eq!((&v1 + &v2).components.as_slice(), &[4.0, -2.0]);
+        assert_eq!((&v1 - &v2).components.as_slice(), &[-2.0, 6.0]);
+        assert_eq!((v1.clone() + &v2).components.as_slice(), &[4.0, -2.0]);
+        assert_eq!((v1.clone() - &v2).components.as_slice(), &[-2.0, 6.0]);
+        assert_eq!((&v2 * 0.5).components.as_slice(), &[1.5, -2.0]);
+        assert_eq!((&v2 / 2.0).components.as_slice(), &[1.5, -2.0]);
+        assert_eq!((v2.clone() / 2.0).components.as_slice(), &[1.5, -2.0]);
+        assert_eq!((-&v1).components.as_slice(), &[-1.0, -2.0]);
+        assert_eq!(-v1.clone(), &v1 * -1.0);
+    }
+
+    #[test]
+    fn test_tangent_vector_compound_assignment() {
+        let mut v = TangentVector::new(DVector::from_vec(vec![1.0, 2.0]));
+        let w = TangentVector::new(DVector::from_vec(vec![0.5, 0.5]));
+        v += &w;
+        assert_eq!(v.components.as_slice(), &[1.5, 2.5]);
+        v -= w.clone();
+        assert_eq!(v.components.as_slice(), &[1.0, 2.0]);
+        v *= 3.0;
+        assert_eq!(v.components.as_slice(), &[3.0, 6.0]);
+        v /= 2.0;
+        assert_eq!(v.components.as_slice(), &[1.5, 3.0]);
+        v += w.clone();
+        v -= &w;
+        assert_eq!(v.components.as_slice(), &[1.5, 3.0]);
+    }
+
+    #[test]
+    fn test_tangent_vector_container_traits() {
+        let mut v = TangentVector::from(vec![1.0, -2.5, 3.0]);
+        assert_eq!(
+            v,
+            TangentVector::from(DVector::from_vec(vec![1.0, -2.5, 3.0]))
+        );
+        assert_eq!(v.as_slice(), &[1.0, -2.5, 3.0]);
+        assert_eq!(v[1], -2.5);
+        v[1] = 2.0;
+        for value in &mut v {
+            *value *= 2.0;
+        }
+        assert_eq!(v.iter().sum::<f64>(), 12.0);
+        assert_eq!((&v).into_iter().count(), 3);
+        assert_eq!(
+            v.clone().into_iter().collect::<Vec<_>>(),
+            vec![2.0, 4.0, 6.0]
+        );
+        assert_eq!(Vec::from(v.clone()), vec![2.0, 4.0, 6.0]);
+        assert_eq!(DVector::from(v.clone()).len(), 3);
+        assert_eq!(
+            (0..3)
+                .map(f64::from)
+                .collect::<TangentVector<f64>>()
+                .as_slice(),
+            &[0.0, 1.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn test_tangent_vector_numerical_utilities() {
+        let mut v = TangentVector::from(vec![3.0, 4.0]);
+        let w = TangentVector::from(vec![1.0, -1.0]);
+        assert_eq!(v.dot(&w), -1.0);
+        assert_eq!(v.normalized().as_slice(), &[0.6, 0.8]);
+        assert_eq!(v.norm(), 5.0);
+
+        assert_eq!(v.scale_to_norm(10.0), 5.0);
+        assert_relative_eq!(v.norm(), 10.0);
+        assert_eq!(v.normalize(), 10.0);
+        assert_relative_eq!(v.norm(), 1.0);
+
+        let mut zero = TangentVector::<f64>::zero(2);
+        assert_eq!(zero.normalize(), 0.0);
+        assert_eq!(zero.scale_to_norm(2.0), 0.0);
+        assert_eq!(zero, TangentVector::zero(2));
+
+        let mut y = TangentVector::from(vec![1.0, 1.0]);
+        y.axpy(2.0, &w);
+        assert_eq!(y.as_slice(), &[3.0, -1.0]);
+        assert_eq!(y.lerp(&w, 0.5).as_slice(), &[2.0, -1.0]);
+        assert_eq!(y.lerp(&w, 0.0), y);
+
+        assert_eq!(y.map(|x| x * x).as_slice(), &[9.0, 1.0]);
+        assert_eq!(y.map(|x| x as f32).as_slice(), &[3.0f32, -1.0]);
+        y.map_in_place(|x: &mut f64| *x = x.abs());
+        assert_eq!(y.as_slice(), &[3.0, 1.0]);
+        assert_eq!(y.zip_map(&w, f64::max).as_slice(), &[3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_tangent_vector_display_and_debug() {
+        let v = TangentVector::from(vec![1.0, 0.25]);
+        assert_eq!(format!("{v}"), "[1, 0.25]");
+        assert_eq!(format!("{v:.2}"), "[1.00, 0.25]");
+        assert_eq!(format!("{}", TangentVector::<f64>::zero(0)), "[]");
+        assert_eq!(
+            format!("{v:?}"),
+            "TangentVector { components: [1.0, 0.25] }"
+        );
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_tangent_vector_approx_eq() {
+        use approx::{assert_abs_diff_eq, assert_relative_ne, assert_ulps_eq};
+
+        let v = TangentVector::new(DVector::from_vec(vec![1.0, -2.0]));
+        let w = TangentVector::new(DVector::from_vec(vec![1.0 + 1e-12, -2.0]));
+        assert_abs_diff_eq!(v, w, epsilon = 1e-10);
+        assert_relative_eq!(v, w, epsilon = 1e-10);
+        assert_ulps_eq!(v, v.clone());
+        assert_relative_ne!(v, TangentVector::new(DVector::from_vec(vec![1.0, 2.0])));
+        assert_relative_ne!(v, TangentVector::zero(3));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tangent_vector_serde_round_trip() {
+        let v = TangentVector::new(DVector::from_vec(vec![1.5, -2.0, 0.25]));
+        let json = serde_json::to_string(&v).unwrap();
+        let back: TangentVector<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.components, v.components);
+    }
+
+    #[test]
+    fn test_views_borrow_matrix_rows_and_columns() {
+        let matrix = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 2.0, 0.0, 3.0, 4.0]);
+        let row = TangentView::row(&matrix, 1);
+        assert_eq!(row.dim(), 3);
+        assert_eq!(row.iter().copied().collect::<Vec<_>>(), vec![0.0, 3.0, 4.0]);
+        assert_eq!(row[2], 4.0);
+        assert_relative_eq!(row.norm(), 5.0);
+        assert_relative_eq!(row.dot(&TangentView::row(&matrix, 0)), 14.0);
+
+        let column = TangentView::column(&matrix, 2);
+        assert_eq!(column.as_slice(), &[2.0, 4.0]);
+        assert_eq!(
+            format!("{column:?}"),
+            "TangentView { components: [2.0, 4.0] }"
+        );
+    }
+
+    #[test]
+    fn test_views_accumulate_into_owned_vectors() {
+        let values = [1.0, -1.0, 0.5];
+        let view = TangentView::from(&values[..]);
+        let mut sum = TangentVector::<f64>::zero(3);
+        sum += view.clone();
+        sum += view.clone();
+        sum -= TangentView::from_slice(&[0.0, 0.0, 1.0]);
+        assert_eq!(sum.as_slice(), &[2.0, -2.0, 0.0]);
+
+        let owned: TangentVector<f64> = view.into();
+        assert_eq!(owned.as_slice(), &values);
+        assert_relative_eq!(owned.as_view().dot(&TangentView::from(&sum)), 4.0);
+    }
 }
@@ -0,0 +1,283 @@
+use crate::validation::check_finite;
+use crate::{Manifold, ManifoldError, Result, TangentVector};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+pub struct Point<M: Manifold + ?Sized> {
+    data: Vec<M::Scalar>,
+    manifold: PhantomData<fn(&M)>,
+}
+
+impl<M: Manifold + ?Sized> Point<M> {
+    pub fn new_unchecked(data: Vec<M::Scalar>) -> Self {
+        Self {
+            data,
+            manifold: PhantomData,
+        }
+    }
+
+    pub fn as_slice(&self) -> &[M::Scalar] {
+        &self.data
+    }
+
+    pub fn into_inner(self) -> Vec<M::Scalar> {
+        self.data
+    }
+}
+
+impl<M: Manifold + ?Sized> Clone for Point<M> {
+    fn clone(&self) -> Self {
+        Self::new_unchecked(self.data.clone())
+    }
+}
+
+impl<M: Manifold + ?Sized> PartialEq for Point<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<M: Manifold + ?Sized> core::fmt::Debug for Point<M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Point").field(&self.data).finish()
+    }
+}
+
+impl<M: Manifold + ?Sized> core::ops::Deref for Point<M> {
+    type Target = [M::Scalar];
+
+    fn deref(&self) -> &[M::Scalar] {
+        &self.data
+    }
+}
+
+impl<M: Manifold + ?Sized> AsRef<[M::Scalar]> for Point<M> {
+    fn as_ref(&self) -> &[M::Scalar] {
+        &self.data
+    }
+}
+
+pub struct Tangent<M: Manifold + ?Sized> {
+    vector: TangentVector<M::Scalar>,
+    manifold: PhantomData<fn(&M)>,
+}
+
+impl<M: Manifold + ?Sized> Tangent<M> {
+    pub fn new_unchecked(vector: TangentVector<M::Scalar>) -> Self {
+        Self {
+            vector,
+            manifold: PhantomData,
+        }
+    }
+
+    pub fn vector(&self) -> &TangentVector<M::Scalar> {
+        &self.vector
+    }
+
+    pub fn into_inner(self) -> TangentVector<M::Scalar> {
+        self.vector
+    }
+
+    pub fn scale(&self, factor: M::Scalar) -> Self {
+        Self::new_unchecked(&self.vector * factor)
+    }
+}
+
+impl<M: Manifold + ?Sized> Clone for Tangent<M> {
+    fn clone(&self) -> Self {
+        Self::new_unchecked(self.vector.clone())
+    }
+}
+
+impl<M: Manifold + ?Sized> PartialEq for Tangent<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vector == other.vector
+    }
+}
+
+impl<M: Manifold + ?Sized> core::fmt::Debug for Tangent<M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Tangent")
+            .field(&self.vector.as_slice())
+            .finish()
+    }
+}
+
+impl<M: Manifold + ?Sized> core::ops::Deref for Tangent<M> {
+    type Target = TangentVector<M::Scalar>;
+
+    fn deref(&self) -> &TangentVector<M::Scalar> {
+        &self.vector
+    }
+}
+
+fn check_len(expected: usize, got: usize) -> Result<()> {
+    if got != expected {
+        return Err(ManifoldError::DimensionMismatch { expected, got });
+    }
+    Ok(())
+}
+
+pub trait TypedManifold: Manifold {
+    fn point(&self, data: impl Into<Vec<Self::Scalar>>) -> Result<Point<Self>> {
+        let data = data.into();
+        check_len(self.ambient_dim(), data.len())?;
+        check_finite(&data, "Point")?;
+        self.check_point(&data)?;
+        Ok(Point::new_unchecked(data))
+    }
+
+    fn tangent(
+        &self,
+        at: &Point<Self>,
+        vector: impl Into<TangentVector<Self::Scalar>>,
+    ) -> Result<Tangent<Self>> {
+        let vector = vector.into();
+        check_len(self.ambient_dim(), vector.dim())?;
+        check_finite(vector.as_slice(), "Tangent vector")?;
+        self.check_tangent_vector(at, &vector)?;
+        Ok(Tangent::new_unchecked(vector))
+    }
+
+    fn point_from<I>(&self, values: I) -> Result<Point<Self>>
+    where
+        I: IntoIterator<Item = Self::Scalar>,
+    {
+        self.point(values.into_iter().collect::<Vec<_>>())
+    }
+
+    fn tangent_at<I>(&self, at: &Point<Self>, values: I) -> Result<Tangent<Self>>
+    where
+        I: IntoIterator<Item = Self::Scalar>,
+    {
+        self.tangent(at, values.into_iter().collect::<TangentVector<_>>())
+    }
+
+    fn project_point<I>(&self, values: I) -> Result<Point<Self>>
+    where
+        I: IntoIterator<Item = Self::Scalar>,
+    {
+        let values: Vec<_> = values.into_iter().collect();
+        check_len(self.ambient_dim(), values.len())?;
+        check_finite(&values, "Point")?;
+        self.project_to_manifold(&values).map(Point::new_unchecked)
+    }
+
+    fn project_tangent<I>(&self, at: &Point<Self>, values: I) -> Result<Tangent<Self>>
+    where
+        I: IntoIterator<Item = Self::Scalar>,
+    {
+        let vector: TangentVector<_> = values.into_iter().collect();
+        check_len(self.ambient_dim(), vector.dim())?;
+        check_finite(vector.as_slice(), "Tangent vector")?;
+        self.project_to_tangent_space(at, &vector)
+            .map(Tangent::new_unchecked)
+    }
+
+    fn typed_exp(&self, at: &Point<Self>, tangent: &Tangent<Self>) -> Result<Point<Self>> {
+        self.exp(at, tangent).map(Point::new_unchecked)
+    }
+
+    fn typed_log(&self, from: &Point<Self>, to: &Point<Self>) -> Result<Tangent<Self>> {
+        self.log(from, to).map(Tangent::new_unchecked)
+    }
+
+    fn typed_distance(&self, a: &Point<Self>, b: &Point<Self>) -> Result<Self::Scalar> {
+        self.distance(a, b)
+    }
+
+    fn typed_inner_product(
+        &self,
+        at: &Point<Self>,
+        u: &Tangent<Self>,
+        v: &Tangent<Self>,
+    ) -> Result<Self::Scalar> {
+        self.inner_product(at, u, v)
+    }
+
+    fn typed_parallel_transport(
+        &self,
+        at: &Point<Self>,
+        tangent: &Tangent<Self>,
+        direction: &Tangent<Self>,
+    ) -> Result<Tangent<Self>> {
+        self.parallel_transport(at, tangent, direction)
+            .map(Tangent::new_unchecked)
+    }
+}
+
+impl<M: Manifold + ?Sized> TypedManifold for M {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+    use nalgebra::DVector;
+
+    #[test]
+    fn test_point_and_tangent_are_validated() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        let p = manifold.point(vec![1.0, 2.0]).unwrap();
+        assert_eq!(p.as_slice(), &[1.0, 2.0]);
+        assert!(matches!(
+            manifold.point([1.0, 2.0, 3.0].as_slice()),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        ));
+
+        let v = manifold.tangent(&p, vec![0.5, -1.0]).unwrap();
+        assert_eq!(v.vector().as_slice(), &[0.5, -1.0]);
+        assert!(manifold.tangent(&p, DVector::from_vec(vec![1.0])).is_err());
+    }
+
+    #[test]
+    fn test_fluent_construction_from_iterators() {
+        let manifold = TestEuclideanManifold { dimension: 3 };
+        let p = manifold.point_from((1..=3).map(f64::from)).unwrap();
+        assert_eq!(p.as_slice(), &[1.0, 2.0, 3.0]);
+        assert!(manifold.point_from([1.0, 2.0]).is_err());
+        assert!(matches!(
+            manifold.point_from([1.0, f64::NAN, 0.0]),
+            Err(ManifoldError::NumericalError(_))
+        ));
+
+        let v = manifold.tangent_at(&p, [0.0, 0.5, 1.0]).unwrap();
+        assert_eq!(v.vector().as_slice(), &[0.0, 0.5, 1.0]);
+        assert!(manifold.tangent_at(&p, [0.0; 4]).is_err());
+
+        let projected = manifold.project_point(vec![4.0, 5.0, 6.0]).unwrap();
+        assert_eq!(projected.into_inner(), vec![4.0, 5.0, 6.0]);
+        assert!(manifold.project_point([f64::INFINITY, 0.0, 0.0]).is_err());
+        let w = manifold.project_tangent(&p, [1.0, 1.0, 1.0]).unwrap();
+        assert_eq!(w.vector().as_slice(), &[1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_typed_operations_round_trip() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        let p = manifold.point(vec![1.0, 2.0]).unwrap();
+        let v = manifold.tangent(&p, vec![0.5, -1.0]).unwrap();
+
+        let q = manifold.typed_exp(&p, &v).unwrap();
+        assert_eq!(q, manifold.point(vec![1.5, 1.0]).unwrap());
+        assert_eq!(manifold.typed_log(&p, &q).unwrap(), v);
+        assert_eq!(
+            manifold.typed_distance(&p, &q).unwrap(),
+            manifold.distance(&p, &q).unwrap()
+        );
+        assert_eq!(manifold.typed_inner_product(&p, &v, &v).unwrap(), 1.25);
+        assert_eq!(
+            manifold.typed_exp(&p, &v.scale(2.0)).unwrap().into_inner(),
+            vec![2.0, 0.0]
+        );
+        assert_eq!(
+            manifold
+                .typed_parallel_transport(&p, &v, &v)
+                .unwrap()
+                .into_inner(),
+            v.clone().into_inner()
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{Manifold, ManifoldError, Result, TangentVector};
+use alloc::format;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use core::sync::atomic::{AtomicU8, Ordering};
+use nalgebra::ComplexField;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValidationLevel {
+    #[default]
+    Strict,
+    OnEntry,
+    Off,
+}
+
+impl ValidationLevel {
+    fn encode(self) -> u8 {
+        match self {
+            ValidationLevel::Strict => 0,
+            ValidationLevel::OnEntry => 1,
+            ValidationLevel::Off => 2,
+        }
+    }
+
+    fn decode(value: u8) -> Self {
+        match value {
+            1 => ValidationLevel::OnEntry,
+            2 => ValidationLevel::Off,
+            _ => ValidationLevel::Strict,
+        }
+    }
+}
+
+static GLOBAL_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static SCOPED_LEVEL: Cell<Option<ValidationLevel>> = const { Cell::new(None) };
+}
+
+#[cfg(feature = "std")]
+pub fn validation_level() -> ValidationLevel {
+    SCOPED_LEVEL
+        .with(Cell::get)
+        .unwrap_or_else(|| ValidationLevel::decode(GLOBAL_LEVEL.load(Ordering::Relaxed)))
+}
+
+#[cfg(not(feature = "std"))]
+pub fn validation_level() -> ValidationLevel {
+    ValidationLevel::decode(GLOBAL_LEVEL.load(Ordering::Relaxed))
+}
+
+pub fn set_validation_level(level: ValidationLevel) -> ValidationLevel {
+    ValidationLevel::decode(GLOBAL_LEVEL.swap(level.encode(), Ordering::Relaxed))
+}
+
+#[cfg(feature = "std")]
+pub fn with_validation_level<R, F>(level: ValidationLevel, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    struct Restore(Option<ValidationLevel>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED_LEVEL.with(|scoped| scoped.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SCOPED_LEVEL.with(|scoped| scoped.replace(Some(level))));
+    f()
+}
+
+pub fn check_finite<T: ComplexField>(values: &[T], what: &str) -> Result<()> {
+    match values.iter().position(|value| !value.is_finite()) {
+        Some(index) => Err(ManifoldError::NumericalError(format!(
+            "{what} contains non-finite value {} at index {index}",
+            values[index]
+        ))),
+        None => Ok(()),
+    }
+}
+
+pub fn check_dimension(manifold: &str, name: &str, value: usize) -> Result<()> {
+    if value == 0 {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "{manifold} requires a positive {name}, got 0"
+        )));
+    }
+    Ok(())
+}
+
+fn checked_point<M>(manifold: &M, point: &[M::Scalar]) -> Result<()>
+where
+    M: Manifold + ?Sized,
+{
+    check_finite(point, "Point")?;
+    manifold.check_point(point)
+}
+
+fn checked_tangent<M>(
+    manifold: &M,
+    point: &[M::Scalar],
+    tangent: &TangentVector<M::Scalar>,
+) -> Result<()>
+where
+    M: Manifold + ?Sized,
+{
+    check_finite(tangent.as_slice(), "Tangent vector")?;
+    manifold.check_tangent_vector(point, tangent)
+}
+
+pub fn validate_point<M>(manifold: &M, point: &[M::Scalar]) -> Result<()>
+where
+    M: Manifold + ?Sized,
+{
+    match validation_level() {
+        ValidationLevel::Strict => checked_point(manifold, point),
+        ValidationLevel::OnEntry | ValidationLevel::Off => Ok(()),
+    }
+}
+
+pub fn validate_tangent<M>(
+    manifold: &M,
+    point: &[M::Scalar],
+    tangent: &TangentVector<M::Scalar>,
+) -> Result<()>
+where
+    M: Manifold + ?Sized,
+{
+    match validation_level() {
+        ValidationLevel::Strict => checked_tangent(manifold, point, tangent),
+        ValidationLevel::OnEntry | ValidationLevel::Off => Ok(()),
+    }
+}
+
+pub fn validate_entry_point<M>(manifold: &M, point: &[M::Scalar]) -> Result<()>
+where
+    M: Manifold + ?Sized,
+{
+    match validation_level() {
+        ValidationLevel::Strict | ValidationLevel::OnEntry => checked_point(manifold, point),
+        ValidationLevel::Off => Ok(()),
+    }
+}
+
+pub fn validate_entry_points<M>(manifold: &M, points: &[Vec<M::Scalar>]) -> Result<()>
+where
+    M: Manifold + ?Sized,
+{
+    for point in points {
+        if point.len() != manifold.ambient_dim() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: manifold.ambient_dim(),
+                got: point.len(),
+            });
+        }
+        validate_entry_point(manifold, point)?;
+    }
+    Ok(())
+}
+
+pub fn validate_entry_tangent<M>(
+    manifold: &M,
+    point: &[M::Scalar],
+    tangent: &TangentVector<M::Scalar>,
+) -> Result<()>
+where
+    M: Manifold + ?Sized,
+{
+    match validation_level() {
+        ValidationLevel::Strict | ValidationLevel::OnEntry => {
+            checked_tangent(manifold, point, tangent)
+        }
+        ValidationLevel::Off => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::tests::TestEuclideanManifold;
+
+    #[test]
+    fn test_default_level_is_strict() {
+        assert_eq!(ValidationLevel::default(), ValidationLevel::Strict);
+        for level in [
+            ValidationLevel::Strict,
+            ValidationLevel::OnEntry,
+            ValidationLevel::Off,
+        ] {
+            assert_eq!(ValidationLevel::decode(level.encode()), level);
+        }
+    }
+
+    #[test]
+    fn test_scoped_level_is_restored() {
+        let outer = validation_level();
+        let inner = with_validation_level(ValidationLevel::Off, || {
+            let nested = with_validation_level(ValidationLevel::OnEntry, validation_level);
+            assert_eq!(nested, ValidationLevel::OnEntry);
+            validation_level()
+        });
+        assert_eq!(inner, ValidationLevel::Off);
+        assert_eq!(validation_level(), outer);
+    }
+
+    #[test]
+    fn test_non_finite_inputs_are_rejected() {
+        let manifold = TestEuclideanManifold { dimension: 2 };
+        let error = validate_point(&manifold, &[1.0, f64::NAN]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Numerical error: Point contains non-finite value NaN at index 1"
+        );
+        let tangent = TangentVector::from(vec![f64::INFINITY, 0.0]);
+        assert!(matches!(
+            validate_entry_tangent(&manifold, &[0.0, 0.0], &tangent),
+            Err(ManifoldError::NumericalError(_))
+        ));
+        assert!(with_validation_level(ValidationLevel::Off, || {
+            validate_entry_point(&manifold, &[f64::NAN, 0.0])
+        })
+        .is_ok());
+
+        assert!(check_dimension("Sphere", "dimension", 0).is_err());
+        assert!(check_dimension("Sphere", "dimension", 2).is_ok());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{Manifold, Result, TangentVector};
+use alloc::vec::Vec;
+use nalgebra::{DMatrix, DVector};
+
+#[derive(Debug, Default)]
+pub struct Workspace {
+    matrices: Vec<DMatrix<f64>>,
+    vectors: Vec<DVector<f64>>,
+}
+
+impl Workspace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn take_matrix(&mut self, rows: usize, cols: usize) -> DMatrix<f64> {
+        match self.matrices.iter().position(|m| m.shape() == (rows, cols)) {
+            Some(index) => {
+                let mut matrix = self.matrices.swap_remove(index);
+                matrix.fill(0.0);
+                matrix
+            }
+            None => DMatrix::zeros(rows, cols),
+        }
+    }
+
+    pub fn give_matrix(&mut self, matrix: DMatrix<f64>) {
+        self.matrices.push(matrix);
+    }
+
+    pub fn take_vector(&mut self, len: usize) -> DVector<f64> {
+        match self.vectors.iter().position(|v| v.len() == len) {
+            Some(index) => {
+                let mut vector = self.vectors.swap_remove(index);
+                vector.fill(0.0);
+                vector
+            }
+            None => DVector::zeros(len),
+        }
+    }
+
+    pub fn give_vector(&mut self, vector: DVector<f64>) {
+        self.vectors.push(vector);
+    }
+
+    pub fn pooled_matrices(&self) -> usize {
+        self.matrices.len()
+    }
+
+    pub fn pooled_vectors(&self) -> usize {
+        self.vectors.len()
+    }
+}
+
+pub trait WorkspaceManifold: Manifold<Scalar = f64> {
+    fn exp_with(
+        &self,
+        point: &[f64],
+        tangent: &TangentVector<f64>,
+        out: &mut Vec<f64>,
+        workspace: &mut Workspace,
+    ) -> Result<()>;
+
+    fn log_with(
+        &self,
+        point: &[f64],
+        other: &[f64],
+        out: &mut TangentVector<f64>,
+        workspace: &mut Workspace,
+    ) -> Result<()>;
+
+    fn inner_product_with(
+        &self,
+        point: &[f64],
+        v1: &TangentVector<f64>,
+        v2: &TangentVector<f64>,
+        workspace: &mut Workspace,
+    ) -> Result<f64>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrices_are_recycled_and_zeroed() {
+        let mut workspace = Workspace::new();
+        let mut matrix = workspace.take_matrix(3, 3);
+        matrix.fill(7.0);
+        workspace.give_matrix(matrix);
+        assert_eq!(workspace.pooled_matrices(), 1);
+
+        let fresh = workspace.take_matrix(2, 4);
+        assert_eq!(workspace.pooled_matrices(), 1);
+        assert_eq!(fresh.shape(), (2, 4));
+
+        let reused = workspace.take_matrix(3, 3);
+        assert_eq!(workspace.pooled_matrices(), 0);
+        assert!(reused.iter().all(|x| *x == 0.0));
+    }
+
+    #[test]
+    fn test_vectors_are_recycled_and_zeroed() {
+        let mut workspace = Workspace::new();
+        let mut vector = workspace.take_vector(5);
+        vector.fill(1.0);
+        workspace.give_vector(vector);
+
+        let reused = workspace.take_vector(5);
+        assert_eq!(reused.len(), 5);
+        assert!(reused.iter().all(|x| *x == 0.0));
+        assert_eq!(workspace.pooled_vectors(), 0);
+    }
+}
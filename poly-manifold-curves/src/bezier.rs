@@ -0,0 +1,243 @@
+use poly_manifold_autodiff::RiemannianOptimizer;
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+use poly_manifold_spaces::PowerManifold;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BezierCurve {
+    pub control_points: Vec<Vec<f64>>,
+}
+
+impl BezierCurve {
+    pub fn new(control_points: Vec<Vec<f64>>) -> Result<Self> {
+        if control_points.len() < 2 {
+            return Err(ManifoldError::InvalidParameter(
+                "A Bézier curve needs at least two control points".to_string(),
+            ));
+        }
+        Ok(Self { control_points })
+    }
+
+    pub fn degree(&self) -> usize {
+        self.control_points.len() - 1
+    }
+
+    pub fn evaluate<M>(&self, manifold: &M, t: f64) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        de_casteljau(manifold, &self.control_points, t)
+    }
+
+    pub fn sample<M>(&self, manifold: &M, count: usize) -> Result<Vec<Vec<f64>>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        if count < 2 {
+            return Err(ManifoldError::InvalidParameter(
+                "Sampling a curve requires at least two points".to_string(),
+            ));
+        }
+        (0..count)
+            .map(|i| self.evaluate(manifold, i as f64 / (count - 1) as f64))
+            .collect()
+    }
+
+    pub fn velocity<M>(&self, manifold: &M, t: f64) -> Result<TangentVector<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let h = 1e-5;
+        let lo = (t - h).max(0.0);
+        let hi = (t + h).min(1.0);
+        let here = self.evaluate(manifold, t)?;
+        let forward = manifold.log(&here, &self.evaluate(manifold, hi)?)?;
+        let backward = manifold.log(&here, &self.evaluate(manifold, lo)?)?;
+        Ok((forward - backward) * (1.0 / (hi - lo)))
+    }
+
+    pub fn fit<M, O>(
+        manifold: &M,
+        times: &[f64],
+        data: &[Vec<f64>],
+        degree: usize,
+        optimizer: &O,
+    ) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64>,
+        O: RiemannianOptimizer,
+    {
+        if times.len() != data.len() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: data.len(),
+                got: times.len(),
+            });
+        }
+        if degree == 0 || data.len() < 2 {
+            return Err(ManifoldError::InvalidParameter(
+                "Fitting requires degree at least 1 and two data points".to_string(),
+            ));
+        }
+        if let Some(t) = times.iter().find(|t| !(0.0..=1.0).contains(*t)) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Curve parameters must lie in [0, 1], got {}",
+                t
+            )));
+        }
+
+        let power = PowerManifold::new(manifold, degree + 1);
+        let initial: Vec<Vec<f64>> = (0..=degree)
+            .map(|k| data[k * (data.len() - 1) / degree].clone())
+            .collect();
+        let initial = power.from_components(&initial)?;
+
+        let cost = |controls: &[f64]| {
+            let Ok(controls) = power.project_to_manifold(controls) else {
+                return f64::INFINITY;
+            };
+            let controls: Vec<Vec<f64>> =
+                power.components(&controls).map(<[f64]>::to_vec).collect();
+            let mut total = 0.0;
+            for (t, y) in times.iter().zip(data) {
+                match de_casteljau(manifold, &controls, *t).and_then(|b| manifold.distance(&b, y)) {
+                    Ok(d) => total += d * d,
+                    Err(_) => return f64::INFINITY,
+                }
+            }
+            0.5 * total
+        };
+
+        let result = optimizer.minimize(&power, &initial, cost)?;
+        Self::new(
+            power
+                .components(&result.point)
+                .map(<[f64]>::to_vec)
+                .collect(),
+        )
+    }
+}
+
+pub(crate) fn de_casteljau<M>(manifold: &M, control_points: &[Vec<f64>], t: f64) -> Result<Vec<f64>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    let mut points = control_points.to_vec();
+    while points.len() > 1 {
+        points = points
+            .windows(2)
+            .map(|pair| {
+                let direction = manifold.log(&pair[0], &pair[1])?;
+                manifold.geodesic(&pair[0], &direction, t)
+            })
+            .collect::<Result<_>>()?;
+    }
+    points.pop().ok_or_else(|| {
+        ManifoldError::InvalidParameter("A Bézier curve needs control points".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_autodiff::GradientDescent;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    #[test]
+    fn test_euclidean_bezier_matches_bernstein_form() {
+        let euclidean = Euclidean::new(2);
+        let curve = BezierCurve::new(vec![vec![0.0, 0.0], vec![1.0, 2.0], vec![3.0, 0.0]]).unwrap();
+        let t = 0.3;
+        let point = curve.evaluate(&euclidean, t).unwrap();
+        let b = [(1.0 - t) * (1.0 - t), 2.0 * t * (1.0 - t), t * t];
+        assert_relative_eq!(point[0], b[1] + 3.0 * b[2], epsilon = 1e-12);
+        assert_relative_eq!(point[1], 2.0 * b[1], epsilon = 1e-12);
+
+        let velocity = curve.velocity(&euclidean, t).unwrap();
+        assert_relative_eq!(
+            velocity.components[0],
+            2.0 * (1.0 - t) + 4.0 * t,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            velocity.components[1],
+            4.0 * (1.0 - t) - 4.0 * t,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_sphere_bezier_interpolates_endpoints() {
+        let sphere = Sphere::new(2);
+        let curve = BezierCurve::new(vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+
+        assert_eq!(curve.degree(), 2);
+        let samples = curve.sample(&sphere, 11).unwrap();
+        for sample in &samples {
+            assert!(sphere.check_point(sample).is_ok());
+        }
+        assert_relative_eq!(samples[0][0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(samples[10][2], 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_linear_bezier_is_geodesic() {
+        let sphere = Sphere::new(2);
+        let curve = BezierCurve::new(vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]]).unwrap();
+        let mid = curve.evaluate(&sphere, 0.5).unwrap();
+        let s = 0.5f64.sqrt();
+        assert_relative_eq!(mid[0], s, epsilon = 1e-10);
+        assert_relative_eq!(mid[1], s, epsilon = 1e-10);
+
+        let velocity = curve.velocity(&sphere, 0.0).unwrap();
+        assert_relative_eq!(velocity.norm(), std::f64::consts::FRAC_PI_2, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_fit_recovers_curve_from_samples() {
+        let sphere = Sphere::new(2);
+        let truth = BezierCurve::new(vec![
+            vec![1.0, 0.0, 0.0],
+            sphere.project_to_manifold(&[1.0, 1.0, 0.5]).unwrap(),
+            vec![0.0, 1.0, 0.0],
+        ])
+        .unwrap();
+        let times: Vec<f64> = (0..9).map(|i| i as f64 / 8.0).collect();
+        let data: Vec<Vec<f64>> = times
+            .iter()
+            .map(|t| truth.evaluate(&sphere, *t).unwrap())
+            .collect();
+
+        let optimizer = GradientDescent::new(0.5, 2000, 1e-14);
+        let fitted = BezierCurve::fit(&sphere, &times, &data, 2, &optimizer).unwrap();
+        for (t, y) in times.iter().zip(&data) {
+            let point = fitted.evaluate(&sphere, *t).unwrap();
+            assert!(sphere.distance(&point, y).unwrap() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let euclidean = Euclidean::new(1);
+        assert!(BezierCurve::new(vec![vec![0.0]]).is_err());
+        let optimizer = GradientDescent::new(0.1, 10, 1e-8);
+        let data = vec![vec![0.0], vec![1.0]];
+        assert!(BezierCurve::fit(&euclidean, &[0.0], &data, 1, &optimizer).is_err());
+        assert!(BezierCurve::fit(&euclidean, &[0.0, 2.0], &data, 1, &optimizer).is_err());
+        assert!(BezierCurve::fit(&euclidean, &[0.0, 1.0], &data, 0, &optimizer).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_bezier_serde_round_trip() {
+        let curve = BezierCurve::new(vec![vec![0.0, 0.0], vec![1.0, 2.0], vec![3.0, 1.0]]).unwrap();
+        let json = serde_json::to_string(&curve).unwrap();
+        let back: BezierCurve = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, curve);
+    }
+}
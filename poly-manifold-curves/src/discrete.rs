@@ -0,0 +1,303 @@
+use poly_manifold_core::{Manifold, ManifoldError, Result};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiscreteCurve {
+    pub points: Vec<Vec<f64>>,
+}
+
+impl DiscreteCurve {
+    pub fn new(points: Vec<Vec<f64>>) -> Result<Self> {
+        if points.len() < 2 {
+            return Err(ManifoldError::InvalidParameter(
+                "A discrete curve needs at least two points".to_string(),
+            ));
+        }
+        Ok(Self { points })
+    }
+
+    pub fn geodesic<M>(manifold: &M, start: &[f64], end: &[f64], count: usize) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        if count < 2 {
+            return Err(ManifoldError::InvalidParameter(
+                "A discrete curve needs at least two points".to_string(),
+            ));
+        }
+        let direction = manifold.log(start, end)?;
+        let points = (0..count)
+            .map(|i| manifold.geodesic(start, &direction, i as f64 / (count - 1) as f64))
+            .collect::<Result<_>>()?;
+        Ok(Self { points })
+    }
+
+    fn last_index(&self) -> Result<usize> {
+        match self.points.len() {
+            0 | 1 => Err(ManifoldError::InvalidParameter(format!(
+                "A discrete curve needs at least two points, got {}",
+                self.points.len()
+            ))),
+            len => Ok(len - 1),
+        }
+    }
+
+    pub fn segment_lengths<M>(&self, manifold: &M) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        self.points
+            .windows(2)
+            .map(|pair| manifold.distance(&pair[0], &pair[1]))
+            .collect()
+    }
+
+    pub fn arc_length<M>(&self, manifold: &M) -> Result<f64>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        Ok(self.segment_lengths(manifold)?.iter().sum())
+    }
+
+    pub fn energy<M>(&self, manifold: &M) -> Result<f64>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let segments = self.last_index()? as f64;
+        Ok(self
+            .segment_lengths(manifold)?
+            .iter()
+            .map(|l| l * l * segments)
+            .sum())
+    }
+
+    pub fn point_at_arc_length<M>(&self, manifold: &M, s: f64) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        self.last_index()?;
+        let lengths = self.segment_lengths(manifold)?;
+        walk(manifold, &self.points, &lengths, s)
+    }
+
+    pub fn resample<M>(&self, manifold: &M, count: usize) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        if count < 2 {
+            return Err(ManifoldError::InvalidParameter(
+                "A discrete curve needs at least two points".to_string(),
+            ));
+        }
+        self.last_index()?;
+        let lengths = self.segment_lengths(manifold)?;
+        let total: f64 = lengths.iter().sum();
+        let points = (0..count)
+            .map(|i| {
+                walk(
+                    manifold,
+                    &self.points,
+                    &lengths,
+                    total * i as f64 / (count - 1) as f64,
+                )
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { points })
+    }
+
+    pub fn curvature<M>(&self, manifold: &M) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        self.points
+            .windows(3)
+            .map(|window| {
+                let back = manifold.log(&window[1], &window[0])?;
+                let forward = manifold.log(&window[1], &window[2])?;
+                let l1 = manifold.norm(&window[1], &back)?;
+                let l2 = manifold.norm(&window[1], &forward)?;
+                if l1 < 1e-12 || l2 < 1e-12 {
+                    return Err(ManifoldError::NumericalError(
+                        "Curvature is undefined for repeated points".to_string(),
+                    ));
+                }
+                let turn = back * (1.0 / l1) + forward * (1.0 / l2);
+                Ok(2.0 * manifold.norm(&window[1], &turn)? / (l1 + l2))
+            })
+            .collect()
+    }
+
+    pub fn straighten<M>(
+        &self,
+        manifold: &M,
+        anchors: &[usize],
+        max_iterations: usize,
+        tolerance: f64,
+    ) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let last = self.last_index()?;
+        if let Some(index) = anchors.iter().find(|i| **i > last) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Anchor index {} is out of range for {} points",
+                index,
+                self.points.len()
+            )));
+        }
+
+        let mut points = self.points.clone();
+        for _iter in 0..max_iterations {
+            let mut largest_move: f64 = 0.0;
+            for i in 1..last {
+                if anchors.contains(&i) {
+                    continue;
+                }
+                let toward_next = manifold.log(&points[i - 1], &points[i + 1])?;
+                let midpoint = manifold.geodesic(&points[i - 1], &toward_next, 0.5)?;
+                largest_move = largest_move.max(manifold.distance(&points[i], &midpoint)?);
+                points[i] = midpoint;
+            }
+            if largest_move < tolerance {
+                return Ok(Self { points });
+            }
+        }
+
+        Err(ManifoldError::ConvergenceError {
+            iterations: max_iterations,
+        })
+    }
+}
+
+fn walk<M>(manifold: &M, points: &[Vec<f64>], lengths: &[f64], s: f64) -> Result<Vec<f64>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    let mut remaining = s.max(0.0);
+    for (i, length) in lengths.iter().enumerate() {
+        if remaining <= *length && *length > 0.0 {
+            let direction = manifold.log(&points[i], &points[i + 1])?;
+            return manifold.geodesic(&points[i], &direction, remaining / length);
+        }
+        remaining -= length;
+    }
+    Ok(points[points.len() - 1].clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn circle(radius: f64, count: usize) -> DiscreteCurve {
+        let points = (0..count)
+            .map(|i| {
+                let angle = PI * i as f64 / (count - 1) as f64;
+                vec![radius * angle.cos(), radius * angle.sin()]
+            })
+            .collect();
+        DiscreteCurve::new(points).unwrap()
+    }
+
+    #[test]
+    fn test_arc_length_of_semicircle() {
+        let euclidean = Euclidean::new(2);
+        let curve = circle(2.0, 200);
+        assert_relative_eq!(
+            curve.arc_length(&euclidean).unwrap(),
+            2.0 * PI,
+            epsilon = 1e-3
+        );
+
+        let curvature = curve.curvature(&euclidean).unwrap();
+        assert_eq!(curvature.len(), 198);
+        for k in curvature {
+            assert_relative_eq!(k, 0.5, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_resample_is_uniform_in_arc_length() {
+        let euclidean = Euclidean::new(1);
+        let curve = DiscreteCurve::new(vec![vec![0.0], vec![0.1], vec![0.2], vec![3.0]]).unwrap();
+        let resampled = curve.resample(&euclidean, 4).unwrap();
+        for (i, point) in resampled.points.iter().enumerate() {
+            assert_relative_eq!(point[0], i as f64, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_sphere_geodesic_curve_has_zero_curvature() {
+        let sphere = Sphere::new(2);
+        let curve =
+            DiscreteCurve::geodesic(&sphere, &[1.0, 0.0, 0.0], &[0.0, 1.0, 0.0], 10).unwrap();
+        assert_relative_eq!(
+            curve.arc_length(&sphere).unwrap(),
+            FRAC_PI_2,
+            epsilon = 1e-10
+        );
+        for k in curve.curvature(&sphere).unwrap() {
+            assert!(k < 1e-8);
+        }
+        let quarter = curve.point_at_arc_length(&sphere, FRAC_PI_2 / 2.0).unwrap();
+        assert_relative_eq!(quarter[0], quarter[1], epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_straightening_converges_to_piecewise_geodesic() {
+        let sphere = Sphere::new(2);
+        let wiggly: Vec<Vec<f64>> = (0..9)
+            .map(|i| {
+                let angle = FRAC_PI_2 * i as f64 / 8.0;
+                let lift = if i == 0 || i == 8 {
+                    0.0
+                } else {
+                    0.3 * (i as f64).sin()
+                };
+                sphere
+                    .project_to_manifold(&[angle.cos(), angle.sin(), lift])
+                    .unwrap()
+            })
+            .collect();
+        let curve = DiscreteCurve::new(wiggly).unwrap();
+        let straight = curve.straighten(&sphere, &[], 5000, 1e-10).unwrap();
+
+        assert!(straight.energy(&sphere).unwrap() < curve.energy(&sphere).unwrap());
+        assert_relative_eq!(
+            straight.arc_length(&sphere).unwrap(),
+            FRAC_PI_2,
+            epsilon = 1e-6
+        );
+        for point in &straight.points {
+            assert_relative_eq!(point[2], 0.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_straightening_respects_anchors() {
+        let euclidean = Euclidean::new(2);
+        let curve = DiscreteCurve::new(vec![
+            vec![0.0, 0.0],
+            vec![0.5, 0.7],
+            vec![1.0, 1.0],
+            vec![1.5, 0.2],
+            vec![2.0, 0.0],
+        ])
+        .unwrap();
+        let straight = curve.straighten(&euclidean, &[2], 1000, 1e-12).unwrap();
+        assert_eq!(straight.points[2], vec![1.0, 1.0]);
+        assert_relative_eq!(straight.points[1][0], 0.5, epsilon = 1e-9);
+        assert_relative_eq!(straight.points[1][1], 0.5, epsilon = 1e-9);
+        assert!(curve.straighten(&euclidean, &[7], 10, 1e-12).is_err());
+
+        let empty = DiscreteCurve { points: Vec::new() };
+        assert!(matches!(
+            empty.straighten(&euclidean, &[], 10, 1e-12),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        assert!(empty.energy(&euclidean).is_err());
+        assert!(empty.resample(&euclidean, 3).is_err());
+    }
+}
@@ -0,0 +1,363 @@
+use nalgebra::Matrix3;
+use poly_manifold_autodiff::optimizer::{GradientDescent, IterativeOptimizer};
+use poly_manifold_autodiff::Preconditioner;
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+use poly_manifold_spaces::SO3;
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polyline {
+    pub vertices: Vec<[f64; 3]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mesh {
+    pub vertices: Vec<[f64; 3]>,
+    pub triangles: Vec<[usize; 3]>,
+}
+
+pub fn ambient_coordinates(point: &[f64]) -> Result<[f64; 3]> {
+    point
+        .try_into()
+        .map_err(|_| ManifoldError::DimensionMismatch {
+            expected: 3,
+            got: point.len(),
+        })
+}
+
+pub fn rotation_vector(point: &[f64]) -> Result<[f64; 3]> {
+    if point.len() != 9 {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: 9,
+            got: point.len(),
+        });
+    }
+    let omega = SO3::rotation_log(&Matrix3::from_row_slice(point));
+    Ok([omega[0], omega[1], omega[2]])
+}
+
+impl Polyline {
+    pub fn from_points<E>(points: &[Vec<f64>], embed: E) -> Result<Self>
+    where
+        E: Fn(&[f64]) -> Result<[f64; 3]>,
+    {
+        let vertices = points
+            .iter()
+            .map(|point| embed(point))
+            .collect::<Result<_>>()?;
+        Ok(Self { vertices })
+    }
+
+    pub fn geodesic<M, E>(
+        manifold: &M,
+        point: &[f64],
+        tangent: &TangentVector<f64>,
+        count: usize,
+        embed: E,
+    ) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+        E: Fn(&[f64]) -> Result<[f64; 3]>,
+    {
+        check_count(count, 2)?;
+        let vertices = (0..count)
+            .map(|i| embed(&manifold.geodesic(point, tangent, i as f64 / (count - 1) as f64)?))
+            .collect::<Result<_>>()?;
+        Ok(Self { vertices })
+    }
+
+    pub fn path<M, E>(
+        manifold: &M,
+        points: &[Vec<f64>],
+        samples_per_segment: usize,
+        embed: E,
+    ) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+        E: Fn(&[f64]) -> Result<[f64; 3]>,
+    {
+        check_count(samples_per_segment, 1)?;
+        let mut vertices = Vec::with_capacity(points.len() * samples_per_segment);
+        for pair in points.windows(2) {
+            let direction = manifold.log(&pair[0], &pair[1])?;
+            for i in 0..samples_per_segment {
+                let t = i as f64 / samples_per_segment as f64;
+                vertices.push(embed(&manifold.geodesic(&pair[0], &direction, t)?)?);
+            }
+        }
+        if let Some(last) = points.last() {
+            vertices.push(embed(last)?);
+        }
+        Ok(Self { vertices })
+    }
+
+    pub fn geodesic_circle<M, E>(
+        manifold: &M,
+        center: &[f64],
+        radius: f64,
+        count: usize,
+        embed: E,
+    ) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+        E: Fn(&[f64]) -> Result<[f64; 3]>,
+    {
+        check_count(count, 3)?;
+        let basis = basis_of_dim(manifold, center, 2)?;
+        let mut vertices = (0..count)
+            .map(|i| {
+                let angle = 2.0 * PI * i as f64 / count as f64;
+                let direction = combine(&basis, &[angle.cos(), angle.sin()]);
+                embed(&manifold.exp(center, &(direction * radius))?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        vertices.push(vertices[0]);
+        Ok(Self { vertices })
+    }
+
+    pub fn length(&self) -> f64 {
+        self.vertices
+            .windows(2)
+            .map(|pair| {
+                let d: f64 = (0..3).map(|k| (pair[1][k] - pair[0][k]).powi(2)).sum();
+                d.sqrt()
+            })
+            .sum()
+    }
+}
+
+impl Mesh {
+    pub fn geodesic_sphere<M, E>(
+        manifold: &M,
+        center: &[f64],
+        radius: f64,
+        rings: usize,
+        segments: usize,
+        embed: E,
+    ) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+        E: Fn(&[f64]) -> Result<[f64; 3]>,
+    {
+        check_count(rings, 2)?;
+        check_count(segments, 3)?;
+        let basis = basis_of_dim(manifold, center, 3)?;
+        let vertex = |polar: f64, azimuth: f64| {
+            let direction = combine(
+                &basis,
+                &[
+                    polar.sin() * azimuth.cos(),
+                    polar.sin() * azimuth.sin(),
+                    polar.cos(),
+                ],
+            );
+            embed(&manifold.exp(center, &(direction * radius))?)
+        };
+
+        let mut vertices = vec![vertex(0.0, 0.0)?];
+        for ring in 1..rings {
+            let polar = PI * ring as f64 / rings as f64;
+            for segment in 0..segments {
+                vertices.push(vertex(polar, 2.0 * PI * segment as f64 / segments as f64)?);
+            }
+        }
+        vertices.push(vertex(PI, 0.0)?);
+
+        let south = vertices.len() - 1;
+        let ring_start = |ring: usize| 1 + (ring - 1) * segments;
+        let mut triangles = Vec::with_capacity(2 * rings * segments);
+        for s in 0..segments {
+            let next = (s + 1) % segments;
+            triangles.push([0, ring_start(1) + s, ring_start(1) + next]);
+            for ring in 1..rings - 1 {
+                let (upper, lower) = (ring_start(ring), ring_start(ring + 1));
+                triangles.push([upper + s, lower + s, lower + next]);
+                triangles.push([upper + s, lower + next, upper + next]);
+            }
+            let last = ring_start(rings - 1);
+            triangles.push([last + s, south, last + next]);
+        }
+        Ok(Self {
+            vertices,
+            triangles,
+        })
+    }
+}
+
+pub fn trajectory<M, F, P>(
+    optimizer: &GradientDescent<P>,
+    manifold: &M,
+    initial_point: &[f64],
+    cost_function: F,
+) -> Result<Vec<Vec<f64>>>
+where
+    M: Manifold<Scalar = f64>,
+    F: Fn(&[f64]) -> f64,
+    P: Preconditioner,
+{
+    let mut state = optimizer.init(manifold, initial_point, &cost_function)?;
+    let mut points = vec![state.point.clone()];
+    for _ in 0..optimizer.max_iterations {
+        let info = optimizer.step(manifold, &mut state, &cost_function)?;
+        points.push(state.point.clone());
+        if info.converged || info.line_search_failed {
+            break;
+        }
+    }
+    Ok(points)
+}
+
+#[cfg(feature = "rerun")]
+pub fn log_polyline(
+    stream: &rerun::RecordingStream,
+    entity_path: &str,
+    polyline: &Polyline,
+) -> Result<()> {
+    stream
+        .log(
+            entity_path,
+            &rerun::LineStrips3D::new([polyline.vertices.iter().map(|v| to_f32(*v))]),
+        )
+        .map_err(|e| ManifoldError::IoError(e.to_string()))
+}
+
+#[cfg(feature = "rerun")]
+pub fn log_mesh(stream: &rerun::RecordingStream, entity_path: &str, mesh: &Mesh) -> Result<()> {
+    let triangles = mesh
+        .triangles
+        .iter()
+        .map(|t| [t[0] as u32, t[1] as u32, t[2] as u32]);
+    stream
+        .log(
+            entity_path,
+            &rerun::Mesh3D::new(mesh.vertices.iter().map(|v| to_f32(*v)))
+                .with_triangle_indices(triangles),
+        )
+        .map_err(|e| ManifoldError::IoError(e.to_string()))
+}
+
+#[cfg(feature = "rerun")]
+fn to_f32(vertex: [f64; 3]) -> [f32; 3] {
+    vertex.map(|x| x as f32)
+}
+
+fn check_count(count: usize, minimum: usize) -> Result<()> {
+    if count < minimum {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Need at least {} samples, got {}",
+            minimum, count
+        )));
+    }
+    Ok(())
+}
+
+fn basis_of_dim<M>(manifold: &M, point: &[f64], dim: usize) -> Result<Vec<TangentVector<f64>>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    if manifold.dim() != dim {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: dim,
+            got: manifold.dim(),
+        });
+    }
+    manifold.tangent_basis(point)
+}
+
+fn combine(basis: &[TangentVector<f64>], coefficients: &[f64]) -> TangentVector<f64> {
+    basis
+        .iter()
+        .zip(coefficients)
+        .fold(TangentVector::zero(basis[0].dim()), |acc, (b, &c)| {
+            acc + &(b * c)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::DVector;
+    use poly_manifold_spaces::Sphere;
+
+    #[test]
+    fn test_geodesic_polyline_on_sphere() {
+        let sphere = Sphere::new(2);
+        let tangent = TangentVector::new(DVector::from_vec(vec![0.0, PI / 2.0, 0.0]));
+        let line = Polyline::geodesic(&sphere, &[1.0, 0.0, 0.0], &tangent, 65, ambient_coordinates)
+            .unwrap();
+
+        assert_eq!(line.vertices.len(), 65);
+        assert_relative_eq!(line.vertices[64][1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(line.length(), PI / 2.0, epsilon = 1e-3);
+        assert!(
+            Polyline::geodesic(&sphere, &[1.0, 0.0, 0.0], &tangent, 1, ambient_coordinates)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_geodesic_circle_and_sphere_stay_at_radius() {
+        let sphere = Sphere::new(2);
+        let north = [0.0, 0.0, 1.0];
+        let circle =
+            Polyline::geodesic_circle(&sphere, &north, 0.5, 32, ambient_coordinates).unwrap();
+        assert_eq!(circle.vertices.len(), 33);
+        assert_eq!(circle.vertices[0], circle.vertices[32]);
+        for v in &circle.vertices {
+            assert_relative_eq!(sphere.distance(&north, v).unwrap(), 0.5, epsilon = 1e-10);
+        }
+
+        let so3 = SO3::new();
+        let identity = SO3::identity();
+        let ball = Mesh::geodesic_sphere(&so3, &identity, 0.3, 6, 8, rotation_vector).unwrap();
+        assert_eq!(ball.vertices.len(), 2 + 5 * 8);
+        assert_eq!(ball.triangles.len(), 2 * 8 + 2 * 4 * 8);
+        assert!(ball
+            .triangles
+            .iter()
+            .flatten()
+            .all(|&i| i < ball.vertices.len()));
+        for v in &ball.vertices {
+            let r = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+            assert_relative_eq!(r, 0.3, epsilon = 1e-10);
+        }
+        assert!(matches!(
+            Mesh::geodesic_sphere(&sphere, &north, 0.3, 6, 8, ambient_coordinates),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 3,
+                got: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_trajectory_path_follows_optimizer() {
+        let sphere = Sphere::new(2);
+        let optimizer = GradientDescent::new(0.2, 200, 1e-10);
+        let cost = |p: &[f64]| -p[2];
+        let points = trajectory(&optimizer, &sphere, &[0.6, 0.0, 0.8], cost).unwrap();
+        let result = optimizer
+            .minimize_from(
+                &sphere,
+                &mut optimizer.init(&sphere, &[0.6, 0.0, 0.8], cost).unwrap(),
+                cost,
+            )
+            .unwrap();
+
+        assert_eq!(points.len(), result.iterations + 1);
+        assert_eq!(points.last().unwrap(), &result.point);
+
+        let line = Polyline::path(&sphere, &points, 4, ambient_coordinates).unwrap();
+        assert_eq!(line.vertices.len(), 4 * (points.len() - 1) + 1);
+        for v in &line.vertices {
+            assert_relative_eq!(
+                v[0] * v[0] + v[1] * v[1] + v[2] * v[2],
+                1.0,
+                epsilon = 1e-10
+            );
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod bezier;
+pub mod discrete;
+pub mod export;
+pub mod spline;
+
+pub use bezier::BezierCurve;
+pub use discrete::DiscreteCurve;
+pub use export::{Mesh, Polyline};
+pub use spline::{CompositeBezier, SmoothingSpline};
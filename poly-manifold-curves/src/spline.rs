@@ -0,0 +1,342 @@
+use crate::bezier::BezierCurve;
+use poly_manifold_autodiff::RiemannianOptimizer;
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+use poly_manifold_spaces::PowerManifold;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeBezier {
+    pub segments: Vec<BezierCurve>,
+}
+
+impl CompositeBezier {
+    pub fn new(segments: Vec<BezierCurve>) -> Result<Self> {
+        if segments.is_empty() {
+            return Err(ManifoldError::InvalidParameter(
+                "A composite curve needs at least one segment".to_string(),
+            ));
+        }
+        Ok(Self { segments })
+    }
+
+    pub fn evaluate<M>(&self, manifold: &M, t: f64) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let (segment, local) = self.locate(t);
+        self.segments[segment].evaluate(manifold, local)
+    }
+
+    pub fn velocity<M>(&self, manifold: &M, t: f64) -> Result<TangentVector<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let (segment, local) = self.locate(t);
+        Ok(self.segments[segment].velocity(manifold, local)? * self.segments.len() as f64)
+    }
+
+    pub fn acceleration_energy<M>(&self, manifold: &M, samples: usize) -> Result<f64>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let samples = samples.max(3);
+        let h = 1.0 / (samples - 1) as f64;
+        let points = (0..samples)
+            .map(|i| self.evaluate(manifold, i as f64 * h))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut energy = 0.0;
+        for window in points.windows(3) {
+            let second =
+                manifold.log(&window[1], &window[0])? + manifold.log(&window[1], &window[2])?;
+            energy += manifold.norm(&window[1], &second)?.powi(2) / h.powi(3);
+        }
+        Ok(energy)
+    }
+
+    fn locate(&self, t: f64) -> (usize, f64) {
+        let scaled = t.clamp(0.0, 1.0) * self.segments.len() as f64;
+        let segment = (scaled.floor() as usize).min(self.segments.len() - 1);
+        (segment, scaled - segment as f64)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmoothingSpline {
+    pub segments: usize,
+    pub smoothing: f64,
+    pub acceleration_samples: usize,
+}
+
+impl SmoothingSpline {
+    pub fn new(segments: usize, smoothing: f64) -> Self {
+        Self {
+            segments,
+            smoothing,
+            acceleration_samples: 8 * segments + 1,
+        }
+    }
+
+    pub fn with_acceleration_samples(mut self, samples: usize) -> Self {
+        self.acceleration_samples = samples;
+        self
+    }
+
+    pub fn assemble<M>(&self, manifold: &M, variables: &[Vec<f64>]) -> Result<CompositeBezier>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let k = self.segments;
+        if variables.len() != 2 * k + 2 {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: 2 * k + 2,
+                got: variables.len(),
+            });
+        }
+        let junctions = &variables[..=k];
+        let exits = &variables[k + 1..];
+
+        let mut entry = exits[0].clone();
+        let mut segments = Vec::with_capacity(k);
+        for s in 0..k {
+            let after = if s == 0 { &exits[0] } else { &entry };
+            segments.push(BezierCurve::new(vec![
+                junctions[s].clone(),
+                after.clone(),
+                exits[s + 1].clone(),
+                junctions[s + 1].clone(),
+            ])?);
+            let reflected = manifold.log(&junctions[s + 1], &exits[s + 1])? * -1.0;
+            entry = manifold.exp(&junctions[s + 1], &reflected)?;
+        }
+        CompositeBezier::new(segments)
+    }
+
+    pub fn fit<M, O>(
+        &self,
+        manifold: &M,
+        times: &[f64],
+        data: &[Vec<f64>],
+        optimizer: &O,
+    ) -> Result<CompositeBezier>
+    where
+        M: Manifold<Scalar = f64>,
+        O: RiemannianOptimizer,
+    {
+        if self.segments == 0 || self.smoothing.is_nan() || self.smoothing < 0.0 {
+            return Err(ManifoldError::InvalidParameter(
+                "Spline needs at least one segment and a non-negative smoothing weight".to_string(),
+            ));
+        }
+        if times.len() != data.len() || data.is_empty() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: data.len(),
+                got: times.len(),
+            });
+        }
+        if let Some(t) = times.iter().find(|t| !(0.0..=1.0).contains(*t)) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Curve parameters must lie in [0, 1], got {}",
+                t
+            )));
+        }
+
+        let variables = self.initial_variables(manifold, times, data)?;
+        let power = PowerManifold::new(manifold, variables.len());
+        let initial = power.from_components(&variables)?;
+
+        let cost = |variables: &[f64]| {
+            let Ok(variables) = power.project_to_manifold(variables) else {
+                return f64::INFINITY;
+            };
+            let variables: Vec<Vec<f64>> =
+                power.components(&variables).map(<[f64]>::to_vec).collect();
+            self.objective(manifold, &variables, times, data)
+                .unwrap_or(f64::INFINITY)
+        };
+
+        let result = optimizer.minimize(&power, &initial, cost)?;
+        let variables: Vec<Vec<f64>> = power
+            .components(&result.point)
+            .map(<[f64]>::to_vec)
+            .collect();
+        self.assemble(manifold, &variables)
+    }
+
+    fn objective<M>(
+        &self,
+        manifold: &M,
+        variables: &[Vec<f64>],
+        times: &[f64],
+        data: &[Vec<f64>],
+    ) -> Result<f64>
+    where
+        M: Manifold<Scalar = f64>,
+    {
+        let curve = self.assemble(manifold, variables)?;
+        let mut fidelity = 0.0;
+        for (t, y) in times.iter().zip(data) {
+            fidelity += manifold
+                .distance(&curve.evaluate(manifold, *t)?, y)?
+                .powi(2);
+        }
+        let smoothness = if self.smoothing > 0.0 {
+            curve.acceleration_energy(manifold, self.acceleration_samples)?
+        } else {
+            0.0
+        };
+        Ok(0.5 * fidelity + 0.5 * self.smoothing * smoothness)
+    }
+
+    fn initial_variables<M>(
+        &self,
+        manifold: &M,
+        times: &[f64],
+        data: &[Vec<f64>],
+    ) -> Result<Vec<Vec<f64>>>
+    where
+        M: Manifold<Scalar = f64>,
+    {
+        let k = self.segments;
+        let nearest = |tau: f64| {
+            times
+                .iter()
+                .enumerate()
+                .min_by(|a, b| (a.1 - tau).abs().total_cmp(&(b.1 - tau).abs()))
+                .map(|(i, _)| data[i].clone())
+                .expect("data is not empty")
+        };
+        let junctions: Vec<Vec<f64>> = (0..=k).map(|s| nearest(s as f64 / k as f64)).collect();
+
+        let mut variables = junctions.clone();
+        let first = manifold.log(&junctions[0], &junctions[1])?;
+        variables.push(manifold.geodesic(&junctions[0], &first, 1.0 / 3.0)?);
+        for s in 0..k {
+            let back = manifold.log(&junctions[s + 1], &junctions[s])?;
+            variables.push(manifold.geodesic(&junctions[s + 1], &back, 1.0 / 3.0)?);
+        }
+        Ok(variables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_autodiff::{GradientDescent, LineSearch};
+    use poly_manifold_core::{RandomSource, SeededRng};
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    fn noisy_arc(sphere: &Sphere, count: usize) -> (Vec<f64>, Vec<Vec<f64>>) {
+        let mut rng = SeededRng::seed_from_u64(8);
+        let times: Vec<f64> = (0..count).map(|i| i as f64 / (count - 1) as f64).collect();
+        let data = times
+            .iter()
+            .map(|t| {
+                let angle = 1.5 * t;
+                let noise = 0.03 * rng.next_gaussian();
+                sphere
+                    .project_to_manifold(&[angle.cos(), angle.sin(), 0.3 * (3.0 * t).sin() + noise])
+                    .unwrap()
+            })
+            .collect();
+        (times, data)
+    }
+
+    #[test]
+    fn test_assembled_spline_is_c1_at_junctions() {
+        let sphere = Sphere::new(2);
+        let spline = SmoothingSpline::new(2, 0.0);
+        let p = |v: [f64; 3]| sphere.project_to_manifold(&v).unwrap();
+        let variables = vec![
+            p([1.0, 0.0, 0.0]),
+            p([1.0, 1.0, 0.2]),
+            p([0.0, 1.0, 0.0]),
+            p([1.0, 0.3, 0.1]),
+            p([1.0, 0.8, 0.3]),
+            p([0.2, 1.0, -0.1]),
+        ];
+        let curve = spline.assemble(&sphere, &variables).unwrap();
+
+        let left = curve.velocity(&sphere, 0.5 - 1e-9).unwrap();
+        let right = curve.velocity(&sphere, 0.5).unwrap();
+        for (l, r) in left.components.iter().zip(right.components.iter()) {
+            assert_relative_eq!(l, r, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_smoothing_trades_fidelity_for_lower_acceleration() {
+        let sphere = Sphere::new(2);
+        let (times, data) = noisy_arc(&sphere, 15);
+        let optimizer =
+            GradientDescent::new(1.0, 200, 1e-10).with_line_search(LineSearch::armijo());
+
+        let loose = SmoothingSpline::new(2, 0.0)
+            .fit(&sphere, &times, &data, &optimizer)
+            .unwrap();
+        let stiff = SmoothingSpline::new(2, 1.0)
+            .fit(&sphere, &times, &data, &optimizer)
+            .unwrap();
+
+        let residual = |curve: &CompositeBezier| -> f64 {
+            times
+                .iter()
+                .zip(&data)
+                .map(|(t, y)| {
+                    sphere
+                        .distance(&curve.evaluate(&sphere, *t).unwrap(), y)
+                        .unwrap()
+                        .powi(2)
+                })
+                .sum()
+        };
+        assert!(residual(&loose) < residual(&stiff));
+        assert!(
+            stiff.acceleration_energy(&sphere, 33).unwrap()
+                < loose.acceleration_energy(&sphere, 33).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fit_reproduces_straight_line_in_euclidean_space() {
+        let euclidean = Euclidean::new(2);
+        let times: Vec<f64> = (0..9).map(|i| i as f64 / 8.0).collect();
+        let data: Vec<Vec<f64>> = times.iter().map(|t| vec![2.0 * t, 1.0 - t]).collect();
+
+        let optimizer =
+            GradientDescent::new(1.0, 500, 1e-14).with_line_search(LineSearch::armijo());
+        let curve = SmoothingSpline::new(2, 0.1)
+            .fit(&euclidean, &times, &data, &optimizer)
+            .unwrap();
+        for (t, y) in times.iter().zip(&data) {
+            let point = curve.evaluate(&euclidean, *t).unwrap();
+            assert_relative_eq!(point[0], y[0], epsilon = 1e-2);
+            assert_relative_eq!(point[1], y[1], epsilon = 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let euclidean = Euclidean::new(1);
+        let optimizer = GradientDescent::new(0.1, 10, 1e-8);
+        let data = vec![vec![0.0], vec![1.0]];
+        assert!(SmoothingSpline::new(0, 0.0)
+            .fit(&euclidean, &[0.0, 1.0], &data, &optimizer)
+            .is_err());
+        assert!(SmoothingSpline::new(1, -1.0)
+            .fit(&euclidean, &[0.0, 1.0], &data, &optimizer)
+            .is_err());
+        assert!(SmoothingSpline::new(1, 0.0)
+            .fit(&euclidean, &[0.0], &data, &optimizer)
+            .is_err());
+        for times in [[0.0, 1.5], [-0.5, 1.0], [0.0, f64::NAN]] {
+            assert!(matches!(
+                SmoothingSpline::new(1, 0.0).fit(&euclidean, &times, &data, &optimizer),
+                Err(ManifoldError::InvalidParameter(_))
+            ));
+        }
+        assert!(CompositeBezier::new(Vec::new()).is_err());
+    }
+}
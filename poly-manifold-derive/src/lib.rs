@@ -0,0 +1,95 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+#[proc_macro_derive(EmbeddedManifold)]
+pub fn derive_embedded_manifold(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::poly_manifold_core::Manifold for #name #type_generics #where_clause {
+            type Scalar = <Self as ::poly_manifold_core::embedded::EmbeddedSubmanifold>::Scalar;
+
+            fn dim(&self) -> usize {
+                ::poly_manifold_core::embedded::EmbeddedSubmanifold::manifold_dim(self)
+            }
+
+            fn ambient_dim(&self) -> usize {
+                ::poly_manifold_core::embedded::EmbeddedSubmanifold::embedding_dim(self)
+            }
+
+            fn check_point(&self, point: &[Self::Scalar]) -> ::poly_manifold_core::Result<()> {
+                ::poly_manifold_core::embedded::check_point(self, point)
+            }
+
+            fn check_tangent_vector(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &::poly_manifold_core::TangentVector<Self::Scalar>,
+            ) -> ::poly_manifold_core::Result<()> {
+                ::poly_manifold_core::embedded::check_tangent_vector(self, point, tangent)
+            }
+
+            fn project_to_manifold(
+                &self,
+                point: &[Self::Scalar],
+            ) -> ::poly_manifold_core::Result<
+                ::poly_manifold_core::embedded::__private::Vec<Self::Scalar>,
+            > {
+                ::poly_manifold_core::embedded::EmbeddedSubmanifold::project_to_manifold(
+                    self, point,
+                )
+            }
+
+            fn project_to_tangent_space(
+                &self,
+                point: &[Self::Scalar],
+                vector: &::poly_manifold_core::TangentVector<Self::Scalar>,
+            ) -> ::poly_manifold_core::Result<::poly_manifold_core::TangentVector<Self::Scalar>> {
+                ::poly_manifold_core::embedded::EmbeddedSubmanifold::project_to_tangent_space(
+                    self, point, vector,
+                )
+            }
+
+            fn exp(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &::poly_manifold_core::TangentVector<Self::Scalar>,
+            ) -> ::poly_manifold_core::Result<
+                ::poly_manifold_core::embedded::__private::Vec<Self::Scalar>,
+            > {
+                ::poly_manifold_core::embedded::retraction(self, point, tangent)
+            }
+
+            fn retraction(
+                &self,
+                point: &[Self::Scalar],
+                tangent: &::poly_manifold_core::TangentVector<Self::Scalar>,
+            ) -> ::poly_manifold_core::Result<
+                ::poly_manifold_core::embedded::__private::Vec<Self::Scalar>,
+            > {
+                ::poly_manifold_core::embedded::retraction(self, point, tangent)
+            }
+
+            fn log(
+                &self,
+                point: &[Self::Scalar],
+                other: &[Self::Scalar],
+            ) -> ::poly_manifold_core::Result<::poly_manifold_core::TangentVector<Self::Scalar>> {
+                ::poly_manifold_core::embedded::inverse_retraction(self, point, other)
+            }
+
+            fn inner_product(
+                &self,
+                _point: &[Self::Scalar],
+                v1: &::poly_manifold_core::TangentVector<Self::Scalar>,
+                v2: &::poly_manifold_core::TangentVector<Self::Scalar>,
+            ) -> ::poly_manifold_core::Result<Self::Scalar> {
+                ::core::result::Result::Ok(::poly_manifold_core::embedded::inner_product(v1, v2))
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,44 @@
+use numpy::{PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2};
+use poly_manifold_core::array::{points_from_array, points_to_array};
+use poly_manifold_core::{ErrorCode, ManifoldError, TangentVector};
+use pyo3::exceptions::{PyIOError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+
+pub(crate) fn to_py_err(error: ManifoldError) -> PyErr {
+    match error.code() {
+        ErrorCode::Convergence | ErrorCode::Backend => PyRuntimeError::new_err(error.to_string()),
+        ErrorCode::Io => PyIOError::new_err(error.to_string()),
+        _ => PyValueError::new_err(error.to_string()),
+    }
+}
+
+pub(crate) fn vector(array: &PyReadonlyArray1<'_, f64>) -> Vec<f64> {
+    array.as_array().to_vec()
+}
+
+pub(crate) fn tangent(array: &PyReadonlyArray1<'_, f64>) -> TangentVector<f64> {
+    TangentVector::from_array(array.as_array().to_owned())
+}
+
+pub(crate) fn points(array: &PyReadonlyArray2<'_, f64>) -> Vec<Vec<f64>> {
+    points_from_array(array.as_array())
+}
+
+pub(crate) fn vector_to_numpy(py: Python<'_>, values: Vec<f64>) -> Bound<'_, PyArray1<f64>> {
+    PyArray1::from_vec(py, values)
+}
+
+pub(crate) fn tangent_to_numpy(
+    py: Python<'_>,
+    tangent: TangentVector<f64>,
+) -> Bound<'_, PyArray1<f64>> {
+    PyArray1::from_owned_array(py, tangent.into_array())
+}
+
+pub(crate) fn points_to_numpy<'py>(
+    py: Python<'py>,
+    points: &[Vec<f64>],
+) -> PyResult<Bound<'py, PyArray2<f64>>> {
+    let array = points_to_array(points).map_err(to_py_err)?;
+    Ok(PyArray2::from_owned_array(py, array))
+}
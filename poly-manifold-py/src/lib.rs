@@ -0,0 +1,21 @@
+mod convert;
+mod manifold;
+mod optimize;
+mod stats;
+
+pub use manifold::PyManifold;
+pub use optimize::{PyGradientDescent, PyOptimizeResult};
+pub use stats::{frechet_mean, PyPga};
+
+use pyo3::prelude::*;
+
+#[pymodule]
+#[pyo3(name = "poly_manifold")]
+fn poly_manifold_py(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyManifold>()?;
+    module.add_class::<PyGradientDescent>()?;
+    module.add_class::<PyOptimizeResult>()?;
+    module.add_class::<PyPga>()?;
+    module.add_function(wrap_pyfunction!(frechet_mean, module)?)?;
+    Ok(())
+}
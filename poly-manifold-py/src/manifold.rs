@@ -0,0 +1,206 @@
+use crate::convert::{tangent, tangent_to_numpy, to_py_err, vector, vector_to_numpy};
+use numpy::{PyArray1, PyReadonlyArray1};
+use poly_manifold_core::Manifold;
+use poly_manifold_spaces::{Euclidean, PoincareBall, Sphere, Stiefel, SO3, SPD};
+use pyo3::prelude::*;
+
+pub(crate) enum Geometry {
+    Euclidean(Euclidean),
+    Sphere(Sphere),
+    Spd(SPD),
+    PoincareBall(PoincareBall),
+    So3(SO3),
+    Stiefel(Stiefel),
+}
+
+impl Geometry {
+    pub(crate) fn manifold(&self) -> &dyn Manifold<Scalar = f64> {
+        match self {
+            Geometry::Euclidean(m) => m,
+            Geometry::Sphere(m) => m,
+            Geometry::Spd(m) => m,
+            Geometry::PoincareBall(m) => m,
+            Geometry::So3(m) => m,
+            Geometry::Stiefel(m) => m,
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Geometry::Euclidean(m) => format!("Euclidean({})", m.dimension),
+            Geometry::Sphere(m) => format!("Sphere({})", m.dimension),
+            Geometry::Spd(m) => format!("SPD({})", m.dimension),
+            Geometry::PoincareBall(m) => format!("PoincareBall({})", m.dimension),
+            Geometry::So3(_) => "SO3()".to_string(),
+            Geometry::Stiefel(m) => format!("Stiefel({}, {})", m.n, m.p),
+        }
+    }
+}
+
+#[pyclass(name = "Manifold", module = "poly_manifold", frozen)]
+pub struct PyManifold {
+    pub(crate) geometry: Geometry,
+}
+
+impl PyManifold {
+    fn new(geometry: Geometry) -> Self {
+        Self { geometry }
+    }
+}
+
+#[pymethods]
+impl PyManifold {
+    #[staticmethod]
+    fn euclidean(dimension: usize) -> PyResult<Self> {
+        let manifold = Euclidean::try_new(dimension).map_err(to_py_err)?;
+        Ok(Self::new(Geometry::Euclidean(manifold)))
+    }
+
+    #[staticmethod]
+    fn sphere(dimension: usize) -> PyResult<Self> {
+        let manifold = Sphere::try_new(dimension).map_err(to_py_err)?;
+        Ok(Self::new(Geometry::Sphere(manifold)))
+    }
+
+    #[staticmethod]
+    fn spd(dimension: usize) -> PyResult<Self> {
+        let manifold = SPD::try_new(dimension).map_err(to_py_err)?;
+        Ok(Self::new(Geometry::Spd(manifold)))
+    }
+
+    #[staticmethod]
+    fn poincare_ball(dimension: usize) -> PyResult<Self> {
+        let manifold = PoincareBall::try_new(dimension).map_err(to_py_err)?;
+        Ok(Self::new(Geometry::PoincareBall(manifold)))
+    }
+
+    #[staticmethod]
+    fn so3() -> Self {
+        Self::new(Geometry::So3(SO3::new()))
+    }
+
+    #[staticmethod]
+    fn stiefel(n: usize, p: usize) -> PyResult<Self> {
+        let manifold = Stiefel::new(n, p).map_err(to_py_err)?;
+        Ok(Self::new(Geometry::Stiefel(manifold)))
+    }
+
+    #[getter]
+    fn dim(&self) -> usize {
+        self.geometry.manifold().dim()
+    }
+
+    #[getter]
+    fn ambient_dim(&self) -> usize {
+        self.geometry.manifold().ambient_dim()
+    }
+
+    fn check_point(&self, point: PyReadonlyArray1<'_, f64>) -> PyResult<()> {
+        self.geometry
+            .manifold()
+            .check_point(&vector(&point))
+            .map_err(to_py_err)
+    }
+
+    fn project<'py>(
+        &self,
+        py: Python<'py>,
+        point: PyReadonlyArray1<'py, f64>,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let projected = self
+            .geometry
+            .manifold()
+            .project_to_manifold(&vector(&point))
+            .map_err(to_py_err)?;
+        Ok(vector_to_numpy(py, projected))
+    }
+
+    fn exp<'py>(
+        &self,
+        py: Python<'py>,
+        point: PyReadonlyArray1<'py, f64>,
+        tangent_vector: PyReadonlyArray1<'py, f64>,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let result = self
+            .geometry
+            .manifold()
+            .exp(&vector(&point), &tangent(&tangent_vector))
+            .map_err(to_py_err)?;
+        Ok(vector_to_numpy(py, result))
+    }
+
+    fn log<'py>(
+        &self,
+        py: Python<'py>,
+        point: PyReadonlyArray1<'py, f64>,
+        other: PyReadonlyArray1<'py, f64>,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let result = self
+            .geometry
+            .manifold()
+            .log(&vector(&point), &vector(&other))
+            .map_err(to_py_err)?;
+        Ok(tangent_to_numpy(py, result))
+    }
+
+    fn distance(
+        &self,
+        point: PyReadonlyArray1<'_, f64>,
+        other: PyReadonlyArray1<'_, f64>,
+    ) -> PyResult<f64> {
+        self.geometry
+            .manifold()
+            .distance(&vector(&point), &vector(&other))
+            .map_err(to_py_err)
+    }
+
+    fn inner_product(
+        &self,
+        point: PyReadonlyArray1<'_, f64>,
+        u: PyReadonlyArray1<'_, f64>,
+        v: PyReadonlyArray1<'_, f64>,
+    ) -> PyResult<f64> {
+        self.geometry
+            .manifold()
+            .inner_product(&vector(&point), &tangent(&u), &tangent(&v))
+            .map_err(to_py_err)
+    }
+
+    fn __repr__(&self) -> String {
+        self.geometry.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometry_dispatch() {
+        let sphere = Geometry::Sphere(Sphere::new(2));
+        assert_eq!(sphere.manifold().dim(), 2);
+        assert_eq!(sphere.manifold().ambient_dim(), 3);
+        assert_eq!(sphere.name(), "Sphere(2)");
+
+        let spd = Geometry::Spd(SPD::new(3));
+        assert_eq!(spd.manifold().dim(), 6);
+        assert_eq!(
+            Geometry::Stiefel(Stiefel::new(4, 2).unwrap()).name(),
+            "Stiefel(4, 2)"
+        );
+    }
+
+    #[test]
+    fn test_constructors_reject_invalid_arguments() {
+        assert!(PyManifold::stiefel(2, 3).is_err());
+        assert!(PyManifold::stiefel(3, 0).is_err());
+        assert!(PyManifold::euclidean(0).is_err());
+        assert!(PyManifold::sphere(0).is_err());
+        assert!(PyManifold::spd(0).is_err());
+        assert!(PyManifold::poincare_ball(0).is_err());
+        assert_eq!(
+            PyManifold::stiefel(3, 2).unwrap().geometry.name(),
+            "Stiefel(3, 2)"
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use crate::convert::{to_py_err, vector, vector_to_numpy};
+use crate::manifold::PyManifold;
+use numpy::{PyArray1, PyReadonlyArray1};
+use poly_manifold_autodiff::{GradientDescent, LineSearch, RiemannianOptimizer};
+use pyo3::prelude::*;
+use std::cell::RefCell;
+
+#[pyclass(name = "OptimizeResult", module = "poly_manifold", frozen, get_all)]
+pub struct PyOptimizeResult {
+    point: Py<PyArray1<f64>>,
+    cost: f64,
+    iterations: usize,
+    converged: bool,
+}
+
+#[pyclass(name = "GradientDescent", module = "poly_manifold", frozen)]
+pub struct PyGradientDescent {
+    inner: GradientDescent,
+}
+
+fn evaluate(cost: &Bound<'_, PyAny>, point: &[f64]) -> PyResult<f64> {
+    cost.call1((PyArray1::from_slice(cost.py(), point),))?
+        .extract()
+}
+
+#[pymethods]
+impl PyGradientDescent {
+    #[new]
+    #[pyo3(signature = (learning_rate = 0.1, max_iterations = 1000, tolerance = 1e-8, line_search = false))]
+    fn new(learning_rate: f64, max_iterations: usize, tolerance: f64, line_search: bool) -> Self {
+        let mut inner = GradientDescent::new(learning_rate, max_iterations, tolerance);
+        if line_search {
+            inner = inner.with_line_search(LineSearch::armijo());
+        }
+        Self { inner }
+    }
+
+    fn minimize<'py>(
+        &self,
+        py: Python<'py>,
+        manifold: &PyManifold,
+        initial_point: PyReadonlyArray1<'py, f64>,
+        cost: Bound<'py, PyAny>,
+    ) -> PyResult<PyOptimizeResult> {
+        let failure = RefCell::new(None);
+        let cost_function = |point: &[f64]| match evaluate(&cost, point) {
+            Ok(value) => value,
+            Err(error) => {
+                failure.borrow_mut().get_or_insert(error);
+                f64::NAN
+            }
+        };
+        let result = self.inner.minimize(
+            &manifold.geometry.manifold(),
+            &vector(&initial_point),
+            cost_function,
+        );
+        if let Some(error) = failure.into_inner() {
+            return Err(error);
+        }
+        let result = result.map_err(to_py_err)?;
+        Ok(PyOptimizeResult {
+            converged: result.converged(),
+            cost: result.cost,
+            iterations: result.iterations,
+            point: vector_to_numpy(py, result.point).unbind(),
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use crate::convert::{points, points_to_numpy, to_py_err, vector, vector_to_numpy};
+use crate::manifold::PyManifold;
+use nalgebra::DVector;
+use numpy::{PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2};
+use poly_manifold_stats::{FrechetMean, PrincipalGeodesicAnalysis};
+use pyo3::prelude::*;
+
+#[pyfunction]
+#[pyo3(signature = (manifold, samples, max_iterations = 100, tolerance = 1e-10))]
+pub fn frechet_mean<'py>(
+    py: Python<'py>,
+    manifold: &PyManifold,
+    samples: PyReadonlyArray2<'py, f64>,
+    max_iterations: usize,
+    tolerance: f64,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let mean = FrechetMean::new(max_iterations, tolerance)
+        .compute(manifold.geometry.manifold(), &points(&samples))
+        .map_err(to_py_err)?;
+    Ok(vector_to_numpy(py, mean))
+}
+
+#[pyclass(name = "PGA", module = "poly_manifold", frozen)]
+pub struct PyPga {
+    manifold: Py<PyManifold>,
+    inner: PrincipalGeodesicAnalysis,
+}
+
+#[pymethods]
+impl PyPga {
+    #[staticmethod]
+    #[pyo3(signature = (manifold, samples, n_components, max_iterations = 100, tolerance = 1e-10))]
+    fn fit(
+        manifold: Bound<'_, PyManifold>,
+        samples: PyReadonlyArray2<'_, f64>,
+        n_components: usize,
+        max_iterations: usize,
+        tolerance: f64,
+    ) -> PyResult<Self> {
+        let inner = PrincipalGeodesicAnalysis::fit(
+            manifold.get().geometry.manifold(),
+            &points(&samples),
+            n_components,
+            &FrechetMean::new(max_iterations, tolerance),
+        )
+        .map_err(to_py_err)?;
+        Ok(Self {
+            manifold: manifold.unbind(),
+            inner,
+        })
+    }
+
+    #[getter]
+    fn mean<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        vector_to_numpy(py, self.inner.mean.clone())
+    }
+
+    #[getter]
+    fn variances<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        vector_to_numpy(py, self.inner.variances.clone())
+    }
+
+    #[getter]
+    fn total_variance(&self) -> f64 {
+        self.inner.total_variance
+    }
+
+    #[getter]
+    fn explained_variance_ratio<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        vector_to_numpy(py, self.inner.explained_variance_ratio())
+    }
+
+    #[getter]
+    fn directions<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray2<f64>>> {
+        let directions: Vec<Vec<f64>> = self
+            .inner
+            .directions
+            .iter()
+            .map(|d| d.components.as_slice().to_vec())
+            .collect();
+        points_to_numpy(py, &directions)
+    }
+
+    fn project<'py>(
+        &self,
+        py: Python<'py>,
+        point: PyReadonlyArray1<'py, f64>,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let scores = self
+            .inner
+            .project(self.manifold.get().geometry.manifold(), &vector(&point))
+            .map_err(to_py_err)?;
+        Ok(vector_to_numpy(py, scores.as_slice().to_vec()))
+    }
+
+    fn reconstruct<'py>(
+        &self,
+        py: Python<'py>,
+        scores: PyReadonlyArray1<'py, f64>,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let point = self
+            .inner
+            .reconstruct(
+                self.manifold.get().geometry.manifold(),
+                &DVector::from_vec(vector(&scores)),
+            )
+            .map_err(to_py_err)?;
+        Ok(vector_to_numpy(py, point))
+    }
+}
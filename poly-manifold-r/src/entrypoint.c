@@ -0,0 +1,7 @@
+// Forward routine registration to Rust so the linker keeps the static library.
+
+void R_init_polymanifold_extendr(void *dll);
+
+void R_init_polymanifold(void *dll) {
+    R_init_polymanifold_extendr(dll);
+}
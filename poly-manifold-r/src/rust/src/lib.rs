@@ -0,0 +1,290 @@
+use extendr_api::prelude::*;
+use nalgebra::DVector;
+use poly_manifold_autodiff::ManifoldSpec;
+use poly_manifold_core::{Manifold as _, ManifoldError, TangentVector};
+use poly_manifold_spaces::SPD;
+use poly_manifold_stats::{DistanceMatrix, FrechetMean, PrincipalGeodesicAnalysis, TensorMetric};
+
+fn to_r_error(error: ManifoldError) -> Error {
+    Error::Other(error.to_string())
+}
+
+fn count(value: i32, name: &str) -> Result<usize> {
+    usize::try_from(value).map_err(|_| Error::Other(format!("{name} must be non-negative")))
+}
+
+fn tangent(values: &[f64]) -> TangentVector<f64> {
+    TangentVector::new(DVector::from_column_slice(values))
+}
+
+fn rows(samples: &RMatrix<f64>) -> Vec<Vec<f64>> {
+    let (nrows, ncols) = (samples.nrows(), samples.ncols());
+    let data = samples.data();
+    (0..nrows)
+        .map(|i| (0..ncols).map(|j| data[j * nrows + i]).collect())
+        .collect()
+}
+
+fn rows_to_matrix(rows: &[Vec<f64>]) -> RMatrix<f64> {
+    let ncols = rows.first().map_or(0, Vec::len);
+    RMatrix::new_matrix(rows.len(), ncols, |i, j| rows[i][j])
+}
+
+fn spd_values(matrix: &RMatrix<f64>) -> Vec<f64> {
+    rows(matrix).concat()
+}
+
+fn spd_matrix(values: &[f64], n: usize) -> RMatrix<f64> {
+    RMatrix::new_matrix(n, n, |i, j| values[i * n + j])
+}
+
+fn tensor_metric(metric: &str) -> Result<TensorMetric> {
+    match metric {
+        "log_euclidean" => Ok(TensorMetric::LogEuclidean),
+        "affine_invariant" => Ok(TensorMetric::AffineInvariant),
+        other => Err(Error::Other(format!(
+            "Unknown metric '{other}'; expected 'log_euclidean' or 'affine_invariant'"
+        ))),
+    }
+}
+
+pub struct Manifold {
+    spec: ManifoldSpec,
+    inner: Box<dyn poly_manifold_core::Manifold<Scalar = f64>>,
+}
+
+impl Manifold {
+    fn new(spec: ManifoldSpec) -> Self {
+        let inner = spec.build();
+        Self { spec, inner }
+    }
+}
+
+#[extendr]
+impl Manifold {
+    fn euclidean(dimension: i32) -> Result<Self> {
+        let dimension = count(dimension, "dimension")?;
+        Ok(Self::new(ManifoldSpec::Euclidean { dimension }))
+    }
+
+    fn sphere(dimension: i32) -> Result<Self> {
+        let dimension = count(dimension, "dimension")?;
+        Ok(Self::new(ManifoldSpec::Sphere { dimension }))
+    }
+
+    fn spd(dimension: i32) -> Result<Self> {
+        let dimension = count(dimension, "dimension")?;
+        Ok(Self::new(ManifoldSpec::Spd { dimension }))
+    }
+
+    fn poincare_ball(dimension: i32) -> Result<Self> {
+        let dimension = count(dimension, "dimension")?;
+        Ok(Self::new(ManifoldSpec::PoincareBall { dimension }))
+    }
+
+    fn so3() -> Self {
+        Self::new(ManifoldSpec::So3)
+    }
+
+    fn stiefel(n: i32, p: i32) -> Result<Self> {
+        Ok(Self::new(ManifoldSpec::Stiefel {
+            n: count(n, "n")?,
+            p: count(p, "p")?,
+        }))
+    }
+
+    fn dim(&self) -> i32 {
+        self.inner.dim() as i32
+    }
+
+    fn ambient_dim(&self) -> i32 {
+        self.inner.ambient_dim() as i32
+    }
+
+    fn project(&self, point: &[f64]) -> Result<Vec<f64>> {
+        self.inner.project_to_manifold(point).map_err(to_r_error)
+    }
+
+    fn exp(&self, point: &[f64], tangent_vector: &[f64]) -> Result<Vec<f64>> {
+        self.inner
+            .exp(point, &tangent(tangent_vector))
+            .map_err(to_r_error)
+    }
+
+    fn log(&self, point: &[f64], other: &[f64]) -> Result<Vec<f64>> {
+        let log = self.inner.log(point, other).map_err(to_r_error)?;
+        Ok(log.components.as_slice().to_vec())
+    }
+
+    fn distance(&self, a: &[f64], b: &[f64]) -> Result<f64> {
+        self.inner.distance(a, b).map_err(to_r_error)
+    }
+
+    fn geodesic(&self, a: &[f64], b: &[f64], t: f64) -> Result<Vec<f64>> {
+        let direction = self.inner.log(a, b).map_err(to_r_error)?;
+        self.inner.geodesic(a, &direction, t).map_err(to_r_error)
+    }
+}
+
+#[extendr]
+fn distance_matrix(manifold: &Manifold, points: RMatrix<f64>) -> Result<RMatrix<f64>> {
+    let distances = DistanceMatrix::default()
+        .compute(&manifold.inner, &rows(&points))
+        .map_err(to_r_error)?;
+    let n = distances.nrows();
+    Ok(RMatrix::new_matrix(n, n, |i, j| distances[(i, j)]))
+}
+
+#[extendr]
+fn frechet_mean(
+    manifold: &Manifold,
+    samples: RMatrix<f64>,
+    #[default = "100L"] max_iterations: i32,
+    #[default = "1e-10"] tolerance: f64,
+) -> Result<Vec<f64>> {
+    FrechetMean::new(count(max_iterations, "max_iterations")?, tolerance)
+        .compute(&manifold.inner, &rows(&samples))
+        .map_err(to_r_error)
+}
+
+pub struct Pga {
+    manifold: Manifold,
+    inner: PrincipalGeodesicAnalysis,
+}
+
+#[extendr]
+impl Pga {
+    fn fit(
+        manifold: &Manifold,
+        samples: RMatrix<f64>,
+        n_components: i32,
+        #[default = "100L"] max_iterations: i32,
+        #[default = "1e-10"] tolerance: f64,
+    ) -> Result<Self> {
+        let inner = PrincipalGeodesicAnalysis::fit(
+            &manifold.inner,
+            &rows(&samples),
+            count(n_components, "n_components")?,
+            &FrechetMean::new(count(max_iterations, "max_iterations")?, tolerance),
+        )
+        .map_err(to_r_error)?;
+        Ok(Self {
+            manifold: Manifold::new(manifold.spec.clone()),
+            inner,
+        })
+    }
+
+    fn mean(&self) -> Vec<f64> {
+        self.inner.mean.clone()
+    }
+
+    fn variances(&self) -> Vec<f64> {
+        self.inner.variances.clone()
+    }
+
+    fn explained_variance_ratio(&self) -> Vec<f64> {
+        self.inner.explained_variance_ratio()
+    }
+
+    fn directions(&self) -> RMatrix<f64> {
+        let directions: Vec<Vec<f64>> = self
+            .inner
+            .directions
+            .iter()
+            .map(|d| d.components.as_slice().to_vec())
+            .collect();
+        rows_to_matrix(&directions)
+    }
+
+    fn project(&self, point: &[f64]) -> Result<Vec<f64>> {
+        let scores = self
+            .inner
+            .project(&self.manifold.inner, point)
+            .map_err(to_r_error)?;
+        Ok(scores.as_slice().to_vec())
+    }
+
+    fn reconstruct(&self, scores: &[f64]) -> Result<Vec<f64>> {
+        self.inner
+            .reconstruct(&self.manifold.inner, &DVector::from_column_slice(scores))
+            .map_err(to_r_error)
+    }
+}
+
+#[extendr]
+fn spd_distance(a: RMatrix<f64>, b: RMatrix<f64>) -> Result<f64> {
+    let spd = SPD::new(a.nrows());
+    spd.distance(&spd_values(&a), &spd_values(&b))
+        .map_err(to_r_error)
+}
+
+#[extendr]
+fn spd_geodesic(a: RMatrix<f64>, b: RMatrix<f64>, t: f64) -> Result<RMatrix<f64>> {
+    let n = a.nrows();
+    let spd = SPD::new(n);
+    let (a, b) = (spd_values(&a), spd_values(&b));
+    let direction = spd.log(&a, &b).map_err(to_r_error)?;
+    let point = spd.geodesic(&a, &direction, t).map_err(to_r_error)?;
+    Ok(spd_matrix(&point, n))
+}
+
+#[extendr]
+fn spd_mean(
+    matrices: List,
+    #[default = "100L"] max_iterations: i32,
+    #[default = "1e-10"] tolerance: f64,
+) -> Result<RMatrix<f64>> {
+    let matrices = matrices
+        .values()
+        .map(|robj| {
+            RMatrix::<f64>::try_from(robj).map(|matrix| (matrix.nrows(), spd_values(&matrix)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let n = matrices.first().map_or(0, |(n, _)| *n);
+    let points: Vec<Vec<f64>> = matrices.into_iter().map(|(_, values)| values).collect();
+    let mean = FrechetMean::new(count(max_iterations, "max_iterations")?, tolerance)
+        .compute(&SPD::new(n), &points)
+        .map_err(to_r_error)?;
+    Ok(spd_matrix(&mean, n))
+}
+
+#[extendr]
+fn fractional_anisotropy(tensor: RMatrix<f64>) -> Result<f64> {
+    poly_manifold_stats::fractional_anisotropy(&spd_values(&tensor)).map_err(to_r_error)
+}
+
+#[extendr]
+fn mean_diffusivity(tensor: RMatrix<f64>) -> Result<f64> {
+    poly_manifold_stats::mean_diffusivity(&spd_values(&tensor)).map_err(to_r_error)
+}
+
+#[extendr]
+fn tensor_interpolate(
+    a: RMatrix<f64>,
+    b: RMatrix<f64>,
+    t: f64,
+    #[default = "'log_euclidean'"] metric: &str,
+) -> Result<RMatrix<f64>> {
+    let tensor = poly_manifold_stats::tensor_interpolate(
+        &spd_values(&a),
+        &spd_values(&b),
+        t,
+        tensor_metric(metric)?,
+    )
+    .map_err(to_r_error)?;
+    Ok(spd_matrix(&tensor, 3))
+}
+
+extendr_module! {
+    mod polymanifold;
+    impl Manifold;
+    impl Pga;
+    fn distance_matrix;
+    fn frechet_mean;
+    fn spd_distance;
+    fn spd_geodesic;
+    fn spd_mean;
+    fn fractional_anisotropy;
+    fn mean_diffusivity;
+    fn tensor_interpolate;
+}
@@ -1,18 +1,77 @@
-use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
-use nalgebra::DVector;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use nalgebra::{DVectorView, DVectorViewMut, RealField};
+use poly_manifold_core::batch::batch_len;
+use poly_manifold_core::surrogate::chordal_distance;
+use poly_manifold_core::validation::{check_dimension, validate_point, validate_tangent};
+use poly_manifold_core::{
+    BatchManifold, DistanceSurrogate, Manifold, ManifoldError, PointEquivalence, Result,
+    TangentVector,
+};
 
-pub struct Euclidean {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Euclidean<T = f64> {
     pub dimension: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scalar: PhantomData<T>,
+}
+
+impl<T> Clone for Euclidean<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Euclidean<T> {}
+
+impl<T> core::fmt::Debug for Euclidean<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Euclidean")
+            .field("dimension", &self.dimension)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for Euclidean<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dimension == other.dimension
+    }
+}
+
+impl<T> Eq for Euclidean<T> {}
+
+impl<T> core::hash::Hash for Euclidean<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.dimension.hash(state);
+    }
 }
 
 impl Euclidean {
     pub fn new(dimension: usize) -> Self {
-        Self { dimension }
+        Self::with_scalar(dimension)
+    }
+
+    pub fn try_new(dimension: usize) -> Result<Self> {
+        check_dimension("Euclidean", "dimension", dimension)?;
+        Ok(Self::new(dimension))
+    }
+
+    pub fn from_ambient_dim(ambient_dim: usize) -> Result<Self> {
+        Self::try_new(ambient_dim)
     }
 }
 
-impl Manifold for Euclidean {
-    type Scalar = f64;
+impl<T: RealField + Copy> Euclidean<T> {
+    pub fn with_scalar(dimension: usize) -> Self {
+        Self {
+            dimension,
+            scalar: PhantomData,
+        }
+    }
+}
+
+impl<T: RealField + Copy> Manifold for Euclidean<T> {
+    type Scalar = T;
 
     fn dim(&self) -> usize {
         self.dimension
@@ -55,16 +114,42 @@ impl Manifold for Euclidean {
         Ok(vector.clone())
     }
 
+    fn project_to_tangent_space_in_place(
+        &self,
+        _point: &[Self::Scalar],
+        _vector: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn euclidean_to_riemannian_gradient_in_place(
+        &self,
+        _point: &[Self::Scalar],
+        _gradient: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     fn exp(
         &self,
         point: &[Self::Scalar],
         tangent: &TangentVector<Self::Scalar>,
     ) -> Result<Vec<Self::Scalar>> {
-        self.check_tangent_vector(point, tangent)?;
-        let result: Vec<f64> = (0..self.dimension)
-            .map(|i| point[i] + tangent.components[i])
-            .collect();
-        Ok(result)
+        validate_tangent(self, point, tangent)?;
+        let result = &tangent.components + DVectorView::from_slice(point, self.dimension);
+        Ok(result.data.into())
+    }
+
+    fn exp_unchecked_into(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        out: &mut Vec<Self::Scalar>,
+    ) -> Result<()> {
+        out.clear();
+        out.extend_from_slice(point);
+        DVectorViewMut::from_slice(out, point.len()).axpy(T::one(), &tangent.components, T::one());
+        Ok(())
     }
 
     fn log(
@@ -72,13 +157,11 @@ impl Manifold for Euclidean {
         point: &[Self::Scalar],
         other: &[Self::Scalar],
     ) -> Result<TangentVector<Self::Scalar>> {
-        self.check_point(point)?;
-        self.check_point(other)?;
-        let mut components = vec![0.0; self.dimension];
-        for i in 0..self.dimension {
-            components[i] = other[i] - point[i];
-        }
-        Ok(TangentVector::new(DVector::from_vec(components)))
+        validate_point(self, point)?;
+        validate_point(self, other)?;
+        let components = DVectorView::from_slice(other, self.dimension)
+            - DVectorView::from_slice(point, self.dimension);
+        Ok(TangentVector::new(components))
     }
 
     fn inner_product(
@@ -87,14 +170,99 @@ impl Manifold for Euclidean {
         v1: &TangentVector<Self::Scalar>,
         v2: &TangentVector<Self::Scalar>,
     ) -> Result<Self::Scalar> {
-        Ok(v1.components.dot(&v2.components))
+        Ok(v1.dot(v2))
+    }
+
+    fn distance(&self, point1: &[Self::Scalar], point2: &[Self::Scalar]) -> Result<Self::Scalar> {
+        validate_point(self, point1)?;
+        validate_point(self, point2)?;
+        Ok(DVectorView::from_slice(point1, self.dimension)
+            .metric_distance(&DVectorView::from_slice(point2, self.dimension)))
+    }
+
+    fn log_volume_change(
+        &self,
+        _point: &[Self::Scalar],
+        _tangent: &TangentVector<Self::Scalar>,
+    ) -> Option<Self::Scalar> {
+        Some(T::zero())
     }
 }
 
+impl BatchManifold for Euclidean {
+    fn exp_batch(&self, points: &[f64], tangents: &[f64], out: &mut [f64]) -> Result<()> {
+        batch_len(self.dimension, &[points.len(), tangents.len(), out.len()])?;
+        out.copy_from_slice(points);
+        DVectorViewMut::from_slice(out, points.len()).axpy(
+            1.0,
+            &DVectorView::from_slice(tangents, tangents.len()),
+            1.0,
+        );
+        Ok(())
+    }
+
+    fn log_batch(&self, points: &[f64], others: &[f64], out: &mut [f64]) -> Result<()> {
+        batch_len(self.dimension, &[points.len(), others.len(), out.len()])?;
+        out.copy_from_slice(others);
+        DVectorViewMut::from_slice(out, others.len()).axpy(
+            -1.0,
+            &DVectorView::from_slice(points, points.len()),
+            1.0,
+        );
+        Ok(())
+    }
+}
+
+impl DistanceSurrogate for Euclidean {
+    fn surrogate_distance(&self, a: &[f64], b: &[f64]) -> Result<f64> {
+        self.check_point(a)?;
+        chordal_distance(a, b)
+    }
+
+    fn distance_lower_bound(&self, surrogate: f64) -> f64 {
+        surrogate
+    }
+
+    fn distance_upper_bound(&self, surrogate: f64) -> f64 {
+        surrogate
+    }
+}
+
+impl<T: RealField + Copy> PointEquivalence for Euclidean<T> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
+    use nalgebra::DVector;
+
+    #[test]
+    fn test_euclidean_standard_traits() {
+        let a = Euclidean::new(3);
+        let b = a;
+        assert_eq!(a, b);
+        assert_ne!(a, Euclidean::new(2));
+        assert_eq!(format!("{a:?}"), "Euclidean { dimension: 3 }");
+        assert_eq!(
+            Euclidean::<f32>::with_scalar(2),
+            Euclidean::<f32>::with_scalar(2)
+        );
+    }
+
+    #[test]
+    fn test_euclidean_try_new() {
+        assert_eq!(Euclidean::try_new(3).unwrap().dim(), 3);
+        assert!(matches!(
+            Euclidean::try_new(0),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_euclidean_from_ambient_dim() {
+        assert_eq!(Euclidean::from_ambient_dim(4).unwrap(), Euclidean::new(4));
+        assert!(Euclidean::from_ambient_dim(0).is_err());
+    }
 
     #[test]
     fn test_euclidean_dimension() {
@@ -145,4 +313,27 @@ mod tests {
         let inner = euclidean.inner_product(&point, &v1, &v2).unwrap();
         assert_relative_eq!(inner, 32.0, epsilon = 1e-10);
     }
+
+    #[test]
+    fn test_euclidean_batch_operations() {
+        let euclidean = Euclidean::new(2);
+        let points = [0.0, 0.0, 1.0, 2.0];
+        let tangents = [3.0, 4.0, -1.0, 0.5];
+        let mut moved = [0.0; 4];
+        euclidean.exp_batch(&points, &tangents, &mut moved).unwrap();
+        assert_eq!(moved, [3.0, 4.0, 0.0, 2.5]);
+
+        let mut logs = [0.0; 4];
+        euclidean.log_batch(&points, &moved, &mut logs).unwrap();
+        assert_eq!(logs, tangents);
+
+        let mut distances = [0.0; 2];
+        euclidean
+            .distance_batch(&points, &moved, &mut distances)
+            .unwrap();
+        assert_relative_eq!(distances[0], 5.0, epsilon = 1e-12);
+        assert!(euclidean
+            .exp_batch(&points, &tangents[..3], &mut moved)
+            .is_err());
+    }
 }
@@ -0,0 +1,575 @@
+use nalgebra::{DVector, SMatrix, SVector};
+use poly_manifold_core::precision::tolerance;
+use poly_manifold_core::profiling::{record, Counter};
+use poly_manifold_core::validation::{validate_point, validate_tangent};
+use poly_manifold_core::{Manifold, ManifoldError, PointEquivalence, Result, TangentVector};
+
+fn check_len(expected: usize, got: usize) -> Result<()> {
+    if expected != got {
+        return Err(ManifoldError::DimensionMismatch { expected, got });
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SphereN<const D: usize>;
+
+impl<const D: usize> SphereN<D> {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn check_static(&self, point: &SVector<f64, D>) -> Result<()> {
+        let norm_sq = point.norm_squared();
+        if (norm_sq - 1.0).abs() > tolerance(1e-10) {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: format!("Point norm is {} instead of 1.0", norm_sq.sqrt()),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn project_static(&self, point: &SVector<f64, D>) -> Result<SVector<f64, D>> {
+        let norm = point.norm();
+        if norm < 1e-10 {
+            return Err(ManifoldError::NumericalError(
+                "Cannot project zero vector to sphere".to_string(),
+            ));
+        }
+        Ok(point / norm)
+    }
+
+    pub fn project_tangent_static(
+        &self,
+        point: &SVector<f64, D>,
+        vector: &SVector<f64, D>,
+    ) -> SVector<f64, D> {
+        vector - point * point.dot(vector)
+    }
+
+    pub fn exp_static(
+        &self,
+        point: &SVector<f64, D>,
+        tangent: &SVector<f64, D>,
+    ) -> SVector<f64, D> {
+        let norm = tangent.norm();
+        if norm < 1e-10 {
+            return *point;
+        }
+        point * norm.cos() + tangent * (norm.sin() / norm)
+    }
+
+    pub fn log_static(
+        &self,
+        point: &SVector<f64, D>,
+        other: &SVector<f64, D>,
+    ) -> Result<SVector<f64, D>> {
+        let dot_product = point.dot(other).clamp(-1.0, 1.0);
+        let theta = dot_product.acos();
+        if theta.abs() < 1e-10 {
+            return Ok(SVector::zeros());
+        }
+        let sin_theta = theta.sin();
+        if sin_theta.abs() < 1e-10 {
+            return Err(ManifoldError::NumericalError(
+                "Points are antipodal, logarithm map is not unique".to_string(),
+            ));
+        }
+        Ok((other - point * dot_product) * (theta / sin_theta))
+    }
+
+    pub fn distance_static(&self, point: &SVector<f64, D>, other: &SVector<f64, D>) -> f64 {
+        point.dot(other).clamp(-1.0, 1.0).acos()
+    }
+
+    fn read_static(&self, values: &[f64]) -> Result<SVector<f64, D>> {
+        check_len(D, values.len())?;
+        Ok(SVector::from_column_slice(values))
+    }
+}
+
+impl<const D: usize> Manifold for SphereN<D> {
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        D.saturating_sub(1)
+    }
+
+    fn ambient_dim(&self) -> usize {
+        D
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.check_static(&self.read_static(point)?)
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        let point = self.read_static(point)?;
+        self.check_static(&point)?;
+        let tangent = self.read_static(tangent.components.as_slice())?;
+        let dot_product = point.dot(&tangent);
+        if dot_product.abs() > tolerance(1e-10) {
+            return Err(ManifoldError::InvalidTangentVector {
+                reason: format!(
+                    "Tangent vector not orthogonal to point, dot product: {}",
+                    dot_product
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        Ok(self
+            .project_static(&self.read_static(point)?)?
+            .as_slice()
+            .to_vec())
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        let point = self.read_static(point)?;
+        self.check_static(&point)?;
+        let vector = self.read_static(vector.components.as_slice())?;
+        let projected = self.project_tangent_static(&point, &vector);
+        Ok(TangentVector::new(DVector::from_column_slice(
+            projected.as_slice(),
+        )))
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        validate_tangent(self, point, tangent)?;
+        self.exp_unchecked(point, tangent)
+    }
+
+    fn exp_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        let point = self.read_static(point)?;
+        let tangent = self.read_static(tangent.components.as_slice())?;
+        Ok(self.exp_static(&point, &tangent).as_slice().to_vec())
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        validate_point(self, point)?;
+        validate_point(self, other)?;
+        self.log_unchecked(point, other)
+    }
+
+    fn log_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        let log = self.log_static(&self.read_static(point)?, &self.read_static(other)?)?;
+        Ok(TangentVector::new(DVector::from_column_slice(
+            log.as_slice(),
+        )))
+    }
+
+    fn inner_product(
+        &self,
+        _point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        Ok(v1.dot(v2))
+    }
+
+    fn distance(&self, point1: &[Self::Scalar], point2: &[Self::Scalar]) -> Result<Self::Scalar> {
+        validate_point(self, point1)?;
+        validate_point(self, point2)?;
+        Ok(self.distance_static(&self.read_static(point1)?, &self.read_static(point2)?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SPDN<const N: usize>;
+
+impl<const N: usize> SPDN<N> {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn factor(&self, point: &SMatrix<f64, N, N>) -> Result<SMatrix<f64, N, N>> {
+        if !is_symmetric_static(point, tolerance(1e-10)) {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: "Matrix is not symmetric".to_string(),
+            });
+        }
+        record(Counter::Cholesky);
+        point
+            .cholesky()
+            .map(|chol| chol.l())
+            .ok_or_else(|| ManifoldError::PointNotOnManifold {
+                reason: "Matrix is not positive definite".to_string(),
+            })
+    }
+
+    fn inverse_factor(&self, l: &SMatrix<f64, N, N>) -> SMatrix<f64, N, N> {
+        l.solve_lower_triangular(&SMatrix::identity())
+            .expect("Cholesky factor has a positive diagonal")
+    }
+
+    fn spectral_map<F>(&self, mat: &SMatrix<f64, N, N>, f: F) -> SMatrix<f64, N, N>
+    where
+        F: Fn(f64) -> f64,
+    {
+        let (eigenvalues, eigenvectors) = jacobi_eigen(mat);
+        let result = eigenvectors
+            * SMatrix::<f64, N, N>::from_diagonal(&eigenvalues.map(f))
+            * eigenvectors.transpose();
+        (result + result.transpose()) * 0.5
+    }
+
+    pub fn check_static(&self, point: &SMatrix<f64, N, N>) -> Result<()> {
+        self.factor(point).map(|_| ())
+    }
+
+    pub fn project_static(&self, point: &SMatrix<f64, N, N>) -> SMatrix<f64, N, N> {
+        let mut symmetric = (point + point.transpose()) * 0.5;
+        for i in 0..N {
+            symmetric[(i, i)] = symmetric[(i, i)].max(1e-10);
+        }
+        symmetric
+    }
+
+    pub fn project_tangent_static(&self, vector: &SMatrix<f64, N, N>) -> SMatrix<f64, N, N> {
+        (vector + vector.transpose()) * 0.5
+    }
+
+    pub fn exp_static(
+        &self,
+        point: &SMatrix<f64, N, N>,
+        tangent: &SMatrix<f64, N, N>,
+    ) -> Result<SMatrix<f64, N, N>> {
+        let l = self.factor(point)?;
+        let l_inv = self.inverse_factor(&l);
+        let w = l_inv * tangent * l_inv.transpose();
+        Ok(l * self.spectral_map(&w, f64::exp) * l.transpose())
+    }
+
+    pub fn log_static(
+        &self,
+        point: &SMatrix<f64, N, N>,
+        other: &SMatrix<f64, N, N>,
+    ) -> Result<SMatrix<f64, N, N>> {
+        let l = self.factor(point)?;
+        self.factor(other)?;
+        let l_inv = self.inverse_factor(&l);
+        let w = l_inv * other * l_inv.transpose();
+        Ok(l * self.spectral_map(&w, f64::ln) * l.transpose())
+    }
+
+    pub fn inner_product_static(
+        &self,
+        point: &SMatrix<f64, N, N>,
+        v1: &SMatrix<f64, N, N>,
+        v2: &SMatrix<f64, N, N>,
+    ) -> Result<f64> {
+        let l_inv = self.inverse_factor(&self.factor(point)?);
+        let w1 = l_inv * v1 * l_inv.transpose();
+        let w2 = l_inv * v2 * l_inv.transpose();
+        Ok(w1.dot(&w2))
+    }
+
+    pub fn distance_static(
+        &self,
+        point: &SMatrix<f64, N, N>,
+        other: &SMatrix<f64, N, N>,
+    ) -> Result<f64> {
+        let l_inv = self.inverse_factor(&self.factor(point)?);
+        self.factor(other)?;
+        let w = l_inv * other * l_inv.transpose();
+        let (eigenvalues, _) = jacobi_eigen(&w);
+        Ok(eigenvalues
+            .iter()
+            .map(|lambda| lambda.ln().powi(2))
+            .sum::<f64>()
+            .sqrt())
+    }
+
+    fn read_static(&self, values: &[f64]) -> Result<SMatrix<f64, N, N>> {
+        check_len(N * N, values.len())?;
+        Ok(SMatrix::from_row_slice(values))
+    }
+
+    fn write_tangent(&self, mat: &SMatrix<f64, N, N>) -> TangentVector<f64> {
+        TangentVector::new(DVector::from_column_slice(mat.transpose().as_slice()))
+    }
+
+    fn check_tangent_static(&self, tangent: &SMatrix<f64, N, N>) -> Result<()> {
+        if !is_symmetric_static(tangent, tolerance(1e-10)) {
+            return Err(ManifoldError::InvalidTangentVector {
+                reason: "Tangent vector matrix is not symmetric".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn jacobi_eigen<const N: usize>(mat: &SMatrix<f64, N, N>) -> (SVector<f64, N>, SMatrix<f64, N, N>) {
+    record(Counter::Eigendecomposition);
+    let mut a = (mat + mat.transpose()) * 0.5;
+    let mut v = SMatrix::<f64, N, N>::identity();
+    let scale = a.norm_squared().max(f64::MIN_POSITIVE);
+
+    for _sweep in 0..64 {
+        let off_diagonal: f64 = (0..N)
+            .flat_map(|i| (i + 1..N).map(move |j| (i, j)))
+            .map(|(i, j)| a[(i, j)] * a[(i, j)])
+            .sum();
+        if off_diagonal <= 1e-30 * scale {
+            break;
+        }
+
+        for p in 0..N {
+            for q in (p + 1)..N {
+                let apq = a[(p, q)];
+                if apq == 0.0 {
+                    continue;
+                }
+                let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for k in 0..N {
+                    let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                    a[(k, p)] = c * akp - s * akq;
+                    a[(k, q)] = s * akp + c * akq;
+                }
+                for k in 0..N {
+                    let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                    a[(p, k)] = c * apk - s * aqk;
+                    a[(q, k)] = s * apk + c * aqk;
+                }
+                for k in 0..N {
+                    let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                    v[(k, p)] = c * vkp - s * vkq;
+                    v[(k, q)] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    (a.diagonal(), v)
+}
+
+fn is_symmetric_static<const N: usize>(mat: &SMatrix<f64, N, N>, tolerance: f64) -> bool {
+    (0..N).all(|i| (i + 1..N).all(|j| (mat[(i, j)] - mat[(j, i)]).abs() <= tolerance))
+}
+
+impl<const N: usize> Manifold for SPDN<N> {
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        N * (N + 1) / 2
+    }
+
+    fn ambient_dim(&self) -> usize {
+        N * N
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.check_static(&self.read_static(point)?)
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.check_point(point)?;
+        self.check_tangent_static(&self.read_static(tangent.components.as_slice())?)
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        let projected = self.project_static(&self.read_static(point)?);
+        Ok(self
+            .write_tangent(&projected)
+            .components
+            .as_slice()
+            .to_vec())
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        _point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        let mat = self.read_static(vector.components.as_slice())?;
+        Ok(self.write_tangent(&self.project_tangent_static(&mat)))
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        let tangent = self.read_static(tangent.components.as_slice())?;
+        self.check_tangent_static(&tangent)?;
+        let result = self.exp_static(&self.read_static(point)?, &tangent)?;
+        Ok(self.write_tangent(&result).components.as_slice().to_vec())
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        let log = self.log_static(&self.read_static(point)?, &self.read_static(other)?)?;
+        Ok(self.write_tangent(&log))
+    }
+
+    fn inner_product(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.inner_product_static(
+            &self.read_static(point)?,
+            &self.read_static(v1.components.as_slice())?,
+            &self.read_static(v2.components.as_slice())?,
+        )
+    }
+
+    fn distance(&self, point1: &[Self::Scalar], point2: &[Self::Scalar]) -> Result<Self::Scalar> {
+        self.distance_static(&self.read_static(point1)?, &self.read_static(point2)?)
+    }
+}
+
+impl<const D: usize> PointEquivalence for SphereN<D> {}
+
+impl<const N: usize> PointEquivalence for SPDN<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Sphere, SPD};
+    use approx::assert_relative_eq;
+    use nalgebra::{Matrix2, Matrix3, Vector3};
+
+    #[test]
+    fn test_sphere_n_matches_dynamic_sphere() {
+        let fixed = SphereN::<3>::new();
+        let dynamic = Sphere::new(2);
+        let p = Vector3::new(1.0, 0.0, 0.0);
+        let v = Vector3::new(0.0, 0.3, -0.4);
+
+        let q = fixed.exp_static(&p, &v);
+        let expected = dynamic
+            .exp(
+                p.as_slice(),
+                &TangentVector::new(DVector::from_column_slice(v.as_slice())),
+            )
+            .unwrap();
+        assert_relative_eq!(q.as_slice(), expected.as_slice(), epsilon = 1e-14);
+
+        let log = fixed.log_static(&p, &q).unwrap();
+        assert_relative_eq!(log, v, epsilon = 1e-12);
+        assert_relative_eq!(fixed.distance_static(&p, &q), 0.5, epsilon = 1e-12);
+        assert!(fixed.log_static(&p, &(-p)).is_err());
+    }
+
+    #[test]
+    fn test_sphere_n_manifold_impl() {
+        let sphere = SphereN::<3>::new();
+        assert_eq!(sphere.dim(), 2);
+        assert_eq!(sphere.ambient_dim(), 3);
+        assert!(sphere.check_point(&[0.0, 0.6, 0.8]).is_ok());
+        assert!(sphere.check_point(&[0.0, 0.6]).is_err());
+        assert!(sphere.check_point(&[0.0, 1.6, 0.8]).is_err());
+
+        let p = [0.0, 0.6, 0.8];
+        let q = sphere.project_to_manifold(&[1.0, 1.0, 0.0]).unwrap();
+        let log = sphere.log(&p, &q).unwrap();
+        let back = sphere.exp(&p, &log).unwrap();
+        assert_relative_eq!(back.as_slice(), q.as_slice(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_spd_n_matches_dynamic_spd() {
+        let fixed = SPDN::<3>::new();
+        let dynamic = SPD::new(3);
+        let p = Matrix3::new(2.0, 0.3, 0.1, 0.3, 1.5, -0.2, 0.1, -0.2, 1.0);
+        let v = Matrix3::new(0.4, 0.1, -0.2, 0.1, -0.3, 0.05, -0.2, 0.05, 0.2);
+        let p_values = p.transpose().as_slice().to_vec();
+        let v_tangent = TangentVector::new(DVector::from_column_slice(v.transpose().as_slice()));
+
+        let q = fixed.exp_static(&p, &v).unwrap();
+        let expected = dynamic.exp(&p_values, &v_tangent).unwrap();
+        assert_relative_eq!(
+            q.transpose().as_slice(),
+            expected.as_slice(),
+            epsilon = 1e-10
+        );
+
+        let log = fixed.log_static(&p, &q).unwrap();
+        assert_relative_eq!(log, v, epsilon = 1e-9);
+
+        let inner = fixed.inner_product_static(&p, &v, &v).unwrap();
+        assert_relative_eq!(
+            inner,
+            dynamic
+                .inner_product(&p_values, &v_tangent, &v_tangent)
+                .unwrap(),
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            fixed.distance_static(&p, &q).unwrap(),
+            inner.sqrt(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_spd_n_static_projections() {
+        let spd = SPDN::<2>::new();
+        let noisy = Matrix2::new(2.0, 0.4, 0.6, -1.0);
+        let projected = spd.project_static(&noisy);
+        assert_eq!(projected, Matrix2::new(2.0, 0.5, 0.5, 1e-10));
+        assert_eq!(
+            spd.project_to_manifold(&[2.0, 0.4, 0.6, -1.0]).unwrap(),
+            projected.transpose().as_slice()
+        );
+        assert_eq!(
+            spd.project_tangent_static(&noisy),
+            Matrix2::new(2.0, 0.5, 0.5, -1.0)
+        );
+    }
+
+    #[test]
+    fn test_spd_n_manifold_impl_rejects_invalid_input() {
+        let spd = SPDN::<2>::new();
+        assert_eq!(spd.dim(), 3);
+        assert!(spd.check_point(&[2.0, 0.5, 0.5, 1.0]).is_ok());
+        assert!(spd.check_point(&[2.0, 0.5, 0.0, 1.0]).is_err());
+        assert!(spd.check_point(&[1.0, 2.0, 2.0, 1.0]).is_err());
+        assert!(spd.check_point(&[1.0, 0.0, 1.0]).is_err());
+
+        let p = [2.0, 0.5, 0.5, 1.0];
+        let asymmetric = TangentVector::new(DVector::from_vec(vec![0.1, 0.2, 0.0, 0.1]));
+        assert!(spd.exp(&p, &asymmetric).is_err());
+    }
+}
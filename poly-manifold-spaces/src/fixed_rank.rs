@@ -0,0 +1,344 @@
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::precision::tolerance;
+use poly_manifold_core::validation::{check_dimension, validate_point, validate_tangent};
+use poly_manifold_core::{
+    Manifold, ManifoldError, MatrixManifold, PointEquivalence, Result, TangentVector,
+};
+
+// Points are full rows×cols matrices of rank exactly `rank`, stored row-major.
+// There is no closed-form geodesic, so `exp` is the truncated-SVD retraction
+// and `log` its first-order inverse, the tangent projection of Y - X.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixedRank {
+    pub rows: usize,
+    pub cols: usize,
+    pub rank: usize,
+}
+
+impl FixedRank {
+    pub fn new(rows: usize, cols: usize, rank: usize) -> Result<Self> {
+        check_dimension("Fixed-rank manifold", "rank", rank)?;
+        if rank > rows.min(cols) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Fixed-rank manifold requires rank <= min(rows, cols), got {rows}x{cols} with rank {rank}"
+            )));
+        }
+        Ok(Self { rows, cols, rank })
+    }
+
+    pub fn to_matrix(&self, vec: &[f64]) -> Result<DMatrix<f64>> {
+        self.check_len(vec.len())?;
+        Ok(DMatrix::from_row_slice(self.rows, self.cols, vec))
+    }
+
+    pub fn from_matrix(&self, mat: &DMatrix<f64>) -> Vec<f64> {
+        mat.transpose().as_slice().to_vec()
+    }
+
+    /// Thin factors `(U, σ, V)` of the leading `rank` singular triplets.
+    pub fn factors(&self, point: &[f64]) -> Result<(DMatrix<f64>, DVector<f64>, DMatrix<f64>)> {
+        let svd = self.to_matrix(point)?.svd(true, true);
+        let (Some(u), Some(v_t)) = (svd.u, svd.v_t) else {
+            return Err(ManifoldError::LinearAlgebraError(
+                "SVD failed on a fixed-rank point".to_string(),
+            ));
+        };
+        let k = self.rank;
+        Ok((
+            u.columns(0, k).into_owned(),
+            svd.singular_values.rows(0, k).into_owned(),
+            v_t.rows(0, k).transpose(),
+        ))
+    }
+
+    /// Projects the Euclidean Hessian and adds the curvature term driven by
+    /// the normal part of the Euclidean gradient (Vandereycken, 2013).
+    pub fn euclidean_to_riemannian_hessian(
+        &self,
+        point: &[f64],
+        euclidean_gradient: &TangentVector<f64>,
+        euclidean_hessian: &TangentVector<f64>,
+        tangent: &TangentVector<f64>,
+    ) -> Result<TangentVector<f64>> {
+        let (u, s, v) = self.factors(point)?;
+        let g = self.to_matrix(euclidean_gradient.components.as_slice())?;
+        let z = self.to_matrix(tangent.components.as_slice())?;
+        let inverse = DMatrix::from_diagonal(&s.map(|sigma| sigma.recip()));
+
+        let up = &z * &v - &u * (u.transpose() * &z * &v);
+        let vp = z.transpose() * &u - &v * (v.transpose() * z.transpose() * &u);
+        let t = &g * vp * &inverse;
+        let left = &t - &u * (u.transpose() * &t);
+        let t = g.transpose() * up * inverse;
+        let right = &t - &v * (v.transpose() * &t);
+
+        let projected = self.project_to_tangent_space(point, euclidean_hessian)?;
+        let correction = left * v.transpose() + u * right.transpose();
+        Ok(TangentVector::new(
+            projected.components + DVector::from_vec(self.from_matrix(&correction)),
+        ))
+    }
+
+    fn check_len(&self, len: usize) -> Result<()> {
+        if len != self.rows * self.cols {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.rows * self.cols,
+                got: len,
+            });
+        }
+        Ok(())
+    }
+
+    fn truncate(&self, mat: DMatrix<f64>) -> Result<Vec<f64>> {
+        let point = self.from_matrix(&mat);
+        let (u, s, v) = self.factors(&point)?;
+        if s[self.rank - 1].is_nan() || s[self.rank - 1] <= rank_threshold(s[0]) {
+            return Err(ManifoldError::NumericalError(format!(
+                "Matrix has rank below {}",
+                self.rank
+            )));
+        }
+        Ok(self.from_matrix(&(u * DMatrix::from_diagonal(&s) * v.transpose())))
+    }
+
+    fn project(&self, point: &[f64], z: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+        let (u, _, v) = self.factors(point)?;
+        let uz = u.transpose() * z;
+        let zv = z * &v;
+        Ok(&u * &uz + &zv * v.transpose() - &u * (uz * &v) * v.transpose())
+    }
+}
+
+impl Manifold for FixedRank {
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        self.rank * (self.rows + self.cols - self.rank)
+    }
+
+    fn ambient_dim(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        let singular_values = self.to_matrix(point)?.singular_values();
+        let largest = singular_values.max();
+        if !largest.is_finite() {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: "Matrix has non-finite entries".to_string(),
+            });
+        }
+        let rank = singular_values
+            .iter()
+            .filter(|&&s| s > rank_threshold(largest))
+            .count();
+        if rank != self.rank {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: format!("Matrix has rank {} instead of {}", rank, self.rank),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.check_point(point)?;
+        let z = self.to_matrix(tangent.components.as_slice())?;
+        let normal = (&z - self.project(point, &z)?).norm();
+        if normal > tolerance::<f64>(1e-8) * z.norm().max(1.0) {
+            return Err(ManifoldError::InvalidTangentVector {
+                reason: format!("Tangent has a normal component of norm {}", normal),
+            });
+        }
+        Ok(())
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.truncate(self.to_matrix(point)?)
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        let z = self.to_matrix(vector.components.as_slice())?;
+        Ok(TangentVector::new(DVector::from_vec(
+            self.from_matrix(&self.project(point, &z)?),
+        )))
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        validate_tangent(self, point, tangent)?;
+        self.retraction(point, tangent)
+    }
+
+    fn retraction(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.truncate(self.to_matrix(point)? + self.to_matrix(tangent.components.as_slice())?)
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        validate_point(self, point)?;
+        validate_point(self, other)?;
+        let difference = self.to_matrix(other)? - self.to_matrix(point)?;
+        Ok(TangentVector::new(DVector::from_vec(
+            self.from_matrix(&self.project(point, &difference)?),
+        )))
+    }
+
+    fn inner_product(
+        &self,
+        _point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        Ok(v1.dot(v2))
+    }
+}
+
+fn rank_threshold(largest: f64) -> f64 {
+    tolerance::<f64>(1e-8) * largest.max(1.0)
+}
+
+impl MatrixManifold for FixedRank {
+    fn matrix_shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+}
+
+impl PointEquivalence for FixedRank {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn sample_point(manifold: &FixedRank) -> Vec<f64> {
+        let left = DMatrix::from_fn(manifold.rows, manifold.rank, |i, j| {
+            ((i * 3 + j + 1) as f64).sin()
+        });
+        let right = DMatrix::from_fn(manifold.cols, manifold.rank, |i, j| {
+            ((i * 5 + j + 2) as f64).cos()
+        });
+        manifold.from_matrix(&(left * right.transpose()))
+    }
+
+    fn sample_tangent(manifold: &FixedRank, point: &[f64]) -> TangentVector<f64> {
+        let raw = TangentVector::new(DVector::from_fn(manifold.ambient_dim(), |i, _| {
+            0.1 * ((i * 7) as f64).sin()
+        }));
+        manifold.project_to_tangent_space(point, &raw).unwrap()
+    }
+
+    #[test]
+    fn test_fixed_rank_new() {
+        assert!(FixedRank::new(3, 4, 0).is_err());
+        assert!(matches!(
+            FixedRank::new(3, 4, 4),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        let manifold = FixedRank::new(5, 4, 2).unwrap();
+        assert_eq!(manifold.dim(), 14);
+        assert_eq!(manifold.ambient_dim(), 20);
+    }
+
+    #[test]
+    fn test_fixed_rank_check_point() {
+        let manifold = FixedRank::new(5, 4, 2).unwrap();
+        let point = sample_point(&manifold);
+        assert!(manifold.check_point(&point).is_ok());
+        assert!(manifold.check_point(&[1.0; 20]).is_err());
+        assert!(matches!(
+            manifold.check_point(&[1.0; 19]),
+            Err(ManifoldError::DimensionMismatch { .. })
+        ));
+        assert!(manifold.project_to_manifold(&[0.0; 20]).is_err());
+    }
+
+    #[test]
+    fn test_fixed_rank_tangent_projection_is_idempotent() {
+        let manifold = FixedRank::new(5, 4, 2).unwrap();
+        let point = sample_point(&manifold);
+        let tangent = sample_tangent(&manifold, &point);
+        assert!(manifold.check_tangent_vector(&point, &tangent).is_ok());
+        let again = manifold.project_to_tangent_space(&point, &tangent).unwrap();
+        assert_relative_eq!(
+            again.components,
+            tangent.components.clone(),
+            epsilon = 1e-12
+        );
+
+        let raw = TangentVector::new(DVector::from_element(20, 1.0));
+        assert!(manifold.check_tangent_vector(&point, &raw).is_err());
+    }
+
+    #[test]
+    fn test_fixed_rank_retraction_is_first_order() {
+        let manifold = FixedRank::new(5, 4, 2).unwrap();
+        let point = sample_point(&manifold);
+        let tangent = sample_tangent(&manifold, &point);
+        for t in [1e-2, 1e-3] {
+            let moved = manifold.retraction(&point, &(&tangent * t)).unwrap();
+            assert!(manifold.check_point(&moved).is_ok());
+            let recovered = manifold.log(&point, &moved).unwrap();
+            assert!((recovered.components / t - &tangent.components).norm() < 10.0 * t);
+        }
+    }
+
+    #[test]
+    fn test_fixed_rank_hessian_matches_finite_differences() {
+        let manifold = FixedRank::new(5, 4, 2).unwrap();
+        let point = sample_point(&manifold);
+        let target = DVector::from_fn(20, |i, _| ((i * 3) as f64).cos());
+        let gradient = |x: &[f64]| {
+            let egrad = TangentVector::new(DVector::from_column_slice(x) - &target);
+            manifold.project_to_tangent_space(x, &egrad).unwrap()
+        };
+        let tangent = sample_tangent(&manifold, &point);
+
+        let egrad = TangentVector::new(DVector::from_column_slice(&point) - &target);
+        let hessian = manifold
+            .euclidean_to_riemannian_hessian(&point, &egrad, &tangent, &tangent)
+            .unwrap();
+
+        let h = 1e-5;
+        let forward = manifold.retraction(&point, &(&tangent * h)).unwrap();
+        let backward = manifold.retraction(&point, &(&tangent * -h)).unwrap();
+        let difference =
+            (gradient(&forward).components - gradient(&backward).components) / (2.0 * h);
+        let expected = manifold
+            .project_to_tangent_space(&point, &TangentVector::new(difference))
+            .unwrap();
+        assert!((hessian.components - expected.components).norm() < 1e-6);
+    }
+
+    #[test]
+    fn test_fixed_rank_factors_reconstruct_the_point() {
+        let manifold = FixedRank::new(4, 6, 2).unwrap();
+        let point = sample_point(&manifold);
+        let (u, s, v) = manifold.factors(&point).unwrap();
+        assert!(s[0] >= s[1]);
+        let rebuilt = u * DMatrix::from_diagonal(&s) * v.transpose();
+        assert_relative_eq!(
+            rebuilt,
+            manifold.to_matrix(&point).unwrap(),
+            epsilon = 1e-12
+        );
+    }
+}
@@ -0,0 +1,662 @@
+use crate::{Euclidean, PoincareBall, Sphere};
+use nalgebra::DVector;
+use poly_manifold_core::batch::batch_len;
+use poly_manifold_core::precision::Promoted;
+use poly_manifold_core::validation::check_finite;
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+use std::sync::mpsc;
+use wgpu::util::DeviceExt;
+
+const WORKGROUP_SIZE: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GpuSpace {
+    Euclidean = 0,
+    Sphere = 1,
+    PoincareBall = 2,
+}
+
+pub trait GpuKernels: Manifold<Scalar = f32> {
+    fn gpu_space(&self) -> GpuSpace;
+}
+
+impl GpuKernels for Euclidean<f32> {
+    fn gpu_space(&self) -> GpuSpace {
+        GpuSpace::Euclidean
+    }
+}
+
+impl GpuKernels for Sphere<f32> {
+    fn gpu_space(&self) -> GpuSpace {
+        GpuSpace::Sphere
+    }
+}
+
+impl GpuKernels for Promoted<PoincareBall> {
+    fn gpu_space(&self) -> GpuSpace {
+        GpuSpace::PoincareBall
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kernel {
+    Exp,
+    Log,
+    Distance,
+    SgdStep,
+}
+
+pub struct GpuBackend {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    layout: wgpu::BindGroupLayout,
+    exp: wgpu::ComputePipeline,
+    log: wgpu::ComputePipeline,
+    distance: wgpu::ComputePipeline,
+    sgd_step: wgpu::ComputePipeline,
+    max_binding_bytes: u64,
+    max_workgroups: u32,
+}
+
+impl GpuBackend {
+    pub fn new() -> Result<Self> {
+        let instance =
+            wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle_from_env());
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .map_err(|error| {
+                ManifoldError::BackendError(format!("No GPU adapter available: {error}"))
+            })?;
+        if !adapter
+            .get_downlevel_capabilities()
+            .flags
+            .contains(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+        {
+            return Err(ManifoldError::BackendError(format!(
+                "GPU adapter {} does not support compute shaders",
+                adapter.get_info().name
+            )));
+        }
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            label: Some("poly-manifold batch"),
+            required_limits: adapter.limits(),
+            ..Default::default()
+        }))
+        .map_err(|error| {
+            ManifoldError::BackendError(format!("Failed to open GPU device: {error}"))
+        })?;
+        Self::from_device(device, queue)
+    }
+
+    pub fn from_device(device: wgpu::Device, queue: wgpu::Queue) -> Result<Self> {
+        let scope = device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("poly-manifold batch kernels"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("gpu.wgsl").into()),
+        });
+        let storage = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("poly-manifold batch bindings"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                storage(1, true),
+                storage(2, true),
+                storage(3, false),
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("poly-manifold batch pipeline"),
+            bind_group_layouts: &[Some(&layout)],
+            immediate_size: 0,
+        });
+        let pipeline = |entry_point| {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(entry_point),
+                layout: Some(&pipeline_layout),
+                module: &module,
+                entry_point: Some(entry_point),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        };
+        let exp = pipeline("exp_batch");
+        let log = pipeline("log_batch");
+        let distance = pipeline("distance_batch");
+        let sgd_step = pipeline("sgd_step_batch");
+        if let Some(error) = pollster::block_on(scope.pop()) {
+            return Err(ManifoldError::BackendError(format!(
+                "Failed to build GPU batch kernels: {error}"
+            )));
+        }
+        let limits = device.limits();
+        Ok(Self {
+            max_binding_bytes: limits.max_storage_buffer_binding_size,
+            max_workgroups: limits.max_compute_workgroups_per_dimension,
+            device,
+            queue,
+            layout,
+            exp,
+            log,
+            distance,
+            sgd_step,
+        })
+    }
+
+    pub fn exp_batch<M: GpuKernels + ?Sized>(
+        &self,
+        manifold: &M,
+        points: &[f32],
+        tangents: &[f32],
+        out: &mut [f32],
+    ) -> Result<()> {
+        let n = manifold.ambient_dim();
+        batch_len(n, &[points.len(), tangents.len(), out.len()])?;
+        for (point, tangent) in points.chunks_exact(n).zip(tangents.chunks_exact(n)) {
+            let tangent = TangentVector::new(DVector::from_column_slice(tangent));
+            check_finite(tangent.components.as_slice(), "Tangent batch")?;
+            manifold.check_point(point)?;
+            manifold.check_tangent_vector(point, &tangent)?;
+        }
+        self.run(Kernel::Exp, manifold, points, tangents, 0.0, out)?;
+        project_rows(manifold, out)
+    }
+
+    pub fn log_batch<M: GpuKernels + ?Sized>(
+        &self,
+        manifold: &M,
+        points: &[f32],
+        others: &[f32],
+        out: &mut [f32],
+    ) -> Result<()> {
+        let n = manifold.ambient_dim();
+        batch_len(n, &[points.len(), others.len(), out.len()])?;
+        for (point, other) in points.chunks_exact(n).zip(others.chunks_exact(n)) {
+            manifold.check_point(point)?;
+            manifold.check_point(other)?;
+        }
+        self.run(Kernel::Log, manifold, points, others, 0.0, out)?;
+        for (point, row) in points.chunks_exact(n).zip(out.chunks_exact_mut(n)) {
+            let tangent = TangentVector::new(DVector::from_column_slice(row));
+            let tangent = manifold.project_to_tangent_space(point, &tangent)?;
+            row.copy_from_slice(tangent.components.as_slice());
+        }
+        Ok(())
+    }
+
+    pub fn distance_batch<M: GpuKernels + ?Sized>(
+        &self,
+        manifold: &M,
+        points: &[f32],
+        others: &[f32],
+        out: &mut [f32],
+    ) -> Result<()> {
+        let n = manifold.ambient_dim();
+        batch_len(n, &[points.len(), others.len(), n * out.len()])?;
+        for (point, other) in points.chunks_exact(n).zip(others.chunks_exact(n)) {
+            manifold.check_point(point)?;
+            manifold.check_point(other)?;
+        }
+        self.run(Kernel::Distance, manifold, points, others, 0.0, out)
+    }
+
+    pub fn sgd_step_batch<M: GpuKernels + ?Sized>(
+        &self,
+        manifold: &M,
+        points: &[f32],
+        euclidean_gradients: &[f32],
+        learning_rate: f32,
+        out: &mut [f32],
+    ) -> Result<()> {
+        let n = manifold.ambient_dim();
+        batch_len(n, &[points.len(), euclidean_gradients.len(), out.len()])?;
+        for point in points.chunks_exact(n) {
+            manifold.check_point(point)?;
+        }
+        check_finite(euclidean_gradients, "Euclidean gradient batch")?;
+        check_finite(&[learning_rate], "Learning rate")?;
+        self.run(
+            Kernel::SgdStep,
+            manifold,
+            points,
+            euclidean_gradients,
+            learning_rate,
+            out,
+        )?;
+        project_rows(manifold, out)
+    }
+
+    fn run<M: GpuKernels + ?Sized>(
+        &self,
+        kernel: Kernel,
+        manifold: &M,
+        a: &[f32],
+        b: &[f32],
+        learning_rate: f32,
+        out: &mut [f32],
+    ) -> Result<()> {
+        let n = manifold.ambient_dim();
+        let count = a.len() / n;
+        if count == 0 {
+            return Ok(());
+        }
+        let out_width = if kernel == Kernel::Distance { 1 } else { n };
+        let row_bytes = (n * core::mem::size_of::<f32>()) as u64;
+        let chunk_rows = usize::try_from(self.max_binding_bytes / row_bytes)
+            .unwrap_or(usize::MAX)
+            .min(self.max_workgroups as usize * self.max_workgroups as usize * WORKGROUP_SIZE);
+        if chunk_rows == 0 {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Rows of {n} values exceed the GPU storage binding limit of {} bytes",
+                self.max_binding_bytes
+            )));
+        }
+        for start in (0..count).step_by(chunk_rows) {
+            let end = (start + chunk_rows).min(count);
+            let result = self.dispatch(
+                kernel,
+                manifold.gpu_space(),
+                n,
+                &a[start * n..end * n],
+                &b[start * n..end * n],
+                learning_rate,
+            )?;
+            out[start * out_width..end * out_width].copy_from_slice(&result);
+        }
+        check_finite(out, "GPU batch result")
+    }
+
+    fn dispatch(
+        &self,
+        kernel: Kernel,
+        space: GpuSpace,
+        dim: usize,
+        a: &[f32],
+        b: &[f32],
+        learning_rate: f32,
+    ) -> Result<Vec<f32>> {
+        let count = a.len() / dim;
+        let out_len = if kernel == Kernel::Distance {
+            count
+        } else {
+            a.len()
+        };
+        let groups = count.div_ceil(WORKGROUP_SIZE);
+        let groups_x = groups.min(self.max_workgroups as usize);
+        let groups_y = groups.div_ceil(groups_x);
+        let params = [
+            space as u32,
+            dim as u32,
+            count as u32,
+            (groups_x * WORKGROUP_SIZE) as u32,
+            learning_rate.to_bits(),
+            0,
+            0,
+            0,
+        ];
+        let pipeline = match kernel {
+            Kernel::Exp => &self.exp,
+            Kernel::Log => &self.log,
+            Kernel::Distance => &self.distance,
+            Kernel::SgdStep => &self.sgd_step,
+        };
+
+        let out_of_memory = self.device.push_error_scope(wgpu::ErrorFilter::OutOfMemory);
+        let validation = self.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let input = |label, contents: &[u8], usage| {
+            self.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some(label),
+                    contents,
+                    usage,
+                })
+        };
+        let params = input(
+            "params",
+            bytemuck::cast_slice(&params),
+            wgpu::BufferUsages::UNIFORM,
+        );
+        let a = input("a", bytemuck::cast_slice(a), wgpu::BufferUsages::STORAGE);
+        let b = input("b", bytemuck::cast_slice(b), wgpu::BufferUsages::STORAGE);
+        let out_size = (out_len * core::mem::size_of::<f32>()) as u64;
+        let out = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("out"),
+            size: out_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size: out_size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("poly-manifold batch"),
+            layout: &self.layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: params.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: a.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: b.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: out.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(groups_x as u32, groups_y as u32, 1);
+        }
+        encoder.copy_buffer_to_buffer(&out, 0, &readback, 0, out_size);
+        self.queue.submit(Some(encoder.finish()));
+
+        let validation = pollster::block_on(validation.pop());
+        let out_of_memory = pollster::block_on(out_of_memory.pop());
+        if let Some(error) = validation.or(out_of_memory) {
+            return Err(ManifoldError::BackendError(format!(
+                "GPU batch dispatch failed: {error}"
+            )));
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        readback.map_async(wgpu::MapMode::Read, .., move |mapped| {
+            let _ = sender.send(mapped);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .map_err(|error| ManifoldError::BackendError(format!("GPU device lost: {error}")))?;
+        receiver
+            .recv()
+            .map_err(|error| ManifoldError::BackendError(error.to_string()))?
+            .map_err(|error| {
+                ManifoldError::BackendError(format!("GPU readback failed: {error}"))
+            })?;
+        let result = {
+            let view = readback.get_mapped_range(..).map_err(|error| {
+                ManifoldError::BackendError(format!("GPU readback failed: {error}"))
+            })?;
+            bytemuck::pod_collect_to_vec(&view)
+        };
+        readback.unmap();
+        Ok(result)
+    }
+}
+
+fn project_rows<M: GpuKernels + ?Sized>(manifold: &M, out: &mut [f32]) -> Result<()> {
+    for row in out.chunks_exact_mut(manifold.ambient_dim()) {
+        row.copy_from_slice(&manifold.project_to_manifold(row)?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_manifold_core::BatchManifold;
+    use std::sync::OnceLock;
+
+    fn backend() -> &'static GpuBackend {
+        static BACKEND: OnceLock<GpuBackend> = OnceLock::new();
+        BACKEND.get_or_init(|| GpuBackend::new().expect("GPU tests need a wgpu adapter"))
+    }
+
+    fn samples(count: usize, n: usize, seed: f64) -> Vec<f64> {
+        (0..count * n)
+            .map(|k| (seed + 0.37 * k as f64).sin())
+            .collect()
+    }
+
+    fn narrow(values: &[f64]) -> Vec<f32> {
+        values.iter().map(|value| *value as f32).collect()
+    }
+
+    fn widen(values: &[f32]) -> Vec<f64> {
+        values.iter().map(|value| f64::from(*value)).collect()
+    }
+
+    fn lift_points<C: BatchManifold>(reference: &C, points: &[f32]) -> Vec<f64> {
+        widen(points)
+            .chunks_exact(reference.ambient_dim())
+            .flat_map(|row| reference.project_to_manifold(row).unwrap())
+            .collect()
+    }
+
+    fn lift_tangents<C: BatchManifold>(
+        reference: &C,
+        points: &[f64],
+        tangents: &[f32],
+    ) -> Vec<f64> {
+        let n = reference.ambient_dim();
+        points
+            .chunks_exact(n)
+            .zip(tangents.chunks_exact(n))
+            .flat_map(|(point, row)| {
+                let vector = TangentVector::new(DVector::from_vec(widen(row)));
+                reference
+                    .project_to_tangent_space(point, &vector)
+                    .unwrap()
+                    .components
+                    .data
+                    .as_vec()
+                    .clone()
+            })
+            .collect()
+    }
+
+    fn points_on<M: Manifold<Scalar = f32>>(manifold: &M, count: usize, scale: f64) -> Vec<f32> {
+        let n = manifold.ambient_dim();
+        samples(count, n, 0.5)
+            .chunks_exact(n)
+            .flat_map(|row| {
+                let row: Vec<f32> = row.iter().map(|x| (x * scale) as f32).collect();
+                manifold.project_to_manifold(&row).unwrap()
+            })
+            .collect()
+    }
+
+    fn tangents_at<M: Manifold<Scalar = f32>>(
+        manifold: &M,
+        points: &[f32],
+        scale: f64,
+    ) -> Vec<f32> {
+        let n = manifold.ambient_dim();
+        points
+            .chunks_exact(n)
+            .zip(samples(points.len() / n, n, 2.0).chunks_exact(n))
+            .flat_map(|(point, row)| {
+                let vector = TangentVector::new(DVector::from_vec(narrow(row)) * scale as f32);
+                manifold
+                    .project_to_tangent_space(point, &vector)
+                    .unwrap()
+                    .components
+                    .data
+                    .as_vec()
+                    .clone()
+            })
+            .collect()
+    }
+
+    fn assert_close(gpu: &[f32], cpu: &[f64]) {
+        assert_eq!(gpu.len(), cpu.len());
+        for (g, c) in gpu.iter().zip(cpu) {
+            assert!((f64::from(*g) - c).abs() < 1e-4, "GPU {g} vs CPU {c}");
+        }
+    }
+
+    // The f64 CPU kernels run on the f32 inputs lifted back onto the manifold,
+    // so the comparison only measures the single-precision device arithmetic.
+    fn assert_matches_cpu<M, C>(manifold: M, reference: C, point_scale: f64)
+    where
+        M: GpuKernels,
+        C: BatchManifold,
+    {
+        let backend = backend();
+        let n = manifold.ambient_dim();
+        let count = 300;
+        let points = points_on(&manifold, count, point_scale);
+        let tangents = tangents_at(&manifold, &points, 0.4);
+        let mut others = vec![0.0; count * n];
+        backend
+            .exp_batch(&manifold, &points, &tangents, &mut others)
+            .unwrap();
+        let points64 = lift_points(&reference, &points);
+        let tangents64 = lift_tangents(&reference, &points64, &tangents);
+        let others64 = lift_points(&reference, &others);
+
+        let mut expected = vec![0.0; count * n];
+        reference
+            .exp_batch(&points64, &tangents64, &mut expected)
+            .unwrap();
+        assert_close(&others, &expected);
+        for row in others.chunks_exact(n) {
+            manifold.check_point(row).unwrap();
+        }
+
+        let mut actual = vec![0.0; count * n];
+        reference
+            .log_batch(&points64, &others64, &mut expected)
+            .unwrap();
+        backend
+            .log_batch(&manifold, &points, &others, &mut actual)
+            .unwrap();
+        assert_close(&actual, &expected);
+
+        let gradients = samples(count, n, 4.0);
+        reference
+            .sgd_step_batch(&points64, &widen(&narrow(&gradients)), 0.05, &mut expected)
+            .unwrap();
+        backend
+            .sgd_step_batch(&manifold, &points, &narrow(&gradients), 0.05, &mut actual)
+            .unwrap();
+        assert_close(&actual, &expected);
+
+        let mut expected = vec![0.0; count];
+        let mut actual = vec![0.0; count];
+        reference
+            .distance_batch(&points64, &others64, &mut expected)
+            .unwrap();
+        backend
+            .distance_batch(&manifold, &points, &others, &mut actual)
+            .unwrap();
+        assert_close(&actual, &expected);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn test_gpu_kernels_match_cpu_on_sphere() {
+        assert_matches_cpu(Sphere::<f32>::with_scalar(4), Sphere::new(4), 1.0);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn test_gpu_kernels_match_cpu_on_poincare_ball() {
+        assert_matches_cpu(
+            Promoted::new(PoincareBall::new(3)),
+            PoincareBall::new(3),
+            0.5,
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn test_gpu_kernels_match_cpu_on_euclidean() {
+        assert_matches_cpu(Euclidean::<f32>::with_scalar(5), Euclidean::new(5), 2.0);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn test_gpu_batches_are_split_at_the_binding_limit() {
+        let mut backend = GpuBackend::new().expect("GPU tests need a wgpu adapter");
+        backend.max_binding_bytes = 7 * 3 * 4;
+        let sphere = Sphere::<f32>::with_scalar(2);
+        let points = points_on(&sphere, 50, 1.0);
+        let tangents = tangents_at(&sphere, &points, 0.3);
+
+        let mut expected = vec![0.0; 150];
+        let mut actual = vec![0.0; 150];
+        let reference = Sphere::new(2);
+        let points64 = lift_points(&reference, &points);
+        let tangents64 = lift_tangents(&reference, &points64, &tangents);
+        reference
+            .exp_batch(&points64, &tangents64, &mut expected)
+            .unwrap();
+        backend
+            .exp_batch(&sphere, &points, &tangents, &mut actual)
+            .unwrap();
+        assert_close(&actual, &expected);
+
+        let mut distances = vec![0.0; 50];
+        backend
+            .distance_batch(&sphere, &points, &actual, &mut distances)
+            .unwrap();
+        let mut expected = vec![0.0; 50];
+        reference
+            .distance_batch(&points64, &lift_points(&reference, &actual), &mut expected)
+            .unwrap();
+        assert_close(&distances, &expected);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn test_gpu_rejects_invalid_batches() {
+        let backend = backend();
+        let sphere = Sphere::<f32>::with_scalar(2);
+        let points = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+
+        let mut out = [0.0; 6];
+        assert!(matches!(
+            backend.log_batch(&sphere, &points, &[-1.0, 0.0, 0.0, 0.0, 1.0, 0.0], &mut out),
+            Err(ManifoldError::NumericalError(_))
+        ));
+        assert!(matches!(
+            backend.exp_batch(
+                &sphere,
+                &points,
+                &[0.0, f32::NAN, 0.0, 0.0, 0.0, 0.0],
+                &mut out
+            ),
+            Err(ManifoldError::NumericalError(_))
+        ));
+        assert!(matches!(
+            backend.exp_batch(&sphere, &points, &[0.0; 3], &mut out),
+            Err(ManifoldError::DimensionMismatch { .. })
+        ));
+        assert!(backend
+            .distance_batch(&sphere, &[2.0, 0.0, 0.0], &[1.0, 0.0, 0.0], &mut [0.0])
+            .is_err());
+        backend.exp_batch(&sphere, &[], &[], &mut []).unwrap();
+    }
+}
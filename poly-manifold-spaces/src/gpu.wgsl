@@ -0,0 +1,269 @@
+struct Params {
+    space: u32,
+    dim: u32,
+    count: u32,
+    row_width: u32,
+    learning_rate: f32,
+    pad0: u32,
+    pad1: u32,
+    pad2: u32,
+}
+
+const EUCLIDEAN: u32 = 0u;
+const SPHERE: u32 = 1u;
+const POINCARE_BALL: u32 = 2u;
+
+const TINY: f32 = 1e-20;
+const BALL_EDGE: f32 = 0.99999;
+const ATANH_LIMIT: f32 = 0.9999999;
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read> a: array<f32>;
+@group(0) @binding(2) var<storage, read> b: array<f32>;
+@group(0) @binding(3) var<storage, read_write> out: array<f32>;
+
+fn row_index(id: vec3<u32>) -> u32 {
+    return id.y * params.row_width + id.x;
+}
+
+fn nan() -> f32 {
+    return bitcast<f32>(0x7fc00000u | (params.dim & 0u));
+}
+
+// The tangent at row `base` is t.x * b + t.y * a, which covers plain tangents
+// (1, 0) and the gradient steps built in `sgd_step_batch`.
+fn tangent_at(base: u32, k: u32, t: vec2<f32>) -> f32 {
+    return t.x * b[base + k] + t.y * a[base + k];
+}
+
+fn dot_ab(base: u32) -> f32 {
+    var sum = 0.0;
+    for (var k = 0u; k < params.dim; k++) {
+        sum += a[base + k] * b[base + k];
+    }
+    return sum;
+}
+
+fn squared_norm_a(base: u32) -> f32 {
+    var sum = 0.0;
+    for (var k = 0u; k < params.dim; k++) {
+        sum += a[base + k] * a[base + k];
+    }
+    return sum;
+}
+
+fn squared_norm_b(base: u32) -> f32 {
+    var sum = 0.0;
+    for (var k = 0u; k < params.dim; k++) {
+        sum += b[base + k] * b[base + k];
+    }
+    return sum;
+}
+
+fn euclidean_exp(base: u32, t: vec2<f32>) {
+    for (var k = 0u; k < params.dim; k++) {
+        out[base + k] = a[base + k] + tangent_at(base, k, t);
+    }
+}
+
+fn sphere_exp(base: u32, t: vec2<f32>) {
+    var vv = 0.0;
+    for (var k = 0u; k < params.dim; k++) {
+        let v = tangent_at(base, k, t);
+        vv += v * v;
+    }
+    let norm = sqrt(vv);
+    var cp = 1.0;
+    var cv = 1.0;
+    if norm > TINY {
+        cp = cos(norm);
+        cv = sin(norm) / norm;
+    }
+    for (var k = 0u; k < params.dim; k++) {
+        out[base + k] = cp * a[base + k] + cv * tangent_at(base, k, t);
+    }
+}
+
+fn poincare_exp(base: u32, t: vec2<f32>) {
+    var pp = 0.0;
+    var pv = 0.0;
+    var vv = 0.0;
+    for (var k = 0u; k < params.dim; k++) {
+        let p = a[base + k];
+        let v = tangent_at(base, k, t);
+        pp += p * p;
+        pv += p * v;
+        vv += v * v;
+    }
+    let norm = sqrt(vv);
+    if norm <= TINY {
+        for (var k = 0u; k < params.dim; k++) {
+            out[base + k] = a[base + k];
+        }
+        return;
+    }
+    // p ⊕ s v with s = tanh(λ |v| / 2) / |v| and λ = 2 / (1 - |p|²).
+    let s = tanh(norm / (1.0 - pp)) / norm;
+    let xy = s * pv;
+    let yy = s * s * vv;
+    let denominator = 1.0 + 2.0 * xy + pp * yy;
+    let cx = (1.0 + 2.0 * xy + yy) / denominator;
+    let cy = s * (1.0 - pp) / denominator;
+    var rr = 0.0;
+    for (var k = 0u; k < params.dim; k++) {
+        let r = cx * a[base + k] + cy * tangent_at(base, k, t);
+        out[base + k] = r;
+        rr += r * r;
+    }
+    let radius = sqrt(rr);
+    if radius >= BALL_EDGE {
+        let shrink = BALL_EDGE / radius;
+        for (var k = 0u; k < params.dim; k++) {
+            out[base + k] *= shrink;
+        }
+    }
+}
+
+fn apply_exp(base: u32, t: vec2<f32>) {
+    switch params.space {
+        case SPHERE: {
+            sphere_exp(base, t);
+        }
+        case POINCARE_BALL: {
+            poincare_exp(base, t);
+        }
+        default: {
+            euclidean_exp(base, t);
+        }
+    }
+}
+
+// Coefficients (cx, cy, denominator) of (-p) ⊕ q = (cx (-p) + cy q) / denominator.
+fn poincare_difference(base: u32) -> vec3<f32> {
+    let pq = -dot_ab(base);
+    let pp = squared_norm_a(base);
+    let qq = squared_norm_b(base);
+    return vec3<f32>(1.0 + 2.0 * pq + qq, 1.0 - pp, 1.0 + 2.0 * pq + pp * qq);
+}
+
+fn poincare_difference_norm(base: u32, c: vec3<f32>) -> f32 {
+    var sum = 0.0;
+    for (var k = 0u; k < params.dim; k++) {
+        let d = (c.y * b[base + k] - c.x * a[base + k]) / c.z;
+        sum += d * d;
+    }
+    return sqrt(sum);
+}
+
+@compute @workgroup_size(64)
+fn exp_batch(@builtin(global_invocation_id) id: vec3<u32>) {
+    let row = row_index(id);
+    if row >= params.count {
+        return;
+    }
+    apply_exp(row * params.dim, vec2<f32>(1.0, 0.0));
+}
+
+@compute @workgroup_size(64)
+fn log_batch(@builtin(global_invocation_id) id: vec3<u32>) {
+    let row = row_index(id);
+    if row >= params.count {
+        return;
+    }
+    let base = row * params.dim;
+    switch params.space {
+        case SPHERE: {
+            let c = clamp(dot_ab(base), -1.0, 1.0);
+            var ww = 0.0;
+            for (var k = 0u; k < params.dim; k++) {
+                let w = b[base + k] - c * a[base + k];
+                ww += w * w;
+            }
+            let norm = sqrt(ww);
+            var scale = 0.0;
+            if norm > TINY {
+                scale = atan2(norm, c) / norm;
+            } else if c < 0.0 {
+                scale = nan();
+            }
+            for (var k = 0u; k < params.dim; k++) {
+                out[base + k] = scale * (b[base + k] - c * a[base + k]);
+            }
+        }
+        case POINCARE_BALL: {
+            let c = poincare_difference(base);
+            let norm = poincare_difference_norm(base, c);
+            var scale = 0.0;
+            if norm > TINY {
+                // 2 / λ(p) · atanh(|d|) / |d|
+                scale = (1.0 - squared_norm_a(base)) * atanh(min(norm, ATANH_LIMIT)) / norm;
+            }
+            for (var k = 0u; k < params.dim; k++) {
+                out[base + k] = scale * (c.y * b[base + k] - c.x * a[base + k]) / c.z;
+            }
+        }
+        default: {
+            for (var k = 0u; k < params.dim; k++) {
+                out[base + k] = b[base + k] - a[base + k];
+            }
+        }
+    }
+}
+
+@compute @workgroup_size(64)
+fn distance_batch(@builtin(global_invocation_id) id: vec3<u32>) {
+    let row = row_index(id);
+    if row >= params.count {
+        return;
+    }
+    let base = row * params.dim;
+    switch params.space {
+        case SPHERE: {
+            var minus = 0.0;
+            var plus = 0.0;
+            for (var k = 0u; k < params.dim; k++) {
+                let p = a[base + k];
+                let q = b[base + k];
+                minus += (p - q) * (p - q);
+                plus += (p + q) * (p + q);
+            }
+            out[row] = 2.0 * atan2(sqrt(minus), sqrt(plus));
+        }
+        case POINCARE_BALL: {
+            let norm = poincare_difference_norm(base, poincare_difference(base));
+            out[row] = 2.0 * atanh(min(norm, ATANH_LIMIT));
+        }
+        default: {
+            var sum = 0.0;
+            for (var k = 0u; k < params.dim; k++) {
+                let d = b[base + k] - a[base + k];
+                sum += d * d;
+            }
+            out[row] = sqrt(sum);
+        }
+    }
+}
+
+@compute @workgroup_size(64)
+fn sgd_step_batch(@builtin(global_invocation_id) id: vec3<u32>) {
+    let row = row_index(id);
+    if row >= params.count {
+        return;
+    }
+    let base = row * params.dim;
+    let lr = params.learning_rate;
+    switch params.space {
+        case SPHERE: {
+            // -lr (g - <p, g> p)
+            apply_exp(base, vec2<f32>(-lr, lr * dot_ab(base)));
+        }
+        case POINCARE_BALL: {
+            // -lr g / λ(p)²
+            let half_gap = 0.5 * (1.0 - squared_norm_a(base));
+            apply_exp(base, vec2<f32>(-lr * half_gap * half_gap, 0.0));
+        }
+        default: {
+            apply_exp(base, vec2<f32>(-lr, 0.0));
+        }
+    }
+}
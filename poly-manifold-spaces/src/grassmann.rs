@@ -0,0 +1,299 @@
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::precision::tolerance;
+use poly_manifold_core::validation::{check_dimension, validate_point, validate_tangent};
+use poly_manifold_core::{
+    Manifold, ManifoldError, MatrixManifold, PointComparison, PointEquivalence, Result,
+    TangentVector,
+};
+
+// A point is any orthonormal n×p basis of the subspace; tangent vectors are
+// horizontal (XᵀV = 0), so the metric is the embedded Frobenius one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grassmann {
+    pub n: usize,
+    pub p: usize,
+}
+
+impl Grassmann {
+    pub fn new(n: usize, p: usize) -> Result<Self> {
+        check_dimension("Grassmann", "subspace dimension", p)?;
+        if p > n {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Grassmann manifold requires p <= n, got n = {n}, p = {p}"
+            )));
+        }
+        Ok(Self { n, p })
+    }
+
+    pub fn to_matrix(&self, vec: &[f64]) -> Result<DMatrix<f64>> {
+        self.check_len(vec.len())?;
+        Ok(DMatrix::from_row_slice(self.n, self.p, vec))
+    }
+
+    pub fn from_matrix(&self, mat: &DMatrix<f64>) -> Vec<f64> {
+        mat.transpose().as_slice().to_vec()
+    }
+
+    fn check_len(&self, len: usize) -> Result<()> {
+        if len != self.n * self.p {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.n * self.p,
+                got: len,
+            });
+        }
+        Ok(())
+    }
+
+    fn tangent(&self, mat: &DMatrix<f64>) -> TangentVector<f64> {
+        TangentVector::new(DVector::from_vec(self.from_matrix(mat)))
+    }
+
+    fn horizontal(&self, x: &DMatrix<f64>, v: &DMatrix<f64>) -> DMatrix<f64> {
+        v - x * (x.transpose() * v)
+    }
+
+    fn polar(&self, mat: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+        let svd = mat.clone().svd(true, true);
+        match (svd.u, svd.v_t) {
+            (Some(u), Some(v_t)) => Ok(u * v_t),
+            _ => Err(ManifoldError::LinearAlgebraError(
+                "SVD failed while projecting to the Grassmann manifold".to_string(),
+            )),
+        }
+    }
+}
+
+impl Manifold for Grassmann {
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        self.p * (self.n - self.p)
+    }
+
+    fn ambient_dim(&self) -> usize {
+        self.n * self.p
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        let x = self.to_matrix(point)?;
+        let error = (x.transpose() * &x - DMatrix::identity(self.p, self.p)).norm();
+        if error.is_nan() || error > tolerance(1e-8) {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: format!("Columns are not orthonormal, |XᵀX - I| = {}", error),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.check_point(point)?;
+        let x = self.to_matrix(point)?;
+        let v = self.to_matrix(tangent.components.as_slice())?;
+        let vertical = (x.transpose() * v).norm();
+        if vertical > tolerance(1e-8) {
+            return Err(ManifoldError::InvalidTangentVector {
+                reason: format!("Tangent is not horizontal, |XᵀV| = {}", vertical),
+            });
+        }
+        Ok(())
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        Ok(self.from_matrix(&self.polar(&self.to_matrix(point)?)?))
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        let x = self.to_matrix(point)?;
+        let v = self.to_matrix(vector.components.as_slice())?;
+        Ok(self.tangent(&self.horizontal(&x, &v)))
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        validate_tangent(self, point, tangent)?;
+        let x = self.to_matrix(point)?;
+        let svd = self
+            .to_matrix(tangent.components.as_slice())?
+            .svd(true, true);
+        let (Some(u), Some(w_t)) = (svd.u, svd.v_t) else {
+            return Err(ManifoldError::LinearAlgebraError(
+                "SVD failed in the Grassmann exponential".to_string(),
+            ));
+        };
+        let cos = DMatrix::from_diagonal(&svd.singular_values.map(f64::cos));
+        let sin = DMatrix::from_diagonal(&svd.singular_values.map(f64::sin));
+        let y = x * w_t.transpose() * cos * &w_t + u * sin * w_t;
+        Ok(self.from_matrix(&self.polar(&y)?))
+    }
+
+    fn retraction(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        let moved = self.to_matrix(point)? + self.to_matrix(tangent.components.as_slice())?;
+        Ok(self.from_matrix(&self.polar(&moved)?))
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        validate_point(self, point)?;
+        validate_point(self, other)?;
+        let x = self.to_matrix(point)?;
+        let y = self.to_matrix(other)?;
+
+        // Rotate the basis of `other` so that XᵀY is symmetric positive
+        // semidefinite, then read the principal angles off its horizontal part.
+        let aligned = &y * self.polar(&(x.transpose() * &y))?.transpose();
+        let svd = self.horizontal(&x, &aligned).svd(true, true);
+        let (Some(u), Some(w_t)) = (svd.u, svd.v_t) else {
+            return Err(ManifoldError::LinearAlgebraError(
+                "SVD failed in the Grassmann logarithm".to_string(),
+            ));
+        };
+        let angles = DMatrix::from_diagonal(&svd.singular_values.map(|s| s.min(1.0).asin()));
+        Ok(self.tangent(&(u * angles * w_t)))
+    }
+
+    fn inner_product(
+        &self,
+        _point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        Ok(v1.dot(v2))
+    }
+}
+
+impl MatrixManifold for Grassmann {
+    fn matrix_shape(&self) -> (usize, usize) {
+        (self.n, self.p)
+    }
+}
+
+// Two bases span the same subspace exactly when their projectors XXᵀ agree.
+impl PointEquivalence for Grassmann {
+    fn points_equivalent_by(&self, a: &[f64], b: &[f64], eq: &mut PointComparison<f64>) -> bool {
+        match (self.to_matrix(a), self.to_matrix(b)) {
+            (Ok(x), Ok(y)) => eq(
+                (&x * x.transpose()).as_slice(),
+                (&y * y.transpose()).as_slice(),
+            ),
+            _ => eq(a, b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn sample_point(grassmann: &Grassmann, seed: usize) -> Vec<f64> {
+        let raw: Vec<f64> = (0..grassmann.n * grassmann.p)
+            .map(|i| ((i * 7 + seed) as f64).sin())
+            .collect();
+        grassmann.project_to_manifold(&raw).unwrap()
+    }
+
+    #[test]
+    fn test_grassmann_new() {
+        assert!(Grassmann::new(3, 0).is_err());
+        assert!(matches!(
+            Grassmann::new(2, 3),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        let grassmann = Grassmann::new(5, 2).unwrap();
+        assert_eq!(grassmann.dim(), 6);
+        assert_eq!(grassmann.ambient_dim(), 10);
+    }
+
+    #[test]
+    fn test_grassmann_rejects_wrong_lengths() {
+        let grassmann = Grassmann::new(3, 2).unwrap();
+        assert!(matches!(
+            grassmann.check_point(&[1.0, 0.0, 0.0]),
+            Err(ManifoldError::DimensionMismatch { .. })
+        ));
+        assert!(grassmann.project_to_manifold(&[1.0; 5]).is_err());
+    }
+
+    #[test]
+    fn test_grassmann_tangent_projection_is_horizontal() {
+        let grassmann = Grassmann::new(5, 2).unwrap();
+        let point = sample_point(&grassmann, 3);
+        let raw = TangentVector::new(DVector::from_fn(10, |i, _| (i as f64).cos()));
+        let tangent = grassmann.project_to_tangent_space(&point, &raw).unwrap();
+        assert!(grassmann.check_tangent_vector(&point, &tangent).is_ok());
+        assert!(grassmann.check_tangent_vector(&point, &raw).is_err());
+    }
+
+    #[test]
+    fn test_grassmann_exp_log_roundtrip() {
+        let grassmann = Grassmann::new(5, 2).unwrap();
+        let point = sample_point(&grassmann, 3);
+        let raw = TangentVector::new(DVector::from_fn(10, |i, _| 0.3 * ((i * 5) as f64).sin()));
+        let tangent = grassmann.project_to_tangent_space(&point, &raw).unwrap();
+        let other = grassmann.exp(&point, &tangent).unwrap();
+        assert!(grassmann.check_point(&other).is_ok());
+
+        let recovered = grassmann.log(&point, &other).unwrap();
+        for (r, t) in recovered.components.iter().zip(tangent.components.iter()) {
+            assert_relative_eq!(r, t, epsilon = 1e-8);
+        }
+        assert_relative_eq!(
+            grassmann.distance(&point, &other).unwrap(),
+            tangent.components.norm(),
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn test_grassmann_distance_ignores_the_choice_of_basis() {
+        let grassmann = Grassmann::new(4, 2).unwrap();
+        let a = sample_point(&grassmann, 1);
+        let b = sample_point(&grassmann, 8);
+        let rotation = DMatrix::from_row_slice(2, 2, &[0.6, -0.8, 0.8, 0.6]);
+        let rotated = grassmann.from_matrix(&(grassmann.to_matrix(&b).unwrap() * rotation));
+
+        assert!(grassmann.points_equivalent_by(&b, &rotated, &mut |x, y| {
+            x.iter().zip(y).all(|(p, q)| (p - q).abs() < 1e-12)
+        }));
+        assert_relative_eq!(
+            grassmann.distance(&a, &b).unwrap(),
+            grassmann.distance(&a, &rotated).unwrap(),
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            grassmann.distance(&b, &rotated).unwrap(),
+            0.0,
+            epsilon = 1e-7
+        );
+    }
+
+    #[test]
+    fn test_grassmann_retraction_stays_on_manifold() {
+        let grassmann = Grassmann::new(6, 3).unwrap();
+        let point = sample_point(&grassmann, 2);
+        let raw = TangentVector::new(DVector::from_fn(18, |i, _| ((i * 3) as f64).cos()));
+        let tangent = grassmann.project_to_tangent_space(&point, &raw).unwrap();
+        let moved = grassmann.retraction(&point, &tangent).unwrap();
+        assert!(grassmann.check_point(&moved).is_ok());
+        assert_eq!(grassmann.tangent_basis(&point).unwrap().len(), 9);
+    }
+}
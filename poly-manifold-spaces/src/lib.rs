@@ -1,7 +1,79 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod euclidean;
+#[cfg(feature = "std")]
+pub mod fixed;
+#[cfg(feature = "std")]
+pub mod fixed_rank;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+#[cfg(feature = "std")]
+pub mod grassmann;
+#[cfg(feature = "std")]
+pub mod poincare;
+#[cfg(feature = "std")]
+pub mod power;
+pub mod prelude;
+pub mod se3;
+pub mod so3;
+#[cfg(feature = "std")]
 pub mod spd;
 pub mod sphere;
+#[cfg(feature = "std")]
+pub mod stiefel;
 
 pub use euclidean::Euclidean;
-pub use spd::SPD;
+#[cfg(feature = "std")]
+pub use fixed::{SphereN, SPDN};
+#[cfg(feature = "std")]
+pub use fixed_rank::FixedRank;
+#[cfg(feature = "gpu")]
+pub use gpu::{GpuBackend, GpuKernels, GpuSpace};
+#[cfg(feature = "std")]
+pub use grassmann::Grassmann;
+#[cfg(feature = "std")]
+pub use poincare::PoincareBall;
+pub use poly_manifold_core::linalg;
+#[cfg(feature = "std")]
+pub use power::PowerManifold;
+pub use se3::SE3;
+pub use so3::SO3;
+#[cfg(feature = "std")]
+pub use spd::{SPDPoint, SPD};
 pub use sphere::Sphere;
+#[cfg(feature = "std")]
+pub use stiefel::Stiefel;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_spaces_are_send_and_sync() {
+        assert_send_sync::<Euclidean>();
+        assert_send_sync::<Sphere<f32>>();
+        assert_send_sync::<SO3>();
+        assert_send_sync::<SE3>();
+        #[cfg(feature = "std")]
+        {
+            assert_send_sync::<SPD>();
+            assert_send_sync::<SPDPoint>();
+            assert_send_sync::<PoincareBall>();
+            assert_send_sync::<Stiefel>();
+            assert_send_sync::<Grassmann>();
+            assert_send_sync::<FixedRank>();
+            assert_send_sync::<SphereN<3>>();
+            assert_send_sync::<SPDN<3>>();
+            assert_send_sync::<PowerManifold<Sphere>>();
+            assert_send_sync::<
+                PowerManifold<Box<dyn poly_manifold_core::Manifold<Scalar = f64> + Send + Sync>>,
+            >();
+        }
+        #[cfg(feature = "gpu")]
+        assert_send_sync::<GpuBackend>();
+    }
+}
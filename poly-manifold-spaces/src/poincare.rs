@@ -0,0 +1,334 @@
+use nalgebra::DVector;
+use poly_manifold_core::surrogate::chordal_distance;
+use poly_manifold_core::validation::{check_dimension, validate_point, validate_tangent};
+use poly_manifold_core::{
+    BatchManifold, DistanceSurrogate, Manifold, ManifoldError, PointEquivalence, Result,
+    TangentVector,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoincareBall {
+    pub dimension: usize,
+}
+
+impl PoincareBall {
+    pub fn new(dimension: usize) -> Self {
+        Self { dimension }
+    }
+
+    pub fn try_new(dimension: usize) -> Result<Self> {
+        check_dimension("Poincare ball", "dimension", dimension)?;
+        Ok(Self::new(dimension))
+    }
+
+    pub fn from_ambient_dim(ambient_dim: usize) -> Result<Self> {
+        Self::try_new(ambient_dim)
+    }
+
+    pub fn conformal_factor(&self, point: &[f64]) -> Result<f64> {
+        self.check_point(point)?;
+        Ok(conformal(point))
+    }
+
+    pub fn mobius_add(&self, x: &[f64], y: &[f64]) -> Vec<f64> {
+        let xy: f64 = x.iter().zip(y).map(|(a, b)| a * b).sum();
+        let xx = squared_norm(x);
+        let yy = squared_norm(y);
+        let denominator = 1.0 + 2.0 * xy + xx * yy;
+        x.iter()
+            .zip(y)
+            .map(|(a, b)| ((1.0 + 2.0 * xy + yy) * a + (1.0 - xx) * b) / denominator)
+            .collect()
+    }
+
+    fn check_len(&self, len: usize) -> Result<()> {
+        if len != self.dimension {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.dimension,
+                got: len,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Manifold for PoincareBall {
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        self.dimension
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.check_len(point.len())?;
+        let norm_sq = squared_norm(point);
+        if norm_sq >= 1.0 || !norm_sq.is_finite() {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: format!("Point norm is {} but must be below 1.0", norm_sq.sqrt()),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.check_point(point)?;
+        self.check_len(tangent.dim())
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.check_len(point.len())?;
+        let max_norm = 1.0 - 1e-5;
+        let norm = squared_norm(point).sqrt();
+        if norm < max_norm {
+            return Ok(point.to_vec());
+        }
+        Ok(point.iter().map(|x| x * max_norm / norm).collect())
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        _point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.check_len(vector.dim())?;
+        Ok(vector.clone())
+    }
+
+    fn euclidean_to_riemannian_gradient(
+        &self,
+        point: &[Self::Scalar],
+        euclidean_gradient: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        validate_tangent(self, point, euclidean_gradient)?;
+        let lambda = conformal(point);
+        Ok(euclidean_gradient * (1.0 / (lambda * lambda)))
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        validate_tangent(self, point, tangent)?;
+        self.exp_unchecked(point, tangent)
+    }
+
+    fn exp_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        let norm = tangent.norm();
+        if norm < 1e-15 {
+            return Ok(point.to_vec());
+        }
+        let scale = (0.5 * conformal(point) * norm).tanh() / norm;
+        let step: Vec<f64> = tangent.components.iter().map(|v| v * scale).collect();
+        self.project_to_manifold(&self.mobius_add(point, &step))
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        validate_point(self, point)?;
+        validate_point(self, other)?;
+        self.log_unchecked(point, other)
+    }
+
+    fn log_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        let negated: Vec<f64> = point.iter().map(|x| -x).collect();
+        let difference = self.mobius_add(&negated, other);
+        let norm = squared_norm(&difference).sqrt();
+        if norm < 1e-15 {
+            return Ok(TangentVector::zero(self.dimension));
+        }
+        let scale = 2.0 / conformal(point) * norm.min(1.0 - 1e-15).atanh() / norm;
+        Ok(TangentVector::new(DVector::from_iterator(
+            self.dimension,
+            difference.iter().map(|d| d * scale),
+        )))
+    }
+
+    fn inner_product(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        validate_tangent(self, point, v1)?;
+        validate_tangent(self, point, v2)?;
+        let lambda = conformal(point);
+        Ok(lambda * lambda * v1.dot(v2))
+    }
+
+    fn distance(&self, point1: &[Self::Scalar], point2: &[Self::Scalar]) -> Result<Self::Scalar> {
+        validate_point(self, point1)?;
+        validate_point(self, point2)?;
+        let diff: f64 = point1
+            .iter()
+            .zip(point2)
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum();
+        let denominator = (1.0 - squared_norm(point1)) * (1.0 - squared_norm(point2));
+        Ok((1.0 + 2.0 * diff / denominator).acosh())
+    }
+
+    fn log_volume_change(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Option<Self::Scalar> {
+        let r = self.norm(point, tangent).ok()?;
+        if r < 1e-10 {
+            return Some(0.0);
+        }
+        Some((self.dimension as f64 - 1.0) * (r.sinh() / r).ln())
+    }
+}
+
+fn squared_norm(x: &[f64]) -> f64 {
+    x.iter().map(|v| v * v).sum()
+}
+
+fn conformal(point: &[f64]) -> f64 {
+    2.0 / (1.0 - squared_norm(point))
+}
+
+impl BatchManifold for PoincareBall {}
+
+// The conformal factor 2 / (1 - |x|²) is at least 2, so every hyperbolic
+// length is at least twice the Euclidean one; there is no upper bound.
+impl DistanceSurrogate for PoincareBall {
+    fn surrogate_distance(&self, a: &[f64], b: &[f64]) -> Result<f64> {
+        self.check_len(a.len())?;
+        chordal_distance(a, b)
+    }
+
+    fn distance_lower_bound(&self, surrogate: f64) -> f64 {
+        2.0 * surrogate
+    }
+}
+
+impl PointEquivalence for PoincareBall {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_poincare_try_new() {
+        assert!(PoincareBall::try_new(0).is_err());
+        assert_eq!(PoincareBall::try_new(2).unwrap().dim(), 2);
+    }
+
+    #[test]
+    fn test_poincare_from_ambient_dim() {
+        let ball = PoincareBall::from_ambient_dim(3).unwrap();
+        assert_eq!(ball.ambient_dim(), 3);
+        assert!(PoincareBall::from_ambient_dim(0).is_err());
+    }
+
+    #[test]
+    fn test_poincare_check_point() {
+        let ball = PoincareBall::new(2);
+        assert!(ball.check_point(&[0.3, 0.4]).is_ok());
+        assert!(ball.check_point(&[0.6, 0.8]).is_err());
+        assert!(ball.check_point(&[0.1]).is_err());
+    }
+
+    #[test]
+    fn test_poincare_distance_from_origin() {
+        let ball = PoincareBall::new(2);
+        let d = ball.distance(&[0.0, 0.0], &[0.5, 0.0]).unwrap();
+        assert_relative_eq!(d, 2.0 * 0.5f64.atanh(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_poincare_exp_log_roundtrip() {
+        let ball = PoincareBall::new(2);
+        let point = [0.3, -0.2];
+        let tangent = TangentVector::new(DVector::from_vec(vec![0.2, 0.5]));
+        let moved = ball.exp(&point, &tangent).unwrap();
+        let recovered = ball.log(&point, &moved).unwrap();
+        assert_relative_eq!(recovered.components[0], 0.2, epsilon = 1e-9);
+        assert_relative_eq!(recovered.components[1], 0.5, epsilon = 1e-9);
+
+        let via_log = ball.norm(&point, &recovered).unwrap();
+        assert_relative_eq!(
+            via_log,
+            ball.distance(&point, &moved).unwrap(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_poincare_riemannian_gradient_rescales() {
+        let ball = PoincareBall::new(2);
+        let point = [0.5, 0.0];
+        let gradient = TangentVector::new(DVector::from_vec(vec![1.0, 0.0]));
+        let riemannian = ball
+            .euclidean_to_riemannian_gradient(&point, &gradient)
+            .unwrap();
+        let lambda: f64 = 2.0 / 0.75;
+        assert_relative_eq!(
+            riemannian.components[0],
+            1.0 / (lambda * lambda),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_poincare_metric_rejects_points_outside_the_ball() {
+        let ball = PoincareBall::new(2);
+        let v = TangentVector::new(DVector::from_vec(vec![1.0, 0.0]));
+        assert_relative_eq!(ball.conformal_factor(&[0.5, 0.0]).unwrap(), 2.0 / 0.75);
+        assert_relative_eq!(
+            ball.inner_product(&[0.5, 0.0], &v, &v).unwrap(),
+            (2.0f64 / 0.75).powi(2)
+        );
+        for point in [[1.0, 0.0], [0.6, 0.9], [f64::NAN, 0.0]] {
+            assert!(ball.conformal_factor(&point).is_err());
+            assert!(ball.inner_product(&point, &v, &v).is_err());
+        }
+        assert!(ball.conformal_factor(&[0.1]).is_err());
+    }
+
+    #[test]
+    fn test_poincare_projection_stays_inside() {
+        let ball = PoincareBall::new(2);
+        let projected = ball.project_to_manifold(&[3.0, 4.0]).unwrap();
+        assert!(ball.check_point(&projected).is_ok());
+    }
+
+    #[test]
+    fn test_euclidean_surrogate_is_a_lower_bound() {
+        let ball = PoincareBall::new(2);
+        let pairs = [
+            ([0.0, 0.0], [0.5, 0.0]),
+            ([0.1, -0.2], [-0.3, 0.6]),
+            ([0.9, 0.0], [0.0, 0.9]),
+        ];
+        for (a, b) in pairs {
+            let surrogate = ball.surrogate_distance(&a, &b).unwrap();
+            let distance = ball.distance(&a, &b).unwrap();
+            assert!(ball.distance_lower_bound(surrogate) <= distance);
+            assert_eq!(ball.distance_upper_bound(surrogate), f64::INFINITY);
+        }
+        let (lower, _) = ball
+            .bounded_distance(&[0.0, 0.0], &[0.5, 0.0], 10.0)
+            .unwrap();
+        assert_relative_eq!(lower, 3.0f64.ln(), epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,424 @@
+use nalgebra::DVector;
+use poly_manifold_core::validation::check_dimension;
+use poly_manifold_core::{
+    Manifold, ManifoldError, PointComparison, PointEquivalence, Result, TangentVector,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerManifold<M> {
+    pub base: M,
+    pub count: usize,
+}
+
+impl<M: Manifold<Scalar = f64>> PowerManifold<M> {
+    pub fn new(base: M, count: usize) -> Self {
+        Self { base, count }
+    }
+
+    pub fn try_new(base: M, count: usize) -> Result<Self> {
+        check_dimension("Power manifold", "component count", count)?;
+        Ok(Self::new(base, count))
+    }
+
+    pub fn from_vec_len(base: M, len: usize) -> Result<Self> {
+        let base_len = base.ambient_dim();
+        check_dimension("Power manifold", "base ambient dimension", base_len)?;
+        if !len.is_multiple_of(base_len) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Power manifold points have k × {base_len} entries, got {len}"
+            )));
+        }
+        Self::try_new(base, len / base_len)
+    }
+
+    /// `None` when `index` is out of range or `point` is too short.
+    pub fn component<'a>(&self, point: &'a [f64], index: usize) -> Option<&'a [f64]> {
+        if index >= self.count {
+            return None;
+        }
+        let n = self.base.ambient_dim();
+        point.get(index * n..(index + 1) * n)
+    }
+
+    pub fn components<'a>(&self, point: &'a [f64]) -> impl Iterator<Item = &'a [f64]> {
+        point.chunks(self.base.ambient_dim().max(1))
+    }
+
+    pub fn from_components(&self, components: &[Vec<f64>]) -> Result<Vec<f64>> {
+        if components.len() != self.count {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.count,
+                got: components.len(),
+            });
+        }
+        let point = components.concat();
+        self.check_point(&point)?;
+        Ok(point)
+    }
+
+    fn check_len(&self, len: usize) -> Result<()> {
+        if len != self.ambient_dim() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.ambient_dim(),
+                got: len,
+            });
+        }
+        Ok(())
+    }
+
+    fn chunk<'a>(&self, point: &'a [f64], index: usize) -> &'a [f64] {
+        let n = self.base.ambient_dim();
+        &point[index * n..(index + 1) * n]
+    }
+
+    fn tangent_component(&self, tangent: &TangentVector<f64>, index: usize) -> TangentVector<f64> {
+        let n = self.base.ambient_dim();
+        TangentVector::new(DVector::from_column_slice(
+            &tangent.components.as_slice()[index * n..(index + 1) * n],
+        ))
+    }
+
+    fn map_tangents<F>(
+        &self,
+        point: &[f64],
+        tangent: &TangentVector<f64>,
+        f: F,
+    ) -> Result<TangentVector<f64>>
+    where
+        F: Fn(&[f64], &TangentVector<f64>) -> Result<TangentVector<f64>>,
+    {
+        self.check_len(point.len())?;
+        self.check_len(tangent.dim())?;
+        let mut components = Vec::with_capacity(point.len());
+        for i in 0..self.count {
+            let mapped = f(self.chunk(point, i), &self.tangent_component(tangent, i))?;
+            components.extend(mapped.components.iter());
+        }
+        Ok(TangentVector::new(DVector::from_vec(components)))
+    }
+
+    fn map_points<F>(&self, point: &[f64], tangent: &TangentVector<f64>, f: F) -> Result<Vec<f64>>
+    where
+        F: Fn(&[f64], &TangentVector<f64>) -> Result<Vec<f64>>,
+    {
+        self.check_len(point.len())?;
+        self.check_len(tangent.dim())?;
+        let mut result = Vec::with_capacity(point.len());
+        for i in 0..self.count {
+            result.extend(f(
+                self.chunk(point, i),
+                &self.tangent_component(tangent, i),
+            )?);
+        }
+        Ok(result)
+    }
+
+    fn map_logs<F>(&self, point: &[f64], other: &[f64], f: F) -> Result<TangentVector<f64>>
+    where
+        F: Fn(&[f64], &[f64]) -> Result<TangentVector<f64>>,
+    {
+        self.check_len(point.len())?;
+        self.check_len(other.len())?;
+        let mut components = Vec::with_capacity(point.len());
+        for i in 0..self.count {
+            let log = f(self.chunk(point, i), self.chunk(other, i))?;
+            components.extend(log.components.iter());
+        }
+        Ok(TangentVector::new(DVector::from_vec(components)))
+    }
+
+    fn sum_inner_products<F>(
+        &self,
+        point: &[f64],
+        v1: &TangentVector<f64>,
+        v2: &TangentVector<f64>,
+        f: F,
+    ) -> Result<f64>
+    where
+        F: Fn(&[f64], &TangentVector<f64>, &TangentVector<f64>) -> Result<f64>,
+    {
+        self.check_len(point.len())?;
+        self.check_len(v1.dim())?;
+        self.check_len(v2.dim())?;
+        let mut total = 0.0;
+        for i in 0..self.count {
+            total += f(
+                self.chunk(point, i),
+                &self.tangent_component(v1, i),
+                &self.tangent_component(v2, i),
+            )?;
+        }
+        Ok(total)
+    }
+}
+
+impl<M: Manifold<Scalar = f64>> Manifold for PowerManifold<M> {
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        self.base.dim() * self.count
+    }
+
+    fn ambient_dim(&self) -> usize {
+        self.base.ambient_dim() * self.count
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.check_len(point.len())?;
+        for i in 0..self.count {
+            self.base.check_point(self.chunk(point, i))?;
+        }
+        Ok(())
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.check_len(point.len())?;
+        self.check_len(tangent.dim())?;
+        for i in 0..self.count {
+            self.base
+                .check_tangent_vector(self.chunk(point, i), &self.tangent_component(tangent, i))?;
+        }
+        Ok(())
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.check_len(point.len())?;
+        let mut result = Vec::with_capacity(point.len());
+        for i in 0..self.count {
+            result.extend(self.base.project_to_manifold(self.chunk(point, i))?);
+        }
+        Ok(result)
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.map_tangents(point, vector, |p, v| {
+            self.base.project_to_tangent_space(p, v)
+        })
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.map_points(point, tangent, |p, v| self.base.exp(p, v))
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.map_logs(point, other, |p, q| self.base.log(p, q))
+    }
+
+    fn inner_product(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.sum_inner_products(point, v1, v2, |p, a, b| self.base.inner_product(p, a, b))
+    }
+
+    fn exp_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.map_points(point, tangent, |p, v| self.base.exp_unchecked(p, v))
+    }
+
+    fn log_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.map_logs(point, other, |p, q| self.base.log_unchecked(p, q))
+    }
+
+    fn inner_product_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.sum_inner_products(point, v1, v2, |p, a, b| {
+            self.base.inner_product_unchecked(p, a, b)
+        })
+    }
+
+    fn retraction(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.map_points(point, tangent, |p, v| self.base.retraction(p, v))
+    }
+
+    fn euclidean_to_riemannian_gradient(
+        &self,
+        point: &[Self::Scalar],
+        euclidean_gradient: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.map_tangents(point, euclidean_gradient, |p, g| {
+            self.base.euclidean_to_riemannian_gradient(p, g)
+        })
+    }
+
+    fn log_volume_change(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Option<Self::Scalar> {
+        if self.check_len(point.len()).is_err() || self.check_len(tangent.dim()).is_err() {
+            return None;
+        }
+        (0..self.count)
+            .map(|i| {
+                self.base
+                    .log_volume_change(self.chunk(point, i), &self.tangent_component(tangent, i))
+            })
+            .sum()
+    }
+}
+
+impl<M: PointEquivalence<Scalar = f64>> PointEquivalence for PowerManifold<M> {
+    fn points_equivalent_by(&self, a: &[f64], b: &[f64], eq: &mut PointComparison<f64>) -> bool {
+        a.len() == b.len()
+            && self
+                .components(a)
+                .zip(self.components(b))
+                .all(|(x, y)| self.base.points_equivalent_by(x, y, eq))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Euclidean, Sphere};
+    use approx::assert_relative_eq;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_power_standard_traits() {
+        let torus = PowerManifold::new(Sphere::new(1), 2);
+        let copy = torus;
+        assert_eq!(copy, torus);
+        assert_ne!(torus, PowerManifold::new(Sphere::new(1), 3));
+        assert_eq!(
+            PowerManifold::new(crate::SO3::new(), 2),
+            PowerManifold::new(crate::SO3::new(), 2)
+        );
+    }
+
+    #[test]
+    fn test_power_try_new() {
+        assert!(PowerManifold::try_new(Sphere::new(2), 0).is_err());
+        assert_eq!(
+            PowerManifold::try_new(Euclidean::new(2), 3)
+                .unwrap()
+                .ambient_dim(),
+            6
+        );
+    }
+
+    #[test]
+    fn test_power_from_vec_len() {
+        let power = PowerManifold::from_vec_len(Sphere::new(2), 12).unwrap();
+        assert_eq!(power.count, 4);
+        assert!(matches!(
+            PowerManifold::from_vec_len(Sphere::new(2), 10),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        assert!(PowerManifold::from_vec_len(Sphere::new(2), 0).is_err());
+        assert!(PowerManifold::from_vec_len(Euclidean::new(0), 4).is_err());
+    }
+
+    #[test]
+    fn test_power_points_equivalent_per_component() {
+        let power = PowerManifold::new(Sphere::new(1), 2);
+        let a = [1.0, 0.0, 0.0, 1.0];
+        assert!(power.points_equivalent(&a, &a));
+        assert!(!power.points_equivalent(&a, &[1.0, 0.0, 0.0, -1.0]));
+        assert!(!power.points_equivalent(&a, &a[..2]));
+    }
+
+    #[test]
+    fn test_power_dimensions() {
+        let power = PowerManifold::new(Sphere::new(2), 4);
+        assert_eq!(power.dim(), 8);
+        assert_eq!(power.ambient_dim(), 12);
+    }
+
+    #[test]
+    fn test_power_check_point() {
+        let power = PowerManifold::new(Sphere::new(2), 2);
+        assert!(power.check_point(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0]).is_ok());
+        assert!(power.check_point(&[1.0, 0.0, 0.0, 0.0, 2.0, 0.0]).is_err());
+        assert!(power.check_point(&[1.0, 0.0, 0.0]).is_err());
+    }
+
+    #[test]
+    fn test_power_distance_combines_components() {
+        let power = PowerManifold::new(Sphere::new(2), 2);
+        let a = [1.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+        let b = [0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        assert_relative_eq!(power.distance(&a, &b).unwrap(), PI / 2.0, epsilon = 1e-10);
+
+        let c = [0.0, 1.0, 0.0, 0.0, 1.0, 0.0];
+        assert_relative_eq!(
+            power.distance(&a, &c).unwrap(),
+            (2.0 * (PI / 2.0).powi(2)).sqrt(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_power_exp_log_roundtrip() {
+        let power = PowerManifold::new(Sphere::new(2), 2);
+        let point = [1.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+        let tangent = TangentVector::new(DVector::from_vec(vec![0.0, 0.3, -0.2, 0.1, 0.4, 0.0]));
+        let moved = power.exp(&point, &tangent).unwrap();
+        assert!(power.check_point(&moved).is_ok());
+
+        let recovered = power.log(&point, &moved).unwrap();
+        for (r, t) in recovered.components.iter().zip(tangent.components.iter()) {
+            assert_relative_eq!(r, t, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_power_of_borrowed_base() {
+        let euclidean = Euclidean::new(1);
+        let power = PowerManifold::new(&euclidean, 3);
+        let point = power
+            .from_components(&[vec![1.0], vec![2.0], vec![3.0]])
+            .unwrap();
+        assert_eq!(power.component(&point, 1), Some(&[2.0][..]));
+        assert_eq!(power.component(&point, 3), None);
+        assert_eq!(power.component(&point[..2], 2), None);
+        assert_eq!(power.components(&point).count(), 3);
+        assert_eq!(power.tangent_basis(&point).unwrap().len(), 3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_power_manifold_serde_round_trip() {
+        let power = PowerManifold::new(Sphere::new(2), 4);
+        let json = serde_json::to_string(&power).unwrap();
+        assert_eq!(json, r#"{"base":{"dimension":2},"count":4}"#);
+        let back: PowerManifold<Sphere> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.dim(), power.dim());
+        assert_eq!(back.base.dimension, 2);
+    }
+}
@@ -0,0 +1,17 @@
+pub use crate::euclidean::Euclidean;
+#[cfg(feature = "std")]
+pub use crate::fixed_rank::FixedRank;
+#[cfg(feature = "std")]
+pub use crate::grassmann::Grassmann;
+#[cfg(feature = "std")]
+pub use crate::poincare::PoincareBall;
+#[cfg(feature = "std")]
+pub use crate::power::PowerManifold;
+pub use crate::se3::SE3;
+pub use crate::so3::SO3;
+#[cfg(feature = "std")]
+pub use crate::spd::SPD;
+pub use crate::sphere::Sphere;
+#[cfg(feature = "std")]
+pub use crate::stiefel::Stiefel;
+pub use poly_manifold_core::prelude::*;
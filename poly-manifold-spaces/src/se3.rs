@@ -0,0 +1,410 @@
+use crate::so3::{rodrigues, SO3};
+use alloc::{string::ToString, vec::Vec};
+use nalgebra::{DVector, Matrix3, Matrix4, Vector3, Vector6};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use poly_manifold_core::validation::{validate_point, validate_tangent};
+use poly_manifold_core::{
+    LieGroup, Manifold, ManifoldError, PointEquivalence, Result, TangentVector,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SE3;
+
+impl SE3 {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn from_parts(rotation: &[f64], translation: &[f64]) -> Result<Vec<f64>> {
+        check_part(9, rotation.len())?;
+        check_part(3, translation.len())?;
+        Ok(rotation.iter().chain(translation).copied().collect())
+    }
+
+    pub fn rotation(point: &[f64]) -> Result<&[f64]> {
+        check_part(12, point.len())?;
+        Ok(&point[..9])
+    }
+
+    pub fn translation(point: &[f64]) -> Result<&[f64]> {
+        check_part(12, point.len())?;
+        Ok(&point[9..12])
+    }
+
+    pub fn to_matrix(point: &[f64]) -> Result<Matrix4<f64>> {
+        Ok(join_pose(
+            &SO3::to_matrix(Self::rotation(point)?)?,
+            &Vector3::from_column_slice(Self::translation(point)?),
+        ))
+    }
+
+    pub fn from_matrix(pose: &Matrix4<f64>) -> Vec<f64> {
+        let (rotation, translation) = split_pose(pose);
+        join_parts(&SO3::from_matrix(&rotation), translation.as_slice())
+    }
+
+    pub fn exp_static(pose: &Matrix4<f64>, twist: &Vector6<f64>) -> Matrix4<f64> {
+        let (rotation, translation) = split_pose(pose);
+        let omega = twist.fixed_rows::<3>(0).into_owned();
+        let velocity = twist.fixed_rows::<3>(3).into_owned();
+        join_pose(
+            &SO3::exp_static(&rotation, &omega),
+            &(translation + velocity),
+        )
+    }
+
+    pub fn log_static(pose: &Matrix4<f64>, other: &Matrix4<f64>) -> Vector6<f64> {
+        let (ra, ta) = split_pose(pose);
+        let (rb, tb) = split_pose(other);
+        let mut twist = Vector6::zeros();
+        twist
+            .fixed_rows_mut::<3>(0)
+            .copy_from(&SO3::log_static(&ra, &rb));
+        twist.fixed_rows_mut::<3>(3).copy_from(&(tb - ta));
+        twist
+    }
+
+    pub fn distance_static(pose: &Matrix4<f64>, other: &Matrix4<f64>) -> f64 {
+        Self::log_static(pose, other).norm()
+    }
+
+    fn check_len(&self, len: usize) -> Result<()> {
+        check_part(12, len)
+    }
+
+    fn split(tangent: &TangentVector<f64>) -> Result<(TangentVector<f64>, Vector3<f64>)> {
+        check_part(12, tangent.dim())?;
+        let rotation = TangentVector::new(DVector::from_column_slice(
+            &tangent.components.as_slice()[..9],
+        ));
+        let translation = Vector3::from_column_slice(&tangent.components.as_slice()[9..12]);
+        Ok((rotation, translation))
+    }
+
+    fn join(rotation: &TangentVector<f64>, translation: &Vector3<f64>) -> TangentVector<f64> {
+        let components: Vec<f64> = rotation
+            .components
+            .iter()
+            .chain(translation.iter())
+            .copied()
+            .collect();
+        TangentVector::new(DVector::from_vec(components))
+    }
+}
+
+impl Default for SE3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Manifold for SE3 {
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        6
+    }
+
+    fn ambient_dim(&self) -> usize {
+        12
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.check_len(point.len())?;
+        SO3.check_point(Self::rotation(point)?)
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.check_point(point)?;
+        self.check_len(tangent.dim())?;
+        SO3.check_tangent_vector(Self::rotation(point)?, &Self::split(tangent)?.0)
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.check_len(point.len())?;
+        let rotation = SO3.project_to_manifold(Self::rotation(point)?)?;
+        Ok(join_parts(&rotation, Self::translation(point)?))
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.check_len(point.len())?;
+        self.check_len(vector.dim())?;
+        let (rotation, translation) = Self::split(vector)?;
+        let rotation = SO3.project_to_tangent_space(Self::rotation(point)?, &rotation)?;
+        Ok(Self::join(&rotation, &translation))
+    }
+
+    fn euclidean_to_riemannian_gradient(
+        &self,
+        point: &[Self::Scalar],
+        euclidean_gradient: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.check_len(point.len())?;
+        self.check_len(euclidean_gradient.dim())?;
+        let (rotation, translation) = Self::split(euclidean_gradient)?;
+        let rotation = SO3.euclidean_to_riemannian_gradient(Self::rotation(point)?, &rotation)?;
+        Ok(Self::join(&rotation, &translation))
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        validate_tangent(self, point, tangent)?;
+        let (rotation, translation) = Self::split(tangent)?;
+        let moved = SO3.exp(Self::rotation(point)?, &rotation)?;
+        let position = Vector3::from_column_slice(Self::translation(point)?) + translation;
+        Ok(join_parts(&moved, position.as_slice()))
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        validate_point(self, point)?;
+        validate_point(self, other)?;
+        let rotation = SO3.log(Self::rotation(point)?, Self::rotation(other)?)?;
+        let translation = Vector3::from_column_slice(Self::translation(other)?)
+            - Vector3::from_column_slice(Self::translation(point)?);
+        Ok(Self::join(&rotation, &translation))
+    }
+
+    fn inner_product(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        let (r1, t1) = Self::split(v1)?;
+        let (r2, t2) = Self::split(v2)?;
+        Ok(SO3.inner_product(Self::rotation(point)?, &r1, &r2)? + t1.dot(&t2))
+    }
+
+    fn log_volume_change(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Option<Self::Scalar> {
+        SO3.log_volume_change(Self::rotation(point).ok()?, &Self::split(tangent).ok()?.0)
+    }
+}
+
+impl LieGroup for SE3 {
+    fn group_identity(&self) -> Vec<Self::Scalar> {
+        join_parts(&SO3::identity(), &[0.0; 3])
+    }
+
+    fn compose(&self, a: &[Self::Scalar], b: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.check_len(a.len())?;
+        self.check_len(b.len())?;
+        let ra = SO3::to_matrix(Self::rotation(a)?)?;
+        let rb = SO3::to_matrix(Self::rotation(b)?)?;
+        let translation = ra * Vector3::from_column_slice(Self::translation(b)?)
+            + Vector3::from_column_slice(Self::translation(a)?);
+        Ok(join_parts(
+            &SO3::from_matrix(&(ra * rb)),
+            translation.as_slice(),
+        ))
+    }
+
+    fn inverse(&self, a: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.check_len(a.len())?;
+        let rt = SO3::to_matrix(Self::rotation(a)?)?.transpose();
+        let translation = -(rt * Vector3::from_column_slice(Self::translation(a)?));
+        Ok(join_parts(&SO3::from_matrix(&rt), translation.as_slice()))
+    }
+
+    fn group_exp(&self, algebra: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        if algebra.len() != 6 {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: 6,
+                got: algebra.len(),
+            });
+        }
+        let omega = Vector3::from_column_slice(&algebra[..3]);
+        let velocity = Vector3::from_column_slice(&algebra[3..]);
+        let translation = left_jacobian(&omega) * velocity;
+        Ok(join_parts(
+            &SO3::from_matrix(&rodrigues(&omega)),
+            translation.as_slice(),
+        ))
+    }
+
+    fn group_log(&self, element: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        validate_point(self, element)?;
+        let omega = SO3::rotation_log(&SO3::to_matrix(Self::rotation(element)?)?);
+        let velocity = left_jacobian(&omega).try_inverse().ok_or_else(|| {
+            ManifoldError::NumericalError(
+                "SE(3) logarithm is singular at this rotation".to_string(),
+            )
+        })? * Vector3::from_column_slice(Self::translation(element)?);
+        Ok(omega.iter().chain(velocity.iter()).copied().collect())
+    }
+}
+
+fn check_part(expected: usize, got: usize) -> Result<()> {
+    if got != expected {
+        return Err(ManifoldError::DimensionMismatch { expected, got });
+    }
+    Ok(())
+}
+
+fn join_parts(rotation: &[f64], translation: &[f64]) -> Vec<f64> {
+    rotation.iter().chain(translation).copied().collect()
+}
+
+fn split_pose(pose: &Matrix4<f64>) -> (Matrix3<f64>, Vector3<f64>) {
+    (
+        pose.fixed_view::<3, 3>(0, 0).into_owned(),
+        pose.fixed_view::<3, 1>(0, 3).into_owned(),
+    )
+}
+
+fn join_pose(rotation: &Matrix3<f64>, translation: &Vector3<f64>) -> Matrix4<f64> {
+    let mut pose = Matrix4::identity();
+    pose.fixed_view_mut::<3, 3>(0, 0).copy_from(rotation);
+    pose.fixed_view_mut::<3, 1>(0, 3).copy_from(translation);
+    pose
+}
+
+fn left_jacobian(omega: &Vector3<f64>) -> Matrix3<f64> {
+    let theta = omega.norm();
+    let k = SO3::hat(omega);
+    if theta < 1e-8 {
+        return Matrix3::identity() + k * 0.5 + k * k / 6.0;
+    }
+    let theta2 = theta * theta;
+    Matrix3::identity()
+        + k * ((1.0 - theta.cos()) / theta2)
+        + k * k * ((theta - theta.sin()) / (theta2 * theta))
+}
+
+impl PointEquivalence for SE3 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn pose() -> Vec<f64> {
+        SE3::from_parts(
+            &SO3::from_axis_angle(&[0.0, 1.0, 1.0], 0.7),
+            &[1.0, -2.0, 0.5],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_se3_check_point() {
+        let se3 = SE3::new();
+        assert!(se3.check_point(&pose()).is_ok());
+        assert!(se3.check_point(&se3.group_identity()).is_ok());
+        assert!(se3.check_point(&SO3::identity()).is_err());
+    }
+
+    #[test]
+    fn test_se3_rejects_wrong_lengths() {
+        let se3 = SE3::new();
+        let short = [0.0; 11];
+        let tangent = TangentVector::zero(12);
+        let mismatch = |error: Option<ManifoldError>| {
+            matches!(error, Some(ManifoldError::DimensionMismatch { .. }))
+        };
+        assert!(mismatch(SE3::from_parts(&[0.0; 8], &[0.0; 3]).err()));
+        assert!(mismatch(SE3::from_parts(&SO3::identity(), &[0.0; 2]).err()));
+        assert!(mismatch(SE3::rotation(&short).err()));
+        assert!(mismatch(SE3::translation(&short).err()));
+        assert!(mismatch(SE3::to_matrix(&short).err()));
+        assert!(mismatch(
+            se3.inner_product(&short, &tangent, &tangent).err()
+        ));
+        assert!(mismatch(
+            se3.inner_product(&pose(), &TangentVector::zero(9), &tangent)
+                .err()
+        ));
+        assert_eq!(se3.log_volume_change(&short, &tangent), None);
+    }
+
+    #[test]
+    fn test_se3_exp_log_roundtrip() {
+        let se3 = SE3::new();
+        let a = pose();
+        let b = SE3::from_parts(
+            &SO3::from_axis_angle(&[1.0, 0.0, 0.0], 0.3),
+            &[0.0, 1.0, 2.0],
+        )
+        .unwrap();
+        let tangent = se3.log(&a, &b).unwrap();
+        let recovered = se3.exp(&a, &tangent).unwrap();
+        for (r, e) in recovered.iter().zip(&b) {
+            assert_relative_eq!(r, e, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_se3_static_operations_match_manifold() {
+        let se3 = SE3::new();
+        let a = pose();
+        let b = SE3::from_parts(
+            &SO3::from_axis_angle(&[1.0, 0.0, 0.0], 0.3),
+            &[0.0, 1.0, 2.0],
+        )
+        .unwrap();
+        let (pa, pb) = (SE3::to_matrix(&a).unwrap(), SE3::to_matrix(&b).unwrap());
+        assert_eq!(SE3::from_matrix(&pa), a);
+        assert_eq!(pa[(3, 3)], 1.0);
+
+        let twist = SE3::log_static(&pa, &pb);
+        assert_relative_eq!(SE3::exp_static(&pa, &twist), pb, epsilon = 1e-9);
+        assert_relative_eq!(
+            SE3::distance_static(&pa, &pb),
+            se3.distance(&a, &b).unwrap(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_se3_group_exp_log_roundtrip() {
+        let se3 = SE3::new();
+        let twist = [0.3, -0.1, 0.8, 1.0, 2.0, -0.5];
+        let element = se3.group_exp(&twist).unwrap();
+        assert!(se3.check_point(&element).is_ok());
+        let recovered = se3.group_log(&element).unwrap();
+        for (r, t) in recovered.iter().zip(&twist) {
+            assert_relative_eq!(r, t, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_se3_compose_and_inverse() {
+        let se3 = SE3::new();
+        let a = pose();
+        let identity = se3.compose(&a, &se3.inverse(&a).unwrap()).unwrap();
+        for (i, e) in identity.iter().zip(se3.group_identity()) {
+            assert_relative_eq!(*i, e, epsilon = 1e-12);
+        }
+
+        let translate = se3.group_exp(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0]).unwrap();
+        let moved = se3.compose(&a, &translate).unwrap();
+        let rotation = SO3::to_matrix(SE3::rotation(&a).unwrap()).unwrap();
+        for k in 0..3 {
+            assert_relative_eq!(
+                SE3::translation(&moved).unwrap()[k],
+                SE3::translation(&a).unwrap()[k] + rotation[(k, 0)],
+                epsilon = 1e-12
+            );
+        }
+    }
+}
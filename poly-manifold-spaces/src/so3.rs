@@ -0,0 +1,509 @@
+use alloc::{format, string::ToString, vec::Vec};
+use core::f64::consts::{PI, SQRT_2};
+use nalgebra::{DMatrix, DVector, Matrix3, Vector3};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use poly_manifold_core::precision::tolerance;
+use poly_manifold_core::surrogate::chordal_distance;
+use poly_manifold_core::validation::{validate_point, validate_tangent};
+use poly_manifold_core::{
+    DistanceSurrogate, LieGroup, Manifold, ManifoldError, MatrixManifold, PointEquivalence, Result,
+    TangentVector,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SO3;
+
+impl SO3 {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn to_matrix(point: &[f64]) -> Result<Matrix3<f64>> {
+        if point.len() != 9 {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: 9,
+                got: point.len(),
+            });
+        }
+        Ok(Matrix3::from_row_slice(point))
+    }
+
+    pub fn from_matrix(matrix: &Matrix3<f64>) -> Vec<f64> {
+        matrix.transpose().as_slice().to_vec()
+    }
+
+    pub fn identity() -> Vec<f64> {
+        Self::from_matrix(&Matrix3::identity())
+    }
+
+    pub fn from_axis_angle(axis: &[f64; 3], angle: f64) -> Vec<f64> {
+        let axis = Vector3::from_column_slice(axis);
+        let norm = axis.norm();
+        if norm < 1e-15 {
+            return Self::identity();
+        }
+        Self::from_matrix(&rodrigues(&(axis * (angle / norm))))
+    }
+
+    pub fn hat(omega: &Vector3<f64>) -> Matrix3<f64> {
+        Matrix3::new(
+            0.0, -omega[2], omega[1], //
+            omega[2], 0.0, -omega[0], //
+            -omega[1], omega[0], 0.0,
+        )
+    }
+
+    pub fn vee(skew: &Matrix3<f64>) -> Vector3<f64> {
+        Vector3::new(
+            0.5 * (skew[(2, 1)] - skew[(1, 2)]),
+            0.5 * (skew[(0, 2)] - skew[(2, 0)]),
+            0.5 * (skew[(1, 0)] - skew[(0, 1)]),
+        )
+    }
+
+    pub fn rotation_log(rotation: &Matrix3<f64>) -> Vector3<f64> {
+        let sin_axis = Self::vee(&(rotation - rotation.transpose())) * 0.5;
+        let sin_theta = sin_axis.norm();
+        let theta = sin_theta.atan2((rotation.trace() - 1.0) / 2.0);
+        if theta < 1e-8 {
+            return sin_axis;
+        }
+        if PI - theta < 1e-6 {
+            let b = (rotation + Matrix3::identity()) * 0.5;
+            let k = (0..3)
+                .max_by(|i, j| b[(*i, *i)].total_cmp(&b[(*j, *j)]))
+                .expect("three diagonal entries");
+            let mut axis = b.column(k).into_owned();
+            axis /= axis.norm();
+            if sin_axis.dot(&axis) < 0.0 {
+                axis = -axis;
+            }
+            return axis * theta;
+        }
+        sin_axis * (theta / sin_theta)
+    }
+
+    pub fn exp_static(rotation: &Matrix3<f64>, omega: &Vector3<f64>) -> Matrix3<f64> {
+        rotation * rodrigues(omega)
+    }
+
+    pub fn log_static(rotation: &Matrix3<f64>, other: &Matrix3<f64>) -> Vector3<f64> {
+        Self::rotation_log(&(rotation.transpose() * other))
+    }
+
+    pub fn distance_static(rotation: &Matrix3<f64>, other: &Matrix3<f64>) -> f64 {
+        Self::log_static(rotation, other).norm()
+    }
+
+    fn check_len(&self, len: usize) -> Result<()> {
+        if len != 9 {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: 9,
+                got: len,
+            });
+        }
+        Ok(())
+    }
+
+    fn body_velocity(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<Vector3<f64>> {
+        let r = Self::to_matrix(point)?;
+        let v = Self::to_matrix(tangent.components.as_slice())?;
+        Ok(Self::vee(&(r.transpose() * v)))
+    }
+
+    fn spatial_tangent(&self, point: &[f64], omega: &Vector3<f64>) -> Result<TangentVector<f64>> {
+        let r = Self::to_matrix(point)?;
+        Ok(TangentVector::new(DVector::from_vec(Self::from_matrix(
+            &(r * Self::hat(omega)),
+        ))))
+    }
+}
+
+impl Default for SO3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Manifold for SO3 {
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        3
+    }
+
+    fn ambient_dim(&self) -> usize {
+        9
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.check_len(point.len())?;
+        let r = Self::to_matrix(point)?;
+        let error = (r.transpose() * r - Matrix3::identity()).norm();
+        if error.is_nan() || error >= tolerance(1e-8) {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: format!("Matrix is not orthogonal, |RᵀR - I| = {}", error),
+            });
+        }
+        if r.determinant() <= 0.0 {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: "Matrix has negative determinant".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.check_point(point)?;
+        self.check_len(tangent.dim())?;
+        let r = Self::to_matrix(point)?;
+        let omega = r.transpose() * Self::to_matrix(tangent.components.as_slice())?;
+        let asymmetry = (omega + omega.transpose()).norm();
+        if asymmetry > tolerance(1e-8) {
+            return Err(ManifoldError::InvalidTangentVector {
+                reason: format!("RᵀV is not skew-symmetric, |RᵀV + VᵀR| = {}", asymmetry),
+            });
+        }
+        Ok(())
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.check_len(point.len())?;
+        let svd = Self::to_matrix(point)?.svd(true, true);
+        let (Some(u), Some(v_t)) = (svd.u, svd.v_t) else {
+            return Err(ManifoldError::LinearAlgebraError(
+                "SVD failed while projecting to SO(3)".to_string(),
+            ));
+        };
+        let mut correction = Matrix3::identity();
+        if (u * v_t).determinant() < 0.0 {
+            correction[(2, 2)] = -1.0;
+        }
+        Ok(Self::from_matrix(&(u * correction * v_t)))
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.check_len(point.len())?;
+        self.check_len(vector.dim())?;
+        self.spatial_tangent(point, &self.body_velocity(point, vector)?)
+    }
+
+    fn euclidean_to_riemannian_gradient(
+        &self,
+        point: &[Self::Scalar],
+        euclidean_gradient: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        Ok(self.project_to_tangent_space(point, euclidean_gradient)? * 2.0)
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        validate_tangent(self, point, tangent)?;
+        let r = Self::to_matrix(point)?;
+        Ok(Self::from_matrix(
+            &(r * rodrigues(&self.body_velocity(point, tangent)?)),
+        ))
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        validate_point(self, point)?;
+        validate_point(self, other)?;
+        let relative = Self::to_matrix(point)?.transpose() * Self::to_matrix(other)?;
+        self.spatial_tangent(point, &Self::rotation_log(&relative))
+    }
+
+    fn inner_product(
+        &self,
+        _point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        Ok(0.5 * v1.dot(v2))
+    }
+
+    fn log_volume_change(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Option<Self::Scalar> {
+        let theta = self.norm(point, tangent).ok()?;
+        if theta < 1e-10 {
+            return Some(0.0);
+        }
+        if theta >= PI {
+            return None;
+        }
+        Some((2.0 * (1.0 - theta.cos()) / (theta * theta)).ln())
+    }
+}
+
+impl LieGroup for SO3 {
+    fn group_identity(&self) -> Vec<Self::Scalar> {
+        Self::identity()
+    }
+
+    fn compose(&self, a: &[Self::Scalar], b: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.check_len(a.len())?;
+        self.check_len(b.len())?;
+        Ok(Self::from_matrix(
+            &(Self::to_matrix(a)? * Self::to_matrix(b)?),
+        ))
+    }
+
+    fn inverse(&self, a: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.check_len(a.len())?;
+        Ok(Self::from_matrix(&Self::to_matrix(a)?.transpose()))
+    }
+
+    fn group_exp(&self, algebra: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        if algebra.len() != 3 {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: 3,
+                got: algebra.len(),
+            });
+        }
+        Ok(Self::from_matrix(&rodrigues(&Vector3::from_column_slice(
+            algebra,
+        ))))
+    }
+
+    fn group_log(&self, element: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        validate_point(self, element)?;
+        Ok(Self::rotation_log(&Self::to_matrix(element)?)
+            .as_slice()
+            .to_vec())
+    }
+}
+
+pub(crate) fn rodrigues(omega: &Vector3<f64>) -> Matrix3<f64> {
+    let theta = omega.norm();
+    let k = SO3::hat(omega);
+    if theta < 1e-8 {
+        return Matrix3::identity() + k + k * k * 0.5;
+    }
+    Matrix3::identity()
+        + k * (theta.sin() / theta)
+        + k * k * ((1.0 - theta.cos()) / (theta * theta))
+}
+
+// Rotation matrices at angle θ ∈ [0, π] are 2√2 sin(θ/2) apart in the
+// Frobenius norm, so chord / √2 ≤ θ ≤ π / (2√2) · chord.
+impl DistanceSurrogate for SO3 {
+    fn surrogate_distance(&self, a: &[f64], b: &[f64]) -> Result<f64> {
+        self.check_len(a.len())?;
+        chordal_distance(a, b)
+    }
+
+    fn distance_lower_bound(&self, surrogate: f64) -> f64 {
+        surrogate / SQRT_2
+    }
+
+    fn distance_upper_bound(&self, surrogate: f64) -> f64 {
+        PI / (2.0 * SQRT_2) * surrogate
+    }
+}
+
+impl MatrixManifold for SO3 {
+    fn matrix_shape(&self) -> (usize, usize) {
+        (3, 3)
+    }
+
+    fn matrix_summary(&self, matrix: &DMatrix<f64>) -> Vec<(&'static str, f64)> {
+        let rotation = matrix.fixed_view::<3, 3>(0, 0).into_owned();
+        alloc::vec![("rotation angle", Self::rotation_log(&rotation).norm())]
+    }
+}
+
+impl PointEquivalence for SO3 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_so3_matrix_conversions() {
+        let so3 = SO3::new();
+        let rotation = SO3::from_axis_angle(&[0.0, 0.0, 1.0], 0.4);
+        let matrix = so3.matrix_from_point(&rotation).unwrap();
+        assert_eq!(
+            matrix.fixed_view::<3, 3>(0, 0),
+            SO3::to_matrix(&rotation).unwrap()
+        );
+        assert_eq!(so3.point_from_matrix(&matrix).unwrap(), rotation);
+        assert!(matches!(
+            SO3::to_matrix(&rotation[..8]),
+            Err(ManifoldError::DimensionMismatch {
+                expected: 9,
+                got: 8
+            })
+        ));
+        assert!(so3.point_from_matrix(&(matrix * 2.0)).is_err());
+
+        let shown = so3.display_point(&rotation).unwrap().to_string();
+        assert!(shown.starts_with("[  0.9211 -0.3894  0.0000 ]\n"));
+        assert!(shown.ends_with("[  0.0000  0.0000  1.0000 ]\nrotation angle = 0.4000"));
+    }
+
+    #[test]
+    fn test_so3_check_point() {
+        let so3 = SO3::new();
+        assert!(so3.check_point(&SO3::identity()).is_ok());
+        assert!(so3
+            .check_point(&SO3::from_axis_angle(&[1.0, 2.0, 3.0], 0.7))
+            .is_ok());
+        assert!(so3
+            .check_point(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0])
+            .is_err());
+        assert!(so3.check_point(&[1.0, 0.0, 0.0]).is_err());
+    }
+
+    #[test]
+    fn test_so3_distance_is_rotation_angle() {
+        let so3 = SO3::new();
+        let a = SO3::from_axis_angle(&[0.0, 0.0, 1.0], 0.3);
+        let b = SO3::from_axis_angle(&[0.0, 0.0, 1.0], 1.2);
+        assert_relative_eq!(so3.distance(&a, &b).unwrap(), 0.9, epsilon = 1e-10);
+
+        let half_turn = SO3::from_axis_angle(&[1.0, 1.0, 0.0], PI);
+        assert_relative_eq!(
+            so3.distance(&SO3::identity(), &half_turn).unwrap(),
+            PI,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_so3_exp_log_roundtrip() {
+        let so3 = SO3::new();
+        let point = SO3::from_axis_angle(&[0.2, -0.5, 1.0], 0.8);
+        let other = SO3::from_axis_angle(&[1.0, 0.3, -0.2], 1.1);
+        let tangent = so3.log(&point, &other).unwrap();
+        assert!(so3.check_tangent_vector(&point, &tangent).is_ok());
+
+        let recovered = so3.exp(&point, &tangent).unwrap();
+        for (r, o) in recovered.iter().zip(&other) {
+            assert_relative_eq!(r, o, epsilon = 1e-9);
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_so3_approx_point_after_round_trip() {
+        let so3 = SO3::new();
+        let point = SO3::from_axis_angle(&[0.2, -0.5, 1.0], 0.8);
+        let other = SO3::from_axis_angle(&[1.0, 0.3, -0.2], 1.1);
+        let tangent = so3.log(&point, &other).unwrap();
+        let recovered = so3.exp(&point, &tangent).unwrap();
+        assert_relative_eq!(
+            so3.approx_point(&recovered),
+            so3.approx_point(&other),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_so3_static_operations_match_manifold() {
+        let so3 = SO3::new();
+        let a = SO3::from_axis_angle(&[0.2, -0.5, 1.0], 0.8);
+        let b = SO3::from_axis_angle(&[1.0, 0.3, -0.2], 1.1);
+        let (ra, rb) = (SO3::to_matrix(&a).unwrap(), SO3::to_matrix(&b).unwrap());
+
+        let omega = SO3::log_static(&ra, &rb);
+        assert_relative_eq!(SO3::exp_static(&ra, &omega), rb, epsilon = 1e-9);
+        assert_relative_eq!(
+            SO3::distance_static(&ra, &rb),
+            so3.distance(&a, &b).unwrap(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_so3_projection() {
+        let so3 = SO3::new();
+        let noisy: Vec<f64> = SO3::from_axis_angle(&[0.0, 1.0, 0.0], 0.5)
+            .iter()
+            .enumerate()
+            .map(|(i, x)| x + 0.01 * (i as f64).sin())
+            .collect();
+        let projected = so3.project_to_manifold(&noisy).unwrap();
+        assert!(so3.check_point(&projected).is_ok());
+
+        let reflection = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0];
+        assert!(so3
+            .check_point(&so3.project_to_manifold(&reflection).unwrap())
+            .is_ok());
+    }
+
+    #[test]
+    fn test_so3_group_operations() {
+        let so3 = SO3::new();
+        let a = SO3::from_axis_angle(&[1.0, 0.0, 0.0], 0.4);
+        let b = so3.group_exp(&[0.1, -0.2, 0.3]).unwrap();
+        let ab = so3.compose(&a, &b).unwrap();
+        assert!(so3.check_point(&ab).is_ok());
+
+        let xi = so3.between(&a, &ab).unwrap();
+        assert_relative_eq!(xi[0], 0.1, epsilon = 1e-10);
+        assert_relative_eq!(xi[1], -0.2, epsilon = 1e-10);
+        assert_relative_eq!(xi[2], 0.3, epsilon = 1e-10);
+
+        let identity = so3.compose(&a, &so3.inverse(&a).unwrap()).unwrap();
+        assert_relative_eq!(
+            so3.distance(&identity, &so3.group_identity()).unwrap(),
+            0.0,
+            epsilon = 1e-10
+        );
+        assert_eq!(so3.perturb(&a, &[0.1, -0.2, 0.3]).unwrap(), ab);
+    }
+
+    #[test]
+    fn test_so3_tangent_basis() {
+        let so3 = SO3::new();
+        let point = SO3::from_axis_angle(&[1.0, 0.0, 1.0], 0.4);
+        let basis = so3.tangent_basis(&point).unwrap();
+        assert_eq!(basis.len(), 3);
+        for (i, u) in basis.iter().enumerate() {
+            for (j, v) in basis.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(
+                    so3.inner_product(&point, u, v).unwrap(),
+                    expected,
+                    epsilon = 1e-10
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_chordal_surrogate_brackets_rotation_angle() {
+        let so3 = SO3::new();
+        let identity = SO3::identity();
+        for angle in [0.0, 0.4, 1.7, 3.0] {
+            let rotation = SO3::from_axis_angle(&[1.0, 2.0, -0.5], angle);
+            let chord = so3.surrogate_distance(&identity, &rotation).unwrap();
+            let distance = so3.distance(&identity, &rotation).unwrap();
+            assert_relative_eq!(distance, angle, epsilon = 1e-9);
+            assert!(so3.distance_lower_bound(chord) <= distance + 1e-12);
+            assert!(so3.distance_upper_bound(chord) >= distance - 1e-12);
+        }
+        assert!(so3.surrogate_distance(&[0.0; 4], &[0.0; 4]).is_err());
+    }
+}
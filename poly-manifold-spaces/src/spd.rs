@@ -1,15 +1,104 @@
-use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
-use nalgebra::{DMatrix, DVector};
+use nalgebra::{DMatrix, DVector, Dyn, SymmetricEigen};
+use poly_manifold_core::linalg::{matrix_exp, matrix_log};
+use poly_manifold_core::precision::tolerance;
+use poly_manifold_core::profiling::{record, Counter};
+use poly_manifold_core::validation::{check_dimension, check_finite, validate_point};
+use poly_manifold_core::{
+    AsPoint, CachedManifold, ErrorContext, Manifold, ManifoldError, MatrixManifold,
+    PointEquivalence, Result, ResultExt, TangentVector, Workspace, WorkspaceManifold,
+};
+use std::sync::OnceLock;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SPD {
     pub dimension: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct SPDPoint {
+    values: Vec<f64>,
+    matrix: DMatrix<f64>,
+    cholesky: DMatrix<f64>,
+    inverse_cholesky: OnceLock<DMatrix<f64>>,
+    inverse: OnceLock<DMatrix<f64>>,
+    eigen: OnceLock<SymmetricEigen<f64, Dyn>>,
+}
+
+impl SPDPoint {
+    fn from_factor(values: &[f64], matrix: DMatrix<f64>, cholesky: DMatrix<f64>) -> Self {
+        Self {
+            values: values.to_vec(),
+            matrix,
+            cholesky,
+            inverse_cholesky: OnceLock::new(),
+            inverse: OnceLock::new(),
+            eigen: OnceLock::new(),
+        }
+    }
+
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    pub fn matrix(&self) -> &DMatrix<f64> {
+        &self.matrix
+    }
+
+    pub fn cholesky(&self) -> &DMatrix<f64> {
+        &self.cholesky
+    }
+
+    pub fn inverse_cholesky(&self) -> &DMatrix<f64> {
+        self.inverse_cholesky.get_or_init(|| {
+            let n = self.cholesky.nrows();
+            self.cholesky
+                .solve_lower_triangular(&DMatrix::identity(n, n))
+                .expect("Cholesky factor has a positive diagonal")
+        })
+    }
+
+    pub fn inverse(&self) -> &DMatrix<f64> {
+        self.inverse.get_or_init(|| {
+            let l_inv = self.inverse_cholesky();
+            l_inv.transpose() * l_inv
+        })
+    }
+
+    pub fn eigen(&self) -> &SymmetricEigen<f64, Dyn> {
+        self.eigen.get_or_init(|| {
+            record(Counter::Eigendecomposition);
+            self.matrix.clone().symmetric_eigen()
+        })
+    }
+}
+
+impl AsPoint<f64> for SPDPoint {
+    fn as_point(&self) -> &[f64] {
+        &self.values
+    }
+}
+
 impl SPD {
     pub fn new(dimension: usize) -> Self {
         Self { dimension }
     }
 
+    pub fn try_new(dimension: usize) -> Result<Self> {
+        check_dimension("SPD", "dimension", dimension)?;
+        Ok(Self::new(dimension))
+    }
+
+    pub fn from_vec_len(len: usize) -> Result<Self> {
+        let dimension = len.isqrt();
+        if dimension * dimension != len {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "SPD points have n² entries, but {len} is not a perfect square"
+            )));
+        }
+        Self::try_new(dimension)
+    }
+
     fn vec_to_matrix(&self, vec: &[f64]) -> DMatrix<f64> {
         assert_eq!(vec.len(), self.dimension * self.dimension);
         DMatrix::from_row_slice(self.dimension, self.dimension, vec)
@@ -22,7 +111,7 @@ impl SPD {
     fn is_symmetric(&self, mat: &DMatrix<f64>) -> bool {
         for i in 0..self.dimension {
             for j in (i + 1)..self.dimension {
-                if (mat[(i, j)] - mat[(j, i)]).abs() > 1e-10 {
+                if (mat[(i, j)] - mat[(j, i)]).abs() > tolerance(1e-10) {
                     return false;
                 }
             }
@@ -30,19 +119,28 @@ impl SPD {
         true
     }
 
-    fn is_positive_definite(&self, mat: &DMatrix<f64>) -> bool {
-        mat.clone().cholesky().is_some()
+    fn is_symmetric_slice(&self, values: &[f64]) -> bool {
+        let n = self.dimension;
+        (0..n).all(|i| {
+            ((i + 1)..n).all(|j| (values[i * n + j] - values[j * n + i]).abs() <= tolerance(1e-10))
+        })
     }
-}
 
-impl Manifold for SPD {
-    type Scalar = f64;
+    pub fn point(&self, values: &[f64]) -> Result<SPDPoint> {
+        let (matrix, cholesky) = self.factorize(values)?;
+        Ok(SPDPoint::from_factor(values, matrix, cholesky))
+    }
 
-    fn dim(&self) -> usize {
-        self.dimension * (self.dimension + 1) / 2
+    fn point_unchecked(&self, values: &[f64]) -> Result<SPDPoint> {
+        let matrix = self.vec_to_matrix(values);
+        record(Counter::Cholesky);
+        let cholesky = matrix.clone().cholesky().ok_or_else(|| {
+            ManifoldError::LinearAlgebraError("Cholesky decomposition failed".to_string())
+        })?;
+        Ok(SPDPoint::from_factor(values, matrix, cholesky.l()))
     }
 
-    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+    fn factorize(&self, point: &[f64]) -> Result<(DMatrix<f64>, DMatrix<f64>)> {
         if point.len() != self.dimension * self.dimension {
             return Err(ManifoldError::DimensionMismatch {
                 expected: self.dimension * self.dimension,
@@ -58,30 +156,25 @@ impl Manifold for SPD {
             });
         }
 
-        if !self.is_positive_definite(&mat) {
-            return Err(ManifoldError::PointNotOnManifold {
+        record(Counter::Cholesky);
+        match mat.clone().cholesky() {
+            Some(chol) => Ok((mat, chol.l())),
+            None => Err(ManifoldError::PointNotOnManifold {
                 reason: "Matrix is not positive definite".to_string(),
-            });
+            }),
         }
-
-        Ok(())
     }
 
-    fn check_tangent_vector(
-        &self,
-        point: &[Self::Scalar],
-        tangent: &TangentVector<Self::Scalar>,
-    ) -> Result<()> {
-        self.check_point(point)?;
+    fn check_tangent_components(&self, tangent: &TangentVector<f64>) -> Result<()> {
         if tangent.dim() != self.dimension * self.dimension {
             return Err(ManifoldError::DimensionMismatch {
                 expected: self.dimension * self.dimension,
                 got: tangent.dim(),
             });
         }
+        check_finite(tangent.components.as_slice(), "Tangent vector")?;
 
-        let tangent_mat = self.vec_to_matrix(tangent.components.as_slice());
-        if !self.is_symmetric(&tangent_mat) {
+        if !self.is_symmetric_slice(tangent.components.as_slice()) {
             return Err(ManifoldError::InvalidTangentVector {
                 reason: "Tangent vector matrix is not symmetric".to_string(),
             });
@@ -90,12 +183,184 @@ impl Manifold for SPD {
         Ok(())
     }
 
+    fn factor_with(&self, point: &[f64], workspace: &mut Workspace) -> Result<DMatrix<f64>> {
+        let n = self.dimension;
+        if point.len() != n * n {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: n * n,
+                got: point.len(),
+            });
+        }
+
+        if !self.is_symmetric_slice(point) {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: "Matrix is not symmetric".to_string(),
+            });
+        }
+
+        let mut mat = workspace.take_matrix(n, n);
+        mat.copy_from_slice(point);
+        record(Counter::Cholesky);
+        match mat.cholesky() {
+            Some(chol) => Ok(chol.unpack()),
+            None => Err(ManifoldError::PointNotOnManifold {
+                reason: "Matrix is not positive definite".to_string(),
+            }),
+        }
+    }
+
+    fn whiten(&self, factor: &DMatrix<f64>, mat: &DMatrix<f64>) -> DMatrix<f64> {
+        let n = self.dimension;
+        let mut out = DMatrix::zeros(n, n);
+        let mut scratch = DMatrix::zeros(n, n);
+        self.whiten_into(factor, mat, &mut out, &mut scratch);
+        out
+    }
+
+    fn whiten_with(
+        &self,
+        factor: &DMatrix<f64>,
+        mat: &DMatrix<f64>,
+        out: &mut DMatrix<f64>,
+        workspace: &mut Workspace,
+    ) {
+        let n = self.dimension;
+        let mut scratch = workspace.take_matrix(n, n);
+        self.whiten_into(factor, mat, out, &mut scratch);
+        workspace.give_matrix(scratch);
+    }
+
+    fn whiten_into(
+        &self,
+        factor: &DMatrix<f64>,
+        mat: &DMatrix<f64>,
+        out: &mut DMatrix<f64>,
+        scratch: &mut DMatrix<f64>,
+    ) {
+        solve_congruence(factor, mat, out, scratch);
+        if factor_condition(factor) < REFINEMENT_THRESHOLD {
+            return;
+        }
+        for _ in 0..REFINEMENT_STEPS {
+            let residual = mat - factor * &*out * factor.transpose();
+            let mut spare = DMatrix::zeros(mat.nrows(), mat.ncols());
+            solve_congruence(factor, &residual, scratch, &mut spare);
+            *out += &*scratch;
+        }
+    }
+
+    fn congruence_with(
+        &self,
+        factor: &DMatrix<f64>,
+        middle: &DMatrix<f64>,
+        out: &mut DMatrix<f64>,
+        workspace: &mut Workspace,
+    ) {
+        let n = self.dimension;
+        let mut left = workspace.take_matrix(n, n);
+        let mut factor_t = workspace.take_matrix(n, n);
+        factor.mul_to(middle, &mut left);
+        factor.transpose_to(&mut factor_t);
+        left.mul_to(&factor_t, out);
+        workspace.give_matrix(left);
+        workspace.give_matrix(factor_t);
+    }
+
+    fn trace_inner_product(
+        &self,
+        point: &SPDPoint,
+        v1: &TangentVector<f64>,
+        v2: &TangentVector<f64>,
+    ) -> f64 {
+        let l = point.cholesky();
+        let w1 = self.whiten(l, &self.vec_to_matrix(v1.components.as_slice()));
+        let w2 = self.whiten(l, &self.vec_to_matrix(v2.components.as_slice()));
+        w1.dot(&w2)
+    }
+
+    fn exp_from_factor(&self, point: &SPDPoint, tangent: &TangentVector<f64>) -> Result<Vec<f64>> {
+        let l = point.cholesky();
+        let v_mat = self.vec_to_matrix(tangent.components.as_slice());
+
+        let w = self.whiten(l, &v_mat);
+
+        let w_exp = matrix_exp(&w)?;
+
+        let result = l * w_exp * l.transpose();
+
+        Ok(self.matrix_to_vec(&result))
+    }
+
+    fn log_from_factor(&self, point: &SPDPoint, other: &[f64]) -> Result<TangentVector<f64>> {
+        let l_p = point.cholesky();
+        let q_mat = self.vec_to_matrix(other);
+
+        let w = self.whiten(l_p, &q_mat);
+
+        let w_log = matrix_log(&w)
+            .with_context(|| ErrorContext::new("log").on(format!("SPD({})", self.dimension)))?;
+
+        let v = l_p * w_log * l_p.transpose();
+
+        Ok(TangentVector::new(DVector::from_vec(
+            self.matrix_to_vec(&v),
+        )))
+    }
+}
+
+const REFINEMENT_THRESHOLD: f64 = 1e8;
+const REFINEMENT_STEPS: usize = 2;
+
+fn solve_congruence(
+    factor: &DMatrix<f64>,
+    mat: &DMatrix<f64>,
+    out: &mut DMatrix<f64>,
+    scratch: &mut DMatrix<f64>,
+) {
+    out.copy_from(mat);
+    factor.solve_lower_triangular_mut(out);
+    out.transpose_to(scratch);
+    factor.solve_lower_triangular_mut(scratch);
+    scratch.transpose_to(out);
+    *out += &*scratch;
+    *out *= 0.5;
+}
+
+fn factor_condition(factor: &DMatrix<f64>) -> f64 {
+    let diagonal = factor.diagonal();
+    (diagonal.max() / diagonal.min()).powi(2)
+}
+
+impl Manifold for SPD {
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        self.dimension * (self.dimension + 1) / 2
+    }
+
+    fn ambient_dim(&self) -> usize {
+        self.dimension * self.dimension
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.factorize(point).map(|_| ())
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.check_point(point)?;
+        self.check_tangent_components(tangent)
+    }
+
     fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
         let mut mat = self.vec_to_matrix(point);
 
         mat = (mat.clone() + mat.transpose()) * 0.5;
 
-        let epsilon = 1e-10;
+        let epsilon = tolerance(1e-10);
         for i in 0..self.dimension {
             mat[(i, i)] = mat[(i, i)].max(epsilon);
         }
@@ -119,27 +384,7 @@ impl Manifold for SPD {
         point: &[Self::Scalar],
         tangent: &TangentVector<Self::Scalar>,
     ) -> Result<Vec<Self::Scalar>> {
-        self.check_tangent_vector(point, tangent)?;
-
-        let p_mat = self.vec_to_matrix(point);
-        let v_mat = self.vec_to_matrix(tangent.components.as_slice());
-
-        let chol = p_mat.cholesky().ok_or_else(|| {
-            ManifoldError::LinearAlgebraError("Cholesky decomposition failed".to_string())
-        })?;
-        let l = chol.l();
-
-        let l_inv = l.clone().try_inverse().ok_or_else(|| {
-            ManifoldError::LinearAlgebraError("Matrix inversion failed".to_string())
-        })?;
-
-        let w = &l_inv * &v_mat * l_inv.transpose();
-
-        let w_exp = matrix_exponential(&w);
-
-        let result = &l * w_exp * l.transpose();
-
-        Ok(self.matrix_to_vec(&result))
+        self.exp_cached(&self.point(point)?, tangent)
     }
 
     fn log(
@@ -147,96 +392,317 @@ impl Manifold for SPD {
         point: &[Self::Scalar],
         other: &[Self::Scalar],
     ) -> Result<TangentVector<Self::Scalar>> {
-        self.check_point(point)?;
-        self.check_point(other)?;
+        self.log_cached(&self.point(point)?, other)
+    }
 
-        let p_mat = self.vec_to_matrix(point);
-        let q_mat = self.vec_to_matrix(other);
+    fn exp_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.exp_from_factor(&self.point_unchecked(point)?, tangent)
+    }
 
-        let chol_p = p_mat.cholesky().ok_or_else(|| {
-            ManifoldError::LinearAlgebraError("Cholesky decomposition failed for point".to_string())
-        })?;
-        let l_p = chol_p.l();
+    fn log_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.log_from_factor(&self.point_unchecked(point)?, other)
+    }
 
-        let l_p_inv = l_p.clone().try_inverse().ok_or_else(|| {
-            ManifoldError::LinearAlgebraError("Matrix inversion failed".to_string())
-        })?;
+    fn inner_product(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        self.check_tangent_components(v1)?;
+        self.check_tangent_components(v2)?;
+        self.inner_product_unchecked(point, v1, v2)
+    }
+
+    fn inner_product_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        let point = self.point_unchecked(point)?;
+        Ok(self.trace_inner_product(&point, v1, v2))
+    }
+}
 
-        let w = &l_p_inv * &q_mat * l_p_inv.transpose();
+impl CachedManifold for SPD {
+    type CachedPoint = SPDPoint;
 
-        let w_log = matrix_logarithm(&w)?;
+    fn cache_point(&self, point: &[Self::Scalar]) -> Result<Self::CachedPoint> {
+        self.point(point)
+    }
 
-        let v = &l_p * w_log * l_p.transpose();
+    fn exp_cached(
+        &self,
+        point: &Self::CachedPoint,
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.check_tangent_components(tangent)?;
+        self.exp_from_factor(point, tangent)
+    }
 
-        Ok(TangentVector::new(DVector::from_vec(
-            self.matrix_to_vec(&v),
-        )))
+    fn log_cached(
+        &self,
+        point: &Self::CachedPoint,
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        validate_point(self, other)?;
+        self.log_from_factor(point, other)
     }
 
-    fn inner_product(
+    fn inner_product_cached(
         &self,
-        point: &[Self::Scalar],
+        point: &Self::CachedPoint,
         v1: &TangentVector<Self::Scalar>,
         v2: &TangentVector<Self::Scalar>,
     ) -> Result<Self::Scalar> {
-        let p_mat = self.vec_to_matrix(point);
-        let p_inv = p_mat.clone().try_inverse().ok_or_else(|| {
-            ManifoldError::LinearAlgebraError("Matrix inversion failed".to_string())
-        })?;
-
-        let v1_mat = self.vec_to_matrix(v1.components.as_slice());
-        let v2_mat = self.vec_to_matrix(v2.components.as_slice());
+        self.check_tangent_components(v1)?;
+        self.check_tangent_components(v2)?;
+        Ok(self.trace_inner_product(point, v1, v2))
+    }
 
-        let tmp = &p_inv * &v1_mat * &p_inv * v2_mat;
-        Ok(tmp.trace())
+    fn distance_cached(
+        &self,
+        a: &Self::CachedPoint,
+        b: &Self::CachedPoint,
+    ) -> Result<Self::Scalar> {
+        let w = self.whiten(a.cholesky(), b.matrix());
+        record(Counter::Eigendecomposition);
+        let eigenvalues = w.symmetric_eigenvalues();
+        if eigenvalues.iter().any(|lambda| *lambda <= 0.0) {
+            return Err(ManifoldError::LinearAlgebraError(
+                "Relative matrix is not positive definite".to_string(),
+            ));
+        }
+        Ok(eigenvalues
+            .iter()
+            .map(|lambda| lambda.ln().powi(2))
+            .sum::<f64>()
+            .sqrt())
     }
 }
 
-fn matrix_exponential(mat: &DMatrix<f64>) -> DMatrix<f64> {
-    let n = mat.nrows();
-    let mut result = DMatrix::identity(n, n);
-    let mut term = DMatrix::identity(n, n);
+impl WorkspaceManifold for SPD {
+    fn exp_with(
+        &self,
+        point: &[f64],
+        tangent: &TangentVector<f64>,
+        out: &mut Vec<f64>,
+        workspace: &mut Workspace,
+    ) -> Result<()> {
+        self.check_tangent_components(tangent)?;
+        let n = self.dimension;
+        let l = self.factor_with(point, workspace)?;
+
+        let mut v = workspace.take_matrix(n, n);
+        let mut w = workspace.take_matrix(n, n);
+        v.copy_from_slice(tangent.components.as_slice());
+        self.whiten_with(&l, &v, &mut w, workspace);
 
-    for k in 1..20 {
-        term = &term * mat / (k as f64);
-        result += &term;
+        let w_exp = matrix_exp(&w)?;
+        self.congruence_with(&l, &w_exp, &mut v, workspace);
 
-        if term.iter().all(|&x| x.abs() < 1e-12) {
-            break;
-        }
+        out.clear();
+        out.extend_from_slice(v.as_slice());
+
+        workspace.give_matrix(l);
+        workspace.give_matrix(v);
+        workspace.give_matrix(w);
+        Ok(())
     }
 
-    result
-}
+    fn log_with(
+        &self,
+        point: &[f64],
+        other: &[f64],
+        out: &mut TangentVector<f64>,
+        workspace: &mut Workspace,
+    ) -> Result<()> {
+        let n = self.dimension;
+        let l = self.factor_with(point, workspace)?;
+        let other_factor = self.factor_with(other, workspace)?;
+        workspace.give_matrix(other_factor);
+
+        let mut q = workspace.take_matrix(n, n);
+        let mut w = workspace.take_matrix(n, n);
+        q.copy_from_slice(other);
+        self.whiten_with(&l, &q, &mut w, workspace);
 
-fn matrix_logarithm(mat: &DMatrix<f64>) -> Result<DMatrix<f64>> {
-    let n = mat.nrows();
-    let identity = DMatrix::identity(n, n);
+        let w_log = matrix_log(&w);
+        if let Ok(w_log) = &w_log {
+            self.congruence_with(&l, w_log, &mut q, workspace);
+            if out.components.len() != n * n {
+                out.components = DVector::zeros(n * n);
+            }
+            out.components.copy_from_slice(q.as_slice());
+        }
 
-    let a = mat - &identity;
+        workspace.give_matrix(l);
+        workspace.give_matrix(q);
+        workspace.give_matrix(w);
+        w_log.map(|_| ())
+    }
 
-    let mut result = DMatrix::zeros(n, n);
-    let mut term = a.clone();
+    fn inner_product_with(
+        &self,
+        point: &[f64],
+        v1: &TangentVector<f64>,
+        v2: &TangentVector<f64>,
+        workspace: &mut Workspace,
+    ) -> Result<f64> {
+        self.check_tangent_components(v1)?;
+        self.check_tangent_components(v2)?;
+        let n = self.dimension;
+        let l = self.factor_with(point, workspace)?;
 
-    for k in 1..50 {
-        let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
-        result += &term * (sign / k as f64);
+        let mut v = workspace.take_matrix(n, n);
+        let mut w1 = workspace.take_matrix(n, n);
+        let mut w2 = workspace.take_matrix(n, n);
+        v.copy_from_slice(v1.components.as_slice());
+        self.whiten_with(&l, &v, &mut w1, workspace);
+        v.copy_from_slice(v2.components.as_slice());
+        self.whiten_with(&l, &v, &mut w2, workspace);
+        let result = w1.dot(&w2);
 
-        term = &term * &a;
+        workspace.give_matrix(l);
+        workspace.give_matrix(v);
+        workspace.give_matrix(w1);
+        workspace.give_matrix(w2);
+        Ok(result)
+    }
+}
 
-        if term.iter().all(|&x| x.abs() < 1e-12) {
-            break;
-        }
+impl MatrixManifold for SPD {
+    fn matrix_shape(&self) -> (usize, usize) {
+        (self.dimension, self.dimension)
     }
 
-    Ok(result)
+    fn matrix_summary(&self, matrix: &DMatrix<f64>) -> Vec<(&'static str, f64)> {
+        let eigenvalues = matrix.clone().symmetric_eigen().eigenvalues;
+        let (min, max) = (eigenvalues.min(), eigenvalues.max());
+        vec![
+            ("min eigenvalue", min),
+            ("max eigenvalue", max),
+            ("condition number", max / min),
+        ]
+    }
 }
 
+impl PointEquivalence for SPD {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
 
+    #[test]
+    fn test_spd_standard_traits() {
+        use std::collections::HashMap;
+
+        let mut names = HashMap::new();
+        names.insert(SPD::new(3), "diffusion tensor");
+        let spd = SPD::new(3);
+        let copy = spd;
+        assert_eq!(names[&copy], "diffusion tensor");
+        assert_eq!(format!("{spd:?}"), "SPD { dimension: 3 }");
+    }
+
+    #[test]
+    fn test_spd_try_new_and_non_finite_inputs() {
+        assert!(SPD::try_new(0).is_err());
+        let spd = SPD::try_new(2).unwrap();
+        let identity = [1.0, 0.0, 0.0, 1.0];
+        assert!(matches!(
+            spd.log(&identity, &[1.0, 0.0, 0.0, f64::INFINITY]),
+            Err(ManifoldError::NumericalError(_))
+        ));
+
+        let finite = TangentVector::from(vec![1.0, 0.0, 0.0, 1.0]);
+        for bad in [f64::INFINITY, f64::NAN] {
+            let tangent = TangentVector::from(vec![bad, 0.0, 0.0, 1.0]);
+            assert!(matches!(
+                spd.exp(&identity, &tangent),
+                Err(ManifoldError::NumericalError(_))
+            ));
+            assert!(matches!(
+                spd.inner_product(&identity, &tangent, &finite),
+                Err(ManifoldError::NumericalError(_))
+            ));
+            assert!(matches!(
+                spd.inner_product(&identity, &finite, &tangent),
+                Err(ManifoldError::NumericalError(_))
+            ));
+            let cached = spd.cache_point(&identity).unwrap();
+            assert!(matches!(
+                spd.exp_cached(&cached, &tangent),
+                Err(ManifoldError::NumericalError(_))
+            ));
+            assert!(matches!(
+                spd.inner_product_cached(&cached, &tangent, &finite),
+                Err(ManifoldError::NumericalError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_spd_from_vec_len() {
+        let point = [2.0, 0.5, 0.0, 0.5, 1.0, 0.0, 0.0, 0.0, 3.0];
+        let spd = SPD::from_vec_len(point.len()).unwrap();
+        assert_eq!(spd, SPD::new(3));
+        assert!(spd.check_point(&point).is_ok());
+        assert!(matches!(
+            SPD::from_vec_len(8),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        assert!(SPD::from_vec_len(0).is_err());
+    }
+
+    #[test]
+    fn test_spd_matrix_conversions() {
+        let spd = SPD::new(2);
+        let matrix = DMatrix::from_row_slice(2, 2, &[2.0, 0.5, 0.5, 1.0]);
+        let point = spd.point_from_matrix(&matrix).unwrap();
+        assert_eq!(point, vec![2.0, 0.5, 0.5, 1.0]);
+        assert_eq!(spd.matrix_from_point(&point).unwrap(), matrix);
+
+        let not_symmetric = DMatrix::from_row_slice(2, 2, &[1.0, 0.5, 0.3, 1.0]);
+        assert!(matches!(
+            spd.point_from_matrix(&not_symmetric),
+            Err(ManifoldError::PointNotOnManifold { .. })
+        ));
+        assert!(spd.point_from_matrix(&DMatrix::identity(3, 3)).is_err());
+
+        let direction = DMatrix::from_row_slice(2, 2, &[0.1, -0.2, -0.2, 0.3]);
+        let tangent = spd.tangent_from_matrix(&point, &direction).unwrap();
+        assert_eq!(spd.matrix_from_tangent(&tangent).unwrap(), direction);
+        assert!(spd.tangent_from_matrix(&point, &not_symmetric).is_err());
+    }
+
+    #[test]
+    fn test_spd_display_summarizes_eigenvalues() {
+        let spd = SPD::new(2);
+        let shown = spd.display_point(&[4.0, 0.0, 0.0, 1.0]).unwrap();
+        assert_eq!(
+            format!("{shown:.2}"),
+            "[ 4.00 0.00 ]\n[ 0.00 1.00 ]\n\
+             min eigenvalue = 1.00, max eigenvalue = 4.00, condition number = 4.00"
+        );
+
+        let tangent = TangentVector::from(vec![0.5, -1.0, -1.0, 0.0]);
+        assert_eq!(
+            format!("{:.1}", spd.display_tangent(&tangent).unwrap()),
+            "[  0.5 -1.0 ]\n[ -1.0  0.0 ]"
+        );
+    }
+
     #[test]
     fn test_spd_dimension() {
         let spd = SPD::new(3);
@@ -281,6 +747,226 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_spd_exp_large_tangent() {
+        let spd = SPD::new(2);
+        let point = vec![1.0, 0.0, 0.0, 1.0];
+        let tangent = TangentVector::new(DVector::from_vec(vec![8.0, 0.0, 0.0, -6.0]));
+
+        let new_point = spd.exp(&point, &tangent).unwrap();
+        assert!(spd.check_point(&new_point).is_ok());
+        assert_relative_eq!(new_point[0], 8.0f64.exp(), max_relative = 1e-12);
+        assert_relative_eq!(new_point[3], (-6.0f64).exp(), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_spd_exp_log_far_apart() {
+        let spd = SPD::new(3);
+        let point = vec![4.0, 1.0, 0.5, 1.0, 3.0, -0.2, 0.5, -0.2, 0.5];
+        let other = vec![0.1, 0.0, 0.0, 0.0, 20.0, 3.0, 0.0, 3.0, 1.0];
+
+        let tangent = spd.log(&point, &other).unwrap();
+        let recovered = spd.exp(&point, &tangent).unwrap();
+        for i in 0..9 {
+            assert_relative_eq!(recovered[i], other[i], epsilon = 1e-9);
+        }
+
+        let large = TangentVector::new(DVector::from_vec(vec![
+            10.0, 3.0, -2.0, 3.0, -8.0, 1.0, -2.0, 1.0, 5.0,
+        ]));
+        let moved = spd.exp(&point, &large).unwrap();
+        let back = spd.log(&point, &moved).unwrap();
+        for i in 0..9 {
+            assert_relative_eq!(back.components[i], large.components[i], epsilon = 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_spd_cached_operations_match_uncached() {
+        let spd = SPD::new(3);
+        let point = vec![4.0, 1.0, 0.5, 1.0, 3.0, -0.2, 0.5, -0.2, 0.5];
+        let other = vec![2.0, 0.3, 0.0, 0.3, 1.0, 0.1, 0.0, 0.1, 6.0];
+        let tangent = TangentVector::new(DVector::from_vec(vec![
+            0.5, 0.2, -0.1, 0.2, -0.3, 0.4, -0.1, 0.4, 0.1,
+        ]));
+        let cached = spd.cache_point(&point).unwrap();
+
+        let exp = spd.exp(&point, &tangent).unwrap();
+        let exp_cached = spd.exp_cached(&cached, &tangent).unwrap();
+        let log = spd.log(&point, &other).unwrap();
+        let log_cached = spd.log_cached(&cached, &other).unwrap();
+        for i in 0..9 {
+            assert_relative_eq!(exp[i], exp_cached[i], epsilon = 1e-12);
+            assert_relative_eq!(log.components[i], log_cached.components[i], epsilon = 1e-12);
+        }
+        assert_relative_eq!(
+            spd.inner_product(&point, &tangent, &log).unwrap(),
+            spd.inner_product_cached(&cached, &tangent, &log).unwrap(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_spd_unchecked_operations_match_checked() {
+        let spd = SPD::new(2);
+        let point = vec![3.0, 1.0, 1.0, 2.0];
+        let other = vec![1.0, -0.5, -0.5, 4.0];
+        let tangent = TangentVector::new(DVector::from_vec(vec![0.4, -0.2, -0.2, 1.5]));
+
+        let exp = spd.exp(&point, &tangent).unwrap();
+        let exp_unchecked = spd.exp_unchecked(&point, &tangent).unwrap();
+        let log = spd.log(&point, &other).unwrap();
+        let log_unchecked = spd.log_unchecked(&point, &other).unwrap();
+        for i in 0..4 {
+            assert_relative_eq!(exp[i], exp_unchecked[i], epsilon = 1e-12);
+            assert_relative_eq!(
+                log.components[i],
+                log_unchecked.components[i],
+                epsilon = 1e-12
+            );
+        }
+        assert!(spd.exp_unchecked(&[-1.0, 0.0, 0.0, 1.0], &tangent).is_err());
+    }
+
+    #[test]
+    fn test_spd_cached_distance_matches_distance() {
+        let spd = SPD::new(3);
+        let p = vec![2.0, 0.3, 0.1, 0.3, 1.5, -0.2, 0.1, -0.2, 1.0];
+        let q = vec![1.0, -0.1, 0.0, -0.1, 3.0, 0.4, 0.0, 0.4, 0.8];
+        let p_cached = spd.point(&p).unwrap();
+        let q_cached = spd.point(&q).unwrap();
+
+        let expected = spd.distance(&p, &q).unwrap();
+        assert_relative_eq!(
+            spd.distance_cached(&p_cached, &q_cached).unwrap(),
+            expected,
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            spd.distance_cached(&q_cached, &p_cached).unwrap(),
+            expected,
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            spd.distance_cached(&p_cached, &p_cached).unwrap(),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_spd_point_as_flexible_input() {
+        use poly_manifold_core::ManifoldExt;
+
+        let spd = SPD::new(2);
+        let point = spd.point(&[2.0, 0.0, 0.0, 2.0]).unwrap();
+        let identity = DMatrix::<f64>::identity(2, 2);
+        assert!(spd.check(&point).is_ok());
+        assert_relative_eq!(
+            spd.distance_between(&point, identity.as_slice()).unwrap(),
+            2.0f64.ln() * 2.0f64.sqrt(),
+            epsilon = 1e-12
+        );
+        let tangent = spd.log_at(identity.as_slice(), &point).unwrap();
+        assert_relative_eq!(
+            spd.exp_at(identity.as_slice(), &tangent)
+                .unwrap()
+                .as_slice(),
+            point.values(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_spd_point_decompositions() {
+        let spd = SPD::new(2);
+        let point = spd.point(&[5.0, 2.0, 2.0, 2.0]).unwrap();
+
+        let identity = point.matrix() * point.inverse();
+        assert_relative_eq!(
+            (identity - DMatrix::identity(2, 2)).norm(),
+            0.0,
+            epsilon = 1e-12
+        );
+        let l = point.cholesky();
+        assert_relative_eq!(
+            (l * l.transpose() - point.matrix()).norm(),
+            0.0,
+            epsilon = 1e-12
+        );
+        let mut eigenvalues: Vec<f64> = point.eigen().eigenvalues.iter().copied().collect();
+        eigenvalues.sort_by(f64::total_cmp);
+        assert_relative_eq!(eigenvalues[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(eigenvalues[1], 6.0, epsilon = 1e-12);
+
+        assert!(spd.point(&[1.0, 2.0, 2.0, 1.0]).is_err());
+        assert!(spd.point(&[1.0, 0.0, 0.0]).is_err());
+    }
+
+    #[test]
+    fn test_spd_workspace_operations_match_allocating() {
+        let spd = SPD::new(3);
+        let p = vec![2.0, 0.3, 0.1, 0.3, 1.5, -0.2, 0.1, -0.2, 1.0];
+        let v1 = TangentVector::new(DVector::from_vec(vec![
+            0.4, 0.1, -0.2, 0.1, -0.3, 0.05, -0.2, 0.05, 0.2,
+        ]));
+        let v2 = TangentVector::new(DVector::from_vec(vec![
+            0.1, 0.0, 0.3, 0.0, 0.2, -0.1, 0.3, -0.1, -0.4,
+        ]));
+        let mut workspace = Workspace::new();
+
+        let mut q = Vec::new();
+        spd.exp_with(&p, &v1, &mut q, &mut workspace).unwrap();
+        let expected_q = spd.exp(&p, &v1).unwrap();
+        for (a, b) in q.iter().zip(expected_q.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-10);
+        }
+
+        let mut log = TangentVector::new(DVector::zeros(0));
+        spd.log_with(&p, &q, &mut log, &mut workspace).unwrap();
+        for (a, b) in log.components.iter().zip(v1.components.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-8);
+        }
+
+        let inner = spd
+            .inner_product_with(&p, &v1, &v2, &mut workspace)
+            .unwrap();
+        assert_relative_eq!(
+            inner,
+            spd.inner_product(&p, &v1, &v2).unwrap(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_spd_workspace_reuses_buffers() {
+        let spd = SPD::new(2);
+        let p = vec![2.0, 0.5, 0.5, 1.0];
+        let v = TangentVector::new(DVector::from_vec(vec![0.1, 0.2, 0.2, -0.1]));
+        let mut workspace = Workspace::new();
+        let mut out = Vec::new();
+
+        spd.exp_with(&p, &v, &mut out, &mut workspace).unwrap();
+        let pooled = workspace.pooled_matrices();
+        for _ in 0..10 {
+            spd.exp_with(&p, &v, &mut out, &mut workspace).unwrap();
+        }
+        assert_eq!(workspace.pooled_matrices(), pooled);
+
+        let asymmetric = vec![2.0, 0.5, 0.0, 1.0];
+        assert!(spd
+            .exp_with(&asymmetric, &v, &mut out, &mut workspace)
+            .is_err());
+        assert!(spd
+            .log_with(
+                &p,
+                &asymmetric,
+                &mut TangentVector::new(DVector::zeros(4)),
+                &mut workspace
+            )
+            .is_err());
+    }
+
     #[test]
     fn test_spd_inner_product() {
         let spd = SPD::new(2);
@@ -291,4 +977,83 @@ mod tests {
         let inner = spd.inner_product(&point, &v1, &v2).unwrap();
         assert_relative_eq!(inner, 0.0, epsilon = 1e-10);
     }
+
+    #[test]
+    fn test_spd_tangent_basis() {
+        let spd = SPD::new(2);
+        let point = vec![2.0, 0.5, 0.5, 1.0];
+        let basis = spd.tangent_basis(&point).unwrap();
+        assert_eq!(basis.len(), 3);
+
+        for (i, u) in basis.iter().enumerate() {
+            assert!(spd.check_tangent_vector(&point, u).is_ok());
+            for (j, v) in basis.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                let inner = spd.inner_product(&point, u, v).unwrap();
+                assert_relative_eq!(inner, expected, epsilon = 1e-10);
+            }
+        }
+    }
+
+    fn ill_conditioned_point() -> Vec<f64> {
+        let (s, c) = 0.3f64.sin_cos();
+        let q = DMatrix::from_row_slice(2, 2, &[c, -s, s, c]);
+        let d = DMatrix::from_diagonal(&nalgebra::DVector::from_vec(vec![1e-6, 1e4]));
+        let p = &q * d * q.transpose();
+        let p = (&p + p.transpose()) * 0.5;
+        p.as_slice().to_vec()
+    }
+
+    #[test]
+    fn test_spd_inner_product_ill_conditioned() {
+        let spd = SPD::new(2);
+        let p = ill_conditioned_point();
+        let v = TangentVector::new(nalgebra::DVector::from_vec(p.clone()));
+        assert_relative_eq!(spd.inner_product(&p, &v, &v).unwrap(), 2.0, epsilon = 1e-8);
+
+        let mut workspace = Workspace::new();
+        let w = TangentVector::new(nalgebra::DVector::from_vec(vec![1.0, 2.0, 2.0, 3.0]));
+        let expected = spd.inner_product(&p, &v, &w).unwrap();
+        let pooled = spd.inner_product_with(&p, &v, &w, &mut workspace).unwrap();
+        assert_relative_eq!(pooled, expected, max_relative = 1e-10);
+    }
+
+    #[test]
+    fn test_spd_exp_log_round_trip_ill_conditioned() {
+        let spd = SPD::new(2);
+        let p = ill_conditioned_point();
+        let q = vec![2.0, 0.5, 0.5, 1.0];
+        let log = spd.log(&p, &q).unwrap();
+        let back = spd.exp(&p, &log).unwrap();
+        for (a, b) in back.iter().zip(q.iter()) {
+            assert_relative_eq!(a, b, max_relative = 1e-5);
+        }
+        assert!(spd
+            .inner_product(&[1.0, 0.0, 0.0, -1.0], &log, &log)
+            .is_err());
+    }
+
+    #[test]
+    fn test_spd_promoted_f32_storage() {
+        use poly_manifold_core::Promoted;
+
+        let spd = SPD::new(2);
+        let promoted = Promoted::new(SPD::new(2));
+        let p = vec![2.0, 0.3, 0.3, 1.0];
+        let q = vec![1.0, -0.2, -0.2, 3.0];
+        let p32: Vec<f32> = p.iter().map(|x| *x as f32).collect();
+        let q32: Vec<f32> = q.iter().map(|x| *x as f32).collect();
+
+        let log = promoted.log(&p32, &q32).unwrap();
+        let expected = spd.log(&p, &q).unwrap();
+        for (a, b) in log.components.iter().zip(expected.components.iter()) {
+            assert_relative_eq!(*a as f64, *b, epsilon = 1e-5);
+        }
+
+        let back = promoted.exp(&p32, &log).unwrap();
+        for (a, b) in back.iter().zip(q32.iter()) {
+            assert_relative_eq!(*a, *b, epsilon = 1e-4);
+        }
+        assert!(promoted.check_point(&[1.0, 0.5, 0.0, 1.0]).is_err());
+    }
 }
@@ -1,27 +1,108 @@
-use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
-use nalgebra::DVector;
-
-pub struct Sphere {
+use alloc::{format, string::ToString, vec::Vec};
+use core::f64::consts::FRAC_PI_2;
+use core::marker::PhantomData;
+use nalgebra::{convert, DVector, DVectorView, DVectorViewMut, RealField};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use poly_manifold_core::batch::batch_len;
+use poly_manifold_core::precision::tolerance;
+use poly_manifold_core::surrogate::chordal_distance;
+use poly_manifold_core::validation::{check_dimension, validate_point, validate_tangent};
+use poly_manifold_core::{
+    BatchManifold, DistanceSurrogate, Manifold, ManifoldError, PointEquivalence, Result,
+    TangentVector, Workspace, WorkspaceManifold,
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere<T = f64> {
     pub dimension: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scalar: PhantomData<T>,
+}
+
+impl<T> Clone for Sphere<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Sphere<T> {}
+
+impl<T> core::fmt::Debug for Sphere<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Sphere")
+            .field("dimension", &self.dimension)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for Sphere<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dimension == other.dimension
+    }
+}
+
+impl<T> Eq for Sphere<T> {}
+
+impl<T> core::hash::Hash for Sphere<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.dimension.hash(state);
+    }
 }
 
 impl Sphere {
     pub fn new(dimension: usize) -> Self {
-        Self { dimension }
+        Self::with_scalar(dimension)
+    }
+
+    pub fn try_new(dimension: usize) -> Result<Self> {
+        check_dimension("Sphere", "dimension", dimension)?;
+        Ok(Self::new(dimension))
+    }
+
+    pub fn from_ambient_dim(ambient_dim: usize) -> Result<Self> {
+        if ambient_dim < 2 {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Sphere requires an ambient dimension of at least 2, got {ambient_dim}"
+            )));
+        }
+        Self::try_new(ambient_dim - 1)
+    }
+}
+
+impl<T: RealField + Copy> Sphere<T> {
+    pub fn with_scalar(dimension: usize) -> Self {
+        Self {
+            dimension,
+            scalar: PhantomData,
+        }
     }
 
     fn embedding_dim(&self) -> usize {
         self.dimension + 1
     }
+
+    fn view<'a>(&self, point: &'a [T]) -> DVectorView<'a, T> {
+        DVectorView::from_slice(point, point.len())
+    }
+
+    fn view_mut<'a>(&self, point: &'a mut [T]) -> DVectorViewMut<'a, T> {
+        let len = point.len();
+        DVectorViewMut::from_slice(point, len)
+    }
 }
 
-impl Manifold for Sphere {
-    type Scalar = f64;
+impl<T: RealField + Copy> Manifold for Sphere<T> {
+    type Scalar = T;
 
     fn dim(&self) -> usize {
         self.dimension
     }
 
+    fn ambient_dim(&self) -> usize {
+        self.embedding_dim()
+    }
+
     fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
         if point.len() != self.embedding_dim() {
             return Err(ManifoldError::DimensionMismatch {
@@ -30,8 +111,8 @@ impl Manifold for Sphere {
             });
         }
 
-        let norm_sq: f64 = point.iter().map(|x| x * x).sum();
-        if (norm_sq - 1.0).abs() > 1e-10 {
+        let norm_sq = self.view(point).norm_squared();
+        if (norm_sq - T::one()).abs() > tolerance(1e-10) {
             return Err(ManifoldError::PointNotOnManifold {
                 reason: format!("Point norm is {} instead of 1.0", norm_sq.sqrt()),
             });
@@ -53,13 +134,9 @@ impl Manifold for Sphere {
             });
         }
 
-        let dot_product: f64 = point
-            .iter()
-            .zip(tangent.components.iter())
-            .map(|(p, t)| p * t)
-            .sum();
+        let dot_product = self.view(point).dot(&tangent.components);
 
-        if dot_product.abs() > 1e-10 {
+        if dot_product.abs() > tolerance(1e-10) {
             return Err(ManifoldError::InvalidTangentVector {
                 reason: format!(
                     "Tangent vector not orthogonal to point, dot product: {}",
@@ -72,13 +149,13 @@ impl Manifold for Sphere {
     }
 
     fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
-        let norm: f64 = point.iter().map(|x| x * x).sum::<f64>().sqrt();
-        if norm < 1e-10 {
+        let norm = self.view(point).norm();
+        if norm < tolerance(1e-10) {
             return Err(ManifoldError::NumericalError(
                 "Cannot project zero vector to sphere".to_string(),
             ));
         }
-        Ok(point.iter().map(|x| x / norm).collect())
+        Ok(self.view(point).unscale(norm).data.into())
     }
 
     fn project_to_tangent_space(
@@ -86,18 +163,13 @@ impl Manifold for Sphere {
         point: &[Self::Scalar],
         vector: &TangentVector<Self::Scalar>,
     ) -> Result<TangentVector<Self::Scalar>> {
-        self.check_point(point)?;
+        validate_point(self, point)?;
 
-        let dot_product: f64 = point
-            .iter()
-            .zip(vector.components.iter())
-            .map(|(p, v)| p * v)
-            .sum();
+        let point = self.view(point);
+        let dot_product = point.dot(&vector.components);
 
         let mut projected = vector.components.clone();
-        for i in 0..self.embedding_dim() {
-            projected[i] -= dot_product * point[i];
-        }
+        projected.axpy(-dot_product, &point, T::one());
 
         Ok(TangentVector::new(projected))
     }
@@ -107,21 +179,69 @@ impl Manifold for Sphere {
         point: &[Self::Scalar],
         tangent: &TangentVector<Self::Scalar>,
     ) -> Result<Vec<Self::Scalar>> {
-        self.check_tangent_vector(point, tangent)?;
+        validate_tangent(self, point, tangent)?;
+        self.exp_unchecked(point, tangent)
+    }
 
+    fn exp_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
         let tangent_norm = tangent.norm();
 
-        if tangent_norm < 1e-10 {
+        if tangent_norm < tolerance(1e-10) {
             return Ok(point.to_vec());
         }
 
-        let mut result = vec![0.0; self.embedding_dim()];
-        for i in 0..self.embedding_dim() {
-            result[i] = point[i] * tangent_norm.cos()
-                + tangent.components[i] * tangent_norm.sin() / tangent_norm;
+        let mut result = &tangent.components * (tangent_norm.sin() / tangent_norm);
+        result.axpy(tangent_norm.cos(), &self.view(point), T::one());
+
+        Ok(result.data.into())
+    }
+
+    fn exp_unchecked_into(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+        out: &mut Vec<Self::Scalar>,
+    ) -> Result<()> {
+        let tangent_norm = tangent.norm();
+
+        out.clear();
+        if tangent_norm < tolerance(1e-10) {
+            out.extend_from_slice(point);
+            return Ok(());
         }
 
-        Ok(result)
+        out.extend_from_slice(point);
+        self.view_mut(out).axpy(
+            tangent_norm.sin() / tangent_norm,
+            &tangent.components,
+            tangent_norm.cos(),
+        );
+        Ok(())
+    }
+
+    fn project_to_tangent_space_in_place(
+        &self,
+        point: &[Self::Scalar],
+        vector: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        validate_point(self, point)?;
+
+        let point = self.view(point);
+        let dot_product = point.dot(&vector.components);
+        vector.components.axpy(-dot_product, &point, T::one());
+        Ok(())
+    }
+
+    fn euclidean_to_riemannian_gradient_in_place(
+        &self,
+        point: &[Self::Scalar],
+        gradient: &mut TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.project_to_tangent_space_in_place(point, gradient)
     }
 
     fn log(
@@ -129,30 +249,37 @@ impl Manifold for Sphere {
         point: &[Self::Scalar],
         other: &[Self::Scalar],
     ) -> Result<TangentVector<Self::Scalar>> {
-        self.check_point(point)?;
-        self.check_point(other)?;
+        validate_point(self, point)?;
+        validate_point(self, other)?;
+        self.log_unchecked(point, other)
+    }
 
-        let dot_product: f64 = point.iter().zip(other.iter()).map(|(p, o)| p * o).sum();
-        let dot_product = dot_product.clamp(-1.0, 1.0);
+    fn log_unchecked(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        let dot_product = self.view(point).dot(&self.view(other));
+        let dot_product = dot_product.clamp(-T::one(), T::one());
 
         let theta = dot_product.acos();
 
-        if theta.abs() < 1e-10 {
+        if theta.abs() < tolerance(1e-10) {
             return Ok(TangentVector::new(DVector::zeros(self.embedding_dim())));
         }
 
         let sin_theta = theta.sin();
-        if sin_theta.abs() < 1e-10 {
+        if sin_theta.abs() < tolerance(1e-10) {
             return Err(ManifoldError::NumericalError(
                 "Points are antipodal, logarithm map is not unique".to_string(),
             ));
         }
 
-        let components: Vec<f64> = (0..self.embedding_dim())
-            .map(|i| (other[i] - point[i] * dot_product) * theta / sin_theta)
-            .collect();
+        let mut components = DVector::from_column_slice(other);
+        components.axpy(-dot_product, &self.view(point), T::one());
+        components *= theta / sin_theta;
 
-        Ok(TangentVector::new(DVector::from_vec(components)))
+        Ok(TangentVector::new(components))
     }
 
     fn inner_product(
@@ -161,16 +288,206 @@ impl Manifold for Sphere {
         v1: &TangentVector<Self::Scalar>,
         v2: &TangentVector<Self::Scalar>,
     ) -> Result<Self::Scalar> {
-        Ok(v1.components.dot(&v2.components))
+        Ok(v1.dot(v2))
+    }
+
+    fn log_volume_change(
+        &self,
+        _point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Option<Self::Scalar> {
+        let r = tangent.norm();
+        if r < tolerance(1e-10) {
+            return Some(T::zero());
+        }
+        if r >= T::pi() {
+            return None;
+        }
+        Some(convert::<f64, T>(self.dimension as f64 - 1.0) * (r.sin() / r).ln())
+    }
+}
+
+impl BatchManifold for Sphere {
+    fn distance_batch(&self, points: &[f64], others: &[f64], out: &mut [f64]) -> Result<()> {
+        let n = self.embedding_dim();
+        batch_len(n, &[points.len(), others.len(), n * out.len()])?;
+        for ((point, other), out) in points
+            .chunks_exact(n)
+            .zip(others.chunks_exact(n))
+            .zip(out.iter_mut())
+        {
+            validate_point(self, point)?;
+            validate_point(self, other)?;
+            *out = self
+                .view(point)
+                .dot(&self.view(other))
+                .clamp(-1.0, 1.0)
+                .acos();
+        }
+        Ok(())
     }
 }
 
+// The chord 2 sin(θ/2) of an arc θ ∈ [0, π] satisfies chord ≤ θ ≤ π/2 · chord.
+impl DistanceSurrogate for Sphere {
+    fn surrogate_distance(&self, a: &[f64], b: &[f64]) -> Result<f64> {
+        if a.len() != self.embedding_dim() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.embedding_dim(),
+                got: a.len(),
+            });
+        }
+        chordal_distance(a, b)
+    }
+
+    fn distance_lower_bound(&self, surrogate: f64) -> f64 {
+        surrogate
+    }
+
+    fn distance_upper_bound(&self, surrogate: f64) -> f64 {
+        FRAC_PI_2 * surrogate
+    }
+}
+
+impl WorkspaceManifold for Sphere {
+    fn exp_with(
+        &self,
+        point: &[f64],
+        tangent: &TangentVector<f64>,
+        out: &mut Vec<f64>,
+        _workspace: &mut Workspace,
+    ) -> Result<()> {
+        validate_tangent(self, point, tangent)?;
+        let tangent_norm = tangent.norm();
+
+        out.clear();
+        if tangent_norm < tolerance(1e-10) {
+            out.extend_from_slice(point);
+            return Ok(());
+        }
+
+        out.extend_from_slice(point);
+        self.view_mut(out).axpy(
+            tangent_norm.sin() / tangent_norm,
+            &tangent.components,
+            tangent_norm.cos(),
+        );
+        Ok(())
+    }
+
+    fn log_with(
+        &self,
+        point: &[f64],
+        other: &[f64],
+        out: &mut TangentVector<f64>,
+        _workspace: &mut Workspace,
+    ) -> Result<()> {
+        validate_point(self, point)?;
+        validate_point(self, other)?;
+
+        let dot_product = self.view(point).dot(&self.view(other)).clamp(-1.0, 1.0);
+        let theta = dot_product.acos();
+
+        if out.components.len() != self.embedding_dim() {
+            out.components = DVector::zeros(self.embedding_dim());
+        }
+
+        if theta.abs() < tolerance(1e-10) {
+            out.components.fill(0.0);
+            return Ok(());
+        }
+
+        let sin_theta = theta.sin();
+        if sin_theta.abs() < tolerance(1e-10) {
+            return Err(ManifoldError::NumericalError(
+                "Points are antipodal, logarithm map is not unique".to_string(),
+            ));
+        }
+
+        out.components.copy_from_slice(other);
+        out.components.axpy(-dot_product, &self.view(point), 1.0);
+        out.components *= theta / sin_theta;
+        Ok(())
+    }
+
+    fn inner_product_with(
+        &self,
+        point: &[f64],
+        v1: &TangentVector<f64>,
+        v2: &TangentVector<f64>,
+        _workspace: &mut Workspace,
+    ) -> Result<f64> {
+        self.inner_product(point, v1, v2)
+    }
+}
+
+impl<T: RealField + Copy> PointEquivalence for Sphere<T> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
     use std::f64::consts::PI;
 
+    #[test]
+    fn test_sphere_standard_traits() {
+        use std::collections::HashSet;
+
+        let spheres: HashSet<Sphere> = [Sphere::new(2), Sphere::new(2), Sphere::new(3)]
+            .into_iter()
+            .collect();
+        assert_eq!(spheres.len(), 2);
+        assert!(spheres.contains(&Sphere::new(3)));
+        let copy = Sphere::<f32>::with_scalar(4);
+        assert_eq!(copy.clone(), copy);
+        assert_eq!(format!("{:?}", Sphere::new(2)), "Sphere { dimension: 2 }");
+    }
+
+    #[test]
+    fn test_sphere_try_new_and_non_finite_inputs() {
+        assert!(Sphere::try_new(0).is_err());
+        let sphere = Sphere::try_new(2).unwrap();
+        let tangent = TangentVector::from(vec![0.0, f64::NAN, 0.0]);
+        assert!(matches!(
+            sphere.exp(&[1.0, 0.0, 0.0], &tangent),
+            Err(ManifoldError::NumericalError(_))
+        ));
+        assert!(matches!(
+            sphere.distance(&[f64::NAN, 0.0, 0.0], &[1.0, 0.0, 0.0]),
+            Err(ManifoldError::NumericalError(_))
+        ));
+    }
+
+    #[test]
+    fn test_sphere_from_ambient_dim() {
+        let point = [0.0, 0.6, 0.8];
+        let sphere = Sphere::from_ambient_dim(point.len()).unwrap();
+        assert_eq!(sphere, Sphere::new(2));
+        assert!(sphere.check_point(&point).is_ok());
+        for ambient_dim in [0, 1] {
+            assert!(matches!(
+                Sphere::from_ambient_dim(ambient_dim),
+                Err(ManifoldError::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_sphere_fluent_construction() {
+        use poly_manifold_core::TypedManifold;
+
+        let sphere = Sphere::new(2);
+        let p = sphere.project_point([3.0, 0.0, 4.0]).unwrap();
+        assert_relative_eq!(p.as_slice(), [0.6, 0.0, 0.8].as_slice(), epsilon = 1e-12);
+        assert!(sphere.point_from([3.0, 0.0, 4.0]).is_err());
+
+        let v = sphere.project_tangent(&p, [1.0, 1.0, 1.0]).unwrap();
+        let radial: f64 = p.iter().zip(v.iter()).map(|(x, y)| x * y).sum();
+        assert_relative_eq!(radial, 0.0, epsilon = 1e-12);
+        assert!(sphere.tangent_at(&p, [1.0, 1.0, 1.0]).is_err());
+        assert!(sphere.tangent_at(&p, [0.0, 1.0, 0.0]).is_ok());
+    }
+
     #[test]
     fn test_sphere_dimension() {
         let sphere = Sphere::new(2);
@@ -185,6 +502,46 @@ mod tests {
         assert!(sphere.check_point(&[0.5, 0.5, 0.5]).is_err());
     }
 
+    #[test]
+    fn test_sphere_tolerance_follows_context() {
+        use poly_manifold_core::{Context, ValidationLevel};
+
+        let drifted = [1.0 + 1e-9, 0.0, 0.0];
+        let sphere = Sphere::new(2);
+        assert!(sphere.check_point(&drifted).is_err());
+
+        let loose = Context::new().with_tolerance_scale(100.0);
+        assert!(loose.scope(|| sphere.check_point(&drifted)).is_ok());
+        let sphere = loose.wrap(sphere).unwrap();
+        assert!(sphere.check_point(&drifted).is_ok());
+        assert!(sphere.inner.check_point(&drifted).is_err());
+
+        let tiny = TangentVector::from(vec![0.0, 1e-9, 0.0]);
+        let mut workspace = Workspace::new();
+        let mut out = Vec::new();
+        sphere
+            .inner
+            .exp_with(&[1.0, 0.0, 0.0], &tiny, &mut out, &mut workspace)
+            .unwrap();
+        assert!(out[1] > 0.0);
+        loose
+            .scope(|| {
+                sphere
+                    .inner
+                    .exp_with(&[1.0, 0.0, 0.0], &tiny, &mut out, &mut workspace)
+            })
+            .unwrap();
+        assert_eq!(out, vec![1.0, 0.0, 0.0]);
+
+        let unchecked = Context::new()
+            .with_validation(ValidationLevel::Off)
+            .wrap(Sphere::new(2))
+            .unwrap();
+        let tangent = TangentVector::from(vec![0.0, 0.1, 0.0]);
+        assert!(unchecked.exp(&[2.0, 0.0, 0.0], &tangent).is_ok());
+        assert!(unchecked.inner.exp(&[2.0, 0.0, 0.0], &tangent).is_err());
+    }
+
     #[test]
     fn test_sphere_project_to_manifold() {
         let sphere = Sphere::new(2);
@@ -247,4 +604,211 @@ mod tests {
         assert_relative_eq!(mid_point[1], sin45, epsilon = 1e-9);
         assert_relative_eq!(mid_point[2], 0.0, epsilon = 1e-9);
     }
+
+    #[test]
+    fn test_sphere_log_volume_change() {
+        let sphere = Sphere::new(2);
+        let point = vec![1.0, 0.0, 0.0];
+        let tangent = TangentVector::new(DVector::from_vec(vec![0.0, PI / 2.0, 0.0]));
+
+        let change = sphere.log_volume_change(&point, &tangent).unwrap();
+        assert_relative_eq!(change, (2.0 / PI).ln(), epsilon = 1e-12);
+        assert_eq!(
+            sphere.log_volume_change(&point, &TangentVector::zero(3)),
+            Some(0.0)
+        );
+
+        let beyond_cut_locus = TangentVector::new(DVector::from_vec(vec![0.0, PI, 0.0]));
+        assert!(sphere
+            .log_volume_change(&point, &beyond_cut_locus)
+            .is_none());
+    }
+
+    #[test]
+    fn test_sphere_unchecked_operations_skip_validation() {
+        let sphere = Sphere::new(2);
+        let point = vec![0.6, 0.8, 0.0];
+        let other = vec![0.0, 0.6, 0.8];
+        let tangent = TangentVector::new(DVector::from_vec(vec![-0.4, 0.3, 0.5]));
+
+        let exp = sphere.exp(&point, &tangent).unwrap();
+        let exp_unchecked = sphere.exp_unchecked(&point, &tangent).unwrap();
+        let log = sphere.log(&point, &other).unwrap();
+        let log_unchecked = sphere.log_unchecked(&point, &other).unwrap();
+        for i in 0..3 {
+            assert_relative_eq!(exp[i], exp_unchecked[i], epsilon = 1e-15);
+            assert_relative_eq!(
+                log.components[i],
+                log_unchecked.components[i],
+                epsilon = 1e-15
+            );
+        }
+
+        let drifted = vec![0.6, 0.8, 1e-6];
+        assert!(sphere.exp(&drifted, &tangent).is_err());
+        assert!(sphere.exp_unchecked(&drifted, &tangent).is_ok());
+    }
+
+    #[test]
+    fn test_sphere_high_dimensional_exp_log() {
+        let sphere = Sphere::new(127);
+        let raw: Vec<f64> = (0..128)
+            .map(|i| ((i * 7 % 13) as f64 - 6.0) / 10.0)
+            .collect();
+        let point = sphere.project_to_manifold(&raw).unwrap();
+        let direction: Vec<f64> = (0..128).map(|i| (i as f64 * 0.37).sin()).collect();
+        let tangent = sphere
+            .project_to_tangent_space(&point, &TangentVector::new(DVector::from_vec(direction)))
+            .unwrap();
+        let tangent = tangent.clone() * (1.2 / tangent.norm());
+
+        let moved = sphere.exp(&point, &tangent).unwrap();
+        assert!(sphere.check_point(&moved).is_ok());
+        assert_relative_eq!(
+            sphere.distance(&point, &moved).unwrap(),
+            1.2,
+            epsilon = 1e-10
+        );
+
+        let recovered = sphere.log(&point, &moved).unwrap();
+        assert_relative_eq!(
+            (recovered.components - tangent.components).norm(),
+            0.0,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_sphere_workspace_operations_match_allocating() {
+        let sphere = Sphere::new(2);
+        let p = vec![1.0, 0.0, 0.0];
+        let q = vec![0.0, 0.6, 0.8];
+        let mut workspace = Workspace::new();
+
+        let mut log = TangentVector::new(DVector::zeros(0));
+        sphere.log_with(&p, &q, &mut log, &mut workspace).unwrap();
+        let expected = sphere.log(&p, &q).unwrap();
+        for (a, b) in log.components.iter().zip(expected.components.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-12);
+        }
+
+        let mut out = Vec::new();
+        sphere.exp_with(&p, &log, &mut out, &mut workspace).unwrap();
+        for (a, b) in out.iter().zip(q.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-10);
+        }
+
+        assert!(sphere
+            .log_with(&p, &[-1.0, 0.0, 0.0], &mut log, &mut workspace)
+            .is_err());
+    }
+
+    #[test]
+    fn test_sphere_f32_exp_log() {
+        let sphere = Sphere::<f32>::with_scalar(2);
+        let point = vec![1.0f32, 0.0, 0.0];
+        let tangent = TangentVector::new(DVector::from_vec(vec![0.0f32, 0.3, 0.4]));
+
+        let new_point = sphere.exp(&point, &tangent).unwrap();
+        assert!(sphere.check_point(&new_point).is_ok());
+
+        let recovered = sphere.log(&point, &new_point).unwrap();
+        for (a, b) in recovered.components.iter().zip(tangent.components.iter()) {
+            assert_relative_eq!(*a, *b, epsilon = 1e-5);
+        }
+        assert_relative_eq!(
+            sphere.distance(&point, &new_point).unwrap(),
+            0.5,
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_sphere_batch_operations_match_pointwise() {
+        let sphere = Sphere::new(2);
+        let points = [1.0, 0.0, 0.0, 0.0, 0.6, 0.8];
+        let tangents = [0.0, 0.3, -0.4, 0.5, 0.0, 0.0];
+
+        let mut moved = [0.0; 6];
+        sphere.exp_batch(&points, &tangents, &mut moved).unwrap();
+        let mut logs = [0.0; 6];
+        sphere.log_batch(&points, &moved, &mut logs).unwrap();
+        let mut distances = [0.0; 2];
+        sphere
+            .distance_batch(&points, &moved, &mut distances)
+            .unwrap();
+
+        for (k, distance) in distances.iter().enumerate() {
+            let range = 3 * k..3 * k + 3;
+            let tangent = TangentVector::new(DVector::from_column_slice(&tangents[range.clone()]));
+            let expected = sphere.exp(&points[range.clone()], &tangent).unwrap();
+            assert_relative_eq!(&moved[range.clone()], expected.as_slice(), epsilon = 1e-14);
+            assert_relative_eq!(&logs[range.clone()], &tangents[range], epsilon = 1e-10);
+            assert_relative_eq!(*distance, 0.5, epsilon = 1e-10);
+        }
+
+        let gradients = [0.0, 0.0, 1.0, 1.0, 1.0, 1.0];
+        let mut stepped = [0.0; 6];
+        sphere
+            .sgd_step_batch(&points, &gradients, 0.1, &mut stepped)
+            .unwrap();
+        for k in 0..2 {
+            let range = 3 * k..3 * k + 3;
+            let gradient =
+                TangentVector::new(DVector::from_column_slice(&gradients[range.clone()]));
+            let step = sphere
+                .euclidean_to_riemannian_gradient(&points[range.clone()], &gradient)
+                .unwrap();
+            let expected = sphere.exp(&points[range.clone()], &(step * -0.1)).unwrap();
+            assert_relative_eq!(&stepped[range], expected.as_slice(), epsilon = 1e-14);
+        }
+
+        let mut short = [0.0; 1];
+        assert!(sphere.distance_batch(&points, &moved, &mut short).is_err());
+    }
+
+    #[test]
+    fn test_chordal_surrogate_brackets_geodesic_distance() {
+        let sphere = Sphere::new(2);
+        let a = [0.0, 0.0, 1.0];
+        for angle in [0.0f64, 0.3, 1.5, 2.9, 3.1] {
+            let b = [angle.sin(), 0.0, angle.cos()];
+            let chord = sphere.surrogate_distance(&a, &b).unwrap();
+            let distance = sphere.distance(&a, &b).unwrap();
+            assert!(sphere.distance_lower_bound(chord) <= distance + 1e-12);
+            assert!(sphere.distance_upper_bound(chord) >= distance - 1e-12);
+        }
+
+        assert!(matches!(
+            sphere.surrogate_distance(&[1.0, 0.0], &[0.0, 1.0]),
+            Err(ManifoldError::DimensionMismatch { .. })
+        ));
+
+        let far = [3.0f64.sin(), 0.0, 3.0f64.cos()];
+        let (lower, upper) = sphere.bounded_distance(&a, &far, 0.5).unwrap();
+        assert_relative_eq!(lower, 2.0 * 1.5f64.sin(), epsilon = 1e-12);
+        assert!(upper > 3.0);
+        let (lower, upper) = sphere.bounded_distance(&a, &far, 10.0).unwrap();
+        assert_relative_eq!(lower, 3.0, epsilon = 1e-12);
+        assert_relative_eq!(upper, 3.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_validation_level_controls_operation_checks() {
+        use poly_manifold_core::validation::with_validation_level;
+        use poly_manifold_core::ValidationLevel;
+
+        let sphere = Sphere::new(2);
+        let off_manifold = [0.0, 0.0, 2.0];
+        let tangent = TangentVector::new(DVector::from_vec(vec![0.1, 0.0, 0.0]));
+        assert!(sphere.exp(&off_manifold, &tangent).is_err());
+
+        for level in [ValidationLevel::OnEntry, ValidationLevel::Off] {
+            with_validation_level(level, || {
+                assert!(sphere.exp(&off_manifold, &tangent).is_ok());
+                assert!(sphere.check_point(&off_manifold).is_err());
+            });
+        }
+        assert!(sphere.log(&off_manifold, &[1.0, 0.0, 0.0]).is_err());
+    }
 }
@@ -0,0 +1,336 @@
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::precision::tolerance;
+use poly_manifold_core::validation::{check_dimension, validate_point, validate_tangent};
+use poly_manifold_core::{
+    Manifold, ManifoldError, MatrixManifold, PointEquivalence, Result, TangentVector,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stiefel {
+    pub n: usize,
+    pub p: usize,
+}
+
+impl Stiefel {
+    pub fn new(n: usize, p: usize) -> Result<Self> {
+        check_dimension("Stiefel", "number of columns", p)?;
+        if p > n {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Stiefel manifold requires p <= n, got n = {n}, p = {p}"
+            )));
+        }
+        Ok(Self { n, p })
+    }
+
+    pub fn from_vec_len(len: usize, p: usize) -> Result<Self> {
+        check_dimension("Stiefel", "number of columns", p)?;
+        if !len.is_multiple_of(p) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Stiefel points have n × {p} entries, but {len} is not a multiple of {p}"
+            )));
+        }
+        Self::new(len / p, p)
+    }
+
+    pub fn to_matrix(&self, vec: &[f64]) -> DMatrix<f64> {
+        assert_eq!(vec.len(), self.n * self.p);
+        DMatrix::from_row_slice(self.n, self.p, vec)
+    }
+
+    pub fn from_matrix(&self, mat: &DMatrix<f64>) -> Vec<f64> {
+        mat.transpose().as_slice().to_vec()
+    }
+
+    fn check_len(&self, len: usize) -> Result<()> {
+        if len != self.n * self.p {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.n * self.p,
+                got: len,
+            });
+        }
+        Ok(())
+    }
+
+    fn tangent_projection(&self, x: &DMatrix<f64>, v: &DMatrix<f64>) -> DMatrix<f64> {
+        let xtv = x.transpose() * v;
+        let sym = (&xtv + xtv.transpose()) * 0.5;
+        v - x * sym
+    }
+
+    fn polar(&self, mat: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+        let svd = mat.clone().svd(true, true);
+        match (svd.u, svd.v_t) {
+            (Some(u), Some(v_t)) => Ok(u * v_t),
+            _ => Err(ManifoldError::LinearAlgebraError(
+                "SVD failed while projecting to the Stiefel manifold".to_string(),
+            )),
+        }
+    }
+}
+
+impl Manifold for Stiefel {
+    type Scalar = f64;
+
+    fn dim(&self) -> usize {
+        self.n * self.p - self.p * (self.p + 1) / 2
+    }
+
+    fn ambient_dim(&self) -> usize {
+        self.n * self.p
+    }
+
+    fn check_point(&self, point: &[Self::Scalar]) -> Result<()> {
+        self.check_len(point.len())?;
+        let x = self.to_matrix(point);
+        let error = (x.transpose() * &x - DMatrix::identity(self.p, self.p)).norm();
+        if error.is_nan() || error > tolerance(1e-8) {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: format!("Columns are not orthonormal, |XᵀX - I| = {}", error),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_tangent_vector(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<()> {
+        self.check_point(point)?;
+        self.check_len(tangent.dim())?;
+        let x = self.to_matrix(point);
+        let v = self.to_matrix(tangent.components.as_slice());
+        let xtv = x.transpose() * v;
+        let asymmetry = (&xtv + xtv.transpose()).norm();
+        if asymmetry > tolerance(1e-8) {
+            return Err(ManifoldError::InvalidTangentVector {
+                reason: format!("XᵀV is not skew-symmetric, |XᵀV + VᵀX| = {}", asymmetry),
+            });
+        }
+        Ok(())
+    }
+
+    fn project_to_manifold(&self, point: &[Self::Scalar]) -> Result<Vec<Self::Scalar>> {
+        self.check_len(point.len())?;
+        Ok(self.from_matrix(&self.polar(&self.to_matrix(point))?))
+    }
+
+    fn project_to_tangent_space(
+        &self,
+        point: &[Self::Scalar],
+        vector: &TangentVector<Self::Scalar>,
+    ) -> Result<TangentVector<Self::Scalar>> {
+        self.check_len(point.len())?;
+        self.check_len(vector.dim())?;
+        let x = self.to_matrix(point);
+        let v = self.to_matrix(vector.components.as_slice());
+        Ok(TangentVector::new(DVector::from_vec(
+            self.from_matrix(&self.tangent_projection(&x, &v)),
+        )))
+    }
+
+    fn exp(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        validate_tangent(self, point, tangent)?;
+        let p = self.p;
+        let x = self.to_matrix(point);
+        let v = self.to_matrix(tangent.components.as_slice());
+        let a = x.transpose() * &v;
+        let s = v.transpose() * &v;
+
+        let mut block = DMatrix::zeros(2 * p, 2 * p);
+        block.view_mut((0, 0), (p, p)).copy_from(&a);
+        block.view_mut((0, p), (p, p)).copy_from(&(-s));
+        block
+            .view_mut((p, 0), (p, p))
+            .copy_from(&DMatrix::identity(p, p));
+        block.view_mut((p, p), (p, p)).copy_from(&a);
+
+        let mut right = DMatrix::zeros(2 * p, p);
+        right.view_mut((0, 0), (p, p)).copy_from(&(-a).exp());
+
+        let mut frame = DMatrix::zeros(self.n, 2 * p);
+        frame.view_mut((0, 0), (self.n, p)).copy_from(&x);
+        frame.view_mut((0, p), (self.n, p)).copy_from(&v);
+
+        let y = frame * block.exp() * right;
+        Ok(self.from_matrix(&y))
+    }
+
+    fn retraction(
+        &self,
+        point: &[Self::Scalar],
+        tangent: &TangentVector<Self::Scalar>,
+    ) -> Result<Vec<Self::Scalar>> {
+        self.check_len(point.len())?;
+        self.check_len(tangent.dim())?;
+        let moved = self.to_matrix(point) + self.to_matrix(tangent.components.as_slice());
+        Ok(self.from_matrix(&self.polar(&moved)?))
+    }
+
+    fn log(
+        &self,
+        point: &[Self::Scalar],
+        other: &[Self::Scalar],
+    ) -> Result<TangentVector<Self::Scalar>> {
+        validate_point(self, point)?;
+        validate_point(self, other)?;
+        let x = self.to_matrix(point);
+        let y = self.to_matrix(other);
+
+        let mut v = self.tangent_projection(&x, &(&y - &x));
+        for _ in 0..200 {
+            let tangent = TangentVector::new(DVector::from_vec(self.from_matrix(&v)));
+            let z = self.to_matrix(&self.exp(point, &tangent)?);
+            let residual = self.tangent_projection(&z, &(&y - &z));
+            if residual.norm() < 1e-12 {
+                return Ok(tangent);
+            }
+            v += self.tangent_projection(&x, &residual);
+        }
+        Err(ManifoldError::NumericalError(
+            "Stiefel logarithm did not converge".to_string(),
+        ))
+    }
+
+    fn inner_product(
+        &self,
+        _point: &[Self::Scalar],
+        v1: &TangentVector<Self::Scalar>,
+        v2: &TangentVector<Self::Scalar>,
+    ) -> Result<Self::Scalar> {
+        Ok(v1.dot(v2))
+    }
+}
+
+impl MatrixManifold for Stiefel {
+    fn matrix_shape(&self) -> (usize, usize) {
+        (self.n, self.p)
+    }
+}
+
+impl PointEquivalence for Stiefel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_stiefel_try_new() {
+        assert!(Stiefel::new(3, 0).is_err());
+        assert!(matches!(
+            Stiefel::new(2, 3),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        assert_eq!(Stiefel::new(3, 2).unwrap().p, 2);
+    }
+
+    #[test]
+    fn test_stiefel_from_vec_len() {
+        assert_eq!(
+            Stiefel::from_vec_len(6, 2).unwrap(),
+            Stiefel::new(3, 2).unwrap()
+        );
+        assert!(matches!(
+            Stiefel::from_vec_len(7, 2),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        assert!(Stiefel::from_vec_len(2, 3).is_err());
+        assert!(Stiefel::from_vec_len(6, 0).is_err());
+    }
+
+    fn sample_point(stiefel: &Stiefel) -> Vec<f64> {
+        let raw: Vec<f64> = (0..stiefel.n * stiefel.p)
+            .map(|i| ((i * 7 + 3) as f64).sin())
+            .collect();
+        stiefel.project_to_manifold(&raw).unwrap()
+    }
+
+    #[test]
+    fn test_stiefel_matrix_conversions() {
+        let stiefel = Stiefel::new(3, 2).unwrap();
+        let frame = DMatrix::from_row_slice(3, 2, &[1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+        let point = stiefel.point_from_matrix(&frame).unwrap();
+        assert_eq!(point, stiefel.from_matrix(&frame));
+        assert_eq!(stiefel.matrix_from_point(&point).unwrap(), frame);
+        assert!(stiefel.point_from_matrix(&(frame * 2.0)).is_err());
+
+        let direction = DMatrix::from_row_slice(3, 2, &[0.0, 0.5, -0.5, 0.0, 1.0, 2.0]);
+        let tangent = stiefel.tangent_from_matrix(&point, &direction).unwrap();
+        assert_eq!(stiefel.matrix_from_tangent(&tangent).unwrap(), direction);
+        assert!(stiefel
+            .tangent_from_matrix(&point, &DMatrix::identity(3, 2))
+            .is_err());
+        assert_eq!(
+            format!("{:.1}", stiefel.display_point(&point).unwrap()),
+            "[ 1.0 0.0 ]\n[ 0.0 1.0 ]\n[ 0.0 0.0 ]"
+        );
+    }
+
+    #[test]
+    fn test_stiefel_dimension() {
+        let stiefel = Stiefel::new(4, 2).unwrap();
+        assert_eq!(stiefel.dim(), 5);
+        assert_eq!(stiefel.ambient_dim(), 8);
+        assert_eq!(Stiefel::new(3, 3).unwrap().dim(), 3);
+    }
+
+    #[test]
+    fn test_stiefel_check_point_and_projection() {
+        let stiefel = Stiefel::new(3, 2).unwrap();
+        assert!(stiefel.check_point(&[1.0, 0.0, 0.0, 1.0, 0.0, 0.0]).is_ok());
+        assert!(stiefel
+            .check_point(&[1.0, 1.0, 0.0, 1.0, 0.0, 0.0])
+            .is_err());
+        assert!(stiefel.check_point(&sample_point(&stiefel)).is_ok());
+    }
+
+    #[test]
+    fn test_stiefel_exp_stays_on_manifold() {
+        let stiefel = Stiefel::new(5, 2).unwrap();
+        let point = sample_point(&stiefel);
+        let raw = TangentVector::new(DVector::from_fn(10, |i, _| (i as f64).cos()));
+        let tangent = stiefel.project_to_tangent_space(&point, &raw).unwrap();
+        assert!(stiefel.check_tangent_vector(&point, &tangent).is_ok());
+
+        let moved = stiefel.exp(&point, &tangent).unwrap();
+        assert!(stiefel.check_point(&moved).is_ok());
+        let retracted = stiefel.retraction(&point, &tangent).unwrap();
+        assert!(stiefel.check_point(&retracted).is_ok());
+    }
+
+    #[test]
+    fn test_stiefel_exp_log_roundtrip() {
+        let stiefel = Stiefel::new(4, 2).unwrap();
+        let point = sample_point(&stiefel);
+        let raw = TangentVector::new(DVector::from_fn(8, |i, _| 0.3 * ((i * 5) as f64).sin()));
+        let tangent = stiefel.project_to_tangent_space(&point, &raw).unwrap();
+        let other = stiefel.exp(&point, &tangent).unwrap();
+
+        let recovered = stiefel.log(&point, &other).unwrap();
+        for (r, t) in recovered.components.iter().zip(tangent.components.iter()) {
+            assert_relative_eq!(r, t, epsilon = 1e-8);
+        }
+        assert_relative_eq!(
+            stiefel.distance(&point, &other).unwrap(),
+            tangent.components.norm(),
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn test_stiefel_tangent_basis() {
+        let stiefel = Stiefel::new(4, 2).unwrap();
+        let point = sample_point(&stiefel);
+        let basis = stiefel.tangent_basis(&point).unwrap();
+        assert_eq!(basis.len(), stiefel.dim());
+        for v in &basis {
+            assert!(stiefel.check_tangent_vector(&point, v).is_ok());
+        }
+    }
+}
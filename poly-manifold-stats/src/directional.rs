@@ -0,0 +1,240 @@
+use crate::spd_random::sample_gamma;
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::{ManifoldError, RandomSource, Result};
+
+fn gaussian_direction<R: RandomSource + ?Sized>(dimension: usize, rng: &mut R) -> DVector<f64> {
+    loop {
+        let v = DVector::from_fn(dimension, |_, _| rng.next_gaussian());
+        let norm = v.norm();
+        if norm > 1e-12 {
+            return v / norm;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UniformSphere {
+    pub dimension: usize,
+}
+
+impl UniformSphere {
+    pub fn new(dimension: usize) -> Self {
+        Self { dimension }
+    }
+
+    pub fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        gaussian_direction(self.dimension + 1, rng)
+            .as_slice()
+            .to_vec()
+    }
+
+    pub fn sample_n<R: RandomSource + ?Sized>(&self, count: usize, rng: &mut R) -> Vec<Vec<f64>> {
+        (0..count).map(|_| self.sample(rng)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UniformRotation {
+    pub n: usize,
+}
+
+impl UniformRotation {
+    pub fn new(n: usize) -> Self {
+        Self { n }
+    }
+
+    pub fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let n = self.n;
+        let gaussian = DMatrix::from_fn(n, n, |_, _| rng.next_gaussian());
+        let qr = gaussian.qr();
+        let r = qr.r();
+        let mut q = qr.q();
+        for j in 0..n {
+            if r[(j, j)] < 0.0 {
+                q.column_mut(j).neg_mut();
+            }
+        }
+        if q.determinant() < 0.0 {
+            q.column_mut(0).neg_mut();
+        }
+        q.transpose().as_slice().to_vec()
+    }
+
+    pub fn sample_n<R: RandomSource + ?Sized>(&self, count: usize, rng: &mut R) -> Vec<Vec<f64>> {
+        (0..count).map(|_| self.sample(rng)).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VonMisesFisher {
+    pub mean: Vec<f64>,
+    pub concentration: f64,
+    mean_vector: DVector<f64>,
+}
+
+impl VonMisesFisher {
+    pub fn new(mean: Vec<f64>, concentration: f64) -> Result<Self> {
+        if mean.len() < 2 {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: 2,
+                got: mean.len(),
+            });
+        }
+        let mean_vector = DVector::from_column_slice(&mean);
+        if (mean_vector.norm() - 1.0).abs() > 1e-10 {
+            return Err(ManifoldError::PointNotOnManifold {
+                reason: format!("Mean direction has norm {}", mean_vector.norm()),
+            });
+        }
+        if !(concentration >= 0.0 && concentration.is_finite()) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Concentration must be non-negative and finite, got {}",
+                concentration
+            )));
+        }
+        Ok(Self {
+            mean,
+            concentration,
+            mean_vector,
+        })
+    }
+
+    fn sample_cosine<R: RandomSource + ?Sized>(&self, rng: &mut R) -> f64 {
+        let kappa = self.concentration;
+        let m = (self.mean.len() - 1) as f64;
+        let b = m / (2.0 * kappa + (4.0 * kappa * kappa + m * m).sqrt());
+        let x0 = (1.0 - b) / (1.0 + b);
+        let c = kappa * x0 + m * (1.0 - x0 * x0).ln();
+        loop {
+            let g1 = sample_gamma(0.5 * m, rng);
+            let g2 = sample_gamma(0.5 * m, rng);
+            let z = g1 / (g1 + g2);
+            let w = (1.0 - (1.0 + b) * z) / (1.0 - (1.0 - b) * z);
+            let u = 1.0 - rng.next_f64();
+            if kappa * w + m * (1.0 - x0 * w).ln() - c >= u.ln() {
+                return w;
+            }
+        }
+    }
+
+    pub fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let w = self.sample_cosine(rng);
+        let mu = &self.mean_vector;
+        let mut v = gaussian_direction(mu.len(), rng);
+        v.axpy(-v.dot(mu), mu, 1.0);
+        let norm = v.norm();
+        if norm > 1e-12 {
+            v /= norm;
+        }
+        (mu * w + v * (1.0 - w * w).max(0.0).sqrt())
+            .as_slice()
+            .to_vec()
+    }
+
+    pub fn sample_n<R: RandomSource + ?Sized>(&self, count: usize, rng: &mut R) -> Vec<Vec<f64>> {
+        (0..count).map(|_| self.sample(rng)).collect()
+    }
+}
+
+#[cfg(feature = "rand")]
+mod distributions {
+    use super::{UniformRotation, UniformSphere, VonMisesFisher};
+    use poly_manifold_core::RandSource;
+    use rand::distr::Distribution;
+    use rand::Rng;
+
+    impl Distribution<Vec<f64>> for UniformSphere {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+            UniformSphere::sample(self, &mut RandSource(rng))
+        }
+    }
+
+    impl Distribution<Vec<f64>> for UniformRotation {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+            UniformRotation::sample(self, &mut RandSource(rng))
+        }
+    }
+
+    impl Distribution<Vec<f64>> for VonMisesFisher {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+            VonMisesFisher::sample(self, &mut RandSource(rng))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::Matrix3;
+    use poly_manifold_core::{Manifold, SeededRng};
+    use poly_manifold_spaces::{Sphere, SO3};
+
+    #[test]
+    fn test_uniform_sphere_is_centered() {
+        let mut rng = SeededRng::seed_from_u64(3);
+        let samples = UniformSphere::new(2).sample_n(5000, &mut rng);
+        let sphere = Sphere::new(2);
+        let mut mean = DVector::zeros(3);
+        for s in &samples {
+            assert!(sphere.check_point(s).is_ok());
+            mean += DVector::from_column_slice(s);
+        }
+        assert!((mean / samples.len() as f64).norm() < 0.05);
+    }
+
+    #[test]
+    fn test_uniform_rotation_lands_on_so3() {
+        let mut rng = SeededRng::seed_from_u64(4);
+        let so3 = SO3::new();
+        let samples = UniformRotation::new(3).sample_n(2000, &mut rng);
+        let mut trace = 0.0;
+        for s in &samples {
+            assert!(so3.check_point(s).is_ok());
+            trace += Matrix3::from_row_slice(s).trace();
+        }
+        assert_relative_eq!(trace / samples.len() as f64, 0.0, epsilon = 0.1);
+    }
+
+    #[test]
+    fn test_von_mises_fisher_mean_resultant_length() {
+        let mut rng = SeededRng::seed_from_u64(5);
+        let kappa = 10.0;
+        let vmf = VonMisesFisher::new(vec![0.0, 0.0, 1.0], kappa).unwrap();
+        let samples = vmf.sample_n(5000, &mut rng);
+        let mean_cosine = samples.iter().map(|s| s[2]).sum::<f64>() / samples.len() as f64;
+        let expected = 1.0 / kappa.tanh() - 1.0 / kappa;
+        assert_relative_eq!(mean_cosine, expected, epsilon = 0.01);
+        for s in &samples {
+            assert!(Sphere::new(2).check_point(s).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_von_mises_fisher_rejects_invalid_parameters() {
+        assert!(VonMisesFisher::new(vec![1.0], 1.0).is_err());
+        assert!(VonMisesFisher::new(vec![1.0, 1.0], 1.0).is_err());
+        assert!(VonMisesFisher::new(vec![1.0, 0.0], -1.0).is_err());
+        assert!(VonMisesFisher::new(vec![1.0, 0.0], 0.0).is_ok());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_samplers_implement_rand_distribution() {
+        use rand::distr::Distribution;
+
+        let vmf = VonMisesFisher::new(vec![1.0, 0.0, 0.0], 50.0).unwrap();
+        let rng = SeededRng::seed_from_u64(6);
+        let samples: Vec<Vec<f64>> = vmf.sample_iter(rng).take(100).collect();
+        assert!(samples.iter().all(|s| s[0] > 0.5));
+
+        let mut a = SeededRng::seed_from_u64(7);
+        let mut b = SeededRng::seed_from_u64(7);
+        let via_rand = Distribution::sample(&UniformSphere::new(3), &mut a);
+        let direct = UniformSphere::new(3).sample(&mut b);
+        assert_eq!(via_rand, direct);
+        assert!(SO3::new()
+            .check_point(&Distribution::sample(&UniformRotation::new(3), &mut a))
+            .is_ok());
+    }
+}
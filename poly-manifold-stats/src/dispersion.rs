@@ -0,0 +1,91 @@
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Manifold, ManifoldError, Result};
+
+pub fn squared_distances<M>(manifold: &M, points: &[Vec<f64>], mean: &[f64]) -> Result<Vec<f64>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    validate_entry_point(manifold, mean)?;
+    points
+        .iter()
+        .map(|point| {
+            validate_entry_point(manifold, point)?;
+            Ok(manifold.distance(mean, point)?.powi(2))
+        })
+        .collect()
+}
+
+pub fn frechet_variance<M>(manifold: &M, points: &[Vec<f64>], mean: &[f64]) -> Result<f64>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    if points.is_empty() {
+        return Err(ManifoldError::InvalidParameter(
+            "Fréchet variance requires at least one point".to_string(),
+        ));
+    }
+    let distances = squared_distances(manifold, points, mean)?;
+    Ok(distances.iter().sum::<f64>() / points.len() as f64)
+}
+
+pub fn frechet_std_dev<M>(manifold: &M, points: &[Vec<f64>], mean: &[f64]) -> Result<f64>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    Ok(frechet_variance(manifold, points, mean)?.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mean::FrechetMean;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_euclidean_variance_matches_sample_variance() {
+        let euclidean = Euclidean::new(1);
+        let points = vec![vec![1.0], vec![3.0], vec![5.0]];
+        let mean = FrechetMean::default().compute(&euclidean, &points).unwrap();
+
+        let distances = squared_distances(&euclidean, &points, &mean).unwrap();
+        assert_relative_eq!(distances[0], 4.0, epsilon = 1e-10);
+        assert_relative_eq!(distances[1], 0.0, epsilon = 1e-10);
+        assert_relative_eq!(
+            frechet_variance(&euclidean, &points, &mean).unwrap(),
+            8.0 / 3.0,
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            frechet_std_dev(&euclidean, &points, &mean).unwrap(),
+            (8.0f64 / 3.0).sqrt(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_sphere_variance_uses_geodesic_distance() {
+        let sphere = Sphere::new(2);
+        let points = vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]];
+        let north = [0.0, 0.0, 1.0];
+        let variance = frechet_variance(&sphere, &points, &north).unwrap();
+        assert_relative_eq!(variance, FRAC_PI_2 * FRAC_PI_2, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_variance_is_zero_for_repeated_point() {
+        let sphere = Sphere::new(2);
+        let points = vec![vec![0.0, 0.0, 1.0]; 4];
+        let variance = frechet_variance(&sphere, &points, &points[0]).unwrap();
+        assert_relative_eq!(variance, 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let sphere = Sphere::new(2);
+        assert!(frechet_variance(&sphere, &[], &[0.0, 0.0, 1.0]).is_err());
+        assert!(squared_distances(&sphere, &[vec![2.0, 0.0, 0.0]], &[0.0, 0.0, 1.0]).is_err());
+        assert!(squared_distances(&sphere, &[vec![1.0, 0.0, 0.0]], &[0.0, 0.0, 2.0]).is_err());
+    }
+}
@@ -0,0 +1,369 @@
+use nalgebra::DMatrix;
+use poly_manifold_core::validation::validate_entry_point;
+#[cfg(feature = "rayon")]
+use poly_manifold_core::Context;
+use poly_manifold_core::{CachedManifold, Manifold, ManifoldError, Result};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistanceMatrix {
+    pub block_size: usize,
+}
+
+impl Default for DistanceMatrix {
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+impl DistanceMatrix {
+    pub fn new(block_size: usize) -> Self {
+        Self { block_size }
+    }
+
+    pub fn compute<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<DMatrix<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        for point in points {
+            validate_entry_point(manifold, point)?;
+        }
+        self.fill(points.len(), |i, j| {
+            manifold.distance(&points[i], &points[j])
+        })
+    }
+
+    pub fn compute_cached<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<DMatrix<f64>>
+    where
+        M: CachedManifold<Scalar = f64>,
+    {
+        let cached = points
+            .iter()
+            .map(|point| manifold.cache_point(point))
+            .collect::<Result<Vec<_>>>()?;
+        self.fill(cached.len(), |i, j| {
+            manifold.distance_cached(&cached[i], &cached[j])
+        })
+    }
+
+    fn tiles(&self, n: usize) -> Result<Vec<(usize, usize)>> {
+        if self.block_size == 0 {
+            return Err(ManifoldError::InvalidParameter(
+                "Block size must be positive".to_string(),
+            ));
+        }
+        let blocks = n.div_ceil(self.block_size);
+        Ok((0..blocks)
+            .flat_map(|bi| (bi..blocks).map(move |bj| (bi, bj)))
+            .collect())
+    }
+
+    fn tile<F>(&self, n: usize, (bi, bj): (usize, usize), distance: &F) -> Result<Vec<f64>>
+    where
+        F: Fn(usize, usize) -> Result<f64>,
+    {
+        let rows = bi * self.block_size..((bi + 1) * self.block_size).min(n);
+        let cols = bj * self.block_size..((bj + 1) * self.block_size).min(n);
+        let mut values = Vec::with_capacity(rows.len() * cols.len());
+        for i in rows {
+            for j in cols.clone() {
+                values.push(if j > i { distance(i, j)? } else { 0.0 });
+            }
+        }
+        Ok(values)
+    }
+
+    fn assemble(&self, n: usize, tiles: &[(usize, usize)], values: Vec<Vec<f64>>) -> DMatrix<f64> {
+        let mut distances = DMatrix::zeros(n, n);
+        for (&(bi, bj), tile) in tiles.iter().zip(values) {
+            let rows = bi * self.block_size..((bi + 1) * self.block_size).min(n);
+            let cols = bj * self.block_size..((bj + 1) * self.block_size).min(n);
+            let width = cols.len();
+            for (r, i) in rows.enumerate() {
+                for (c, j) in cols.clone().enumerate() {
+                    if j > i {
+                        let d = tile[r * width + c];
+                        distances[(i, j)] = d;
+                        distances[(j, i)] = d;
+                    }
+                }
+            }
+        }
+        distances
+    }
+
+    fn fill<F>(&self, n: usize, distance: F) -> Result<DMatrix<f64>>
+    where
+        F: Fn(usize, usize) -> Result<f64>,
+    {
+        let tiles = self.tiles(n)?;
+        let values = tiles
+            .iter()
+            .map(|tile| self.tile(n, *tile, &distance))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.assemble(n, &tiles, values))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl DistanceMatrix {
+    pub fn par_compute<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<DMatrix<f64>>
+    where
+        M: Manifold<Scalar = f64> + Sync + ?Sized,
+    {
+        for point in points {
+            validate_entry_point(manifold, point)?;
+        }
+        let context = Context::current();
+        in_context_pool(&context, || {
+            self.par_fill(&context, points.len(), |i, j| {
+                manifold.distance(&points[i], &points[j])
+            })
+        })
+    }
+
+    pub fn par_compute_cached<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<DMatrix<f64>>
+    where
+        M: CachedManifold<Scalar = f64> + Sync,
+        M::CachedPoint: Send + Sync,
+    {
+        use poly_manifold_core::profiling::{absorb, measure, snapshot};
+        use rayon::prelude::*;
+
+        let context = Context::current();
+        in_context_pool(&context, || {
+            let before = snapshot();
+            let (cached, counts): (Vec<_>, Vec<_>) = points
+                .par_iter()
+                .map(|point| measure(|| context.scope(|| manifold.cache_point(point))))
+                .unzip();
+            absorb(&before, counts.into_iter().sum());
+            let cached = cached.into_iter().collect::<Result<Vec<_>>>()?;
+            self.par_fill(&context, cached.len(), |i, j| {
+                manifold.distance_cached(&cached[i], &cached[j])
+            })
+        })
+    }
+
+    fn par_fill<F>(&self, context: &Context, n: usize, distance: F) -> Result<DMatrix<f64>>
+    where
+        F: Fn(usize, usize) -> Result<f64> + Sync,
+    {
+        use poly_manifold_core::profiling::{absorb, measure, snapshot};
+        use rayon::prelude::*;
+
+        let tiles = self.tiles(n)?;
+        let before = snapshot();
+        let (values, counts): (Vec<_>, Vec<_>) = tiles
+            .par_iter()
+            .map(|tile| measure(|| context.scope(|| self.tile(n, *tile, &distance))))
+            .unzip();
+        absorb(&before, counts.into_iter().sum());
+        let values = values.into_iter().collect::<Result<Vec<_>>>()?;
+        Ok(self.assemble(n, &tiles, values))
+    }
+}
+
+// Runs `run` on a pool sized by `Context::threads`, or on the global rayon pool
+// when no thread count is set.
+#[cfg(feature = "rayon")]
+fn in_context_pool<R, F>(context: &Context, run: F) -> Result<R>
+where
+    R: Send,
+    F: FnOnce() -> Result<R> + Send,
+{
+    use poly_manifold_core::profiling::{absorb, measure, snapshot};
+
+    let Some(threads) = context.threads else {
+        return run();
+    };
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .map_err(|e| ManifoldError::InvalidParameter(format!("Cannot build thread pool: {e}")))?;
+    let before = snapshot();
+    let (value, counts) = pool.install(|| measure(run));
+    absorb(&before, counts);
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Sphere, SPD};
+
+    fn naive<M: Manifold<Scalar = f64>>(manifold: &M, points: &[Vec<f64>]) -> DMatrix<f64> {
+        let n = points.len();
+        DMatrix::from_fn(n, n, |i, j| {
+            manifold.distance(&points[i], &points[j]).unwrap()
+        })
+    }
+
+    fn sphere_points(n: usize) -> Vec<Vec<f64>> {
+        (0..n)
+            .map(|k| {
+                let theta = 0.3 * k as f64;
+                let phi = 0.1 + 0.05 * k as f64;
+                vec![phi.sin() * theta.cos(), phi.sin() * theta.sin(), phi.cos()]
+            })
+            .collect()
+    }
+
+    fn spd_points(n: usize) -> Vec<Vec<f64>> {
+        (0..n)
+            .map(|k| {
+                let t = k as f64;
+                let off = 0.3 * (0.7 * t).sin();
+                vec![1.0 + 0.1 * t, off, off, 2.0 + (0.4 * t).cos()]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_blocked_matches_naive_for_ragged_blocks() {
+        let sphere = Sphere::new(2);
+        let points = sphere_points(23);
+        let expected = naive(&sphere, &points);
+        for block_size in [1, 4, 7, 64] {
+            let distances = DistanceMatrix::new(block_size)
+                .compute(&sphere, &points)
+                .unwrap();
+            assert_relative_eq!(distances, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_cached_matches_naive_for_spd() {
+        let spd = SPD::new(2);
+        let points = spd_points(17);
+        let expected = naive(&spd, &points);
+        let distances = DistanceMatrix::new(5)
+            .compute_cached(&spd, &points)
+            .unwrap();
+        assert_relative_eq!(distances, expected, epsilon = 1e-9);
+        assert_relative_eq!(distances.clone(), distances.transpose(), epsilon = 0.0);
+    }
+
+    #[test]
+    fn test_empty_and_single_point() {
+        let sphere = Sphere::new(2);
+        let matrix = DistanceMatrix::default();
+        assert_eq!(matrix.compute(&sphere, &[]).unwrap().shape(), (0, 0));
+        let single = matrix.compute(&sphere, &[vec![0.0, 0.0, 1.0]]).unwrap();
+        assert_eq!(single, DMatrix::zeros(1, 1));
+    }
+
+    #[test]
+    fn test_invalid_inputs_are_rejected() {
+        let sphere = Sphere::new(2);
+        let points = vec![vec![0.0, 0.0, 1.0], vec![2.0, 0.0, 0.0]];
+        assert!(DistanceMatrix::default().compute(&sphere, &points).is_err());
+        assert!(DistanceMatrix::new(0)
+            .compute(&sphere, &points[..1])
+            .is_err());
+
+        let spd = SPD::new(2);
+        let asymmetric = vec![vec![1.0, 0.5, 0.0, 1.0]];
+        assert!(DistanceMatrix::default()
+            .compute_cached(&spd, &asymmetric)
+            .is_err());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let spd = SPD::new(2);
+        let points = spd_points(30);
+        let matrix = DistanceMatrix::new(4);
+        let sequential = matrix.compute_cached(&spd, &points).unwrap();
+        let parallel = matrix.par_compute_cached(&spd, &points).unwrap();
+        assert_relative_eq!(sequential, parallel, epsilon = 1e-14);
+        let parallel = matrix.par_compute(&spd, &points).unwrap();
+        assert_relative_eq!(sequential, parallel, epsilon = 1e-9);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_runs_on_context_threads() {
+        use poly_manifold_core::{validation_level, TangentVector, ValidationLevel};
+
+        // Checks that every distance runs inside the caller's context.
+        struct Probe(Sphere);
+
+        impl Manifold for Probe {
+            type Scalar = f64;
+
+            fn dim(&self) -> usize {
+                self.0.dim()
+            }
+
+            fn ambient_dim(&self) -> usize {
+                self.0.ambient_dim()
+            }
+
+            fn check_point(&self, point: &[f64]) -> Result<()> {
+                self.0.check_point(point)
+            }
+
+            fn check_tangent_vector(
+                &self,
+                point: &[f64],
+                tangent: &TangentVector<f64>,
+            ) -> Result<()> {
+                self.0.check_tangent_vector(point, tangent)
+            }
+
+            fn project_to_manifold(&self, point: &[f64]) -> Result<Vec<f64>> {
+                self.0.project_to_manifold(point)
+            }
+
+            fn project_to_tangent_space(
+                &self,
+                point: &[f64],
+                vector: &TangentVector<f64>,
+            ) -> Result<TangentVector<f64>> {
+                self.0.project_to_tangent_space(point, vector)
+            }
+
+            fn exp(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<Vec<f64>> {
+                self.0.exp(point, tangent)
+            }
+
+            fn log(&self, point: &[f64], other: &[f64]) -> Result<TangentVector<f64>> {
+                self.0.log(point, other)
+            }
+
+            fn inner_product(
+                &self,
+                point: &[f64],
+                v1: &TangentVector<f64>,
+                v2: &TangentVector<f64>,
+            ) -> Result<f64> {
+                self.0.inner_product(point, v1, v2)
+            }
+
+            fn distance(&self, a: &[f64], b: &[f64]) -> Result<f64> {
+                assert_eq!(rayon::current_num_threads(), 2);
+                assert_eq!(validation_level(), ValidationLevel::OnEntry);
+                self.0.distance(a, b)
+            }
+        }
+
+        let probe = Probe(Sphere::new(2));
+        let points: Vec<Vec<f64>> = (0..12)
+            .map(|i| {
+                let t = i as f64 * 0.4;
+                vec![t.cos(), t.sin(), 0.0]
+            })
+            .collect();
+        let context = Context::new()
+            .with_threads(2)
+            .with_validation(ValidationLevel::OnEntry);
+        let parallel = context
+            .scope(|| DistanceMatrix::new(3).par_compute(&probe, &points))
+            .unwrap();
+        let sequential = DistanceMatrix::new(3).compute(&probe.0, &points).unwrap();
+        assert_relative_eq!(sequential, parallel, epsilon = 1e-14);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::dti::{affine_invariant_mean, flatten, symmetrize};
+use crate::spd_random::spectral_map;
+use nalgebra::DMatrix;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{ManifoldError, Result};
+use poly_manifold_spaces::SPD;
+
+#[derive(Debug, Clone)]
+pub struct Recentering {
+    pub mean: DMatrix<f64>,
+    whitening: DMatrix<f64>,
+}
+
+impl Recentering {
+    pub fn fit(covariances: &[Vec<f64>]) -> Result<Self> {
+        let matrices = covariance_matrices(covariances)?;
+        let mean = uniform_mean(&matrices)?;
+        Ok(Self {
+            whitening: spectral_map(&mean, |l| 1.0 / l.sqrt()),
+            mean,
+        })
+    }
+
+    pub fn dim(&self) -> usize {
+        self.mean.nrows()
+    }
+
+    pub fn apply(&self, covariances: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        congruence(&self.whitening, covariances)
+    }
+
+    pub fn inverse_apply(&self, covariances: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        congruence(&spectral_map(&self.mean, f64::sqrt), covariances)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DomainTransport {
+    pub source_mean: DMatrix<f64>,
+    pub target_mean: DMatrix<f64>,
+    pub transport: DMatrix<f64>,
+}
+
+impl DomainTransport {
+    pub fn fit(source: &[Vec<f64>], target: &[Vec<f64>]) -> Result<Self> {
+        let source_mean = uniform_mean(&covariance_matrices(source)?)?;
+        let target_mean = uniform_mean(&covariance_matrices(target)?)?;
+        Self::between(source_mean, target_mean)
+    }
+
+    pub fn between(source_mean: DMatrix<f64>, target_mean: DMatrix<f64>) -> Result<Self> {
+        let n = source_mean.nrows();
+        if target_mean.nrows() != n {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: n,
+                got: target_mean.nrows(),
+            });
+        }
+        let spd = SPD::new(n);
+        validate_entry_point(&spd, &flatten(&source_mean))?;
+        validate_entry_point(&spd, &flatten(&target_mean))?;
+
+        let sqrt_source = spectral_map(&source_mean, f64::sqrt);
+        let inv_sqrt_source = spectral_map(&source_mean, |l| 1.0 / l.sqrt());
+        let relative = symmetrize(&inv_sqrt_source * &target_mean * &inv_sqrt_source);
+        let transport = &sqrt_source * spectral_map(&relative, f64::sqrt) * &inv_sqrt_source;
+        Ok(Self {
+            source_mean,
+            target_mean,
+            transport,
+        })
+    }
+
+    pub fn dim(&self) -> usize {
+        self.transport.nrows()
+    }
+
+    pub fn apply(&self, covariances: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        congruence(&self.transport, covariances)
+    }
+
+    pub fn inverse_apply(&self, covariances: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+        let inverse = self.transport.clone().try_inverse().ok_or_else(|| {
+            ManifoldError::NumericalError("Transport map is singular".to_string())
+        })?;
+        congruence(&inverse, covariances)
+    }
+}
+
+fn covariance_matrices(covariances: &[Vec<f64>]) -> Result<Vec<DMatrix<f64>>> {
+    let first = covariances.first().ok_or_else(|| {
+        ManifoldError::InvalidParameter("Cannot fit on an empty covariance set".to_string())
+    })?;
+    let n = (first.len() as f64).sqrt().round() as usize;
+    let spd = SPD::new(n);
+    covariances
+        .iter()
+        .map(|c| {
+            validate_entry_point(&spd, c)?;
+            Ok(DMatrix::from_row_slice(n, n, c))
+        })
+        .collect()
+}
+
+fn uniform_mean(matrices: &[DMatrix<f64>]) -> Result<DMatrix<f64>> {
+    let weights = vec![1.0 / matrices.len() as f64; matrices.len()];
+    affine_invariant_mean(matrices, &weights)
+}
+
+fn congruence(map: &DMatrix<f64>, covariances: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+    let n = map.nrows();
+    let spd = SPD::new(n);
+    covariances
+        .iter()
+        .map(|c| {
+            validate_entry_point(&spd, c)?;
+            let matrix = DMatrix::from_row_slice(n, n, c);
+            Ok(flatten(&symmetrize(map * matrix * map.transpose())))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spd_random::SpdLogNormal;
+    use approx::assert_relative_eq;
+    use poly_manifold_core::SeededRng;
+
+    fn affine_invariant_distance(a: &[f64], b: &[f64]) -> f64 {
+        let n = (a.len() as f64).sqrt() as usize;
+        let a = DMatrix::from_row_slice(n, n, a);
+        let b = DMatrix::from_row_slice(n, n, b);
+        let inv_sqrt_a = spectral_map(&a, |l| 1.0 / l.sqrt());
+        spectral_map(&symmetrize(&inv_sqrt_a * b * &inv_sqrt_a), f64::ln).norm()
+    }
+
+    fn domain(mean: &[f64], seed: u64) -> Vec<Vec<f64>> {
+        let mut rng = SeededRng::seed_from_u64(seed);
+        SpdLogNormal::new(DMatrix::from_row_slice(3, 3, mean), 0.3)
+            .unwrap()
+            .sample_n(40, &mut rng)
+    }
+
+    const SOURCE: [f64; 9] = [2.0, 0.3, 0.1, 0.3, 1.0, 0.2, 0.1, 0.2, 0.5];
+    const TARGET: [f64; 9] = [0.6, -0.1, 0.0, -0.1, 3.0, 0.4, 0.0, 0.4, 1.5];
+
+    #[test]
+    fn test_recentering_moves_mean_to_identity() {
+        let source = domain(&SOURCE, 1);
+        let recentering = Recentering::fit(&source).unwrap();
+        let recentered = recentering.apply(&source).unwrap();
+
+        let refit = Recentering::fit(&recentered).unwrap();
+        let identity = DMatrix::<f64>::identity(3, 3);
+        assert_relative_eq!((refit.mean - identity).norm(), 0.0, epsilon = 1e-8);
+
+        let restored = recentering.inverse_apply(&recentered).unwrap();
+        for (r, s) in restored.iter().zip(&source) {
+            assert_relative_eq!(affine_invariant_distance(r, s), 0.0, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_transport_maps_source_mean_to_target_mean() {
+        let transport = DomainTransport::fit(&domain(&SOURCE, 2), &domain(&TARGET, 3)).unwrap();
+        let moved = transport.apply(&[flatten(&transport.source_mean)]).unwrap();
+        assert_relative_eq!(
+            affine_invariant_distance(&moved[0], &flatten(&transport.target_mean)),
+            0.0,
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn test_transport_is_an_isometry() {
+        let source = domain(&SOURCE, 4);
+        let transport = DomainTransport::fit(&source, &domain(&TARGET, 5)).unwrap();
+        let moved = transport.apply(&source).unwrap();
+        for i in 0..5 {
+            assert_relative_eq!(
+                affine_invariant_distance(&source[i], &source[i + 5]),
+                affine_invariant_distance(&moved[i], &moved[i + 5]),
+                epsilon = 1e-8
+            );
+        }
+        let back = transport.inverse_apply(&moved).unwrap();
+        for (b, s) in back.iter().zip(&source) {
+            assert_relative_eq!(affine_invariant_distance(b, s), 0.0, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_fitted_transform_aligns_held_out_sessions() {
+        let shift = DMatrix::from_row_slice(3, 3, &[1.5, 0.2, 0.0, -0.3, 0.8, 0.1, 0.0, 0.4, 1.2]);
+        let shifted = |set: &[Vec<f64>]| -> Vec<Vec<f64>> {
+            set.iter()
+                .map(|c| flatten(&(&shift * DMatrix::from_row_slice(3, 3, c) * shift.transpose())))
+                .collect()
+        };
+        let train = domain(&SOURCE, 6);
+        let transport = DomainTransport::fit(&shifted(&train), &train).unwrap();
+
+        let held_out = domain(&SOURCE, 7);
+        let aligned = transport.apply(&shifted(&held_out)).unwrap();
+        let before: f64 = shifted(&held_out)
+            .iter()
+            .map(|c| affine_invariant_distance(c, &flatten(&transport.target_mean)))
+            .sum();
+        let after: f64 = aligned
+            .iter()
+            .map(|c| affine_invariant_distance(c, &flatten(&transport.target_mean)))
+            .sum();
+        assert!(after < 0.5 * before);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(Recentering::fit(&[]).is_err());
+        assert!(Recentering::fit(&[vec![1.0, 2.0, 2.0, 1.0]]).is_err());
+        let recentering = Recentering::fit(&[vec![2.0, 0.0, 0.0, 1.0]]).unwrap();
+        assert_eq!(recentering.dim(), 2);
+        assert!(recentering.apply(&[vec![1.0; 9]]).is_err());
+        assert!(
+            DomainTransport::between(DMatrix::identity(2, 2), DMatrix::identity(3, 3)).is_err()
+        );
+    }
+}
@@ -0,0 +1,411 @@
+use nalgebra::{DMatrix, Matrix3};
+use poly_manifold_core::linalg::{matrix_exp, matrix_log};
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+use poly_manifold_spaces::SPD;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TensorMetric {
+    #[default]
+    LogEuclidean,
+    AffineInvariant,
+}
+
+pub fn fractional_anisotropy(tensor: &[f64]) -> Result<f64> {
+    let eigenvalues = tensor_eigenvalues(tensor)?;
+    let mean = eigenvalues.iter().sum::<f64>() / 3.0;
+    let spread: f64 = eigenvalues.iter().map(|l| (l - mean).powi(2)).sum();
+    let magnitude: f64 = eigenvalues.iter().map(|l| l * l).sum();
+    Ok((1.5 * spread / magnitude).sqrt())
+}
+
+pub fn mean_diffusivity(tensor: &[f64]) -> Result<f64> {
+    Ok(tensor_eigenvalues(tensor)?.iter().sum::<f64>() / 3.0)
+}
+
+pub fn tensor_interpolate(a: &[f64], b: &[f64], t: f64, metric: TensorMetric) -> Result<Vec<f64>> {
+    match metric {
+        TensorMetric::LogEuclidean => {
+            let log =
+                matrix_log(&tensor_matrix(a)?)? * (1.0 - t) + matrix_log(&tensor_matrix(b)?)? * t;
+            Ok(flatten(&symmetrize(matrix_exp(&log)?)))
+        }
+        TensorMetric::AffineInvariant => {
+            let spd = SPD::new(3);
+            let moved = spd.exp(a, &(spd.log(a, b)? * t))?;
+            Ok(flatten(&symmetrize(DMatrix::from_row_slice(3, 3, &moved))))
+        }
+    }
+}
+
+pub fn tensor_weighted_mean(
+    tensors: &[Vec<f64>],
+    weights: &[f64],
+    metric: TensorMetric,
+) -> Result<Vec<f64>> {
+    if tensors.is_empty() || tensors.len() != weights.len() {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: tensors.len(),
+            got: weights.len(),
+        });
+    }
+    if weights.iter().any(|w| *w < 0.0 || !w.is_finite()) {
+        return Err(ManifoldError::InvalidParameter(
+            "Weights must be non-negative and finite".to_string(),
+        ));
+    }
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return Err(ManifoldError::InvalidParameter(
+            "Weights must not all be zero".to_string(),
+        ));
+    }
+    let matrices = tensors
+        .iter()
+        .map(|t| tensor_matrix(t))
+        .collect::<Result<Vec<_>>>()?;
+
+    let weights: Vec<f64> = weights.iter().map(|w| w / total).collect();
+    let mean = match metric {
+        TensorMetric::LogEuclidean => log_euclidean_mean(&matrices, &weights)?,
+        TensorMetric::AffineInvariant => affine_invariant_mean(&matrices, &weights)?,
+    };
+    Ok(flatten(&mean))
+}
+
+pub(crate) fn log_euclidean_mean(
+    matrices: &[DMatrix<f64>],
+    weights: &[f64],
+) -> Result<DMatrix<f64>> {
+    let n = matrices[0].nrows();
+    let mut log = DMatrix::zeros(n, n);
+    for (m, w) in matrices.iter().zip(weights) {
+        log += matrix_log(m)? * *w;
+    }
+    Ok(symmetrize(matrix_exp(&symmetrize(log))?))
+}
+
+pub(crate) fn affine_invariant_mean(
+    matrices: &[DMatrix<f64>],
+    weights: &[f64],
+) -> Result<DMatrix<f64>> {
+    let n = matrices[0].nrows();
+    let spd = SPD::new(n);
+    let points: Vec<Vec<f64>> = matrices.iter().map(flatten).collect();
+    let mut mean = flatten(&log_euclidean_mean(matrices, weights)?);
+    for _ in 0..100 {
+        let mut direction = TangentVector::zero(n * n);
+        for (point, w) in points.iter().zip(weights) {
+            direction += spd.log(&mean, point)? * *w;
+        }
+        let step = spd.norm(&mean, &direction)?;
+        mean = flatten(&symmetrize(DMatrix::from_row_slice(
+            n,
+            n,
+            &spd.exp(&mean, &direction)?,
+        )));
+        if step < 1e-12 {
+            return Ok(DMatrix::from_row_slice(n, n, &mean));
+        }
+    }
+    Err(ManifoldError::ConvergenceError { iterations: 100 })
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TensorField {
+    pub shape: [usize; 3],
+    pub tensors: Vec<Vec<f64>>,
+}
+
+impl TensorField {
+    pub fn new(shape: [usize; 3], tensors: Vec<Vec<f64>>) -> Result<Self> {
+        check_shape(shape)?;
+        let count = shape.iter().product();
+        if tensors.len() != count {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: count,
+                got: tensors.len(),
+            });
+        }
+        let spd = SPD::new(3);
+        for tensor in &tensors {
+            validate_entry_point(&spd, tensor)?;
+        }
+        Ok(Self { shape, tensors })
+    }
+
+    pub fn index(&self, voxel: [usize; 3]) -> usize {
+        voxel[0] + self.shape[0] * (voxel[1] + self.shape[1] * voxel[2])
+    }
+
+    pub fn get(&self, voxel: [usize; 3]) -> &[f64] {
+        &self.tensors[self.index(voxel)]
+    }
+
+    pub fn interpolate(&self, position: [f64; 3], metric: TensorMetric) -> Result<Vec<f64>> {
+        check_shape(self.shape)?;
+        let mut lower = [0usize; 3];
+        let mut fraction = [0.0; 3];
+        for axis in 0..3 {
+            let extent = (self.shape[axis] - 1) as f64;
+            if !(0.0..=extent).contains(&position[axis]) {
+                return Err(ManifoldError::InvalidParameter(format!(
+                    "Position {:?} lies outside the field",
+                    position
+                )));
+            }
+            lower[axis] = (position[axis].floor() as usize).min(self.shape[axis].saturating_sub(2));
+            fraction[axis] = position[axis] - lower[axis] as f64;
+        }
+
+        let mut tensors = Vec::with_capacity(8);
+        let mut weights = Vec::with_capacity(8);
+        for corner in 0..8 {
+            let mut voxel = lower;
+            let mut weight = 1.0;
+            for axis in 0..3 {
+                let upper = (corner >> axis) & 1 == 1;
+                if upper {
+                    voxel[axis] = (voxel[axis] + 1).min(self.shape[axis] - 1);
+                }
+                weight *= if upper {
+                    fraction[axis]
+                } else {
+                    1.0 - fraction[axis]
+                };
+            }
+            if weight > 0.0 {
+                tensors.push(self.get(voxel).to_vec());
+                weights.push(weight);
+            }
+        }
+        tensor_weighted_mean(&tensors, &weights, metric)
+    }
+
+    pub fn smooth(&self, sigma: f64, radius: usize, metric: TensorMetric) -> Result<TensorField> {
+        if !(sigma > 0.0 && sigma.is_finite()) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Smoothing width must be positive and finite, got {}",
+                sigma
+            )));
+        }
+        let r = radius as isize;
+        let mut smoothed = Vec::with_capacity(self.tensors.len());
+        for z in 0..self.shape[2] {
+            for y in 0..self.shape[1] {
+                for x in 0..self.shape[0] {
+                    let mut tensors = Vec::new();
+                    let mut weights = Vec::new();
+                    for dz in -r..=r {
+                        for dy in -r..=r {
+                            for dx in -r..=r {
+                                let neighbor = [x as isize + dx, y as isize + dy, z as isize + dz];
+                                if neighbor
+                                    .iter()
+                                    .zip(&self.shape)
+                                    .any(|(c, n)| *c < 0 || *c >= *n as isize)
+                                {
+                                    continue;
+                                }
+                                let squared = (dx * dx + dy * dy + dz * dz) as f64;
+                                weights.push((-0.5 * squared / (sigma * sigma)).exp());
+                                tensors.push(self.get(neighbor.map(|c| c as usize)).to_vec());
+                            }
+                        }
+                    }
+                    smoothed.push(tensor_weighted_mean(&tensors, &weights, metric)?);
+                }
+            }
+        }
+        Ok(TensorField {
+            shape: self.shape,
+            tensors: smoothed,
+        })
+    }
+
+    pub fn fractional_anisotropy_map(&self) -> Result<Vec<f64>> {
+        self.tensors
+            .iter()
+            .map(|t| fractional_anisotropy(t))
+            .collect()
+    }
+
+    pub fn mean_diffusivity_map(&self) -> Result<Vec<f64>> {
+        self.tensors.iter().map(|t| mean_diffusivity(t)).collect()
+    }
+}
+
+fn check_shape(shape: [usize; 3]) -> Result<()> {
+    if shape.contains(&0) {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Tensor field extents must be positive, got {:?}",
+            shape
+        )));
+    }
+    Ok(())
+}
+
+fn tensor_matrix(tensor: &[f64]) -> Result<DMatrix<f64>> {
+    validate_entry_point(&SPD::new(3), tensor)?;
+    Ok(DMatrix::from_row_slice(3, 3, tensor))
+}
+
+fn tensor_eigenvalues(tensor: &[f64]) -> Result<[f64; 3]> {
+    validate_entry_point(&SPD::new(3), tensor)?;
+    let eigen = Matrix3::from_row_slice(tensor).symmetric_eigen();
+    Ok([
+        eigen.eigenvalues[0],
+        eigen.eigenvalues[1],
+        eigen.eigenvalues[2],
+    ])
+}
+
+pub(crate) fn symmetrize(matrix: DMatrix<f64>) -> DMatrix<f64> {
+    (&matrix + matrix.transpose()) * 0.5
+}
+
+pub(crate) fn flatten(matrix: &DMatrix<f64>) -> Vec<f64> {
+    matrix.transpose().as_slice().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_core::Manifold;
+
+    fn diagonal(values: [f64; 3]) -> Vec<f64> {
+        vec![
+            values[0], 0.0, 0.0, //
+            0.0, values[1], 0.0, //
+            0.0, 0.0, values[2],
+        ]
+    }
+
+    fn rotated_tensor(angle: f64, values: [f64; 3]) -> Vec<f64> {
+        let (s, c) = angle.sin_cos();
+        let rotation = DMatrix::from_row_slice(3, 3, &[c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0]);
+        let tensor =
+            &rotation * DMatrix::from_row_slice(3, 3, &diagonal(values)) * rotation.transpose();
+        flatten(&symmetrize(tensor))
+    }
+
+    #[test]
+    fn test_anisotropy_indices() {
+        assert_relative_eq!(
+            fractional_anisotropy(&diagonal([2.0; 3])).unwrap(),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert!(fractional_anisotropy(&diagonal([1.0, 1e-6, 1e-6])).unwrap() > 0.999);
+        let fa = fractional_anisotropy(&rotated_tensor(0.7, [1.7, 0.3, 0.2])).unwrap();
+        let expected = fractional_anisotropy(&diagonal([1.7, 0.3, 0.2])).unwrap();
+        assert_relative_eq!(fa, expected, epsilon = 1e-10);
+        assert_relative_eq!(
+            mean_diffusivity(&diagonal([1.0, 2.0, 3.0])).unwrap(),
+            2.0,
+            epsilon = 1e-12
+        );
+        assert!(fractional_anisotropy(&diagonal([1.0, -1.0, 1.0])).is_err());
+    }
+
+    #[test]
+    fn test_interpolation_of_commuting_tensors_is_geometric() {
+        let a = diagonal([1.0, 4.0, 0.5]);
+        let b = diagonal([9.0, 1.0, 0.5]);
+        for metric in [TensorMetric::LogEuclidean, TensorMetric::AffineInvariant] {
+            let start = tensor_interpolate(&a, &b, 0.0, metric).unwrap();
+            let middle = tensor_interpolate(&a, &b, 0.5, metric).unwrap();
+            for (s, e) in start.iter().zip(&a) {
+                assert_relative_eq!(s, e, epsilon = 1e-10);
+            }
+            assert_relative_eq!(middle[0], 3.0, epsilon = 1e-10);
+            assert_relative_eq!(middle[4], 2.0, epsilon = 1e-10);
+            assert_relative_eq!(middle[8], 0.5, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_means_avoid_swelling() {
+        let tensors = vec![
+            rotated_tensor(0.0, [1.5, 0.2, 0.2]),
+            rotated_tensor(1.2, [1.5, 0.2, 0.2]),
+            rotated_tensor(2.1, [1.5, 0.2, 0.2]),
+        ];
+        let weights = [0.2, 0.5, 0.3];
+        let determinant = |t: &[f64]| DMatrix::from_row_slice(3, 3, t).determinant();
+        let euclidean: Vec<f64> = (0..9)
+            .map(|k| tensors.iter().zip(&weights).map(|(t, w)| w * t[k]).sum())
+            .collect();
+
+        for metric in [TensorMetric::LogEuclidean, TensorMetric::AffineInvariant] {
+            let mean = tensor_weighted_mean(&tensors, &weights, metric).unwrap();
+            assert!(SPD::new(3).check_point(&mean).is_ok());
+            assert_relative_eq!(determinant(&mean), 1.5 * 0.2 * 0.2, epsilon = 1e-10);
+            assert!(determinant(&mean) < determinant(&euclidean));
+        }
+    }
+
+    #[test]
+    fn test_field_interpolation_hits_voxels() {
+        let tensors: Vec<Vec<f64>> = (0..8)
+            .map(|k| rotated_tensor(0.2 * k as f64, [1.0 + k as f64 * 0.1, 0.4, 0.3]))
+            .collect();
+        let field = TensorField::new([2, 2, 2], tensors).unwrap();
+
+        let voxel = field
+            .interpolate([1.0, 0.0, 1.0], TensorMetric::AffineInvariant)
+            .unwrap();
+        for (v, e) in voxel.iter().zip(field.get([1, 0, 1])) {
+            assert_relative_eq!(v, e, epsilon = 1e-10);
+        }
+        let center = field
+            .interpolate([0.5, 0.5, 0.5], TensorMetric::LogEuclidean)
+            .unwrap();
+        assert!(SPD::new(3).check_point(&center).is_ok());
+        assert!(field
+            .interpolate([1.5, 0.0, 0.0], TensorMetric::LogEuclidean)
+            .is_err());
+        assert_eq!(field.fractional_anisotropy_map().unwrap().len(), 8);
+
+        assert!(matches!(
+            TensorField::new([0, 2, 2], Vec::new()),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        let empty = TensorField {
+            shape: [2, 0, 2],
+            tensors: Vec::new(),
+        };
+        assert!(matches!(
+            empty.interpolate([0.0; 3], TensorMetric::LogEuclidean),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_smoothing_preserves_constant_field_and_reduces_variation() {
+        let constant =
+            TensorField::new([3, 3, 1], vec![rotated_tensor(0.4, [1.2, 0.5, 0.3]); 9]).unwrap();
+        let smoothed = constant.smooth(1.0, 1, TensorMetric::LogEuclidean).unwrap();
+        for (s, c) in smoothed.tensors.iter().zip(&constant.tensors) {
+            for (a, b) in s.iter().zip(c) {
+                assert_relative_eq!(a, b, epsilon = 1e-10);
+            }
+        }
+
+        let noisy: Vec<Vec<f64>> = (0..9)
+            .map(|k| diagonal([1.0 + 0.5 * (k % 2) as f64, 0.5, 0.5]))
+            .collect();
+        let field = TensorField::new([3, 3, 1], noisy).unwrap();
+        let smoothed = field.smooth(1.0, 1, TensorMetric::AffineInvariant).unwrap();
+        let range = |f: &TensorField| {
+            let md = f.mean_diffusivity_map().unwrap();
+            md.iter().cloned().fold(f64::MIN, f64::max)
+                - md.iter().cloned().fold(f64::MAX, f64::min)
+        };
+        assert!(range(&smoothed) < 0.5 * range(&field));
+        assert!(field.smooth(0.0, 1, TensorMetric::LogEuclidean).is_err());
+    }
+}
@@ -0,0 +1,282 @@
+use crate::mean::FrechetMean;
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::{Manifold, ManifoldError, RandomSource, Result, TangentVector};
+use std::f64::consts::PI;
+
+#[derive(Clone)]
+pub struct TangentGaussian {
+    pub mean: Vec<f64>,
+    pub covariance: DMatrix<f64>,
+    basis: Vec<TangentVector<f64>>,
+    cholesky: DMatrix<f64>,
+}
+
+impl TangentGaussian {
+    pub fn new<M>(manifold: &M, mean: Vec<f64>, covariance: DMatrix<f64>) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let basis = manifold.tangent_basis(&mean)?;
+        if covariance.nrows() != basis.len() || covariance.ncols() != basis.len() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: basis.len(),
+                got: covariance.nrows(),
+            });
+        }
+        let cholesky = covariance
+            .clone()
+            .cholesky()
+            .ok_or_else(|| {
+                ManifoldError::InvalidParameter(
+                    "Covariance must be symmetric positive definite".to_string(),
+                )
+            })?
+            .l();
+
+        Ok(Self {
+            mean,
+            covariance,
+            basis,
+            cholesky,
+        })
+    }
+
+    pub fn isotropic<M>(manifold: &M, mean: Vec<f64>, variance: f64) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let d = manifold.dim();
+        Self::new(manifold, mean, DMatrix::identity(d, d) * variance)
+    }
+
+    pub fn fit<M>(manifold: &M, points: &[Vec<f64>], estimator: &FrechetMean) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let mean = estimator.compute(manifold, points)?;
+        let basis = manifold.tangent_basis(&mean)?;
+        let d = basis.len();
+
+        let mut covariance = DMatrix::zeros(d, d);
+        for point in points {
+            let c = coordinates(manifold, &mean, &basis, point)?;
+            covariance += &c * c.transpose();
+        }
+        covariance /= points.len() as f64;
+
+        Self::new(manifold, mean, covariance)
+    }
+
+    pub fn dim(&self) -> usize {
+        self.basis.len()
+    }
+
+    pub fn tangent_coordinates<M>(&self, manifold: &M, point: &[f64]) -> Result<DVector<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        coordinates(manifold, &self.mean, &self.basis, point)
+    }
+
+    pub fn sample<M, R>(&self, manifold: &M, rng: &mut R) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+        R: RandomSource + ?Sized,
+    {
+        let z = DVector::from_fn(self.dim(), |_, _| rng.next_gaussian());
+        let c = &self.cholesky * z;
+        let mut tangent = TangentVector::zero(self.mean.len());
+        for (b, w) in self.basis.iter().zip(c.iter()) {
+            tangent += b * *w;
+        }
+        manifold.exp(&self.mean, &tangent)
+    }
+
+    pub fn sample_n<M, R>(&self, manifold: &M, count: usize, rng: &mut R) -> Result<Vec<Vec<f64>>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+        R: RandomSource + ?Sized,
+    {
+        (0..count).map(|_| self.sample(manifold, rng)).collect()
+    }
+
+    #[cfg(feature = "rand")]
+    pub fn sampler<'a, M>(&'a self, manifold: &'a M) -> TangentGaussianSampler<'a, M>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        TangentGaussianSampler {
+            gaussian: self,
+            manifold,
+        }
+    }
+
+    pub fn tangent_log_density<M>(&self, manifold: &M, point: &[f64]) -> Result<f64>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let c = self.tangent_coordinates(manifold, point)?;
+        let whitened = self.cholesky.solve_lower_triangular(&c).ok_or_else(|| {
+            ManifoldError::LinearAlgebraError("Triangular solve failed".to_string())
+        })?;
+        let log_det: f64 = self.cholesky.diagonal().iter().map(|l| l.ln()).sum::<f64>() * 2.0;
+        Ok(-0.5 * (whitened.norm_squared() + log_det + self.dim() as f64 * (2.0 * PI).ln()))
+    }
+
+    /// Points at or beyond the cut locus of the mean, where the volume correction is undefined
+    /// on a manifold that otherwise provides one, have density zero (`-inf`).
+    pub fn log_density<M>(&self, manifold: &M, point: &[f64]) -> Result<f64>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let density = self.tangent_log_density(manifold, point)?;
+        let tangent = manifold.log(&self.mean, point)?;
+        Ok(match manifold.log_volume_change(&self.mean, &tangent) {
+            Some(change) => density - change,
+            None if manifold
+                .log_volume_change(&self.mean, &TangentVector::zero(self.mean.len()))
+                .is_some() =>
+            {
+                f64::NEG_INFINITY
+            }
+            None => density,
+        })
+    }
+}
+
+#[cfg(feature = "rand")]
+pub struct TangentGaussianSampler<'a, M: ?Sized> {
+    gaussian: &'a TangentGaussian,
+    manifold: &'a M,
+}
+
+#[cfg(feature = "rand")]
+impl<M> rand::distr::Distribution<Result<Vec<f64>>> for TangentGaussianSampler<'_, M>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Result<Vec<f64>> {
+        let mut source = poly_manifold_core::RandSource(rng);
+        self.gaussian.sample(self.manifold, &mut source)
+    }
+}
+
+fn coordinates<M>(
+    manifold: &M,
+    mean: &[f64],
+    basis: &[TangentVector<f64>],
+    point: &[f64],
+) -> Result<DVector<f64>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    let tangent = manifold.log(mean, point)?;
+    basis
+        .iter()
+        .map(|b| manifold.inner_product(mean, &tangent, b))
+        .collect::<Result<Vec<_>>>()
+        .map(DVector::from_vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_core::SeededRng;
+    use poly_manifold_spaces::{Euclidean, Sphere, SO3};
+
+    #[test]
+    fn test_euclidean_log_density_matches_normal() {
+        let euclidean = Euclidean::new(1);
+        let gaussian = TangentGaussian::isotropic(&euclidean, vec![1.0], 4.0).unwrap();
+        let expected = -0.5 * (0.25 + (2.0 * PI * 4.0).ln());
+        assert_relative_eq!(
+            gaussian.log_density(&euclidean, &[2.0]).unwrap(),
+            expected,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_sphere_log_density_includes_volume_change() {
+        let sphere = Sphere::new(2);
+        let gaussian = TangentGaussian::isotropic(&sphere, vec![0.0, 0.0, 1.0], 0.5).unwrap();
+        let point = [1.0f64.sin(), 0.0, 1.0f64.cos()];
+
+        let tangent = gaussian.tangent_log_density(&sphere, &point).unwrap();
+        let corrected = gaussian.log_density(&sphere, &point).unwrap();
+        assert_relative_eq!(corrected - tangent, -(1.0f64.sin()).ln(), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_log_density_is_zero_density_at_cut_locus() {
+        let so3 = SO3::new();
+        let gaussian = TangentGaussian::isotropic(&so3, SO3::identity(), 0.5).unwrap();
+        let half_turn = SO3::from_axis_angle(&[0.0, 0.0, 1.0], PI);
+
+        assert!(gaussian
+            .tangent_log_density(&so3, &half_turn)
+            .unwrap()
+            .is_finite());
+        assert_eq!(
+            gaussian.log_density(&so3, &half_turn).unwrap(),
+            f64::NEG_INFINITY
+        );
+    }
+
+    #[test]
+    fn test_samples_lie_on_manifold_and_fit_recovers_parameters() {
+        let sphere = Sphere::new(2);
+        let mean = vec![0.0, 0.0, 1.0];
+        let covariance = DMatrix::from_row_slice(2, 2, &[0.04, 0.0, 0.0, 0.01]);
+        let gaussian = TangentGaussian::new(&sphere, mean, covariance).unwrap();
+
+        let mut rng = SeededRng::seed_from_u64(42);
+        let samples = gaussian.sample_n(&sphere, 4000, &mut rng).unwrap();
+        for sample in samples.iter().take(10) {
+            assert!(sphere.check_point(sample).is_ok());
+        }
+
+        let fitted = TangentGaussian::fit(&sphere, &samples, &FrechetMean::default()).unwrap();
+        assert_relative_eq!(fitted.mean[2], 1.0, epsilon = 1e-2);
+        let mut variances: Vec<f64> = fitted
+            .covariance
+            .symmetric_eigen()
+            .eigenvalues
+            .iter()
+            .copied()
+            .collect();
+        variances.sort_by(f64::total_cmp);
+        assert_relative_eq!(variances[0], 0.01, epsilon = 2e-3);
+        assert_relative_eq!(variances[1], 0.04, epsilon = 5e-3);
+    }
+
+    #[test]
+    fn test_rejects_invalid_covariance() {
+        let sphere = Sphere::new(2);
+        let mean = vec![0.0, 0.0, 1.0];
+        assert!(TangentGaussian::new(&sphere, mean.clone(), DMatrix::identity(3, 3)).is_err());
+        assert!(TangentGaussian::new(&sphere, mean, DMatrix::zeros(2, 2)).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sampler_implements_rand_distribution() {
+        use rand::distr::Distribution;
+
+        let sphere = Sphere::new(2);
+        let gaussian = TangentGaussian::isotropic(&sphere, vec![0.0, 0.0, 1.0], 0.01).unwrap();
+        let sampler = gaussian.sampler(&sphere);
+        let mut a = SeededRng::seed_from_u64(11);
+        let mut b = SeededRng::seed_from_u64(11);
+        let via_rand = sampler.sample(&mut a).unwrap();
+        assert_eq!(via_rand, gaussian.sample(&sphere, &mut b).unwrap());
+
+        let samples = sampler
+            .sample_iter(a)
+            .take(50)
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert!(samples.iter().all(|s| sphere.check_point(s).is_ok()));
+    }
+}
@@ -0,0 +1,282 @@
+use poly_manifold_autodiff::{GradientDescent, IterativeOptimizer};
+use poly_manifold_core::{Context, Manifold, ManifoldError, RandomSource, Result, SeededRng};
+use poly_manifold_spaces::{PoincareBall, PowerManifold};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphEmbedding {
+    pub points: Vec<Vec<f64>>,
+    pub epoch_losses: Vec<f64>,
+}
+
+impl GraphEmbedding {
+    pub fn distance(&self, u: usize, v: usize) -> Result<f64> {
+        let point = |i: usize| {
+            self.points.get(i).ok_or_else(|| {
+                ManifoldError::InvalidParameter(format!(
+                    "Node {} is out of range for {} nodes",
+                    i,
+                    self.points.len()
+                ))
+            })
+        };
+        let (p, q) = (point(u)?, point(v)?);
+        PoincareBall::new(p.len()).distance(p, q)
+    }
+
+    pub fn lorentz_points(&self) -> Vec<Vec<f64>> {
+        self.points.iter().map(|p| poincare_to_lorentz(p)).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoincareEmbedding {
+    pub dimension: usize,
+    pub epochs: usize,
+    pub learning_rate: f64,
+    pub negatives: usize,
+    pub burn_in_epochs: usize,
+    pub burn_in_factor: f64,
+    pub initial_radius: f64,
+    pub seed: u64,
+}
+
+impl PoincareEmbedding {
+    pub fn new(dimension: usize, epochs: usize, learning_rate: f64) -> Self {
+        Self {
+            dimension,
+            epochs,
+            learning_rate,
+            negatives: 10,
+            burn_in_epochs: 10,
+            burn_in_factor: 0.1,
+            initial_radius: 1e-3,
+            seed: Context::current().seed,
+        }
+    }
+
+    pub fn with_negatives(mut self, negatives: usize) -> Self {
+        self.negatives = negatives;
+        self
+    }
+
+    pub fn with_burn_in(mut self, epochs: usize, factor: f64) -> Self {
+        self.burn_in_epochs = epochs;
+        self.burn_in_factor = factor;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_context(mut self, context: &Context) -> Self {
+        self.seed = context.seed;
+        self
+    }
+
+    pub fn train(&self, num_nodes: usize, edges: &[(usize, usize)]) -> Result<GraphEmbedding> {
+        if self.dimension == 0 || num_nodes < 2 {
+            return Err(ManifoldError::InvalidParameter(
+                "Embedding needs a positive dimension and at least two nodes".to_string(),
+            ));
+        }
+        let positive = |x: f64| x > 0.0 && x.is_finite();
+        if !(positive(self.learning_rate) && positive(self.burn_in_factor)) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Learning rate and burn-in factor must be positive and finite, got {} and {}",
+                self.learning_rate, self.burn_in_factor
+            )));
+        }
+        if let Some(&(u, v)) = edges
+            .iter()
+            .find(|(u, v)| *u >= num_nodes || *v >= num_nodes || u == v)
+        {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Invalid edge ({}, {}) for {} nodes",
+                u, v, num_nodes
+            )));
+        }
+
+        let ball = PoincareBall::new(self.dimension);
+        let mut rng = SeededRng::seed_from_u64(self.seed);
+        let mut points: Vec<Vec<f64>> = (0..num_nodes)
+            .map(|_| {
+                (0..self.dimension)
+                    .map(|_| self.initial_radius * (2.0 * rng.next_f64() - 1.0))
+                    .collect()
+            })
+            .collect();
+
+        let mut adjacency = vec![HashSet::new(); num_nodes];
+        for &(u, v) in edges {
+            adjacency[u].insert(v);
+            adjacency[v].insert(u);
+        }
+
+        let mut order: Vec<usize> = (0..edges.len()).collect();
+        let mut epoch_losses = Vec::with_capacity(self.epochs);
+        for epoch in 0..self.epochs {
+            let rate = if epoch < self.burn_in_epochs {
+                self.learning_rate * self.burn_in_factor
+            } else {
+                self.learning_rate
+            };
+            let optimizer = GradientDescent::builder()
+                .learning_rate(rate)
+                .max_iterations(1)
+                .build()?;
+            rng.shuffle(&mut order);
+
+            let mut epoch_loss = 0.0;
+            for &e in &order {
+                let (u, v) = edges[e];
+                let mut candidates = vec![v];
+                for _ in 0..self.negatives * 10 {
+                    if candidates.len() > self.negatives {
+                        break;
+                    }
+                    let Some(w) = rng.next_below(num_nodes) else {
+                        break;
+                    };
+                    if w != u && !adjacency[u].contains(&w) && !candidates.contains(&w) {
+                        candidates.push(w);
+                    }
+                }
+                epoch_loss += update(&ball, &mut points, u, &candidates, &optimizer)?;
+            }
+            epoch_losses.push(epoch_loss / edges.len().max(1) as f64);
+        }
+
+        Ok(GraphEmbedding {
+            points,
+            epoch_losses,
+        })
+    }
+}
+
+// One stochastic step on the anchor and its candidates, the first of which is the positive
+// neighbour; returns the negative-sampling loss before the step.
+fn update(
+    ball: &PoincareBall,
+    points: &mut [Vec<f64>],
+    u: usize,
+    candidates: &[usize],
+    optimizer: &GradientDescent,
+) -> Result<f64> {
+    let nodes: Vec<usize> = std::iter::once(u)
+        .chain(candidates.iter().copied())
+        .collect();
+    let local = PowerManifold::new(ball, nodes.len());
+    let initial: Vec<f64> = nodes.iter().flat_map(|n| points[*n].clone()).collect();
+    let cost = |p: &[f64]| negative_sampling_loss(ball, p).unwrap_or(f64::INFINITY);
+
+    let mut state = optimizer.init(&local, &initial, cost)?;
+    let loss = state.cost;
+    optimizer.step(&local, &mut state, cost)?;
+    for (node, point) in nodes.iter().zip(state.point.chunks(ball.dimension)) {
+        points[*node] = point.to_vec();
+    }
+    Ok(loss)
+}
+
+fn negative_sampling_loss(ball: &PoincareBall, local: &[f64]) -> Result<f64> {
+    let mut chunks = local.chunks(ball.dimension);
+    let anchor = chunks.next().unwrap_or_default();
+    let distances = chunks
+        .map(|c| ball.distance(anchor, c))
+        .collect::<Result<Vec<_>>>()?;
+    let shift = distances.iter().cloned().fold(f64::INFINITY, f64::min);
+    let total: f64 = distances.iter().map(|d| (-(d - shift)).exp()).sum();
+    Ok(distances[0] - shift + total.ln())
+}
+
+pub fn poincare_to_lorentz(point: &[f64]) -> Vec<f64> {
+    let norm_sq: f64 = point.iter().map(|x| x * x).sum();
+    let denominator = 1.0 - norm_sq;
+    let mut lorentz = Vec::with_capacity(point.len() + 1);
+    lorentz.push((1.0 + norm_sq) / denominator);
+    lorentz.extend(point.iter().map(|x| 2.0 * x / denominator));
+    lorentz
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn tree() -> (usize, Vec<(usize, usize)>) {
+        (7, vec![(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (2, 6)])
+    }
+
+    #[test]
+    fn test_training_separates_edges_from_non_edges() {
+        let (n, edges) = tree();
+        let embedding = PoincareEmbedding::new(2, 300, 0.3)
+            .with_negatives(4)
+            .with_seed(7)
+            .train(n, &edges)
+            .unwrap();
+
+        let first = embedding.epoch_losses[0];
+        let last = *embedding.epoch_losses.last().unwrap();
+        assert!(last < first);
+
+        let ball = PoincareBall::new(2);
+        for point in &embedding.points {
+            assert!(ball.check_point(point).is_ok());
+        }
+        let edge_mean = edges
+            .iter()
+            .map(|(u, v)| embedding.distance(*u, *v).unwrap())
+            .sum::<f64>()
+            / edges.len() as f64;
+        let leaf_mean = [(3, 5), (4, 6), (3, 6), (4, 5)]
+            .iter()
+            .map(|(u, v)| embedding.distance(*u, *v).unwrap())
+            .sum::<f64>()
+            / 4.0;
+        assert!(edge_mean < leaf_mean);
+    }
+
+    #[test]
+    fn test_lorentz_points_lie_on_hyperboloid() {
+        let (n, edges) = tree();
+        let embedding = PoincareEmbedding::new(3, 5, 0.1).train(n, &edges).unwrap();
+        for point in embedding.lorentz_points() {
+            let spatial: f64 = point[1..].iter().map(|x| x * x).sum();
+            assert_relative_eq!(point[0] * point[0] - spatial, 1.0, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_invalid_graph() {
+        let trainer = PoincareEmbedding::new(2, 1, 0.1);
+        assert!(trainer.train(3, &[(0, 3)]).is_err());
+        assert!(trainer.train(3, &[(1, 1)]).is_err());
+        assert!(trainer.train(1, &[]).is_err());
+        assert!(PoincareEmbedding::new(0, 1, 0.1)
+            .train(3, &[(0, 1)])
+            .is_err());
+        for rate in [0.0, -0.1, f64::NAN] {
+            assert!(matches!(
+                PoincareEmbedding::new(2, 1, rate).train(3, &[(0, 1)]),
+                Err(ManifoldError::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_distance_rejects_unknown_nodes() {
+        let (n, edges) = tree();
+        let embedding = PoincareEmbedding::new(2, 1, 0.1).train(n, &edges).unwrap();
+        assert!(embedding.distance(0, 6).is_ok());
+        assert!(matches!(
+            embedding.distance(0, 7),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+    }
+}
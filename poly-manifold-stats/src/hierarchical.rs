@@ -0,0 +1,304 @@
+use crate::kmeans::check_cluster_count;
+use crate::kmedoids::pairwise_distances;
+use crate::mean::FrechetMean;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Manifold, ManifoldError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Linkage {
+    Single,
+    Complete,
+    #[default]
+    Average,
+    Centroid,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Merge {
+    pub left: usize,
+    pub right: usize,
+    pub distance: f64,
+    pub size: usize,
+    /// Fréchet mean of the merged cluster, only computed for [`Linkage::Centroid`].
+    pub centroid: Option<Vec<f64>>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dendrogram {
+    pub num_points: usize,
+    pub merges: Vec<Merge>,
+}
+
+impl Dendrogram {
+    pub fn cut(&self, k: usize) -> Result<Vec<usize>> {
+        check_cluster_count(k, self.num_points)?;
+        Ok(self.labels(self.num_points - k))
+    }
+
+    pub fn cut_at_height(&self, height: f64) -> Vec<usize> {
+        let applied = self
+            .merges
+            .iter()
+            .take_while(|m| m.distance <= height)
+            .count();
+        self.labels(applied)
+    }
+
+    pub fn leaf_order(&self) -> Vec<usize> {
+        let Some(root) = self.merges.len().checked_sub(1) else {
+            return (0..self.num_points).collect();
+        };
+        let mut order = Vec::with_capacity(self.num_points);
+        let mut stack = vec![self.num_points + root];
+        while let Some(node) = stack.pop() {
+            if node < self.num_points {
+                order.push(node);
+            } else {
+                let merge = &self.merges[node - self.num_points];
+                stack.push(merge.right);
+                stack.push(merge.left);
+            }
+        }
+        order
+    }
+
+    fn labels(&self, applied: usize) -> Vec<usize> {
+        let mut parent: Vec<usize> = (0..self.num_points + applied).collect();
+        for (i, merge) in self.merges[..applied].iter().enumerate() {
+            parent[merge.left] = self.num_points + i;
+            parent[merge.right] = self.num_points + i;
+        }
+        let root = |mut node: usize| {
+            while parent[node] != node {
+                node = parent[node];
+            }
+            node
+        };
+
+        let mut roots: Vec<usize> = Vec::new();
+        (0..self.num_points)
+            .map(|i| {
+                let r = root(i);
+                match roots.iter().position(|x| *x == r) {
+                    Some(label) => label,
+                    None => {
+                        roots.push(r);
+                        roots.len() - 1
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HierarchicalClustering {
+    pub linkage: Linkage,
+    pub estimator: FrechetMean,
+}
+
+impl Default for HierarchicalClustering {
+    fn default() -> Self {
+        Self::new(Linkage::default())
+    }
+}
+
+impl HierarchicalClustering {
+    pub fn new(linkage: Linkage) -> Self {
+        Self {
+            linkage,
+            estimator: FrechetMean::new(200, 1e-10).with_step_size(0.5),
+        }
+    }
+
+    pub fn with_estimator(mut self, estimator: FrechetMean) -> Self {
+        self.estimator = estimator;
+        self
+    }
+
+    pub fn fit<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<Dendrogram>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let n = points.len();
+        if n == 0 {
+            return Err(ManifoldError::InvalidParameter(
+                "Cannot cluster an empty point set".to_string(),
+            ));
+        }
+        for point in points {
+            validate_entry_point(manifold, point)?;
+        }
+
+        let mut distances = pairwise_distances(manifold, points)?;
+        let mut node: Vec<usize> = (0..n).collect();
+        let mut members: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+        let mut centroids: Vec<Vec<f64>> = points.to_vec();
+        let mut active = vec![true; n];
+        let mut merges = Vec::with_capacity(n - 1);
+
+        for _ in 1..n {
+            let mut best = (usize::MAX, usize::MAX, f64::INFINITY);
+            for i in (0..n).filter(|i| active[*i]) {
+                for j in (i + 1..n).filter(|j| active[*j]) {
+                    if distances[(i, j)] < best.2 {
+                        best = (i, j, distances[(i, j)]);
+                    }
+                }
+            }
+            let (a, b, distance) = best;
+            if a == usize::MAX {
+                return Err(ManifoldError::NumericalError(
+                    "Linkage distances are not finite".to_string(),
+                ));
+            }
+
+            let (size_a, size_b) = (members[a].len() as f64, members[b].len() as f64);
+            let merged: Vec<usize> = members[a].iter().chain(&members[b]).copied().collect();
+            let by_centroid = self.linkage == Linkage::Centroid;
+            if by_centroid {
+                let merged_points: Vec<Vec<f64>> =
+                    merged.iter().map(|i| points[*i].clone()).collect();
+                centroids[a] = self.estimator.compute(manifold, &merged_points)?;
+            }
+
+            for k in (0..n).filter(|k| active[*k] && *k != a && *k != b) {
+                let updated = match self.linkage {
+                    Linkage::Single => distances[(a, k)].min(distances[(b, k)]),
+                    Linkage::Complete => distances[(a, k)].max(distances[(b, k)]),
+                    Linkage::Average => {
+                        (size_a * distances[(a, k)] + size_b * distances[(b, k)])
+                            / (size_a + size_b)
+                    }
+                    Linkage::Centroid => manifold.distance(&centroids[a], &centroids[k])?,
+                };
+                distances[(a, k)] = updated;
+                distances[(k, a)] = updated;
+            }
+
+            merges.push(Merge {
+                left: node[a],
+                right: node[b],
+                distance,
+                size: merged.len(),
+                centroid: by_centroid.then(|| centroids[a].clone()),
+            });
+            node[a] = n + merges.len() - 1;
+            members[a] = merged;
+            active[b] = false;
+        }
+
+        Ok(Dendrogram {
+            num_points: n,
+            merges,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, PoincareBall};
+
+    fn taxonomy() -> Vec<Vec<f64>> {
+        vec![
+            vec![0.02, 0.01],
+            vec![-0.01, 0.03],
+            vec![0.0, -0.02],
+            vec![0.85, 0.05],
+            vec![0.88, -0.02],
+            vec![0.83, 0.1],
+            vec![-0.1, -0.86],
+            vec![-0.02, -0.9],
+        ]
+    }
+
+    #[test]
+    fn test_separates_groups_in_poincare_ball() {
+        let ball = PoincareBall::new(2);
+        for linkage in [
+            Linkage::Single,
+            Linkage::Complete,
+            Linkage::Average,
+            Linkage::Centroid,
+        ] {
+            let dendrogram = HierarchicalClustering::new(linkage)
+                .fit(&ball, &taxonomy())
+                .unwrap();
+            assert_eq!(dendrogram.merges.len(), 7);
+            assert_eq!(dendrogram.merges[6].size, 8);
+            assert_eq!(dendrogram.cut(3).unwrap(), vec![0, 0, 0, 1, 1, 1, 2, 2]);
+            assert!(dendrogram
+                .merges
+                .iter()
+                .all(|m| m.centroid.is_some() == (linkage == Linkage::Centroid)));
+        }
+    }
+
+    #[test]
+    fn test_single_linkage_heights_on_a_line() {
+        let euclidean = Euclidean::new(1);
+        let points = vec![vec![0.0], vec![1.0], vec![3.0], vec![7.0]];
+        let dendrogram = HierarchicalClustering::new(Linkage::Single)
+            .fit(&euclidean, &points)
+            .unwrap();
+        let heights: Vec<f64> = dendrogram.merges.iter().map(|m| m.distance).collect();
+        assert_eq!(heights, vec![1.0, 2.0, 4.0]);
+        assert_eq!(
+            (dendrogram.merges[0].left, dendrogram.merges[0].right),
+            (0, 1)
+        );
+        assert_eq!(
+            (dendrogram.merges[1].left, dendrogram.merges[1].right),
+            (4, 2)
+        );
+        assert_eq!(dendrogram.leaf_order(), vec![0, 1, 2, 3]);
+        assert_eq!(dendrogram.cut_at_height(1.5), vec![0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_average_linkage_is_monotone() {
+        let ball = PoincareBall::new(2);
+        let dendrogram = HierarchicalClustering::default()
+            .fit(&ball, &taxonomy())
+            .unwrap();
+        for pair in dendrogram.merges.windows(2) {
+            assert!(pair[0].distance <= pair[1].distance + 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_root_centroid_is_hyperbolic_mean() {
+        let ball = PoincareBall::new(2);
+        let points = taxonomy();
+        let dendrogram = HierarchicalClustering::new(Linkage::Centroid)
+            .fit(&ball, &points)
+            .unwrap();
+        let root = dendrogram.merges.last().unwrap().centroid.as_ref().unwrap();
+        let mean = FrechetMean::new(1000, 1e-10)
+            .compute(&ball, &points)
+            .unwrap();
+        assert!(ball.check_point(root).is_ok());
+        assert_relative_eq!(ball.distance(root, &mean).unwrap(), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let ball = PoincareBall::new(2);
+        assert!(HierarchicalClustering::default().fit(&ball, &[]).is_err());
+        assert!(HierarchicalClustering::default()
+            .fit(&ball, &[vec![1.5, 0.0]])
+            .is_err());
+        let single = HierarchicalClustering::default()
+            .fit(&ball, &[vec![0.1, 0.0]])
+            .unwrap();
+        assert!(single.merges.is_empty());
+        assert_eq!(single.cut(1).unwrap(), vec![0]);
+        assert!(single.cut(2).is_err());
+    }
+}
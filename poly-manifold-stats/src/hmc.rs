@@ -0,0 +1,290 @@
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{
+    Context, Manifold, ManifoldError, RandomSource, Result, SeededRng, TangentVector,
+};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HmcSamples {
+    pub samples: Vec<Vec<f64>>,
+    pub acceptance_rate: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeodesicHmc {
+    pub step_size: f64,
+    pub leapfrog_steps: usize,
+    pub step_jitter: f64,
+    pub burn_in: usize,
+    pub seed: u64,
+}
+
+impl GeodesicHmc {
+    pub fn new(step_size: f64, leapfrog_steps: usize) -> Self {
+        Self {
+            step_size,
+            leapfrog_steps,
+            step_jitter: 0.2,
+            burn_in: 100,
+            seed: Context::current().seed,
+        }
+    }
+
+    pub fn with_step_jitter(mut self, step_jitter: f64) -> Self {
+        self.step_jitter = step_jitter;
+        self
+    }
+
+    pub fn with_burn_in(mut self, burn_in: usize) -> Self {
+        self.burn_in = burn_in;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_context(mut self, context: &Context) -> Self {
+        self.seed = context.seed;
+        self
+    }
+
+    pub fn sample<M, F>(
+        &self,
+        manifold: &M,
+        initial: &[f64],
+        log_density: F,
+        num_samples: usize,
+    ) -> Result<HmcSamples>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+        F: Fn(&[f64]) -> f64,
+    {
+        if !(self.step_size > 0.0 && self.step_size.is_finite()) || self.leapfrog_steps == 0 {
+            return Err(ManifoldError::InvalidParameter(
+                "Step size must be positive and at least one leapfrog step is required".to_string(),
+            ));
+        }
+        if !(0.0..1.0).contains(&self.step_jitter) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Step jitter must lie in [0, 1), got {}",
+                self.step_jitter
+            )));
+        }
+        validate_entry_point(manifold, initial)?;
+        let initial_density = log_density(initial);
+        if !initial_density.is_finite() {
+            return Err(ManifoldError::InvalidParameter(
+                "Log-density must be finite at the initial point".to_string(),
+            ));
+        }
+
+        let mut rng = SeededRng::seed_from_u64(self.seed);
+        let mut current = initial.to_vec();
+        let mut current_density = initial_density;
+        let mut samples = Vec::with_capacity(num_samples);
+        let mut accepted = 0;
+
+        for iteration in 0..self.burn_in + num_samples {
+            let basis = manifold.tangent_basis(&current)?;
+            let mut momentum = TangentVector::zero(current.len());
+            for direction in &basis {
+                momentum += direction * rng.next_gaussian();
+            }
+            let kinetic = 0.5 * manifold.inner_product(&current, &momentum, &momentum)?;
+            let step_size =
+                self.step_size * (1.0 + self.step_jitter * (2.0 * rng.next_f64() - 1.0));
+
+            if let Some((proposal, proposal_density, proposal_kinetic)) =
+                self.trajectory(manifold, &current, momentum, step_size, &log_density)
+            {
+                let log_ratio = proposal_density - proposal_kinetic - (current_density - kinetic);
+                if rng.next_f64().ln() < log_ratio {
+                    current = proposal;
+                    current_density = proposal_density;
+                    if iteration >= self.burn_in {
+                        accepted += 1;
+                    }
+                }
+            }
+
+            if iteration >= self.burn_in {
+                samples.push(current.clone());
+            }
+        }
+
+        let acceptance_rate = if num_samples > 0 {
+            accepted as f64 / num_samples as f64
+        } else {
+            0.0
+        };
+        Ok(HmcSamples {
+            samples,
+            acceptance_rate,
+        })
+    }
+
+    fn trajectory<M, F>(
+        &self,
+        manifold: &M,
+        start: &[f64],
+        mut momentum: TangentVector<f64>,
+        eps: f64,
+        log_density: &F,
+    ) -> Option<(Vec<f64>, f64, f64)>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+        F: Fn(&[f64]) -> f64,
+    {
+        let mut point = start.to_vec();
+        momentum += intrinsic_gradient(manifold, &point, log_density).ok()? * (0.5 * eps);
+
+        for step in 0..self.leapfrog_steps {
+            let displacement = &momentum * eps;
+            let next = manifold.exp(&point, &displacement).ok()?;
+            let next = manifold.project_to_manifold(&next).ok()?;
+            let velocity = match manifold.log(&next, &point) {
+                Ok(back) => back * (-1.0 / eps),
+                Err(_) => manifold
+                    .parallel_transport(&point, &momentum, &displacement)
+                    .ok()?,
+            };
+            momentum = manifold.project_to_tangent_space(&next, &velocity).ok()?;
+            point = next;
+
+            let scale = if step + 1 == self.leapfrog_steps {
+                0.5 * eps
+            } else {
+                eps
+            };
+            momentum += intrinsic_gradient(manifold, &point, log_density).ok()? * scale;
+        }
+
+        let density = log_density(&point);
+        if !density.is_finite() {
+            return None;
+        }
+        let kinetic = 0.5 * manifold.inner_product(&point, &momentum, &momentum).ok()?;
+        Some((point, density, kinetic))
+    }
+}
+
+fn intrinsic_gradient<M, F>(
+    manifold: &M,
+    point: &[f64],
+    log_density: &F,
+) -> Result<TangentVector<f64>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+    F: Fn(&[f64]) -> f64,
+{
+    let h = 1e-5;
+    let mut gradient = TangentVector::zero(point.len());
+    for direction in manifold.tangent_basis(point)? {
+        let forward = manifold.exp(point, &(&direction * h))?;
+        let backward = manifold.exp(point, &(&direction * -h))?;
+        let slope = (log_density(&forward) - log_density(&backward)) / (2.0 * h);
+        if !slope.is_finite() {
+            return Err(ManifoldError::NumericalError(
+                "Log-density gradient is not finite".to_string(),
+            ));
+        }
+        gradient += direction * slope;
+    }
+    Ok(gradient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere, SO3};
+
+    #[test]
+    fn test_euclidean_standard_gaussian_moments() {
+        let euclidean = Euclidean::new(2);
+        let log_density = |x: &[f64]| -0.5 * (x[0] * x[0] + x[1] * x[1]);
+        let result = GeodesicHmc::new(0.3, 10)
+            .with_seed(1)
+            .sample(&euclidean, &[2.0, -2.0], log_density, 3000)
+            .unwrap();
+
+        let n = result.samples.len() as f64;
+        let mean: f64 = result.samples.iter().map(|x| x[0]).sum::<f64>() / n;
+        let var: f64 = result
+            .samples
+            .iter()
+            .map(|x| (x[0] - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        assert_relative_eq!(mean, 0.0, epsilon = 0.1);
+        assert_relative_eq!(var, 1.0, epsilon = 0.15);
+        assert!(result.acceptance_rate > 0.8);
+    }
+
+    #[test]
+    fn test_von_mises_fisher_on_sphere() {
+        let sphere = Sphere::new(2);
+        let kappa = 5.0;
+        let log_density = |x: &[f64]| kappa * x[2];
+        let result = GeodesicHmc::new(0.1, 10)
+            .with_seed(2)
+            .sample(&sphere, &[1.0, 0.0, 0.0], log_density, 4000)
+            .unwrap();
+
+        for sample in &result.samples {
+            assert!(sphere.check_point(sample).is_ok());
+        }
+        let mean_cosine: f64 =
+            result.samples.iter().map(|x| x[2]).sum::<f64>() / result.samples.len() as f64;
+        let expected = 1.0 / kappa.tanh() - 1.0 / kappa;
+        assert_relative_eq!(mean_cosine, expected, epsilon = 0.03);
+    }
+
+    #[test]
+    fn test_concentrated_rotations() {
+        let so3 = SO3::new();
+        let center = SO3::from_axis_angle(&[0.0, 1.0, 0.0], 0.8);
+        let concentration = 50.0;
+        let log_density = |x: &[f64]| {
+            let trace: f64 = (0..3)
+                .map(|i| {
+                    (0..3)
+                        .map(|k| center[3 * k + i] * x[3 * k + i])
+                        .sum::<f64>()
+                })
+                .sum();
+            concentration * trace
+        };
+        let result = GeodesicHmc::new(0.05, 10)
+            .with_seed(3)
+            .sample(&so3, &SO3::identity(), log_density, 500)
+            .unwrap();
+
+        let mean_angle: f64 = result
+            .samples
+            .iter()
+            .map(|x| so3.distance(x, &center).unwrap())
+            .sum::<f64>()
+            / result.samples.len() as f64;
+        assert!(mean_angle < 0.4);
+        assert!(result.acceptance_rate > 0.5);
+    }
+
+    #[test]
+    fn test_invalid_configuration() {
+        let sphere = Sphere::new(2);
+        let log_density = |x: &[f64]| x[2];
+        assert!(GeodesicHmc::new(0.0, 5)
+            .sample(&sphere, &[0.0, 0.0, 1.0], log_density, 10)
+            .is_err());
+        assert!(GeodesicHmc::new(0.1, 0)
+            .sample(&sphere, &[0.0, 0.0, 1.0], log_density, 10)
+            .is_err());
+        assert!(GeodesicHmc::new(0.1, 5)
+            .sample(&sphere, &[0.0, 0.0, 2.0], log_density, 10)
+            .is_err());
+    }
+}
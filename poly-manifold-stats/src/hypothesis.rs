@@ -0,0 +1,337 @@
+use crate::mean::FrechetMean;
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::{
+    Context, Manifold, ManifoldError, RandomSource, Result, SeededRng, TangentVector,
+};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PermutationTestResult {
+    pub statistic: f64,
+    pub p_value: f64,
+    pub permutations: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PermutationTest {
+    pub permutations: usize,
+    pub seed: u64,
+    pub estimator: FrechetMean,
+}
+
+impl Default for PermutationTest {
+    fn default() -> Self {
+        Self::new(999)
+    }
+}
+
+impl PermutationTest {
+    pub fn new(permutations: usize) -> Self {
+        Self {
+            permutations,
+            seed: Context::current().seed,
+            estimator: FrechetMean::default(),
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_context(mut self, context: &Context) -> Self {
+        self.seed = context.seed;
+        self
+    }
+
+    pub fn with_estimator(mut self, estimator: FrechetMean) -> Self {
+        self.estimator = estimator;
+        self
+    }
+
+    pub fn two_sample<M>(
+        &self,
+        manifold: &M,
+        first: &[Vec<f64>],
+        second: &[Vec<f64>],
+    ) -> Result<PermutationTestResult>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        if self.permutations == 0 {
+            return Err(ManifoldError::InvalidParameter(
+                "Permutation test requires at least one permutation".to_string(),
+            ));
+        }
+        if first.is_empty() || second.is_empty() {
+            return Err(ManifoldError::InvalidParameter(
+                "Both samples must be non-empty".to_string(),
+            ));
+        }
+
+        let statistic = self.mean_distance(manifold, first, second)?;
+        let mut pooled: Vec<Vec<f64>> = first.iter().chain(second).cloned().collect();
+        let mut rng = SeededRng::seed_from_u64(self.seed);
+        let mut exceedances = 0;
+        for _ in 0..self.permutations {
+            rng.shuffle(&mut pooled);
+            let (a, b) = pooled.split_at(first.len());
+            if self.mean_distance(manifold, a, b)? >= statistic {
+                exceedances += 1;
+            }
+        }
+
+        Ok(PermutationTestResult {
+            statistic,
+            p_value: (exceedances + 1) as f64 / (self.permutations + 1) as f64,
+            permutations: self.permutations,
+        })
+    }
+
+    fn mean_distance<M>(&self, manifold: &M, first: &[Vec<f64>], second: &[Vec<f64>]) -> Result<f64>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let a = self.estimator.compute(manifold, first)?;
+        let b = self.estimator.compute(manifold, second)?;
+        manifold.distance(&a, &b)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BootstrapRegion {
+    pub mean: Vec<f64>,
+    pub level: f64,
+    pub radius: f64,
+    pub covariance: DMatrix<f64>,
+    pub replicates: Vec<Vec<f64>>,
+}
+
+impl BootstrapRegion {
+    pub fn contains<M>(&self, manifold: &M, point: &[f64]) -> Result<bool>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        Ok(manifold.distance(&self.mean, point)? <= self.radius)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bootstrap {
+    pub resamples: usize,
+    pub seed: u64,
+    pub estimator: FrechetMean,
+}
+
+impl Default for Bootstrap {
+    fn default() -> Self {
+        Self::new(500)
+    }
+}
+
+impl Bootstrap {
+    pub fn new(resamples: usize) -> Self {
+        Self {
+            resamples,
+            seed: Context::current().seed,
+            estimator: FrechetMean::default(),
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_context(mut self, context: &Context) -> Self {
+        self.seed = context.seed;
+        self
+    }
+
+    pub fn with_estimator(mut self, estimator: FrechetMean) -> Self {
+        self.estimator = estimator;
+        self
+    }
+
+    pub fn mean_region<M>(
+        &self,
+        manifold: &M,
+        points: &[Vec<f64>],
+        level: f64,
+    ) -> Result<BootstrapRegion>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        if !(level > 0.0 && level < 1.0) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Confidence level must lie in (0, 1), got {}",
+                level
+            )));
+        }
+        if self.resamples == 0 {
+            return Err(ManifoldError::InvalidParameter(
+                "Bootstrap requires at least one resample".to_string(),
+            ));
+        }
+
+        let mean = self.estimator.compute(manifold, points)?;
+        let tangents = points
+            .iter()
+            .map(|p| manifold.log(&mean, p))
+            .collect::<Result<Vec<_>>>()?;
+        let basis = manifold.tangent_basis(&mean)?;
+        let n = points.len();
+
+        let mut rng = SeededRng::seed_from_u64(self.seed);
+        let mut replicates = Vec::with_capacity(self.resamples);
+        let mut radii = Vec::with_capacity(self.resamples);
+        let mut coordinates = DMatrix::zeros(basis.len(), self.resamples);
+        for r in 0..self.resamples {
+            let mut shift = TangentVector::zero(mean.len());
+            for _ in 0..n {
+                if let Some(i) = rng.next_below(n) {
+                    shift += &tangents[i] * (1.0 / n as f64);
+                }
+            }
+            for (j, e) in basis.iter().enumerate() {
+                coordinates[(j, r)] = manifold.inner_product(&mean, &shift, e)?;
+            }
+            let replicate = manifold.exp(&mean, &shift)?;
+            radii.push(manifold.distance(&mean, &replicate)?);
+            replicates.push(replicate);
+        }
+
+        radii.sort_by(f64::total_cmp);
+        let rank = ((level * self.resamples as f64).ceil() as usize).clamp(1, self.resamples);
+        let centroid: DVector<f64> = coordinates.column_mean();
+        let centered = DMatrix::from_fn(basis.len(), self.resamples, |i, j| {
+            coordinates[(i, j)] - centroid[i]
+        });
+        let covariance = &centered * centered.transpose() / (self.resamples.max(2) - 1) as f64;
+
+        Ok(BootstrapRegion {
+            mean,
+            level,
+            radius: radii[rank - 1],
+            covariance,
+            replicates,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    fn cap_samples(center: &[f64], spread: f64, count: usize, seed: u64) -> Vec<Vec<f64>> {
+        let sphere = Sphere::new(2);
+        let mut rng = SeededRng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| {
+                let noise: Vec<f64> = (0..3).map(|_| spread * rng.next_gaussian()).collect();
+                let tangent = sphere
+                    .project_to_tangent_space(center, &TangentVector::new(DVector::from_vec(noise)))
+                    .unwrap();
+                sphere.exp(center, &tangent).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_distributions_are_not_rejected() {
+        let sphere = Sphere::new(2);
+        let a = cap_samples(&[0.0, 0.0, 1.0], 0.2, 20, 1);
+        let b = cap_samples(&[0.0, 0.0, 1.0], 0.2, 20, 2);
+        let result = PermutationTest::new(199)
+            .two_sample(&sphere, &a, &b)
+            .unwrap();
+        assert!(result.p_value > 0.05);
+        assert_eq!(result.permutations, 199);
+    }
+
+    #[test]
+    fn test_shifted_means_are_detected() {
+        let sphere = Sphere::new(2);
+        let tilted = [0.3f64.sin(), 0.0, 0.3f64.cos()];
+        let a = cap_samples(&[0.0, 0.0, 1.0], 0.2, 20, 3);
+        let b = cap_samples(&tilted, 0.2, 20, 4);
+        let result = PermutationTest::new(199)
+            .with_seed(7)
+            .two_sample(&sphere, &a, &b)
+            .unwrap();
+        assert_relative_eq!(result.p_value, 1.0 / 200.0);
+        assert!(result.statistic > 0.15);
+    }
+
+    #[test]
+    fn test_bootstrap_radius_matches_standard_error_in_euclidean_space() {
+        let euclidean = Euclidean::new(1);
+        let mut rng = SeededRng::seed_from_u64(5);
+        let points: Vec<Vec<f64>> = (0..100).map(|_| vec![2.0 * rng.next_gaussian()]).collect();
+        let region = Bootstrap::new(2000)
+            .mean_region(&euclidean, &points, 0.95)
+            .unwrap();
+
+        let mean = points.iter().map(|p| p[0]).sum::<f64>() / 100.0;
+        let variance = points.iter().map(|p| (p[0] - mean).powi(2)).sum::<f64>() / 100.0;
+        let standard_error = (variance / 100.0).sqrt();
+        assert_relative_eq!(region.radius, 1.96 * standard_error, max_relative = 0.15);
+        assert_relative_eq!(
+            region.covariance[(0, 0)],
+            standard_error.powi(2),
+            max_relative = 0.15
+        );
+    }
+
+    #[test]
+    fn test_bootstrap_region_on_sphere_covers_center() {
+        let sphere = Sphere::new(2);
+        let points = cap_samples(&[0.0, 1.0, 0.0], 0.1, 50, 6);
+        let region = Bootstrap::new(300)
+            .with_seed(1)
+            .mean_region(&sphere, &points, 0.9)
+            .unwrap();
+        assert!(region.contains(&sphere, &[0.0, 1.0, 0.0]).unwrap());
+        assert!(!region.contains(&sphere, &[1.0, 0.0, 0.0]).unwrap());
+        assert_eq!(region.replicates.len(), 300);
+        assert_eq!(region.covariance.nrows(), 2);
+        for replicate in &region.replicates {
+            assert!(sphere.check_point(replicate).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_invalid_configuration() {
+        let euclidean = Euclidean::new(1);
+        let points = vec![vec![0.0], vec![1.0]];
+        assert!(PermutationTest::new(0)
+            .two_sample(&euclidean, &points, &points)
+            .is_err());
+        assert!(PermutationTest::default()
+            .two_sample(&euclidean, &points, &[])
+            .is_err());
+        assert!(Bootstrap::default()
+            .mean_region(&euclidean, &points, 1.0)
+            .is_err());
+        assert!(Bootstrap::new(0)
+            .mean_region(&euclidean, &points, 0.9)
+            .is_err());
+        assert!(Bootstrap::default()
+            .mean_region(&euclidean, &[], 0.9)
+            .is_err());
+    }
+
+    #[test]
+    fn test_seeds_follow_the_context() {
+        let context = Context::new().with_seed(9);
+        assert_eq!(context.scope(|| PermutationTest::new(99)).seed, 9);
+        assert_eq!(context.scope(Bootstrap::default).seed, 9);
+        assert_eq!(PermutationTest::new(99).with_context(&context).seed, 9);
+        assert_eq!(Bootstrap::new(10).with_context(&context).seed, 9);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::mds::{ManifoldMds, MdsEmbedding};
+use crate::neighbors::VantagePointTree;
+use nalgebra::DMatrix;
+use petgraph::algo::dijkstra;
+use petgraph::graph::{NodeIndex, UnGraph};
+use poly_manifold_autodiff::RiemannianOptimizer;
+use poly_manifold_core::{Manifold, ManifoldError, Result};
+
+pub fn knn_graph<M>(manifold: &M, points: &[Vec<f64>], k: usize) -> Result<UnGraph<(), f64>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    if k == 0 || k >= points.len() {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Neighbor count must be between 1 and {}, got {}",
+            points.len().saturating_sub(1),
+            k
+        )));
+    }
+    let tree = VantagePointTree::build(manifold, points.to_vec())?;
+    let mut graph = UnGraph::with_capacity(points.len(), points.len() * k);
+    for _ in points {
+        graph.add_node(());
+    }
+    for (i, point) in points.iter().enumerate() {
+        for neighbor in tree.nearest(manifold, point, k + 1)? {
+            if neighbor.index != i {
+                graph.update_edge(
+                    NodeIndex::new(i),
+                    NodeIndex::new(neighbor.index),
+                    neighbor.distance,
+                );
+            }
+        }
+    }
+    Ok(graph)
+}
+
+pub fn graph_geodesic_distances(graph: &UnGraph<(), f64>) -> Result<DMatrix<f64>> {
+    let n = graph.node_count();
+    let mut distances = DMatrix::zeros(n, n);
+    for i in 0..n {
+        let reached = dijkstra(graph, NodeIndex::new(i), None, |edge| *edge.weight());
+        if reached.len() != n {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Neighbor graph is disconnected: node {} reaches {} of {} nodes",
+                i,
+                reached.len(),
+                n
+            )));
+        }
+        for (node, distance) in reached {
+            distances[(i, node.index())] = distance;
+        }
+    }
+    Ok((&distances + distances.transpose()) * 0.5)
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Isomap {
+    pub neighbors: usize,
+    pub mds: ManifoldMds,
+}
+
+impl Isomap {
+    pub fn new(neighbors: usize) -> Self {
+        Self {
+            neighbors,
+            mds: ManifoldMds::default(),
+        }
+    }
+
+    pub fn with_mds(mut self, mds: ManifoldMds) -> Self {
+        self.mds = mds;
+        self
+    }
+
+    pub fn geodesic_distances<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<DMatrix<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        graph_geodesic_distances(&knn_graph(manifold, points, self.neighbors)?)
+    }
+
+    pub fn embed<D, T, O>(
+        &self,
+        data_manifold: &D,
+        points: &[Vec<f64>],
+        target: &T,
+        anchor: &[f64],
+        optimizer: &O,
+    ) -> Result<MdsEmbedding>
+    where
+        D: Manifold<Scalar = f64> + ?Sized,
+        T: Manifold<Scalar = f64>,
+        O: RiemannianOptimizer,
+    {
+        let distances = self.geodesic_distances(data_manifold, points)?;
+        self.mds.embed(target, &distances, anchor, optimizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kmedoids::pairwise_distances;
+    use approx::assert_relative_eq;
+    use poly_manifold_autodiff::{GradientDescent, LineSearch};
+    use poly_manifold_spaces::{Euclidean, Sphere};
+    use std::f64::consts::PI;
+
+    fn arc(n: usize) -> Vec<Vec<f64>> {
+        (0..n)
+            .map(|i| {
+                let angle = PI * i as f64 / (n - 1) as f64;
+                vec![angle.cos(), angle.sin()]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_graph_distances_follow_the_arc() {
+        let points = arc(9);
+        let graph = knn_graph(&Euclidean::new(2), &points, 2).unwrap();
+        assert!(graph.edge_count() >= 8);
+
+        let distances = graph_geodesic_distances(&graph).unwrap();
+        let chord = 2.0 * (PI / 16.0).sin();
+        assert_relative_eq!(distances[(0, 1)], chord, epsilon = 1e-12);
+        assert!(distances[(0, 8)] > 2.0 && distances[(0, 8)] < PI);
+        assert_eq!(distances, distances.transpose());
+    }
+
+    #[test]
+    fn test_isomap_unrolls_arc_into_a_line() {
+        let points = arc(8);
+        let optimizer =
+            GradientDescent::new(1.0, 2000, 1e-14).with_line_search(LineSearch::armijo());
+        let embedding = Isomap::new(2)
+            .with_mds(ManifoldMds::new(1.0).with_seed(2))
+            .embed(
+                &Euclidean::new(2),
+                &points,
+                &Euclidean::new(1),
+                &[0.0],
+                &optimizer,
+            )
+            .unwrap();
+
+        let chords = pairwise_distances(&Euclidean::new(2), &points).unwrap();
+        let direct = ManifoldMds::new(1.0)
+            .with_seed(2)
+            .embed(&Euclidean::new(1), &chords, &[0.0], &optimizer)
+            .unwrap();
+        assert!(embedding.stress < 0.01);
+        assert!(embedding.stress < direct.stress);
+
+        let coordinates: Vec<f64> = embedding.points.iter().map(|p| p[0]).collect();
+        let increasing = coordinates.windows(2).all(|w| w[0] < w[1]);
+        let decreasing = coordinates.windows(2).all(|w| w[0] > w[1]);
+        assert!(increasing || decreasing);
+    }
+
+    #[test]
+    fn test_disconnected_graph_and_invalid_neighbor_count() {
+        let sphere = Sphere::new(2);
+        let points = vec![
+            vec![1.0, 0.0, 0.0],
+            sphere.project_to_manifold(&[1.0, 0.05, 0.0]).unwrap(),
+            vec![0.0, 0.0, 1.0],
+            sphere.project_to_manifold(&[0.0, 0.05, 1.0]).unwrap(),
+        ];
+        assert!(matches!(
+            Isomap::new(1).geodesic_distances(&sphere, &points),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        assert!(Isomap::new(2).geodesic_distances(&sphere, &points).is_ok());
+        assert!(knn_graph(&sphere, &points, 0).is_err());
+        assert!(knn_graph(&sphere, &points, 4).is_err());
+    }
+}
@@ -0,0 +1,337 @@
+use crate::dispersion::frechet_std_dev;
+use crate::kmedoids::pairwise_distances;
+use crate::mean::FrechetMean;
+use crate::neighbors::VantagePointTree;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{DistanceSurrogate, Manifold, ManifoldError, Result};
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kernel {
+    #[default]
+    Gaussian,
+    Epanechnikov,
+}
+
+impl Kernel {
+    pub fn evaluate(&self, u: f64, dim: usize) -> f64 {
+        match self {
+            Kernel::Gaussian => (-0.5 * u * u).exp() / (2.0 * PI).powf(dim as f64 / 2.0),
+            Kernel::Epanechnikov => {
+                if u >= 1.0 {
+                    0.0
+                } else {
+                    (dim as f64 + 2.0) / (2.0 * unit_ball_volume(dim)) * (1.0 - u * u)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KernelDensity {
+    pub samples: Vec<Vec<f64>>,
+    pub bandwidth: f64,
+    pub kernel: Kernel,
+}
+
+impl KernelDensity {
+    pub fn new(samples: Vec<Vec<f64>>, bandwidth: f64) -> Result<Self> {
+        if samples.is_empty() {
+            return Err(ManifoldError::InvalidParameter(
+                "Kernel density estimation requires at least one sample".to_string(),
+            ));
+        }
+        if !(bandwidth > 0.0 && bandwidth.is_finite()) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Bandwidth must be positive and finite, got {}",
+                bandwidth
+            )));
+        }
+        Ok(Self {
+            samples,
+            bandwidth,
+            kernel: Kernel::default(),
+        })
+    }
+
+    pub fn with_kernel(mut self, kernel: Kernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    pub fn evaluate<M>(&self, manifold: &M, query: &[f64]) -> Result<f64>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let dim = manifold.dim();
+        let mut total = 0.0;
+        for sample in &self.samples {
+            let u = manifold.distance(query, sample)? / self.bandwidth;
+            total += self.kernel.evaluate(u, dim);
+        }
+        Ok(total / (self.samples.len() as f64 * self.bandwidth.powi(dim as i32)))
+    }
+
+    pub fn evaluate_indexed<M>(
+        &self,
+        manifold: &M,
+        index: &VantagePointTree,
+        query: &[f64],
+    ) -> Result<f64>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        validate_entry_point(manifold, query)?;
+        if index.len() != self.samples.len() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.samples.len(),
+                got: index.len(),
+            });
+        }
+        let dim = manifold.dim();
+        let total: f64 = index
+            .within_radius(manifold, query, self.support_radius())?
+            .iter()
+            .map(|n| self.kernel.evaluate(n.distance / self.bandwidth, dim))
+            .sum();
+        Ok(total / (self.samples.len() as f64 * self.bandwidth.powi(dim as i32)))
+    }
+
+    pub fn evaluate_pruned<M>(&self, manifold: &M, query: &[f64]) -> Result<f64>
+    where
+        M: DistanceSurrogate + ?Sized,
+    {
+        validate_entry_point(manifold, query)?;
+        let dim = manifold.dim();
+        let cutoff = self.support_radius();
+        let mut total = 0.0;
+        for sample in &self.samples {
+            let (lower, upper) = manifold.bounded_distance(query, sample, cutoff)?;
+            if lower == upper && lower <= cutoff {
+                total += self.kernel.evaluate(lower / self.bandwidth, dim);
+            }
+        }
+        Ok(total / (self.samples.len() as f64 * self.bandwidth.powi(dim as i32)))
+    }
+
+    pub fn evaluate_many<M>(&self, manifold: &M, queries: &[Vec<f64>]) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        queries
+            .iter()
+            .map(|query| self.evaluate(manifold, query))
+            .collect()
+    }
+
+    pub fn sample_mode<M>(&self, manifold: &M) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let densities = self.evaluate_many(manifold, &self.samples)?;
+        let best = densities
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(i, _)| i)
+            .expect("at least one sample");
+        Ok(self.samples[best].clone())
+    }
+
+    fn support_radius(&self) -> f64 {
+        let support = match self.kernel {
+            Kernel::Gaussian => 8.0,
+            Kernel::Epanechnikov => 1.0,
+        };
+        support * self.bandwidth
+    }
+}
+
+pub fn silverman_bandwidth<M>(
+    manifold: &M,
+    samples: &[Vec<f64>],
+    estimator: &FrechetMean,
+) -> Result<f64>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    let mean = estimator.compute(manifold, samples)?;
+    let d = manifold.dim() as f64;
+    let spread = frechet_std_dev(manifold, samples, &mean)? / d.sqrt();
+    Ok(spread * (4.0 / ((d + 2.0) * samples.len() as f64)).powf(1.0 / (d + 4.0)))
+}
+
+pub fn median_distance_bandwidth<M>(manifold: &M, samples: &[Vec<f64>]) -> Result<f64>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    if samples.len() < 2 {
+        return Err(ManifoldError::InvalidParameter(
+            "Median heuristic requires at least two samples".to_string(),
+        ));
+    }
+    let distances = pairwise_distances(manifold, samples)?;
+    let mut upper: Vec<f64> = (0..samples.len())
+        .flat_map(|i| ((i + 1)..samples.len()).map(move |j| (i, j)))
+        .map(|(i, j)| distances[(i, j)])
+        .collect();
+    upper.sort_by(f64::total_cmp);
+    Ok(upper[upper.len() / 2])
+}
+
+fn unit_ball_volume(dim: usize) -> f64 {
+    match dim {
+        0 => 1.0,
+        1 => 2.0,
+        _ => 2.0 * PI / dim as f64 * unit_ball_volume(dim - 2),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, PoincareBall, Sphere};
+
+    #[test]
+    fn test_euclidean_gaussian_kde_matches_closed_form() {
+        let euclidean = Euclidean::new(1);
+        let kde = KernelDensity::new(vec![vec![0.0], vec![2.0]], 0.5).unwrap();
+        let density = kde.evaluate(&euclidean, &[1.0]).unwrap();
+        let expected = (-0.5f64 * 4.0).exp() / (2.0 * PI).sqrt() / 0.5;
+        assert_relative_eq!(density, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_epanechnikov_integrates_to_one_in_euclidean_plane() {
+        let euclidean = Euclidean::new(2);
+        let kde = KernelDensity::new(vec![vec![0.0, 0.0]], 1.0)
+            .unwrap()
+            .with_kernel(Kernel::Epanechnikov);
+        let step = 0.02;
+        let mut integral = 0.0;
+        for i in -60..60 {
+            for j in -60..60 {
+                let point = [(i as f64 + 0.5) * step, (j as f64 + 0.5) * step];
+                integral += kde.evaluate(&euclidean, &point).unwrap() * step * step;
+            }
+        }
+        assert_relative_eq!(integral, 1.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_sample_mode_on_sphere() {
+        let sphere = Sphere::new(2);
+        let mut samples: Vec<Vec<f64>> = [[0.05, 0.0], [-0.04, 0.02], [0.0, -0.05], [0.0, 0.0]]
+            .iter()
+            .map(|o| sphere.project_to_manifold(&[o[0], o[1], 1.0]).unwrap())
+            .collect();
+        samples.push(vec![1.0, 0.0, 0.0]);
+
+        let bandwidth = median_distance_bandwidth(&sphere, &samples).unwrap();
+        let kde = KernelDensity::new(samples, bandwidth).unwrap();
+        let mode = kde.sample_mode(&sphere).unwrap();
+        assert!(mode[2] > 0.99);
+
+        let densities = kde
+            .evaluate_many(&sphere, &[vec![0.0, 0.0, 1.0], vec![0.0, -1.0, 0.0]])
+            .unwrap();
+        assert!(densities[0] > densities[1]);
+    }
+
+    #[test]
+    fn test_indexed_evaluation_matches_direct_sum() {
+        let euclidean = Euclidean::new(2);
+        let samples: Vec<Vec<f64>> = (0..400)
+            .map(|i| vec![(i % 20) as f64 * 0.5, (i / 20) as f64 * 0.5])
+            .collect();
+        let index = VantagePointTree::build(&euclidean, samples.clone()).unwrap();
+        for kernel in [Kernel::Gaussian, Kernel::Epanechnikov] {
+            let kde = KernelDensity::new(samples.clone(), 0.8)
+                .unwrap()
+                .with_kernel(kernel);
+            for query in [[1.3, 2.2], [9.0, 0.4], [-3.0, -3.0]] {
+                assert_relative_eq!(
+                    kde.evaluate_indexed(&euclidean, &index, &query).unwrap(),
+                    kde.evaluate(&euclidean, &query).unwrap(),
+                    epsilon = 1e-12
+                );
+            }
+        }
+
+        let sphere = Sphere::new(2);
+        let kde = KernelDensity::new(vec![vec![0.0, 0.0, 1.0]], 0.5).unwrap();
+        let index = VantagePointTree::build(&sphere, kde.samples.clone()).unwrap();
+        assert!(kde
+            .evaluate_indexed(&sphere, &index, &[0.0, 0.0, 2.0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_silverman_bandwidth_shrinks_with_sample_size() {
+        let euclidean = Euclidean::new(1);
+        let small: Vec<Vec<f64>> = (0..10).map(|i| vec![(i % 5) as f64]).collect();
+        let large: Vec<Vec<f64>> = (0..1000).map(|i| vec![(i % 5) as f64]).collect();
+        let estimator = FrechetMean::default();
+        let h_small = silverman_bandwidth(&euclidean, &small, &estimator).unwrap();
+        let h_large = silverman_bandwidth(&euclidean, &large, &estimator).unwrap();
+        assert!(h_large < h_small);
+        assert_relative_eq!(
+            h_small,
+            2.0f64.sqrt() * (4.0f64 / 30.0).powf(0.2),
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(KernelDensity::new(Vec::new(), 1.0).is_err());
+        assert!(KernelDensity::new(vec![vec![0.0]], 0.0).is_err());
+        assert!(median_distance_bandwidth(&Euclidean::new(1), &[vec![0.0]]).is_err());
+    }
+
+    #[test]
+    fn test_pruned_evaluation_matches_direct_sum() {
+        let sphere = Sphere::new(2);
+        let ball = PoincareBall::new(2);
+        let sphere_samples: Vec<Vec<f64>> = (0..200)
+            .map(|i| {
+                let angle = i as f64 * 0.37;
+                let raw = [angle.cos(), angle.sin(), (i % 7) as f64 * 0.3 - 0.9];
+                sphere.project_to_manifold(&raw).unwrap()
+            })
+            .collect();
+        let ball_samples: Vec<Vec<f64>> = (0..200)
+            .map(|i| vec![(i % 20) as f64 * 0.04 - 0.4, (i / 20) as f64 * 0.08 - 0.4])
+            .collect();
+
+        for kernel in [Kernel::Gaussian, Kernel::Epanechnikov] {
+            let kde = KernelDensity::new(sphere_samples.clone(), 0.2)
+                .unwrap()
+                .with_kernel(kernel);
+            let query = sphere.project_to_manifold(&[0.2, 0.9, 0.1]).unwrap();
+            assert_relative_eq!(
+                kde.evaluate_pruned(&sphere, &query).unwrap(),
+                kde.evaluate(&sphere, &query).unwrap(),
+                epsilon = 1e-12
+            );
+
+            let kde = KernelDensity::new(ball_samples.clone(), 0.1)
+                .unwrap()
+                .with_kernel(kernel);
+            assert_relative_eq!(
+                kde.evaluate_pruned(&ball, &[0.1, 0.05]).unwrap(),
+                kde.evaluate(&ball, &[0.1, 0.05]).unwrap(),
+                epsilon = 1e-12
+            );
+        }
+
+        let kde = KernelDensity::new(sphere_samples, 0.2).unwrap();
+        for query in [vec![0.0, 0.0, 2.0], vec![1.0, 0.0]] {
+            assert!(kde.evaluate_pruned(&sphere, &query).is_err());
+        }
+    }
+}
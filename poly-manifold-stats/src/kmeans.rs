@@ -0,0 +1,473 @@
+use crate::mean::FrechetMean;
+use crate::neighbors::VantagePointTree;
+#[cfg(feature = "arrow")]
+use arrow::array::{Array, ArrayRef, UInt64Array};
+#[cfg(feature = "arrow")]
+use arrow::datatypes::{DataType, Field, Schema};
+#[cfg(feature = "arrow")]
+use arrow::record_batch::RecordBatch;
+#[cfg(feature = "arrow")]
+use poly_manifold_core::columnar::{arrow_error, point_column, POINT_COLUMN};
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{
+    Context, DistanceSurrogate, Manifold, ManifoldError, RandomSource, Result, SeededRng,
+};
+#[cfg(feature = "arrow")]
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Clustering {
+    pub centers: Vec<Vec<f64>>,
+    pub labels: Vec<usize>,
+    pub inertia: f64,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+#[cfg(feature = "arrow")]
+impl Clustering {
+    pub fn to_record_batch(&self, points: &[Vec<f64>]) -> Result<RecordBatch> {
+        if points.len() != self.labels.len() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.labels.len(),
+                got: points.len(),
+            });
+        }
+        let points = point_column(points)?;
+        let schema = Schema::new(vec![
+            Field::new(POINT_COLUMN, points.data_type().clone(), false),
+            Field::new("label", DataType::UInt64, false),
+        ]);
+        let labels = UInt64Array::from_iter_values(self.labels.iter().map(|&l| l as u64));
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(points) as ArrayRef, Arc::new(labels)],
+        )
+        .map_err(arrow_error)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KMeans {
+    pub k: usize,
+    pub max_iterations: usize,
+    pub seed: u64,
+    pub mean: FrechetMean,
+}
+
+impl KMeans {
+    pub fn new(k: usize, max_iterations: usize) -> Self {
+        Self {
+            k,
+            max_iterations,
+            seed: Context::current().seed,
+            mean: FrechetMean::default(),
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_context(mut self, context: &Context) -> Self {
+        self.seed = context.seed;
+        self
+    }
+
+    pub fn with_mean(mut self, mean: FrechetMean) -> Self {
+        self.mean = mean;
+        self
+    }
+
+    pub fn fit<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<Clustering>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        self.fit_with(manifold, points, |centers| {
+            assign(manifold, points, centers)
+        })
+    }
+
+    pub fn fit_pruned<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<Clustering>
+    where
+        M: DistanceSurrogate + ?Sized,
+    {
+        self.fit_with(manifold, points, |centers| {
+            assign_pruned(manifold, points, centers)
+        })
+    }
+
+    pub fn fit_indexed<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<Clustering>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        self.fit_with(manifold, points, |centers| {
+            assign_indexed(manifold, points, centers)
+        })
+    }
+
+    fn fit_with<M, A>(&self, manifold: &M, points: &[Vec<f64>], assignment: A) -> Result<Clustering>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+        A: Fn(&[Vec<f64>]) -> Result<(Vec<usize>, f64)>,
+    {
+        check_cluster_count(self.k, points.len())?;
+        for point in points {
+            validate_entry_point(manifold, point)?;
+        }
+
+        let mut rng = SeededRng::seed_from_u64(self.seed);
+        let mut centers: Vec<Vec<f64>> = kmeans_plus_plus(manifold, points, self.k, &mut rng)?
+            .into_iter()
+            .map(|i| points[i].clone())
+            .collect();
+        let (mut labels, mut inertia) = assignment(&centers)?;
+
+        let mut iterations = 0;
+        let mut converged = false;
+        while iterations < self.max_iterations {
+            iterations += 1;
+            for (c, center) in centers.iter_mut().enumerate() {
+                let members: Vec<Vec<f64>> = points
+                    .iter()
+                    .zip(&labels)
+                    .filter(|(_, label)| **label == c)
+                    .map(|(point, _)| point.clone())
+                    .collect();
+                if !members.is_empty() {
+                    *center = self.mean.compute(manifold, &members)?;
+                }
+            }
+
+            let (new_labels, new_inertia) = assignment(&centers)?;
+            let unchanged = new_labels == labels;
+            labels = new_labels;
+            inertia = new_inertia;
+            if unchanged {
+                converged = true;
+                break;
+            }
+        }
+
+        Ok(Clustering {
+            centers,
+            labels,
+            inertia,
+            iterations,
+            converged,
+        })
+    }
+}
+
+pub(crate) fn check_cluster_count(k: usize, n_points: usize) -> Result<()> {
+    if k == 0 || k > n_points {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Number of clusters must be between 1 and {}, got {}",
+            n_points, k
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn kmeans_plus_plus<M, R>(
+    manifold: &M,
+    points: &[Vec<f64>],
+    k: usize,
+    rng: &mut R,
+) -> Result<Vec<usize>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+    R: RandomSource + ?Sized,
+{
+    let first = rng.next_below(points.len()).ok_or_else(|| {
+        ManifoldError::InvalidParameter("k-means++ needs at least one point".to_string())
+    })?;
+    let mut chosen = vec![first];
+    let mut nearest: Vec<f64> = points
+        .iter()
+        .map(|p| Ok(manifold.distance(&points[chosen[0]], p)?.powi(2)))
+        .collect::<Result<_>>()?;
+
+    while chosen.len() < k {
+        let total: f64 = nearest.iter().sum();
+        let next = if total > 0.0 {
+            let mut target = rng.next_f64() * total;
+            let mut index = points.len() - 1;
+            for (i, d) in nearest.iter().enumerate() {
+                if target < *d {
+                    index = i;
+                    break;
+                }
+                target -= d;
+            }
+            index
+        } else {
+            (0..points.len())
+                .find(|i| !chosen.contains(i))
+                .expect("k does not exceed the number of points")
+        };
+        chosen.push(next);
+        for (d, p) in nearest.iter_mut().zip(points) {
+            *d = d.min(manifold.distance(&points[next], p)?.powi(2));
+        }
+    }
+
+    Ok(chosen)
+}
+
+fn assign<M>(manifold: &M, points: &[Vec<f64>], centers: &[Vec<f64>]) -> Result<(Vec<usize>, f64)>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    let mut labels = Vec::with_capacity(points.len());
+    let mut inertia = 0.0;
+    for point in points {
+        let mut best = (0, f64::INFINITY);
+        for (c, center) in centers.iter().enumerate() {
+            let d = manifold.distance(center, point)?.powi(2);
+            if d < best.1 {
+                best = (c, d);
+            }
+        }
+        labels.push(best.0);
+        inertia += best.1;
+    }
+    Ok((labels, inertia))
+}
+
+fn assign_indexed<M>(
+    manifold: &M,
+    points: &[Vec<f64>],
+    centers: &[Vec<f64>],
+) -> Result<(Vec<usize>, f64)>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    let index = VantagePointTree::build(manifold, centers.to_vec())?;
+    let mut labels = Vec::with_capacity(points.len());
+    let mut inertia = 0.0;
+    for point in points {
+        let nearest = index.nearest(manifold, point, 1)?[0];
+        labels.push(nearest.index);
+        inertia += nearest.distance.powi(2);
+    }
+    Ok((labels, inertia))
+}
+
+fn assign_pruned<M>(
+    manifold: &M,
+    points: &[Vec<f64>],
+    centers: &[Vec<f64>],
+) -> Result<(Vec<usize>, f64)>
+where
+    M: DistanceSurrogate + ?Sized,
+{
+    let mut labels = Vec::with_capacity(points.len());
+    let mut inertia = 0.0;
+    let mut candidates = Vec::with_capacity(centers.len());
+    for point in points {
+        candidates.clear();
+        for (c, center) in centers.iter().enumerate() {
+            let surrogate = manifold.surrogate_distance(center, point)?;
+            candidates.push((manifold.distance_lower_bound(surrogate), c));
+        }
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut best = (0, f64::INFINITY);
+        for &(lower, c) in &candidates {
+            if lower * lower > best.1 {
+                break;
+            }
+            let d = manifold.distance(&centers[c], point)?.powi(2);
+            if d < best.1 || (d == best.1 && c < best.0) {
+                best = (c, d);
+            }
+        }
+        labels.push(best.0);
+        inertia += best.1;
+    }
+    Ok((labels, inertia))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    fn sphere_clusters(sphere: &Sphere) -> Vec<Vec<f64>> {
+        let offsets = [[0.05, 0.0], [-0.05, 0.02], [0.0, -0.04], [0.03, 0.03]];
+        let mut points = Vec::new();
+        for offset in &offsets {
+            points.push(
+                sphere
+                    .project_to_manifold(&[offset[0], offset[1], 1.0])
+                    .unwrap(),
+            );
+            points.push(
+                sphere
+                    .project_to_manifold(&[1.0, offset[0], offset[1]])
+                    .unwrap(),
+            );
+        }
+        points
+    }
+
+    #[test]
+    fn test_kmeans_separates_sphere_clusters() {
+        let sphere = Sphere::new(2);
+        let points = sphere_clusters(&sphere);
+        let clustering = KMeans::new(2, 50)
+            .with_seed(3)
+            .fit(&sphere, &points)
+            .unwrap();
+
+        assert!(clustering.converged);
+        for pair in clustering.labels.chunks(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+        assert!(clustering
+            .labels
+            .iter()
+            .step_by(2)
+            .all(|l| *l == clustering.labels[0]));
+        for center in &clustering.centers {
+            assert!(sphere.check_point(center).is_ok());
+        }
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_clustering_record_batch() {
+        use poly_manifold_core::columnar::points_from_record_batch;
+
+        let euclidean = Euclidean::new(1);
+        let points = vec![vec![0.0], vec![1.0], vec![10.0], vec![12.0]];
+        let clustering = KMeans::new(2, 50).fit(&euclidean, &points).unwrap();
+        let batch = clustering.to_record_batch(&points).unwrap();
+
+        assert_eq!(
+            points_from_record_batch(&batch, POINT_COLUMN).unwrap(),
+            points
+        );
+        let labels = batch
+            .column_by_name("label")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap();
+        assert_eq!(labels.value(0), labels.value(1));
+        assert_ne!(labels.value(1), labels.value(2));
+        assert!(clustering.to_record_batch(&points[..3]).is_err());
+    }
+
+    #[test]
+    fn test_kmeans_centers_are_cluster_means() {
+        let euclidean = Euclidean::new(1);
+        let points = vec![vec![0.0], vec![1.0], vec![10.0], vec![12.0]];
+        let clustering = KMeans::new(2, 50).fit(&euclidean, &points).unwrap();
+
+        let mut centers: Vec<f64> = clustering.centers.iter().map(|c| c[0]).collect();
+        centers.sort_by(f64::total_cmp);
+        assert_relative_eq!(centers[0], 0.5, epsilon = 1e-8);
+        assert_relative_eq!(centers[1], 11.0, epsilon = 1e-8);
+        assert_relative_eq!(clustering.inertia, 0.25 * 2.0 + 1.0 * 2.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_kmeans_is_reproducible_for_a_seed() {
+        let sphere = Sphere::new(2);
+        let points = sphere_clusters(&sphere);
+        let first = KMeans::new(3, 50)
+            .with_seed(7)
+            .fit(&sphere, &points)
+            .unwrap();
+        let second = KMeans::new(3, 50)
+            .with_seed(7)
+            .fit(&sphere, &points)
+            .unwrap();
+        assert_eq!(first.labels, second.labels);
+    }
+
+    #[test]
+    fn test_kmeans_plus_plus_picks_distinct_points() {
+        let euclidean = Euclidean::new(1);
+        let points = vec![vec![0.0], vec![0.0], vec![5.0]];
+        let mut rng = SeededRng::seed_from_u64(1);
+        let chosen = kmeans_plus_plus(&euclidean, &points, 3, &mut rng).unwrap();
+        let mut sorted = chosen.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 3);
+    }
+
+    #[test]
+    fn test_kmeans_rejects_invalid_cluster_count() {
+        let euclidean = Euclidean::new(1);
+        let points = vec![vec![0.0], vec![1.0]];
+        assert!(KMeans::new(0, 10).fit(&euclidean, &points).is_err());
+        assert!(KMeans::new(3, 10).fit(&euclidean, &points).is_err());
+    }
+
+    #[test]
+    fn test_pruned_fit_matches_exact_fit() {
+        let sphere = Sphere::new(2);
+        let points = sphere_clusters(&sphere);
+        for k in [2, 3, 4] {
+            let kmeans = KMeans::new(k, 50).with_seed(11);
+            let exact = kmeans.fit(&sphere, &points).unwrap();
+            let pruned = kmeans.fit_pruned(&sphere, &points).unwrap();
+            assert_eq!(pruned.labels, exact.labels);
+            assert_eq!(pruned.iterations, exact.iterations);
+            assert_relative_eq!(pruned.inertia, exact.inertia, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_indexed_fit_matches_exact_fit() {
+        let sphere = Sphere::new(2);
+        let points = sphere_clusters(&sphere);
+        for k in [2, 3, 4] {
+            let kmeans = KMeans::new(k, 50).with_seed(11);
+            let exact = kmeans.fit(&sphere, &points).unwrap();
+            let indexed = kmeans.fit_indexed(&sphere, &points).unwrap();
+            assert_eq!(indexed.labels, exact.labels);
+            assert_eq!(indexed.iterations, exact.iterations);
+            assert_relative_eq!(indexed.inertia, exact.inertia, epsilon = 1e-12);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_kmeans_serde_round_trip() {
+        let sphere = Sphere::new(2);
+        let points = vec![
+            vec![0.8, 0.6, 0.0],
+            vec![0.6, 0.8, 0.0],
+            vec![0.0, 0.0, 1.0],
+            vec![0.0, 0.6, 0.8],
+        ];
+        let kmeans = KMeans::new(2, 50).with_seed(3);
+        let json = serde_json::to_string(&kmeans).unwrap();
+        let restored: KMeans = serde_json::from_str(&json).unwrap();
+        let expected = kmeans.fit(&sphere, &points).unwrap();
+        let clustering = restored.fit(&sphere, &points).unwrap();
+        assert_eq!(clustering.labels, expected.labels);
+
+        let json = serde_json::to_string(&clustering).unwrap();
+        let back: Clustering = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.labels, clustering.labels);
+        assert_relative_eq!(back.inertia, clustering.inertia);
+    }
+
+    #[test]
+    fn test_kmeans_seed_follows_the_context() {
+        let context = Context::new().with_seed(7);
+        assert_eq!(context.scope(|| KMeans::new(3, 50)).seed, 7);
+        assert_eq!(KMeans::new(3, 50).with_context(&context).seed, 7);
+        assert_eq!(KMeans::new(3, 50).seed, Context::current().seed);
+    }
+}
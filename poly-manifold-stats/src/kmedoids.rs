@@ -0,0 +1,191 @@
+use crate::distance_matrix::DistanceMatrix;
+use crate::kmeans::{check_cluster_count, Clustering};
+use nalgebra::DMatrix;
+use poly_manifold_core::{Manifold, ManifoldError, Result};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KMedoids {
+    pub k: usize,
+    pub max_iterations: usize,
+}
+
+impl KMedoids {
+    pub fn new(k: usize, max_iterations: usize) -> Self {
+        Self { k, max_iterations }
+    }
+
+    pub fn fit<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<Clustering>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let distances = pairwise_distances(manifold, points)?;
+        let (medoids, iterations, converged) = self.medoids(&distances)?;
+        let labels: Vec<usize> = (0..points.len())
+            .map(|i| nearest(&distances, &medoids, i).0)
+            .collect();
+        let inertia = labels
+            .iter()
+            .enumerate()
+            .map(|(i, c)| distances[(medoids[*c], i)].powi(2))
+            .sum();
+
+        Ok(Clustering {
+            centers: medoids.iter().map(|m| points[*m].clone()).collect(),
+            labels,
+            inertia,
+            iterations,
+            converged,
+        })
+    }
+
+    pub fn medoids(&self, distances: &DMatrix<f64>) -> Result<(Vec<usize>, usize, bool)> {
+        let n = distances.nrows();
+        if distances.ncols() != n {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: n,
+                got: distances.ncols(),
+            });
+        }
+        check_cluster_count(self.k, n)?;
+
+        let mut medoids: Vec<usize> = Vec::with_capacity(self.k);
+        while medoids.len() < self.k {
+            let next = (0..n)
+                .filter(|c| !medoids.contains(c))
+                .min_by(|a, b| {
+                    let cost_a = total_cost(distances, &[medoids.as_slice(), &[*a]].concat());
+                    let cost_b = total_cost(distances, &[medoids.as_slice(), &[*b]].concat());
+                    cost_a.total_cmp(&cost_b)
+                })
+                .expect("k does not exceed the number of points");
+            medoids.push(next);
+        }
+
+        let mut cost = total_cost(distances, &medoids);
+        let mut iterations = 0;
+        while iterations < self.max_iterations {
+            iterations += 1;
+            let mut best_swap = None;
+            let mut best_cost = cost;
+            for slot in 0..self.k {
+                for candidate in (0..n).filter(|c| !medoids.contains(c)) {
+                    let mut trial = medoids.clone();
+                    trial[slot] = candidate;
+                    let trial_cost = total_cost(distances, &trial);
+                    if trial_cost < best_cost - 1e-12 {
+                        best_cost = trial_cost;
+                        best_swap = Some((slot, candidate));
+                    }
+                }
+            }
+
+            match best_swap {
+                Some((slot, candidate)) => {
+                    medoids[slot] = candidate;
+                    cost = best_cost;
+                }
+                None => return Ok((medoids, iterations, true)),
+            }
+        }
+
+        Ok((medoids, iterations, false))
+    }
+}
+
+pub fn pairwise_distances<M>(manifold: &M, points: &[Vec<f64>]) -> Result<DMatrix<f64>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    DistanceMatrix::default().compute(manifold, points)
+}
+
+fn nearest(distances: &DMatrix<f64>, medoids: &[usize], point: usize) -> (usize, f64) {
+    medoids
+        .iter()
+        .enumerate()
+        .map(|(c, m)| (c, distances[(*m, point)]))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("at least one medoid")
+}
+
+fn total_cost(distances: &DMatrix<f64>, medoids: &[usize]) -> f64 {
+    (0..distances.nrows())
+        .map(|i| nearest(distances, medoids, i).1)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, SPD};
+
+    #[test]
+    fn test_kmedoids_picks_actual_samples() {
+        let euclidean = Euclidean::new(1);
+        let points = vec![
+            vec![0.0],
+            vec![1.0],
+            vec![2.0],
+            vec![10.0],
+            vec![11.0],
+            vec![12.0],
+        ];
+        let clustering = KMedoids::new(2, 20).fit(&euclidean, &points).unwrap();
+
+        assert!(clustering.converged);
+        let mut centers: Vec<f64> = clustering.centers.iter().map(|c| c[0]).collect();
+        centers.sort_by(f64::total_cmp);
+        assert_eq!(centers, vec![1.0, 11.0]);
+        assert_eq!(clustering.labels[0], clustering.labels[2]);
+        assert_ne!(clustering.labels[0], clustering.labels[3]);
+        assert_relative_eq!(clustering.inertia, 4.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_kmedoids_on_spd_without_means() {
+        let spd = SPD::new(2);
+        let points = vec![
+            vec![1.0, 0.0, 0.0, 1.0],
+            vec![1.1, 0.0, 0.0, 1.0],
+            vec![1.0, 0.05, 0.05, 1.1],
+            vec![1.8, 0.0, 0.0, 1.9],
+            vec![1.9, 0.1, 0.1, 1.8],
+        ];
+        let clustering = KMedoids::new(2, 20).fit(&spd, &points).unwrap();
+        assert!(clustering.labels[..3]
+            .iter()
+            .all(|l| *l == clustering.labels[0]));
+        assert!(clustering.labels[3..]
+            .iter()
+            .all(|l| *l == clustering.labels[3]));
+        assert_ne!(clustering.labels[0], clustering.labels[3]);
+    }
+
+    #[test]
+    fn test_medoids_from_distance_matrix() {
+        let distances = DMatrix::from_row_slice(
+            4,
+            4,
+            &[
+                0.0, 1.0, 5.0, 6.0, //
+                1.0, 0.0, 5.0, 6.0, //
+                5.0, 5.0, 0.0, 1.0, //
+                6.0, 6.0, 1.0, 0.0,
+            ],
+        );
+        let (medoids, _, converged) = KMedoids::new(2, 10).medoids(&distances).unwrap();
+        assert!(converged);
+        assert_eq!(medoids.len(), 2);
+        assert!(medoids.iter().any(|m| *m < 2));
+        assert!(medoids.iter().any(|m| *m >= 2));
+    }
+
+    #[test]
+    fn test_kmedoids_rejects_invalid_input() {
+        let distances = DMatrix::zeros(3, 2);
+        assert!(KMedoids::new(1, 10).medoids(&distances).is_err());
+        assert!(KMedoids::new(4, 10).medoids(&DMatrix::zeros(3, 3)).is_err());
+    }
+}
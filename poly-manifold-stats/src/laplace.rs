@@ -0,0 +1,196 @@
+use crate::gaussian::TangentGaussian;
+use nalgebra::DMatrix;
+use poly_manifold_autodiff::RiemannianOptimizer;
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+use std::f64::consts::PI;
+
+#[derive(Clone)]
+pub struct LaplaceApproximation {
+    pub mode: Vec<f64>,
+    pub log_posterior_at_mode: f64,
+    pub hessian: DMatrix<f64>,
+    pub gaussian: TangentGaussian,
+    pub log_marginal_likelihood: f64,
+}
+
+impl LaplaceApproximation {
+    pub fn fit<M, O, F>(
+        manifold: &M,
+        initial: &[f64],
+        log_posterior: F,
+        optimizer: &O,
+    ) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64>,
+        O: RiemannianOptimizer,
+        F: Fn(&[f64]) -> f64,
+    {
+        let cost = |point: &[f64]| match manifold.project_to_manifold(point) {
+            Ok(point) => -log_posterior(&point),
+            Err(_) => f64::INFINITY,
+        };
+        let mode = optimizer
+            .minimize(manifold, initial, cost)?
+            .into_converged()?;
+        let mode = manifold.project_to_manifold(&mode)?;
+        let log_posterior_at_mode = log_posterior(&mode);
+        if !log_posterior_at_mode.is_finite() {
+            return Err(ManifoldError::NumericalError(
+                "Log-posterior is not finite at the mode".to_string(),
+            ));
+        }
+
+        let hessian = tangent_hessian(manifold, &mode, |p| -log_posterior(p), 1e-4)?;
+        let cholesky = hessian.clone().cholesky().ok_or_else(|| {
+            ManifoldError::NumericalError(
+                "Hessian at the mode is not positive definite".to_string(),
+            )
+        })?;
+        let log_det: f64 = cholesky.l().diagonal().iter().map(|d| 2.0 * d.ln()).sum();
+        let d = hessian.nrows() as f64;
+        let log_marginal_likelihood =
+            log_posterior_at_mode + 0.5 * d * (2.0 * PI).ln() - 0.5 * log_det;
+
+        let covariance = cholesky.inverse();
+        let covariance = (&covariance + covariance.transpose()) * 0.5;
+        let gaussian = TangentGaussian::new(manifold, mode.clone(), covariance)?;
+        Ok(Self {
+            mode,
+            log_posterior_at_mode,
+            hessian,
+            gaussian,
+            log_marginal_likelihood,
+        })
+    }
+}
+
+pub fn tangent_hessian<M, F>(
+    manifold: &M,
+    point: &[f64],
+    function: F,
+    step: f64,
+) -> Result<DMatrix<f64>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+    F: Fn(&[f64]) -> f64,
+{
+    if !(step > 0.0 && step.is_finite()) {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Finite-difference step must be positive, got {}",
+            step
+        )));
+    }
+    let basis = manifold.tangent_basis(point)?;
+    let d = basis.len();
+    let evaluate = |coefficients: &[(usize, f64)]| -> Result<f64> {
+        let mut tangent = TangentVector::zero(point.len());
+        for (i, c) in coefficients {
+            tangent += &basis[*i] * *c;
+        }
+        Ok(function(&manifold.exp(point, &tangent)?))
+    };
+
+    let center = function(point);
+    let mut hessian = DMatrix::zeros(d, d);
+    for i in 0..d {
+        let plus = evaluate(&[(i, step)])?;
+        let minus = evaluate(&[(i, -step)])?;
+        hessian[(i, i)] = (plus - 2.0 * center + minus) / (step * step);
+        for j in 0..i {
+            let value = (evaluate(&[(i, step), (j, step)])?
+                - evaluate(&[(i, step), (j, -step)])?
+                - evaluate(&[(i, -step), (j, step)])?
+                + evaluate(&[(i, -step), (j, -step)])?)
+                / (4.0 * step * step);
+            hessian[(i, j)] = value;
+            hessian[(j, i)] = value;
+        }
+    }
+    if hessian.iter().any(|h| !h.is_finite()) {
+        return Err(ManifoldError::NumericalError(
+            "Tangent Hessian has non-finite entries".to_string(),
+        ));
+    }
+    Ok(hessian)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_autodiff::{GradientDescent, LineSearch};
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    fn optimizer() -> GradientDescent {
+        GradientDescent::new(1.0, 1000, 1e-10).with_line_search(LineSearch::armijo())
+    }
+
+    #[test]
+    fn test_gaussian_posterior_is_exact() {
+        let euclidean = Euclidean::new(2);
+        let precision = DMatrix::from_row_slice(2, 2, &[2.0, 0.5, 0.5, 1.0]);
+        let center = [1.0, -2.0];
+        let constant = -3.0;
+        let log_posterior = |x: &[f64]| {
+            let r = nalgebra::DVector::from_vec(vec![x[0] - center[0], x[1] - center[1]]);
+            constant - 0.5 * (r.transpose() * &precision * &r)[(0, 0)]
+        };
+
+        let laplace =
+            LaplaceApproximation::fit(&euclidean, &[0.0, 0.0], log_posterior, &optimizer())
+                .unwrap();
+
+        assert_relative_eq!(laplace.mode[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(laplace.mode[1], -2.0, epsilon = 1e-5);
+        let expected_covariance = precision.clone().try_inverse().unwrap();
+        assert_relative_eq!(
+            (&laplace.gaussian.covariance - expected_covariance).norm(),
+            0.0,
+            epsilon = 1e-5
+        );
+        let expected = constant + (2.0 * PI).ln() - 0.5 * precision.determinant().ln();
+        assert_relative_eq!(laplace.log_marginal_likelihood, expected, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_von_mises_fisher_evidence() {
+        let sphere = Sphere::new(2);
+        let kappa = 20.0;
+        let log_posterior = |x: &[f64]| kappa * x[2];
+        let initial = sphere.project_to_manifold(&[0.5, 0.3, 1.0]).unwrap();
+
+        let laplace =
+            LaplaceApproximation::fit(&sphere, &initial, log_posterior, &optimizer()).unwrap();
+
+        assert_relative_eq!(laplace.mode[2], 1.0, epsilon = 1e-8);
+        assert_relative_eq!(laplace.hessian[(0, 0)], kappa, epsilon = 1e-4);
+        assert_relative_eq!(laplace.hessian[(0, 1)], 0.0, epsilon = 1e-4);
+        let exact = (4.0 * PI * kappa.sinh() / kappa).ln();
+        assert_relative_eq!(laplace.log_marginal_likelihood, exact, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_tangent_hessian_of_saddle() {
+        let euclidean = Euclidean::new(2);
+        let hessian = tangent_hessian(
+            &euclidean,
+            &[0.0, 0.0],
+            |x| x[0] * x[0] - 3.0 * x[0] * x[1],
+            1e-4,
+        )
+        .unwrap();
+        assert_relative_eq!(hessian[(0, 0)], 2.0, epsilon = 1e-6);
+        assert_relative_eq!(hessian[(0, 1)], -3.0, epsilon = 1e-6);
+        assert_relative_eq!(hessian[(1, 1)], 0.0, epsilon = 1e-6);
+        assert!(tangent_hessian(&euclidean, &[0.0, 0.0], |x| x[0], 0.0).is_err());
+    }
+
+    #[test]
+    fn test_flat_direction_is_rejected() {
+        let euclidean = Euclidean::new(2);
+        let log_posterior = |x: &[f64]| -(x[0] - 1.0).powi(2);
+        let result =
+            LaplaceApproximation::fit(&euclidean, &[0.0, 0.0], log_posterior, &optimizer());
+        assert!(matches!(result, Err(ManifoldError::NumericalError(_))));
+    }
+}
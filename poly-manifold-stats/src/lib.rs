@@ -0,0 +1,115 @@
+pub mod directional;
+pub mod dispersion;
+pub mod distance_matrix;
+pub mod domain_adaptation;
+pub mod dti;
+pub mod gaussian;
+pub mod graph_embedding;
+pub mod hierarchical;
+pub mod hmc;
+pub mod hypothesis;
+#[cfg(feature = "petgraph")]
+pub mod isomap;
+pub mod kde;
+pub mod kmeans;
+pub mod kmedoids;
+pub mod laplace;
+pub mod mds;
+pub mod mean;
+pub mod neighbors;
+pub mod particle_filter;
+pub mod pga;
+pub mod procrustes;
+pub mod rotation_averaging;
+pub mod shrinkage;
+pub mod smoothing;
+pub mod spd_random;
+
+pub use directional::{UniformRotation, UniformSphere, VonMisesFisher};
+pub use dispersion::{frechet_std_dev, frechet_variance, squared_distances};
+pub use distance_matrix::DistanceMatrix;
+pub use domain_adaptation::{DomainTransport, Recentering};
+pub use dti::{
+    fractional_anisotropy, mean_diffusivity, tensor_interpolate, tensor_weighted_mean, TensorField,
+    TensorMetric,
+};
+pub use gaussian::TangentGaussian;
+#[cfg(feature = "rand")]
+pub use gaussian::TangentGaussianSampler;
+pub use graph_embedding::{poincare_to_lorentz, GraphEmbedding, PoincareEmbedding};
+pub use hierarchical::{Dendrogram, HierarchicalClustering, Linkage, Merge};
+pub use hmc::{GeodesicHmc, HmcSamples};
+pub use hypothesis::{Bootstrap, BootstrapRegion, PermutationTest, PermutationTestResult};
+#[cfg(feature = "petgraph")]
+pub use isomap::{graph_geodesic_distances, knn_graph, Isomap};
+pub use kde::{median_distance_bandwidth, silverman_bandwidth, Kernel, KernelDensity};
+pub use kmeans::{Clustering, KMeans};
+pub use kmedoids::{pairwise_distances, KMedoids};
+pub use laplace::{tangent_hessian, LaplaceApproximation};
+pub use mds::{normalized_stress, raw_stress, ManifoldMds, MdsEmbedding};
+pub use mean::{blend, FrechetMean};
+pub use neighbors::{Neighbor, VantagePointTree};
+pub use particle_filter::ParticleFilter;
+pub use pga::PrincipalGeodesicAnalysis;
+pub use procrustes::{
+    orthogonal_procrustes, stiefel_procrustes, GeneralizedAlignment, GeneralizedProcrustes,
+    ProcrustesAlignment,
+};
+pub use rotation_averaging::{
+    chordal_initialization, chordal_mean, relative_residuals, RelativeRotation, RobustLoss,
+    RotationAveraging,
+};
+pub use shrinkage::{
+    geodesic_shrinkage, sample_covariance, GeodesicShrinkage, ShrinkageEstimate, ShrinkageTarget,
+};
+pub use smoothing::{exponential_smoothing, geodesic_moving_average, LieKalmanFilter};
+pub use spd_random::{SpdLogNormal, Wishart};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_estimators_and_results_are_send_and_sync() {
+        assert_send_sync::<FrechetMean>();
+        assert_send_sync::<PrincipalGeodesicAnalysis>();
+        assert_send_sync::<KMeans>();
+        assert_send_sync::<Clustering>();
+        assert_send_sync::<KMedoids>();
+        assert_send_sync::<TangentGaussian>();
+        assert_send_sync::<KernelDensity>();
+        assert_send_sync::<VantagePointTree>();
+        assert_send_sync::<DistanceMatrix>();
+        assert_send_sync::<GeodesicHmc>();
+        assert_send_sync::<HmcSamples>();
+        assert_send_sync::<LaplaceApproximation>();
+        assert_send_sync::<ParticleFilter>();
+        assert_send_sync::<LieKalmanFilter>();
+        assert_send_sync::<HierarchicalClustering>();
+        assert_send_sync::<Dendrogram>();
+        assert_send_sync::<ManifoldMds>();
+        assert_send_sync::<MdsEmbedding>();
+        assert_send_sync::<PoincareEmbedding>();
+        assert_send_sync::<GraphEmbedding>();
+        assert_send_sync::<RotationAveraging>();
+        assert_send_sync::<GeodesicShrinkage>();
+        assert_send_sync::<ShrinkageEstimate>();
+        assert_send_sync::<TensorField>();
+        assert_send_sync::<Recentering>();
+        assert_send_sync::<DomainTransport>();
+        assert_send_sync::<PermutationTest>();
+        assert_send_sync::<PermutationTestResult>();
+        assert_send_sync::<Bootstrap>();
+        assert_send_sync::<BootstrapRegion>();
+        assert_send_sync::<GeneralizedProcrustes>();
+        assert_send_sync::<GeneralizedAlignment>();
+        assert_send_sync::<ProcrustesAlignment>();
+        assert_send_sync::<Wishart>();
+        assert_send_sync::<SpdLogNormal>();
+        assert_send_sync::<VonMisesFisher>();
+        assert_send_sync::<UniformSphere>();
+        assert_send_sync::<UniformRotation>();
+    }
+}
@@ -0,0 +1,276 @@
+use nalgebra::DMatrix;
+use poly_manifold_autodiff::RiemannianOptimizer;
+use poly_manifold_core::random::random_tangent;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Context, Manifold, ManifoldError, RandomSource, Result, SeededRng};
+use poly_manifold_spaces::PowerManifold;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MdsEmbedding {
+    pub points: Vec<Vec<f64>>,
+    pub stress: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ManifoldMds {
+    pub initial_radius: f64,
+    pub seed: u64,
+}
+
+impl Default for ManifoldMds {
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+impl ManifoldMds {
+    pub fn new(initial_radius: f64) -> Self {
+        Self {
+            initial_radius,
+            seed: Context::current().seed,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_context(mut self, context: &Context) -> Self {
+        self.seed = context.seed;
+        self
+    }
+
+    pub fn embed<M, O>(
+        &self,
+        manifold: &M,
+        dissimilarities: &DMatrix<f64>,
+        anchor: &[f64],
+        optimizer: &O,
+    ) -> Result<MdsEmbedding>
+    where
+        M: Manifold<Scalar = f64>,
+        O: RiemannianOptimizer,
+    {
+        let n = check_dissimilarities(dissimilarities)?;
+        validate_entry_point(manifold, anchor)?;
+
+        let mut rng = SeededRng::seed_from_u64(self.seed);
+        let mut initial = Vec::with_capacity(n);
+        for _ in 0..n {
+            let direction = random_tangent(manifold, anchor, &mut rng)?;
+            let norm = manifold.norm(anchor, &direction)?;
+            let scale = if norm > 0.0 {
+                self.initial_radius * rng.next_f64() / norm
+            } else {
+                0.0
+            };
+            initial.push(manifold.exp(anchor, &(direction * scale))?);
+        }
+        self.embed_from(manifold, dissimilarities, initial, optimizer)
+    }
+
+    pub fn embed_from<M, O>(
+        &self,
+        manifold: &M,
+        dissimilarities: &DMatrix<f64>,
+        initial: Vec<Vec<f64>>,
+        optimizer: &O,
+    ) -> Result<MdsEmbedding>
+    where
+        M: Manifold<Scalar = f64>,
+        O: RiemannianOptimizer,
+    {
+        let n = check_dissimilarities(dissimilarities)?;
+        if initial.len() != n {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: n,
+                got: initial.len(),
+            });
+        }
+
+        let power = PowerManifold::new(manifold, n);
+        let start = power.from_components(&initial)?;
+        let cost = |flat: &[f64]| {
+            let Ok(flat) = power.project_to_manifold(flat) else {
+                return f64::INFINITY;
+            };
+            let points: Vec<Vec<f64>> = power.components(&flat).map(<[f64]>::to_vec).collect();
+            raw_stress(manifold, &points, dissimilarities).unwrap_or(f64::INFINITY)
+        };
+
+        let result = optimizer.minimize(&power, &start, cost)?;
+        let points: Vec<Vec<f64>> = power
+            .components(&result.point)
+            .map(<[f64]>::to_vec)
+            .collect();
+        let stress = normalized_stress(manifold, &points, dissimilarities)?;
+        Ok(MdsEmbedding { points, stress })
+    }
+}
+
+pub fn raw_stress<M>(
+    manifold: &M,
+    points: &[Vec<f64>],
+    dissimilarities: &DMatrix<f64>,
+) -> Result<f64>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    let n = check_dissimilarities(dissimilarities)?;
+    if points.len() != n {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: n,
+            got: points.len(),
+        });
+    }
+    let mut stress = 0.0;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let residual = manifold.distance(&points[i], &points[j])? - dissimilarities[(i, j)];
+            stress += residual * residual;
+        }
+    }
+    Ok(stress)
+}
+
+pub fn normalized_stress<M>(
+    manifold: &M,
+    points: &[Vec<f64>],
+    dissimilarities: &DMatrix<f64>,
+) -> Result<f64>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    let raw = raw_stress(manifold, points, dissimilarities)?;
+    let n = dissimilarities.nrows();
+    let scale: f64 = (0..n)
+        .flat_map(|i| ((i + 1)..n).map(move |j| (i, j)))
+        .map(|(i, j)| dissimilarities[(i, j)].powi(2))
+        .sum();
+    if scale <= 0.0 {
+        return Ok(raw.sqrt());
+    }
+    Ok((raw / scale).sqrt())
+}
+
+fn check_dissimilarities(dissimilarities: &DMatrix<f64>) -> Result<usize> {
+    let n = dissimilarities.nrows();
+    if dissimilarities.ncols() != n {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: n,
+            got: dissimilarities.ncols(),
+        });
+    }
+    if n < 2 {
+        return Err(ManifoldError::InvalidParameter(
+            "MDS requires at least two items".to_string(),
+        ));
+    }
+    for i in 0..n {
+        for j in 0..n {
+            let d = dissimilarities[(i, j)];
+            if d < 0.0 || !d.is_finite() || (d - dissimilarities[(j, i)]).abs() > 1e-10 {
+                return Err(ManifoldError::InvalidParameter(
+                    "Dissimilarities must be symmetric, finite and non-negative".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kmedoids::pairwise_distances;
+    use poly_manifold_autodiff::{GradientDescent, LineSearch};
+    use poly_manifold_spaces::{Euclidean, PoincareBall, Sphere};
+
+    fn optimizer() -> GradientDescent {
+        GradientDescent::new(1.0, 2000, 1e-14).with_line_search(LineSearch::armijo())
+    }
+
+    #[test]
+    fn test_euclidean_mds_recovers_configuration() {
+        let euclidean = Euclidean::new(2);
+        let truth = vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 1.0],
+        ];
+        let distances = pairwise_distances(&euclidean, &truth).unwrap();
+
+        let embedding = ManifoldMds::new(1.0)
+            .with_seed(3)
+            .embed(&euclidean, &distances, &[0.0, 0.0], &optimizer())
+            .unwrap();
+        assert!(embedding.stress < 1e-4);
+    }
+
+    #[test]
+    fn test_sphere_mds_fits_geodesic_distances() {
+        let sphere = Sphere::new(2);
+        let truth = vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+            sphere.project_to_manifold(&[1.0, 1.0, 1.0]).unwrap(),
+        ];
+        let distances = pairwise_distances(&sphere, &truth).unwrap();
+
+        let embedding = ManifoldMds::new(0.5)
+            .with_seed(4)
+            .embed(&sphere, &distances, &[0.0, 0.0, 1.0], &optimizer())
+            .unwrap();
+        assert!(embedding.stress < 1e-3);
+        for point in &embedding.points {
+            assert!(sphere.check_point(point).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_hyperbolic_mds_embeds_star_tree() {
+        let ball = PoincareBall::new(2);
+        let mut distances = DMatrix::from_element(5, 5, 4.0);
+        for i in 0..5 {
+            distances[(i, i)] = 0.0;
+        }
+        for leaf in 1..5 {
+            distances[(0, leaf)] = 2.0;
+            distances[(leaf, 0)] = 2.0;
+        }
+
+        let embedding = ManifoldMds::new(0.3)
+            .with_seed(1)
+            .embed(&ball, &distances, &[0.0, 0.0], &optimizer())
+            .unwrap();
+
+        let euclidean = Euclidean::new(2);
+        let flat = ManifoldMds::new(0.3)
+            .with_seed(1)
+            .embed(&euclidean, &distances, &[0.0, 0.0], &optimizer())
+            .unwrap();
+        assert!(embedding.stress < flat.stress);
+    }
+
+    #[test]
+    fn test_invalid_dissimilarities() {
+        let euclidean = Euclidean::new(1);
+        let asymmetric = DMatrix::from_row_slice(2, 2, &[0.0, 1.0, 2.0, 0.0]);
+        let negative = DMatrix::from_row_slice(2, 2, &[0.0, -1.0, -1.0, 0.0]);
+        let mds = ManifoldMds::default();
+        assert!(mds
+            .embed(&euclidean, &asymmetric, &[0.0], &optimizer())
+            .is_err());
+        assert!(mds
+            .embed(&euclidean, &negative, &[0.0], &optimizer())
+            .is_err());
+        assert!(mds
+            .embed(&euclidean, &DMatrix::zeros(1, 1), &[0.0], &optimizer())
+            .is_err());
+    }
+}
@@ -0,0 +1,301 @@
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrechetMean {
+    pub max_iterations: usize,
+    pub tolerance: f64,
+    pub step_size: f64,
+}
+
+impl Default for FrechetMean {
+    fn default() -> Self {
+        Self::new(100, 1e-10)
+    }
+}
+
+impl FrechetMean {
+    pub fn new(max_iterations: usize, tolerance: f64) -> Self {
+        Self {
+            max_iterations,
+            tolerance,
+            step_size: 1.0,
+        }
+    }
+
+    pub fn with_step_size(mut self, step_size: f64) -> Self {
+        self.step_size = step_size;
+        self
+    }
+
+    pub fn compute<M>(&self, manifold: &M, points: &[Vec<f64>]) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let weights = vec![1.0; points.len()];
+        self.compute_weighted(manifold, points, &weights)
+    }
+
+    pub fn compute_weighted<M>(
+        &self,
+        manifold: &M,
+        points: &[Vec<f64>],
+        weights: &[f64],
+    ) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let total = check_weighted_points(manifold, points, weights)?;
+
+        let start = weights
+            .iter()
+            .position(|w| *w > 0.0)
+            .expect("total weight is positive");
+        let mut mean = points[start].clone();
+
+        for _iter in 0..self.max_iterations {
+            let mut direction = TangentVector::zero(mean.len());
+            for (point, weight) in points.iter().zip(weights) {
+                if *weight > 0.0 {
+                    direction += manifold.log(&mean, point)? * (weight / total);
+                }
+            }
+
+            if manifold.norm(&mean, &direction)? < self.tolerance {
+                return Ok(mean);
+            }
+            mean = manifold.exp(&mean, &(direction * self.step_size))?;
+        }
+
+        Err(ManifoldError::ConvergenceError {
+            iterations: self.max_iterations,
+        })
+    }
+
+    pub fn blend<M>(&self, manifold: &M, points: &[Vec<f64>], weights: &[f64]) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let total = check_weighted_points(manifold, points, weights)?;
+        let objective = |x: &[f64]| -> Result<f64> {
+            let mut value = 0.0;
+            for (point, weight) in points.iter().zip(weights) {
+                if *weight > 0.0 {
+                    value += 0.5 * weight / total * manifold.distance(x, point)?.powi(2);
+                }
+            }
+            Ok(value)
+        };
+
+        let start = (0..points.len())
+            .max_by(|a, b| weights[*a].total_cmp(&weights[*b]))
+            .expect("points are non-empty");
+        let mut mean = points[start].clone();
+        let mut value = objective(&mean)?;
+
+        for _iter in 0..self.max_iterations {
+            let mut direction = TangentVector::zero(mean.len());
+            for (point, weight) in points.iter().zip(weights) {
+                if *weight > 0.0 {
+                    direction += manifold.log(&mean, point)? * (weight / total);
+                }
+            }
+
+            let norm = manifold.norm(&mean, &direction)?;
+            if norm < self.tolerance {
+                return Ok(mean);
+            }
+
+            let mut step = self.step_size;
+            loop {
+                if let Ok(candidate) = manifold.exp(&mean, &(&direction * step)) {
+                    if let Ok(candidate_value) = objective(&candidate) {
+                        if candidate_value <= value - 0.5 * step * norm * norm {
+                            mean = candidate;
+                            value = candidate_value;
+                            break;
+                        }
+                    }
+                }
+                step *= 0.5;
+                if step < 1e-12 {
+                    return Err(ManifoldError::NumericalError(
+                        "Barycenter line search failed to decrease the Fréchet function"
+                            .to_string(),
+                    ));
+                }
+            }
+        }
+
+        Err(ManifoldError::ConvergenceError {
+            iterations: self.max_iterations,
+        })
+    }
+}
+
+pub fn blend<M>(manifold: &M, points: &[Vec<f64>], weights: &[f64]) -> Result<Vec<f64>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    FrechetMean::default().blend(manifold, points, weights)
+}
+
+fn check_weighted_points<M>(manifold: &M, points: &[Vec<f64>], weights: &[f64]) -> Result<f64>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    if points.is_empty() {
+        return Err(ManifoldError::InvalidParameter(
+            "Fréchet mean requires at least one point".to_string(),
+        ));
+    }
+    if weights.len() != points.len() {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: points.len(),
+            got: weights.len(),
+        });
+    }
+    if weights.iter().any(|w| *w < 0.0 || !w.is_finite()) {
+        return Err(ManifoldError::InvalidParameter(
+            "Weights must be non-negative and finite".to_string(),
+        ));
+    }
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return Err(ManifoldError::InvalidParameter(
+            "Weights must not all be zero".to_string(),
+        ));
+    }
+    for point in points {
+        validate_entry_point(manifold, point)?;
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere, SO3, SPD};
+
+    #[test]
+    fn test_euclidean_mean_is_arithmetic_mean() {
+        let euclidean = Euclidean::new(2);
+        let points = vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![1.0, 3.0]];
+        let mean = FrechetMean::default().compute(&euclidean, &points).unwrap();
+        assert_relative_eq!(mean[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(mean[1], 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_sphere_mean_of_symmetric_points() {
+        let sphere = Sphere::new(2);
+        let s = 0.5f64.sqrt();
+        let points = vec![
+            vec![s, 0.0, s],
+            vec![-s, 0.0, s],
+            vec![0.0, s, s],
+            vec![0.0, -s, s],
+        ];
+        let mean = FrechetMean::default().compute(&sphere, &points).unwrap();
+        assert_relative_eq!(mean[0], 0.0, epsilon = 1e-8);
+        assert_relative_eq!(mean[1], 0.0, epsilon = 1e-8);
+        assert_relative_eq!(mean[2], 1.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_spd_mean_of_commuting_matrices_is_geometric() {
+        let spd = SPD::new(2);
+        let points = vec![vec![1.0, 0.0, 0.0, 1.5], vec![1.5, 0.0, 0.0, 1.0]];
+        let mean = FrechetMean::default().compute(&spd, &points).unwrap();
+        assert_relative_eq!(mean[0], 1.5f64.sqrt(), epsilon = 1e-6);
+        assert_relative_eq!(mean[3], 1.5f64.sqrt(), epsilon = 1e-6);
+        assert_relative_eq!(mean[1], 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let euclidean = Euclidean::new(1);
+        let points = vec![vec![0.0], vec![4.0]];
+        let mean = FrechetMean::default()
+            .compute_weighted(&euclidean, &points, &[3.0, 1.0])
+            .unwrap();
+        assert_relative_eq!(mean[0], 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let euclidean = Euclidean::new(1);
+        let estimator = FrechetMean::default();
+        assert!(estimator.compute(&euclidean, &[]).is_err());
+        assert!(estimator
+            .compute_weighted(&euclidean, &[vec![1.0]], &[0.0])
+            .is_err());
+        assert!(estimator
+            .compute_weighted(&euclidean, &[vec![1.0]], &[1.0, 2.0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_blend_two_points_interpolates() {
+        let euclidean = Euclidean::new(2);
+        let points = vec![vec![0.0, 0.0], vec![4.0, 8.0]];
+        let blended = blend(&euclidean, &points, &[0.75, 0.25]).unwrap();
+        assert_relative_eq!(blended[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(blended[1], 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_blend_spd_is_weighted_geometric_mean() {
+        let spd = SPD::new(2);
+        let points = vec![
+            vec![1.2, 0.0, 0.0, 0.8],
+            vec![0.9, 0.0, 0.0, 1.1],
+            vec![1.0, 0.0, 0.0, 1.3],
+        ];
+        let weights = [0.5, 0.3, 0.2];
+        let blended = blend(&spd, &points, &weights).unwrap();
+
+        let expected = |k: usize| {
+            points
+                .iter()
+                .zip(&weights)
+                .map(|(p, w)| w * p[k].ln())
+                .sum::<f64>()
+                .exp()
+        };
+        assert_relative_eq!(blended[0], expected(0), epsilon = 1e-6);
+        assert_relative_eq!(blended[3], expected(3), epsilon = 1e-6);
+        assert_relative_eq!(blended[1], 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_blend_rotations_about_common_axis() {
+        let so3 = SO3::new();
+        let points = vec![
+            SO3::from_axis_angle(&[0.0, 0.0, 1.0], 0.0),
+            SO3::from_axis_angle(&[0.0, 0.0, 1.0], 0.6),
+            SO3::from_axis_angle(&[0.0, 0.0, 1.0], 1.5),
+        ];
+        let blended = blend(&so3, &points, &[1.0, 2.0, 1.0]).unwrap();
+        let expected = SO3::from_axis_angle(&[0.0, 0.0, 1.0], (0.0 + 1.2 + 1.5) / 4.0);
+        assert_relative_eq!(
+            so3.distance(&blended, &expected).unwrap(),
+            0.0,
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn test_blend_with_overshooting_step_still_converges() {
+        let euclidean = Euclidean::new(1);
+        let points = vec![vec![-1.0], vec![3.0]];
+        let blended = FrechetMean::default()
+            .with_step_size(4.0)
+            .blend(&euclidean, &points, &[1.0, 1.0])
+            .unwrap();
+        assert_relative_eq!(blended[0], 1.0, epsilon = 1e-10);
+        assert!(blend(&euclidean, &points, &[-1.0, 2.0]).is_err());
+    }
+}
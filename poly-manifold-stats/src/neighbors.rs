@@ -0,0 +1,677 @@
+#[cfg(feature = "rkyv")]
+use poly_manifold_core::table::archive_error;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{DistanceSurrogate, Manifold, ManifoldError, Result};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Neighbor {
+    pub index: usize,
+    pub distance: f64,
+}
+
+impl Eq for Neighbor {}
+
+impl Ord for Neighbor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+impl PartialOrd for Neighbor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+struct Node {
+    vantage: usize,
+    radius: f64,
+    inside: Option<usize>,
+    outside: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct VantagePointTree {
+    points: Vec<Vec<f64>>,
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+impl VantagePointTree {
+    pub fn build<M>(manifold: &M, points: Vec<Vec<f64>>) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        for point in &points {
+            validate_entry_point(manifold, point)?;
+        }
+        let mut tree = Self {
+            points,
+            nodes: Vec::new(),
+            root: None,
+        };
+        let mut items: Vec<(usize, f64)> = (0..tree.points.len()).map(|i| (i, 0.0)).collect();
+        tree.root = tree.build_node(manifold, &mut items)?;
+        Ok(tree)
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> &[Vec<f64>] {
+        &self.points
+    }
+
+    pub fn nearest<M>(&self, manifold: &M, query: &[f64], k: usize) -> Result<Vec<Neighbor>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        validate_entry_point(manifold, query)?;
+        self.nearest_with(&exact_bracket(manifold), query, k)
+    }
+
+    pub fn nearest_pruned<M>(&self, manifold: &M, query: &[f64], k: usize) -> Result<Vec<Neighbor>>
+    where
+        M: DistanceSurrogate + ?Sized,
+    {
+        validate_entry_point(manifold, query)?;
+        self.nearest_with(&|a, b, tau| manifold.bounded_distance(a, b, tau), query, k)
+    }
+
+    pub fn within_radius<M>(
+        &self,
+        manifold: &M,
+        query: &[f64],
+        radius: f64,
+    ) -> Result<Vec<Neighbor>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        check_radius(radius)?;
+        validate_entry_point(manifold, query)?;
+        self.within_radius_with(&exact_bracket(manifold), query, radius)
+    }
+
+    pub fn within_radius_pruned<M>(
+        &self,
+        manifold: &M,
+        query: &[f64],
+        radius: f64,
+    ) -> Result<Vec<Neighbor>>
+    where
+        M: DistanceSurrogate + ?Sized,
+    {
+        check_radius(radius)?;
+        validate_entry_point(manifold, query)?;
+        self.within_radius_with(
+            &|a, b, tau| manifold.bounded_distance(a, b, tau),
+            query,
+            radius,
+        )
+    }
+
+    fn build_node<M>(&mut self, manifold: &M, items: &mut [(usize, f64)]) -> Result<Option<usize>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let Some(((vantage, _), rest)) = items.split_first_mut() else {
+            return Ok(None);
+        };
+        let vantage = *vantage;
+        for item in rest.iter_mut() {
+            item.1 = manifold.distance(&self.points[vantage], &self.points[item.0])?;
+        }
+
+        let split = rest.len() / 2;
+        let mut radius = 0.0;
+        if !rest.is_empty() {
+            rest.select_nth_unstable_by(split, |a, b| a.1.total_cmp(&b.1));
+            radius = rest[split].1;
+        }
+
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            vantage,
+            radius,
+            inside: None,
+            outside: None,
+        });
+        let (inside, outside) = rest.split_at_mut(split);
+        let inside = self.build_node(manifold, inside)?;
+        let outside = self.build_node(manifold, outside)?;
+        self.nodes[id].inside = inside;
+        self.nodes[id].outside = outside;
+        Ok(Some(id))
+    }
+}
+
+trait TreeStorage {
+    fn root(&self) -> Option<usize>;
+
+    fn node(&self, id: usize) -> Node;
+
+    fn point<'a>(&'a self, index: usize, scratch: &'a mut Vec<f64>) -> &'a [f64];
+
+    fn nearest_with<B>(&self, bracket: &B, query: &[f64], k: usize) -> Result<Vec<Neighbor>>
+    where
+        B: Fn(&[f64], &[f64], f64) -> Result<(f64, f64)>,
+    {
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.search_nearest(bracket, self.root(), query, k, &mut heap, &mut Vec::new())?;
+        }
+        Ok(heap.into_sorted_vec())
+    }
+
+    fn within_radius_with<B>(
+        &self,
+        bracket: &B,
+        query: &[f64],
+        radius: f64,
+    ) -> Result<Vec<Neighbor>>
+    where
+        B: Fn(&[f64], &[f64], f64) -> Result<(f64, f64)>,
+    {
+        let mut found = Vec::new();
+        self.search_radius(
+            bracket,
+            self.root(),
+            query,
+            radius,
+            &mut found,
+            &mut Vec::new(),
+        )?;
+        found.sort();
+        Ok(found)
+    }
+
+    fn search_nearest<B>(
+        &self,
+        bracket: &B,
+        node: Option<usize>,
+        query: &[f64],
+        k: usize,
+        heap: &mut BinaryHeap<Neighbor>,
+        scratch: &mut Vec<f64>,
+    ) -> Result<()>
+    where
+        B: Fn(&[f64], &[f64], f64) -> Result<(f64, f64)>,
+    {
+        let Some(node) = node.map(|id| self.node(id)) else {
+            return Ok(());
+        };
+        let bound = |heap: &BinaryHeap<Neighbor>| {
+            if heap.len() < k {
+                f64::INFINITY
+            } else {
+                heap.peek().map_or(f64::INFINITY, |n| n.distance)
+            }
+        };
+        let (lower, upper) = bracket(query, self.point(node.vantage, scratch), bound(heap))?;
+        if lower == upper {
+            heap.push(Neighbor {
+                index: node.vantage,
+                distance: lower,
+            });
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+
+        let order = if lower < node.radius {
+            [(node.inside, true), (node.outside, false)]
+        } else {
+            [(node.outside, false), (node.inside, true)]
+        };
+        for (child, is_inside) in order {
+            let tau = bound(heap);
+            let reachable = if is_inside {
+                lower - tau <= node.radius
+            } else {
+                upper + tau >= node.radius
+            };
+            if reachable {
+                self.search_nearest(bracket, child, query, k, heap, scratch)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn search_radius<B>(
+        &self,
+        bracket: &B,
+        node: Option<usize>,
+        query: &[f64],
+        radius: f64,
+        found: &mut Vec<Neighbor>,
+        scratch: &mut Vec<f64>,
+    ) -> Result<()>
+    where
+        B: Fn(&[f64], &[f64], f64) -> Result<(f64, f64)>,
+    {
+        let Some(node) = node.map(|id| self.node(id)) else {
+            return Ok(());
+        };
+        let (lower, upper) = bracket(query, self.point(node.vantage, scratch), radius)?;
+        if lower == upper && lower <= radius {
+            found.push(Neighbor {
+                index: node.vantage,
+                distance: lower,
+            });
+        }
+        if lower - radius <= node.radius {
+            self.search_radius(bracket, node.inside, query, radius, found, scratch)?;
+        }
+        if upper + radius >= node.radius {
+            self.search_radius(bracket, node.outside, query, radius, found, scratch)?;
+        }
+        Ok(())
+    }
+}
+
+impl TreeStorage for VantagePointTree {
+    fn root(&self) -> Option<usize> {
+        self.root
+    }
+
+    fn node(&self, id: usize) -> Node {
+        self.nodes[id]
+    }
+
+    fn point<'a>(&'a self, index: usize, _scratch: &'a mut Vec<f64>) -> &'a [f64] {
+        &self.points[index]
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl VantagePointTree {
+    pub fn to_bytes(&self) -> Result<rkyv::util::AlignedVec> {
+        rkyv::to_bytes::<rkyv::rancor::Error>(self).map_err(archive_error)
+    }
+
+    pub fn access(bytes: &[u8]) -> Result<&ArchivedVantagePointTree> {
+        let tree = rkyv::access::<ArchivedVantagePointTree, rkyv::rancor::Error>(bytes)
+            .map_err(archive_error)?;
+        let (nodes, points) = (tree.nodes.len(), tree.points.len());
+        let in_bounds = |child: Option<usize>| child.is_none_or(|id| id < nodes);
+        let valid = in_bounds(tree.root())
+            && (0..nodes).map(|id| tree.node(id)).all(|node| {
+                node.vantage < points && in_bounds(node.inside) && in_bounds(node.outside)
+            });
+        if !valid {
+            return Err(ManifoldError::IoError(
+                "Invalid archive: tree references out-of-range nodes or points".to_string(),
+            ));
+        }
+        Ok(tree)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedVantagePointTree {
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn nearest<M>(&self, manifold: &M, query: &[f64], k: usize) -> Result<Vec<Neighbor>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        validate_entry_point(manifold, query)?;
+        self.nearest_with(&exact_bracket(manifold), query, k)
+    }
+
+    pub fn nearest_pruned<M>(&self, manifold: &M, query: &[f64], k: usize) -> Result<Vec<Neighbor>>
+    where
+        M: DistanceSurrogate + ?Sized,
+    {
+        validate_entry_point(manifold, query)?;
+        self.nearest_with(&|a, b, tau| manifold.bounded_distance(a, b, tau), query, k)
+    }
+
+    pub fn within_radius<M>(
+        &self,
+        manifold: &M,
+        query: &[f64],
+        radius: f64,
+    ) -> Result<Vec<Neighbor>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        check_radius(radius)?;
+        validate_entry_point(manifold, query)?;
+        self.within_radius_with(&exact_bracket(manifold), query, radius)
+    }
+
+    pub fn within_radius_pruned<M>(
+        &self,
+        manifold: &M,
+        query: &[f64],
+        radius: f64,
+    ) -> Result<Vec<Neighbor>>
+    where
+        M: DistanceSurrogate + ?Sized,
+    {
+        check_radius(radius)?;
+        validate_entry_point(manifold, query)?;
+        self.within_radius_with(
+            &|a, b, tau| manifold.bounded_distance(a, b, tau),
+            query,
+            radius,
+        )
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl TreeStorage for ArchivedVantagePointTree {
+    fn root(&self) -> Option<usize> {
+        self.root.as_ref().map(|id| id.to_native() as usize)
+    }
+
+    fn node(&self, id: usize) -> Node {
+        let node = &self.nodes[id];
+        Node {
+            vantage: node.vantage.to_native() as usize,
+            radius: node.radius.to_native(),
+            inside: node.inside.as_ref().map(|id| id.to_native() as usize),
+            outside: node.outside.as_ref().map(|id| id.to_native() as usize),
+        }
+    }
+
+    fn point<'a>(&'a self, index: usize, scratch: &'a mut Vec<f64>) -> &'a [f64] {
+        scratch.clear();
+        scratch.extend(self.points[index].iter().map(|x| x.to_native()));
+        scratch
+    }
+}
+
+fn exact_bracket<M>(manifold: &M) -> impl Fn(&[f64], &[f64], f64) -> Result<(f64, f64)> + '_
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    move |a, b, _| {
+        let distance = manifold.distance(a, b)?;
+        Ok((distance, distance))
+    }
+}
+
+fn check_radius(radius: f64) -> Result<()> {
+    if radius.is_nan() || radius < 0.0 {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Search radius must be non-negative, got {}",
+            radius
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_manifold_core::{RandomSource, SeededRng, TangentVector};
+    use poly_manifold_spaces::{Euclidean, PoincareBall, Sphere};
+    use std::cell::Cell;
+
+    struct CountingSphere {
+        sphere: Sphere,
+        exact: Cell<usize>,
+    }
+
+    impl Manifold for CountingSphere {
+        type Scalar = f64;
+
+        fn dim(&self) -> usize {
+            self.sphere.dim()
+        }
+
+        fn check_point(&self, point: &[f64]) -> Result<()> {
+            self.sphere.check_point(point)
+        }
+
+        fn check_tangent_vector(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<()> {
+            self.sphere.check_tangent_vector(point, tangent)
+        }
+
+        fn project_to_manifold(&self, point: &[f64]) -> Result<Vec<f64>> {
+            self.sphere.project_to_manifold(point)
+        }
+
+        fn project_to_tangent_space(
+            &self,
+            point: &[f64],
+            vector: &TangentVector<f64>,
+        ) -> Result<TangentVector<f64>> {
+            self.sphere.project_to_tangent_space(point, vector)
+        }
+
+        fn exp(&self, point: &[f64], tangent: &TangentVector<f64>) -> Result<Vec<f64>> {
+            self.sphere.exp(point, tangent)
+        }
+
+        fn log(&self, point: &[f64], other: &[f64]) -> Result<TangentVector<f64>> {
+            self.sphere.log(point, other)
+        }
+
+        fn inner_product(
+            &self,
+            point: &[f64],
+            v1: &TangentVector<f64>,
+            v2: &TangentVector<f64>,
+        ) -> Result<f64> {
+            self.sphere.inner_product(point, v1, v2)
+        }
+
+        fn distance(&self, point1: &[f64], point2: &[f64]) -> Result<f64> {
+            self.exact.set(self.exact.get() + 1);
+            self.sphere.distance(point1, point2)
+        }
+    }
+
+    impl DistanceSurrogate for CountingSphere {
+        fn surrogate_distance(&self, a: &[f64], b: &[f64]) -> Result<f64> {
+            self.sphere.surrogate_distance(a, b)
+        }
+
+        fn distance_lower_bound(&self, surrogate: f64) -> f64 {
+            self.sphere.distance_lower_bound(surrogate)
+        }
+
+        fn distance_upper_bound(&self, surrogate: f64) -> f64 {
+            self.sphere.distance_upper_bound(surrogate)
+        }
+    }
+
+    fn brute_force<M: Manifold<Scalar = f64>>(
+        manifold: &M,
+        points: &[Vec<f64>],
+        query: &[f64],
+    ) -> Vec<Neighbor> {
+        let mut all: Vec<Neighbor> = points
+            .iter()
+            .enumerate()
+            .map(|(index, p)| Neighbor {
+                index,
+                distance: manifold.distance(query, p).unwrap(),
+            })
+            .collect();
+        all.sort();
+        all
+    }
+
+    fn sphere_points(count: usize, seed: u64) -> Vec<Vec<f64>> {
+        let sphere = Sphere::new(2);
+        let mut rng = SeededRng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| {
+                let raw = [
+                    rng.next_gaussian(),
+                    rng.next_gaussian(),
+                    rng.next_gaussian().abs() + 0.5,
+                ];
+                sphere.project_to_manifold(&raw).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_knn_matches_brute_force_in_euclidean_space() {
+        let euclidean = Euclidean::new(3);
+        let mut rng = SeededRng::seed_from_u64(1);
+        let points: Vec<Vec<f64>> = (0..300)
+            .map(|_| (0..3).map(|_| rng.next_gaussian()).collect())
+            .collect();
+        let tree = VantagePointTree::build(&euclidean, points.clone()).unwrap();
+        assert_eq!(tree.len(), 300);
+
+        for _ in 0..10 {
+            let query: Vec<f64> = (0..3).map(|_| rng.next_gaussian()).collect();
+            let expected = brute_force(&euclidean, &points, &query);
+            let found = tree.nearest(&euclidean, &query, 7).unwrap();
+            assert_eq!(found, expected[..7].to_vec());
+        }
+    }
+
+    #[test]
+    fn test_knn_on_sphere() {
+        let sphere = Sphere::new(2);
+        let points = sphere_points(200, 2);
+        let tree = VantagePointTree::build(&sphere, points.clone()).unwrap();
+
+        let query = sphere.project_to_manifold(&[0.3, -0.2, 1.0]).unwrap();
+        let expected = brute_force(&sphere, &points, &query);
+        let found = tree.nearest(&sphere, &query, 5).unwrap();
+        let indices: Vec<usize> = found.iter().map(|n| n.index).collect();
+        let expected: Vec<usize> = expected[..5].iter().map(|n| n.index).collect();
+        assert_eq!(indices, expected);
+    }
+
+    #[test]
+    fn test_radius_query_matches_brute_force() {
+        let sphere = Sphere::new(2);
+        let points = sphere_points(250, 3);
+        let tree = VantagePointTree::build(&sphere, points.clone()).unwrap();
+
+        let query = vec![0.0, 0.0, 1.0];
+        let expected: Vec<usize> = brute_force(&sphere, &points, &query)
+            .into_iter()
+            .filter(|n| n.distance <= 0.4)
+            .map(|n| n.index)
+            .collect();
+        let found: Vec<usize> = tree
+            .within_radius(&sphere, &query, 0.4)
+            .unwrap()
+            .iter()
+            .map(|n| n.index)
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(found, expected);
+        assert!(tree.within_radius(&sphere, &query, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let euclidean = Euclidean::new(1);
+        let empty = VantagePointTree::build(&euclidean, Vec::new()).unwrap();
+        assert!(empty.is_empty());
+        assert!(empty.nearest(&euclidean, &[0.0], 3).unwrap().is_empty());
+
+        let points = vec![vec![0.0], vec![1.0], vec![1.0], vec![5.0]];
+        let tree = VantagePointTree::build(&euclidean, points).unwrap();
+        assert_eq!(tree.nearest(&euclidean, &[0.9], 10).unwrap().len(), 4);
+        assert!(tree.nearest(&euclidean, &[0.9], 0).unwrap().is_empty());
+        let ties = tree.within_radius(&euclidean, &[1.0], 0.0).unwrap();
+        assert_eq!(ties.len(), 2);
+    }
+
+    #[test]
+    fn test_pruned_knn_matches_exact_with_fewer_geodesic_evaluations() {
+        let counting = CountingSphere {
+            sphere: Sphere::new(2),
+            exact: Cell::new(0),
+        };
+        let points = sphere_points(400, 4);
+        let tree = VantagePointTree::build(&counting.sphere, points).unwrap();
+
+        for raw in [[0.3, -0.2, 1.0], [-0.5, 0.1, 0.7], [0.0, 1.0, 0.2]] {
+            let query = counting.project_to_manifold(&raw).unwrap();
+            counting.exact.set(0);
+            let expected = tree.nearest(&counting, &query, 6).unwrap();
+            let exact_evaluations = counting.exact.get();
+
+            counting.exact.set(0);
+            let found = tree.nearest_pruned(&counting, &query, 6).unwrap();
+            assert_eq!(found, expected);
+            assert!(counting.exact.get() < exact_evaluations);
+        }
+    }
+
+    #[test]
+    fn test_pruned_radius_query_matches_exact() {
+        let ball = PoincareBall::new(2);
+        let mut rng = SeededRng::seed_from_u64(5);
+        let points: Vec<Vec<f64>> = (0..300)
+            .map(|_| vec![0.6 * rng.next_f64() - 0.3, 0.6 * rng.next_f64() - 0.3])
+            .collect();
+        let tree = VantagePointTree::build(&ball, points).unwrap();
+
+        let query = [0.05, -0.1];
+        let expected = tree.within_radius(&ball, &query, 0.3).unwrap();
+        let found = tree.within_radius_pruned(&ball, &query, 0.3).unwrap();
+        assert!(!expected.is_empty());
+        assert_eq!(found, expected);
+        assert!(tree.within_radius_pruned(&ball, &query, -1.0).is_err());
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_archived_tree_matches_owned_tree() {
+        let sphere = Sphere::new(2);
+        let points = sphere_points(150, 9);
+        let tree = VantagePointTree::build(&sphere, points).unwrap();
+        let bytes = tree.to_bytes().unwrap();
+        let archived = VantagePointTree::access(&bytes).unwrap();
+        assert_eq!(archived.len(), 150);
+
+        let query = sphere.project_to_manifold(&[0.1, 0.4, 1.0]).unwrap();
+        assert_eq!(
+            archived.nearest(&sphere, &query, 6).unwrap(),
+            tree.nearest(&sphere, &query, 6).unwrap()
+        );
+        assert_eq!(
+            archived.nearest_pruned(&sphere, &query, 6).unwrap(),
+            tree.nearest(&sphere, &query, 6).unwrap()
+        );
+        assert_eq!(
+            archived.within_radius(&sphere, &query, 0.5).unwrap(),
+            tree.within_radius(&sphere, &query, 0.5).unwrap()
+        );
+        assert_eq!(
+            archived.within_radius_pruned(&sphere, &query, 0.5).unwrap(),
+            tree.within_radius(&sphere, &query, 0.5).unwrap()
+        );
+        assert!(VantagePointTree::access(&bytes[1..]).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+use crate::mean::FrechetMean;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Context, LieGroup, ManifoldError, RandomSource, Result, SeededRng};
+
+#[derive(Debug, Clone)]
+pub struct ParticleFilter {
+    pub particles: Vec<Vec<f64>>,
+    pub weights: Vec<f64>,
+    pub resample_threshold: f64,
+    rng: SeededRng,
+}
+
+impl ParticleFilter {
+    pub fn new(particles: Vec<Vec<f64>>, seed: u64) -> Result<Self> {
+        if particles.is_empty() {
+            return Err(ManifoldError::InvalidParameter(
+                "Particle filter requires at least one particle".to_string(),
+            ));
+        }
+        let weights = vec![1.0 / particles.len() as f64; particles.len()];
+        Ok(Self {
+            particles,
+            weights,
+            resample_threshold: 0.5,
+            rng: SeededRng::seed_from_u64(seed),
+        })
+    }
+
+    pub fn from_prior<G>(
+        group: &G,
+        mean: &[f64],
+        noise_std: &[f64],
+        count: usize,
+        seed: u64,
+    ) -> Result<Self>
+    where
+        G: LieGroup<Scalar = f64>,
+    {
+        validate_entry_point(group, mean)?;
+        let mut filter = Self::new(vec![mean.to_vec(); count], seed)?;
+        let zero = vec![0.0; group.dim()];
+        filter.propagate(group, &zero, noise_std)?;
+        Ok(filter)
+    }
+
+    pub fn with_resample_threshold(mut self, resample_threshold: f64) -> Self {
+        self.resample_threshold = resample_threshold;
+        self
+    }
+
+    pub fn with_context(mut self, context: &Context) -> Self {
+        self.rng = context.rng();
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    pub fn predict<G>(
+        &mut self,
+        group: &G,
+        velocity: &[f64],
+        dt: f64,
+        noise_std: &[f64],
+    ) -> Result<()>
+    where
+        G: LieGroup<Scalar = f64>,
+    {
+        if dt.is_nan() || dt < 0.0 {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Time step must be non-negative, got {}",
+                dt
+            )));
+        }
+        let displacement: Vec<f64> = velocity.iter().map(|v| v * dt).collect();
+        let noise: Vec<f64> = noise_std.iter().map(|s| s * dt.sqrt()).collect();
+        self.propagate(group, &displacement, &noise)
+    }
+
+    pub fn update<F>(&mut self, log_likelihood: F) -> Result<()>
+    where
+        F: Fn(&[f64]) -> f64,
+    {
+        if !(0.0..=1.0).contains(&self.resample_threshold) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Resample threshold must lie in [0, 1], got {}",
+                self.resample_threshold
+            )));
+        }
+        let log_weights: Vec<f64> = self
+            .particles
+            .iter()
+            .zip(&self.weights)
+            .map(|(particle, weight)| weight.ln() + log_likelihood(particle))
+            .collect();
+        let max = log_weights
+            .iter()
+            .copied()
+            .filter(|w| !w.is_nan())
+            .fold(f64::NEG_INFINITY, f64::max);
+        if !max.is_finite() {
+            return Err(ManifoldError::NumericalError(
+                "All particles have zero likelihood".to_string(),
+            ));
+        }
+        let unnormalized: Vec<f64> = log_weights
+            .iter()
+            .map(|w| if w.is_nan() { 0.0 } else { (w - max).exp() })
+            .collect();
+        let total: f64 = unnormalized.iter().sum();
+        self.weights = unnormalized.into_iter().map(|w| w / total).collect();
+
+        if self.effective_sample_size() < self.resample_threshold * self.len() as f64 {
+            self.resample();
+        }
+        Ok(())
+    }
+
+    pub fn effective_sample_size(&self) -> f64 {
+        1.0 / self.weights.iter().map(|w| w * w).sum::<f64>()
+    }
+
+    pub fn resample(&mut self) {
+        let n = self.len();
+        let offset = self.rng.next_f64() / n as f64;
+        let mut resampled = Vec::with_capacity(n);
+        let mut cumulative = self.weights[0];
+        let mut index = 0;
+        for k in 0..n {
+            let target = offset + k as f64 / n as f64;
+            while cumulative < target && index + 1 < n {
+                index += 1;
+                cumulative += self.weights[index];
+            }
+            resampled.push(self.particles[index].clone());
+        }
+        self.particles = resampled;
+        self.weights = vec![1.0 / n as f64; n];
+    }
+
+    pub fn estimate<G>(&self, group: &G, estimator: &FrechetMean) -> Result<Vec<f64>>
+    where
+        G: LieGroup<Scalar = f64>,
+    {
+        estimator.blend(group, &self.particles, &self.weights)
+    }
+
+    fn propagate<G>(&mut self, group: &G, displacement: &[f64], noise_std: &[f64]) -> Result<()>
+    where
+        G: LieGroup<Scalar = f64>,
+    {
+        let d = group.dim();
+        for len in [displacement.len(), noise_std.len()] {
+            if len != d {
+                return Err(ManifoldError::DimensionMismatch {
+                    expected: d,
+                    got: len,
+                });
+            }
+        }
+        for particle in &mut self.particles {
+            let algebra: Vec<f64> = displacement
+                .iter()
+                .zip(noise_std)
+                .map(|(v, s)| v + s * self.rng.next_gaussian())
+                .collect();
+            *particle = group.project_to_manifold(&group.perturb(particle, &algebra)?)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_manifold_core::Manifold;
+    use poly_manifold_spaces::{SE3, SO3};
+
+    #[test]
+    fn test_attitude_tracking_on_so3() {
+        let so3 = SO3::new();
+        let omega = [0.1, 0.2, -0.1];
+        let dt = 0.1;
+        let mut rng = SeededRng::seed_from_u64(9);
+        let mut truth = SO3::from_axis_angle(&[1.0, 0.0, 0.0], 0.3);
+
+        let mut filter =
+            ParticleFilter::from_prior(&so3, &SO3::identity(), &[0.4; 3], 400, 1).unwrap();
+        for _ in 0..30 {
+            truth = so3.perturb(&truth, &omega.map(|w| w * dt)).unwrap();
+            let noise: Vec<f64> = (0..3).map(|_| 0.05 * rng.next_gaussian()).collect();
+            let measurement = so3.perturb(&truth, &noise).unwrap();
+
+            filter.predict(&so3, &omega, dt, &[0.05; 3]).unwrap();
+            filter
+                .update(|x| {
+                    let d = so3.distance(x, &measurement).unwrap_or(f64::INFINITY);
+                    -0.5 * d * d / (0.05 * 0.05)
+                })
+                .unwrap();
+        }
+
+        let estimate = filter.estimate(&so3, &FrechetMean::default()).unwrap();
+        assert!(so3.check_point(&estimate).is_ok());
+        assert!(so3.distance(&estimate, &truth).unwrap() < 0.06);
+    }
+
+    #[test]
+    fn test_pose_tracking_on_se3() {
+        let se3 = SE3::new();
+        let twist = [0.0, 0.0, 0.3, 1.0, 0.0, 0.0];
+        let dt = 0.1;
+        let mut rng = SeededRng::seed_from_u64(4);
+        let mut truth = se3.group_identity();
+
+        let mut filter =
+            ParticleFilter::from_prior(&se3, &truth, &[0.1, 0.1, 0.1, 0.3, 0.3, 0.3], 400, 2)
+                .unwrap();
+        for _ in 0..20 {
+            truth = se3.perturb(&truth, &twist.map(|v| v * dt)).unwrap();
+            let position: Vec<f64> = SE3::translation(&truth)
+                .unwrap()
+                .iter()
+                .map(|p| p + 0.05 * rng.next_gaussian())
+                .collect();
+            let heading = so3_noisy(&truth, &mut rng);
+
+            filter.predict(&se3, &twist, dt, &[0.03; 6]).unwrap();
+            filter
+                .update(|x| {
+                    let dp: f64 = SE3::translation(x)
+                        .unwrap()
+                        .iter()
+                        .zip(&position)
+                        .map(|(a, b)| (a - b).powi(2))
+                        .sum();
+                    let dr = SO3::new()
+                        .distance(SE3::rotation(x).unwrap(), &heading)
+                        .unwrap_or(f64::INFINITY);
+                    -0.5 * (dp + dr * dr) / (0.05 * 0.05)
+                })
+                .unwrap();
+        }
+
+        let estimate = filter.estimate(&se3, &FrechetMean::default()).unwrap();
+        assert!(se3.distance(&estimate, &truth).unwrap() < 0.1);
+    }
+
+    fn so3_noisy(pose: &[f64], rng: &mut SeededRng) -> Vec<f64> {
+        let noise: Vec<f64> = (0..3).map(|_| 0.05 * rng.next_gaussian()).collect();
+        SO3::new()
+            .perturb(SE3::rotation(pose).unwrap(), &noise)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_degenerate_weights_trigger_resampling() {
+        let so3 = SO3::new();
+        let particles: Vec<Vec<f64>> = (0..10)
+            .map(|k| SO3::from_axis_angle(&[0.0, 0.0, 1.0], 0.1 * k as f64))
+            .collect();
+        let mut filter = ParticleFilter::new(particles.clone(), 0).unwrap();
+        assert!((filter.effective_sample_size() - 10.0).abs() < 1e-12);
+
+        let favourite = particles[3].clone();
+        filter
+            .update(|x| {
+                if x == favourite.as_slice() {
+                    0.0
+                } else {
+                    -50.0
+                }
+            })
+            .unwrap();
+        assert!((filter.effective_sample_size() - 10.0).abs() < 1e-12);
+        assert!(filter.particles.iter().all(|p| p == &favourite));
+        assert!(so3.check_point(&filter.particles[0]).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let so3 = SO3::new();
+        assert!(ParticleFilter::new(Vec::new(), 0).is_err());
+        let mut filter = ParticleFilter::new(vec![SO3::identity(); 4], 0).unwrap();
+        assert!(filter.update(|_| f64::NEG_INFINITY).is_err());
+        assert!(filter.predict(&so3, &[0.0; 2], 0.1, &[0.0; 3]).is_err());
+        assert!(filter.predict(&so3, &[0.0; 3], -1.0, &[0.0; 3]).is_err());
+        for threshold in [-0.1, 1.5, f64::NAN] {
+            let mut filter = ParticleFilter::new(vec![SO3::identity(); 4], 0)
+                .unwrap()
+                .with_resample_threshold(threshold);
+            assert!(matches!(
+                filter.update(|_| 0.0),
+                Err(ManifoldError::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_context_seeds_the_filter() {
+        let so3 = SO3::new();
+        let mut seeded = ParticleFilter::new(vec![SO3::identity(); 20], 5).unwrap();
+        let mut from_context = ParticleFilter::new(vec![SO3::identity(); 20], 0)
+            .unwrap()
+            .with_context(&Context::new().with_seed(5));
+        seeded.predict(&so3, &[0.1; 3], 0.1, &[0.2; 3]).unwrap();
+        from_context
+            .predict(&so3, &[0.1; 3], 0.1, &[0.2; 3])
+            .unwrap();
+        assert_eq!(seeded.particles, from_context.particles);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::mean::FrechetMean;
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::{Manifold, ManifoldError, Result, TangentVector};
+
+#[derive(Clone)]
+pub struct PrincipalGeodesicAnalysis {
+    pub mean: Vec<f64>,
+    pub directions: Vec<TangentVector<f64>>,
+    pub variances: Vec<f64>,
+    pub total_variance: f64,
+}
+
+impl PrincipalGeodesicAnalysis {
+    pub fn fit<M>(
+        manifold: &M,
+        points: &[Vec<f64>],
+        n_components: usize,
+        estimator: &FrechetMean,
+    ) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let mean = estimator.compute(manifold, points)?;
+        Self::fit_with_mean(manifold, points, mean, n_components)
+    }
+
+    pub fn fit_with_mean<M>(
+        manifold: &M,
+        points: &[Vec<f64>],
+        mean: Vec<f64>,
+        n_components: usize,
+    ) -> Result<Self>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        if points.is_empty() {
+            return Err(ManifoldError::InvalidParameter(
+                "PGA requires at least one point".to_string(),
+            ));
+        }
+        if n_components == 0 || n_components > manifold.dim() {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Number of components must be between 1 and {}, got {}",
+                manifold.dim(),
+                n_components
+            )));
+        }
+
+        let basis = manifold.tangent_basis(&mean)?;
+        let d = basis.len();
+        let mut coordinates = DMatrix::zeros(d, points.len());
+        for (k, point) in points.iter().enumerate() {
+            let tangent = manifold.log(&mean, point)?;
+            for (j, b) in basis.iter().enumerate() {
+                coordinates[(j, k)] = manifold.inner_product(&mean, &tangent, b)?;
+            }
+        }
+
+        let covariance = &coordinates * coordinates.transpose() / points.len() as f64;
+        let eigen = covariance.symmetric_eigen();
+        let mut order: Vec<usize> = (0..d).collect();
+        order.sort_by(|a, b| eigen.eigenvalues[*b].total_cmp(&eigen.eigenvalues[*a]));
+
+        let mut directions = Vec::with_capacity(n_components);
+        let mut variances = Vec::with_capacity(n_components);
+        for &index in order.iter().take(n_components) {
+            let weights = eigen.eigenvectors.column(index);
+            let mut direction = TangentVector::zero(mean.len());
+            for (b, w) in basis.iter().zip(weights.iter()) {
+                direction += b * *w;
+            }
+            directions.push(direction);
+            variances.push(eigen.eigenvalues[index].max(0.0));
+        }
+
+        Ok(Self {
+            mean,
+            directions,
+            variances,
+            total_variance: eigen.eigenvalues.iter().map(|v| v.max(0.0)).sum(),
+        })
+    }
+
+    pub fn n_components(&self) -> usize {
+        self.directions.len()
+    }
+
+    pub fn explained_variance_ratio(&self) -> Vec<f64> {
+        if self.total_variance <= 0.0 {
+            return vec![0.0; self.variances.len()];
+        }
+        self.variances
+            .iter()
+            .map(|v| v / self.total_variance)
+            .collect()
+    }
+
+    pub fn project<M>(&self, manifold: &M, point: &[f64]) -> Result<DVector<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        let tangent = manifold.log(&self.mean, point)?;
+        self.directions
+            .iter()
+            .map(|u| manifold.inner_product(&self.mean, &tangent, u))
+            .collect::<Result<Vec<_>>>()
+            .map(DVector::from_vec)
+    }
+
+    pub fn reconstruct<M>(&self, manifold: &M, scores: &DVector<f64>) -> Result<Vec<f64>>
+    where
+        M: Manifold<Scalar = f64> + ?Sized,
+    {
+        if scores.len() > self.directions.len() {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: self.directions.len(),
+                got: scores.len(),
+            });
+        }
+        let mut tangent = TangentVector::zero(self.mean.len());
+        for (u, s) in self.directions.iter().zip(scores.iter()) {
+            tangent += u * *s;
+        }
+        manifold.exp(&self.mean, &tangent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_spaces::{Euclidean, Sphere};
+
+    #[test]
+    fn test_pga_recovers_dominant_direction_in_euclidean_space() {
+        let euclidean = Euclidean::new(2);
+        let points: Vec<Vec<f64>> = (-3..=3)
+            .map(|i| {
+                let t = i as f64;
+                vec![2.0 * t, 0.1 * (t * 1.7).sin()]
+            })
+            .collect();
+
+        let pga = PrincipalGeodesicAnalysis::fit(&euclidean, &points, 1, &FrechetMean::default())
+            .unwrap();
+        let direction = &pga.directions[0].components;
+        assert_relative_eq!(direction[0].abs(), 1.0, epsilon = 1e-3);
+        assert!(pga.explained_variance_ratio()[0] > 0.99);
+    }
+
+    #[test]
+    fn test_pga_on_great_circle_reconstructs_samples() {
+        let sphere = Sphere::new(2);
+        let points: Vec<Vec<f64>> = [-0.6f64, -0.3, 0.0, 0.3, 0.6]
+            .iter()
+            .map(|a| vec![a.sin(), 0.0, a.cos()])
+            .collect();
+
+        let pga =
+            PrincipalGeodesicAnalysis::fit(&sphere, &points, 1, &FrechetMean::default()).unwrap();
+        assert_relative_eq!(pga.mean[2], 1.0, epsilon = 1e-8);
+        assert_relative_eq!(pga.explained_variance_ratio()[0], 1.0, epsilon = 1e-8);
+
+        for point in &points {
+            let scores = pga.project(&sphere, point).unwrap();
+            let reconstructed = pga.reconstruct(&sphere, &scores).unwrap();
+            for (r, p) in reconstructed.iter().zip(point) {
+                assert_relative_eq!(r, p, epsilon = 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pga_variances_are_sorted() {
+        let sphere = Sphere::new(2);
+        let points = vec![
+            sphere.project_to_manifold(&[0.3, 0.1, 1.0]).unwrap(),
+            sphere.project_to_manifold(&[-0.3, -0.1, 1.0]).unwrap(),
+            sphere.project_to_manifold(&[0.2, -0.05, 1.0]).unwrap(),
+            sphere.project_to_manifold(&[-0.25, 0.08, 1.0]).unwrap(),
+        ];
+        let pga =
+            PrincipalGeodesicAnalysis::fit(&sphere, &points, 2, &FrechetMean::default()).unwrap();
+        assert_eq!(pga.n_components(), 2);
+        assert!(pga.variances[0] >= pga.variances[1]);
+        let inner = sphere
+            .inner_product(&pga.mean, &pga.directions[0], &pga.directions[1])
+            .unwrap();
+        assert_relative_eq!(inner, 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_pga_rejects_invalid_component_count() {
+        let sphere = Sphere::new(2);
+        let points = vec![vec![0.0, 0.0, 1.0]];
+        let estimator = FrechetMean::default();
+        assert!(PrincipalGeodesicAnalysis::fit(&sphere, &points, 0, &estimator).is_err());
+        assert!(PrincipalGeodesicAnalysis::fit(&sphere, &points, 3, &estimator).is_err());
+    }
+}
@@ -0,0 +1,343 @@
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_autodiff::RiemannianOptimizer;
+use poly_manifold_core::{Manifold, ManifoldError, Result};
+use poly_manifold_spaces::Stiefel;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcrustesAlignment {
+    pub transform: DMatrix<f64>,
+    pub aligned: DMatrix<f64>,
+    pub residual: f64,
+}
+
+impl ProcrustesAlignment {
+    fn new(source: &DMatrix<f64>, target: &DMatrix<f64>, transform: DMatrix<f64>) -> Self {
+        let aligned = source * &transform;
+        let residual = (&aligned - target).norm_squared();
+        Self {
+            transform,
+            aligned,
+            residual,
+        }
+    }
+}
+
+pub fn orthogonal_procrustes(
+    source: &DMatrix<f64>,
+    target: &DMatrix<f64>,
+    allow_reflection: bool,
+) -> Result<ProcrustesAlignment> {
+    check_shapes(source, target)?;
+    if source.ncols() != target.ncols() {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: source.ncols(),
+            got: target.ncols(),
+        });
+    }
+    let transform =
+        rotation_from_cross_covariance(&(source.transpose() * target), allow_reflection)?;
+    Ok(ProcrustesAlignment::new(source, target, transform))
+}
+
+pub fn stiefel_procrustes<O>(
+    source: &DMatrix<f64>,
+    target: &DMatrix<f64>,
+    optimizer: &O,
+) -> Result<ProcrustesAlignment>
+where
+    O: RiemannianOptimizer,
+{
+    check_shapes(source, target)?;
+    if target.ncols() > source.ncols() {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Target has {} columns but source only has {}",
+            target.ncols(),
+            source.ncols()
+        )));
+    }
+    let stiefel = Stiefel::new(source.ncols(), target.ncols())?;
+    let start =
+        stiefel.project_to_manifold(&stiefel.from_matrix(&(source.transpose() * target)))?;
+    let cost = |point: &[f64]| {
+        let Ok(point) = stiefel.project_to_manifold(point) else {
+            return f64::INFINITY;
+        };
+        (source * stiefel.to_matrix(&point) - target).norm_squared()
+    };
+
+    let result = optimizer.minimize(&stiefel, &start, cost)?;
+    let transform = stiefel.to_matrix(&stiefel.project_to_manifold(&result.point)?);
+    Ok(ProcrustesAlignment::new(source, target, transform))
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeneralizedAlignment {
+    pub consensus: DMatrix<f64>,
+    pub transforms: Vec<DMatrix<f64>>,
+    pub centroids: Vec<DVector<f64>>,
+    pub aligned: Vec<DMatrix<f64>>,
+    pub residual: f64,
+    pub iterations: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeneralizedProcrustes {
+    pub max_iterations: usize,
+    pub tolerance: f64,
+    pub allow_reflection: bool,
+}
+
+impl Default for GeneralizedProcrustes {
+    fn default() -> Self {
+        Self::new(100, 1e-10)
+    }
+}
+
+impl GeneralizedProcrustes {
+    pub fn new(max_iterations: usize, tolerance: f64) -> Self {
+        Self {
+            max_iterations,
+            tolerance,
+            allow_reflection: false,
+        }
+    }
+
+    pub fn with_reflection(mut self, allow_reflection: bool) -> Self {
+        self.allow_reflection = allow_reflection;
+        self
+    }
+
+    pub fn align(&self, configurations: &[DMatrix<f64>]) -> Result<GeneralizedAlignment> {
+        let Some(first) = configurations.first() else {
+            return Err(ManifoldError::InvalidParameter(
+                "Generalized Procrustes requires at least one configuration".to_string(),
+            ));
+        };
+        for configuration in configurations {
+            check_shapes(first, configuration)?;
+            if configuration.ncols() != first.ncols() {
+                return Err(ManifoldError::DimensionMismatch {
+                    expected: first.ncols(),
+                    got: configuration.ncols(),
+                });
+            }
+        }
+
+        let centroids: Vec<DVector<f64>> = configurations
+            .iter()
+            .map(|c| c.row_mean().transpose())
+            .collect();
+        let centered: Vec<DMatrix<f64>> = configurations
+            .iter()
+            .zip(&centroids)
+            .map(|(c, centroid)| {
+                let mut c = c.clone();
+                for mut row in c.row_iter_mut() {
+                    row -= centroid.transpose();
+                }
+                c
+            })
+            .collect();
+
+        let mut consensus = centered[0].clone();
+        let mut previous = f64::INFINITY;
+        for iteration in 1..=self.max_iterations {
+            let mut transforms = Vec::with_capacity(centered.len());
+            let mut aligned = Vec::with_capacity(centered.len());
+            for configuration in &centered {
+                let alignment =
+                    orthogonal_procrustes(configuration, &consensus, self.allow_reflection)?;
+                transforms.push(alignment.transform);
+                aligned.push(alignment.aligned);
+            }
+
+            consensus = aligned
+                .iter()
+                .fold(DMatrix::zeros(first.nrows(), first.ncols()), |acc, a| {
+                    acc + a
+                })
+                / aligned.len() as f64;
+            let residual: f64 = aligned
+                .iter()
+                .map(|a| (a - &consensus).norm_squared())
+                .sum();
+
+            if previous - residual <= self.tolerance * (1.0 + residual) {
+                return Ok(GeneralizedAlignment {
+                    consensus,
+                    transforms,
+                    centroids,
+                    aligned,
+                    residual,
+                    iterations: iteration,
+                });
+            }
+            previous = residual;
+        }
+
+        Err(ManifoldError::ConvergenceError {
+            iterations: self.max_iterations,
+        })
+    }
+}
+
+fn rotation_from_cross_covariance(
+    cross: &DMatrix<f64>,
+    allow_reflection: bool,
+) -> Result<DMatrix<f64>> {
+    let svd = cross.clone().svd(true, true);
+    let (Some(u), Some(v_t)) = (svd.u, svd.v_t) else {
+        return Err(ManifoldError::LinearAlgebraError(
+            "SVD failed while solving the Procrustes problem".to_string(),
+        ));
+    };
+    let mut transform = &u * &v_t;
+    if !allow_reflection && transform.determinant() < 0.0 {
+        let weakest = svd
+            .singular_values
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        let mut correction = DMatrix::identity(cross.nrows(), cross.ncols());
+        correction[(weakest, weakest)] = -1.0;
+        transform = u * correction * v_t;
+    }
+    Ok(transform)
+}
+
+fn check_shapes(source: &DMatrix<f64>, target: &DMatrix<f64>) -> Result<()> {
+    if source.nrows() != target.nrows() {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: source.nrows(),
+            got: target.nrows(),
+        });
+    }
+    if source.nrows() == 0 || source.ncols() == 0 {
+        return Err(ManifoldError::InvalidParameter(
+            "Procrustes alignment requires non-empty configurations".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_autodiff::{GradientDescent, LineSearch};
+    use poly_manifold_spaces::SO3;
+
+    fn configuration() -> DMatrix<f64> {
+        DMatrix::from_row_slice(
+            5,
+            3,
+            &[
+                1.0, 0.0, 0.2, //
+                0.0, 2.0, -0.5, //
+                -1.0, 0.5, 1.0, //
+                0.3, -1.2, 0.4, //
+                0.7, 0.9, -1.1,
+            ],
+        )
+    }
+
+    #[test]
+    fn test_orthogonal_procrustes_recovers_rotation() {
+        let source = configuration();
+        let rotation = SO3::to_matrix(&SO3::from_axis_angle(&[1.0, 2.0, -1.0], 1.3)).unwrap();
+        let rotation = DMatrix::from_iterator(3, 3, rotation.iter().copied());
+        let target = &source * &rotation;
+
+        let alignment = orthogonal_procrustes(&source, &target, false).unwrap();
+        assert_relative_eq!(alignment.residual, 0.0, epsilon = 1e-18);
+        assert_relative_eq!(
+            (alignment.transform - rotation).norm(),
+            0.0,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_reflection_handling() {
+        let source = configuration();
+        let mut reflection = DMatrix::identity(3, 3);
+        reflection[(2, 2)] = -1.0;
+        let target = &source * &reflection;
+
+        let with_reflection = orthogonal_procrustes(&source, &target, true).unwrap();
+        assert_relative_eq!(with_reflection.residual, 0.0, epsilon = 1e-18);
+
+        let rotation_only = orthogonal_procrustes(&source, &target, false).unwrap();
+        assert_relative_eq!(rotation_only.transform.determinant(), 1.0, epsilon = 1e-10);
+        assert!(rotation_only.residual > 1e-3);
+    }
+
+    #[test]
+    fn test_stiefel_procrustes_recovers_projection() {
+        let source = DMatrix::from_fn(8, 4, |i, j| {
+            ((i * i + 3 * j * j + i * j) as f64).sin() * (1.0 + j as f64)
+        });
+        let stiefel = Stiefel::new(4, 2).unwrap();
+        let truth = stiefel.to_matrix(
+            &stiefel
+                .project_to_manifold(&[0.3, -1.0, 0.8, 0.2, -0.5, 0.7, 0.1, 0.4])
+                .unwrap(),
+        );
+        let target = &source * &truth;
+
+        let optimizer =
+            GradientDescent::new(1.0, 2000, 1e-14).with_line_search(LineSearch::armijo());
+        let alignment = stiefel_procrustes(&source, &target, &optimizer).unwrap();
+
+        assert!(stiefel
+            .check_point(&stiefel.from_matrix(&alignment.transform))
+            .is_ok());
+        assert!(alignment.residual < 1e-8);
+        assert_relative_eq!((alignment.transform - truth).norm(), 0.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_generalized_procrustes_aligns_rotated_copies() {
+        let base = configuration();
+        let configurations: Vec<DMatrix<f64>> = (0..4)
+            .map(|k| {
+                let rotation =
+                    SO3::to_matrix(&SO3::from_axis_angle(&[1.0, k as f64, 0.5], 0.4 * k as f64))
+                        .unwrap();
+                let rotation = DMatrix::from_iterator(3, 3, rotation.iter().copied());
+                let mut moved = &base * rotation;
+                for mut row in moved.row_iter_mut() {
+                    row[0] += k as f64;
+                    row[2] -= 2.0 * k as f64;
+                }
+                moved
+            })
+            .collect();
+
+        let alignment = GeneralizedProcrustes::default()
+            .align(&configurations)
+            .unwrap();
+        assert_relative_eq!(alignment.residual, 0.0, epsilon = 1e-16);
+        assert_eq!(alignment.transforms.len(), 4);
+        for (k, centroid) in alignment.centroids.iter().enumerate() {
+            let expected = configurations[k].row_mean().transpose();
+            assert_relative_eq!((centroid - expected).norm(), 0.0, epsilon = 1e-12);
+        }
+        for aligned in &alignment.aligned {
+            assert_relative_eq!((aligned - &alignment.consensus).norm(), 0.0, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_shape_mismatch() {
+        let source = configuration();
+        let target = DMatrix::zeros(4, 3);
+        assert!(orthogonal_procrustes(&source, &target, false).is_err());
+        assert!(GeneralizedProcrustes::default().align(&[]).is_err());
+    }
+}
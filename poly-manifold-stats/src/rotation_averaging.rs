@@ -0,0 +1,402 @@
+use nalgebra::{DMatrix, Matrix3};
+use poly_manifold_autodiff::RiemannianOptimizer;
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{Manifold, ManifoldError, Result};
+use poly_manifold_spaces::{PowerManifold, SO3};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RobustLoss {
+    #[default]
+    Squared,
+    Huber(f64),
+    Cauchy(f64),
+}
+
+impl RobustLoss {
+    pub fn evaluate(&self, residual: f64) -> f64 {
+        match *self {
+            RobustLoss::Squared => 0.5 * residual * residual,
+            RobustLoss::Huber(delta) => {
+                if residual.abs() <= delta {
+                    0.5 * residual * residual
+                } else {
+                    delta * (residual.abs() - 0.5 * delta)
+                }
+            }
+            RobustLoss::Cauchy(scale) => 0.5 * scale * scale * (residual / scale).powi(2).ln_1p(),
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        match *self {
+            RobustLoss::Squared => Ok(()),
+            RobustLoss::Huber(scale) | RobustLoss::Cauchy(scale) => {
+                if scale > 0.0 && scale.is_finite() {
+                    Ok(())
+                } else {
+                    Err(ManifoldError::InvalidParameter(format!(
+                        "Robust loss scale must be positive and finite, got {}",
+                        scale
+                    )))
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelativeRotation {
+    pub from: usize,
+    pub to: usize,
+    pub rotation: Vec<f64>,
+}
+
+impl RelativeRotation {
+    pub fn new(from: usize, to: usize, rotation: Vec<f64>) -> Self {
+        Self { from, to, rotation }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RotationAveraging {
+    pub loss: RobustLoss,
+}
+
+impl RotationAveraging {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_loss(mut self, loss: RobustLoss) -> Self {
+        self.loss = loss;
+        self
+    }
+
+    pub fn average<O>(&self, rotations: &[Vec<f64>], optimizer: &O) -> Result<Vec<f64>>
+    where
+        O: RiemannianOptimizer,
+    {
+        self.loss.validate()?;
+        let so3 = SO3::new();
+        let start = chordal_mean(rotations)?;
+        let cost = |point: &[f64]| {
+            let Ok(point) = so3.project_to_manifold(point) else {
+                return f64::INFINITY;
+            };
+            let mut total = 0.0;
+            for rotation in rotations {
+                match so3.distance(&point, rotation) {
+                    Ok(residual) => total += self.loss.evaluate(residual),
+                    Err(_) => return f64::INFINITY,
+                }
+            }
+            total
+        };
+        let result = optimizer.minimize(&so3, &start, cost)?;
+        so3.project_to_manifold(&result.point)
+    }
+
+    pub fn average_relative<O>(
+        &self,
+        num_rotations: usize,
+        measurements: &[RelativeRotation],
+        optimizer: &O,
+    ) -> Result<Vec<Vec<f64>>>
+    where
+        O: RiemannianOptimizer,
+    {
+        self.loss.validate()?;
+        let initial = chordal_initialization(num_rotations, measurements)?;
+        let power = PowerManifold::new(SO3::new(), num_rotations);
+        let start = power.from_components(&initial)?;
+        let cost = |flat: &[f64]| {
+            let Ok(flat) = power.project_to_manifold(flat) else {
+                return f64::INFINITY;
+            };
+            let rotations: Vec<Vec<f64>> = power.components(&flat).map(<[f64]>::to_vec).collect();
+            match relative_residuals(&rotations, measurements) {
+                Ok(residuals) => residuals.iter().map(|r| self.loss.evaluate(*r)).sum(),
+                Err(_) => f64::INFINITY,
+            }
+        };
+
+        let result = optimizer.minimize(&power, &start, cost)?;
+        let flat = power.project_to_manifold(&result.point)?;
+        let rotations = power
+            .components(&flat)
+            .map(SO3::to_matrix)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(fix_gauge(&rotations))
+    }
+}
+
+pub fn chordal_mean(rotations: &[Vec<f64>]) -> Result<Vec<f64>> {
+    if rotations.is_empty() {
+        return Err(ManifoldError::InvalidParameter(
+            "Rotation averaging requires at least one rotation".to_string(),
+        ));
+    }
+    let so3 = SO3::new();
+    let mut sum = Matrix3::zeros();
+    for rotation in rotations {
+        validate_entry_point(&so3, rotation)?;
+        sum += SO3::to_matrix(rotation)?;
+    }
+    so3.project_to_manifold(&SO3::from_matrix(&sum))
+}
+
+pub fn chordal_initialization(
+    num_rotations: usize,
+    measurements: &[RelativeRotation],
+) -> Result<Vec<Vec<f64>>> {
+    check_measurements(num_rotations, measurements)?;
+    let n = 3 * num_rotations;
+    let mut blocks = DMatrix::zeros(n, n);
+    for k in 0..num_rotations {
+        blocks
+            .fixed_view_mut::<3, 3>(3 * k, 3 * k)
+            .copy_from(&Matrix3::identity());
+    }
+    for measurement in measurements {
+        let relative = SO3::to_matrix(&measurement.rotation)?;
+        let (i, j) = (measurement.from, measurement.to);
+        let mut upper = blocks.fixed_view_mut::<3, 3>(3 * j, 3 * i);
+        upper += relative;
+        let mut lower = blocks.fixed_view_mut::<3, 3>(3 * i, 3 * j);
+        lower += relative.transpose();
+    }
+
+    let eigen = blocks.symmetric_eigen();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|a, b| eigen.eigenvalues[*b].total_cmp(&eigen.eigenvalues[*a]));
+    let mut frame = DMatrix::zeros(n, 3);
+    for (column, index) in order.iter().take(3).enumerate() {
+        frame.set_column(column, &eigen.eigenvectors.column(*index));
+    }
+
+    let determinant: f64 = (0..num_rotations)
+        .map(|k| frame.fixed_view::<3, 3>(3 * k, 0).determinant())
+        .sum();
+    if determinant < 0.0 {
+        frame = -frame;
+    }
+
+    let so3 = SO3::new();
+    let mut rotations = Vec::with_capacity(num_rotations);
+    for k in 0..num_rotations {
+        let block: Matrix3<f64> = frame.fixed_view::<3, 3>(3 * k, 0).into_owned();
+        let projected = so3.project_to_manifold(&SO3::from_matrix(&block))?;
+        rotations.push(SO3::to_matrix(&projected)?);
+    }
+    Ok(fix_gauge(&rotations))
+}
+
+pub fn relative_residuals(
+    rotations: &[Vec<f64>],
+    measurements: &[RelativeRotation],
+) -> Result<Vec<f64>> {
+    check_measurements(rotations.len(), measurements)?;
+    let so3 = SO3::new();
+    measurements
+        .iter()
+        .map(|measurement| {
+            let predicted = SO3::to_matrix(&rotations[measurement.to])?
+                * SO3::to_matrix(&rotations[measurement.from])?.transpose();
+            so3.distance(&SO3::from_matrix(&predicted), &measurement.rotation)
+        })
+        .collect()
+}
+
+fn fix_gauge(rotations: &[Matrix3<f64>]) -> Vec<Vec<f64>> {
+    let reference = rotations[0].transpose();
+    rotations
+        .iter()
+        .map(|rotation| SO3::from_matrix(&(rotation * reference)))
+        .collect()
+}
+
+fn check_measurements(num_rotations: usize, measurements: &[RelativeRotation]) -> Result<()> {
+    if num_rotations == 0 {
+        return Err(ManifoldError::InvalidParameter(
+            "Rotation averaging requires at least one rotation".to_string(),
+        ));
+    }
+    let so3 = SO3::new();
+    let mut observed = vec![false; num_rotations];
+    observed[0] = num_rotations == 1;
+    for measurement in measurements {
+        let (i, j) = (measurement.from, measurement.to);
+        if i >= num_rotations || j >= num_rotations || i == j {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Invalid relative rotation between {} and {} for {} rotations",
+                i, j, num_rotations
+            )));
+        }
+        validate_entry_point(&so3, &measurement.rotation)?;
+        observed[i] = true;
+        observed[j] = true;
+    }
+    if let Some(index) = observed.iter().position(|seen| !seen) {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Rotation {} is not observed by any relative measurement",
+            index
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mean::FrechetMean;
+    use approx::assert_relative_eq;
+    use poly_manifold_autodiff::{GradientDescent, LineSearch};
+    use poly_manifold_core::random::random_tangent;
+    use poly_manifold_core::SeededRng;
+
+    fn optimizer() -> GradientDescent {
+        GradientDescent::new(1.0, 500, 1e-12).with_line_search(LineSearch::armijo())
+    }
+
+    fn perturb(so3: &SO3, rotation: &[f64], scale: f64, rng: &mut SeededRng) -> Vec<f64> {
+        let direction = random_tangent(so3, rotation, rng).unwrap();
+        so3.exp(rotation, &(direction * scale)).unwrap()
+    }
+
+    #[test]
+    fn test_single_average_matches_karcher_mean() {
+        let so3 = SO3::new();
+        let center = SO3::from_axis_angle(&[1.0, -1.0, 0.5], 0.9);
+        let mut rng = SeededRng::seed_from_u64(7);
+        let samples: Vec<Vec<f64>> = (0..12)
+            .map(|_| perturb(&so3, &center, 0.2, &mut rng))
+            .collect();
+
+        let average = RotationAveraging::new()
+            .average(&samples, &optimizer())
+            .unwrap();
+        let karcher = FrechetMean::default().compute(&so3, &samples).unwrap();
+
+        assert!(so3.check_point(&average).is_ok());
+        assert!(so3.distance(&average, &karcher).unwrap() < 1e-4);
+        assert!(so3.distance(&average, &center).unwrap() < 0.15);
+    }
+
+    #[test]
+    fn test_robust_average_rejects_outliers() {
+        let so3 = SO3::new();
+        let center = SO3::from_axis_angle(&[0.0, 0.0, 1.0], 0.4);
+        let mut rng = SeededRng::seed_from_u64(11);
+        let mut samples: Vec<Vec<f64>> = (0..10)
+            .map(|_| perturb(&so3, &center, 0.02, &mut rng))
+            .collect();
+        samples.push(SO3::from_axis_angle(&[1.0, 0.0, 0.0], 2.5));
+        samples.push(SO3::from_axis_angle(&[0.0, 1.0, 0.0], 2.0));
+        samples.push(SO3::from_axis_angle(&[1.0, 1.0, 0.0], 2.2));
+
+        let squared = RotationAveraging::new()
+            .average(&samples, &optimizer())
+            .unwrap();
+        let robust = RotationAveraging::new()
+            .with_loss(RobustLoss::Cauchy(0.1))
+            .average(&samples, &optimizer())
+            .unwrap();
+
+        let squared_error = so3.distance(&squared, &center).unwrap();
+        let robust_error = so3.distance(&robust, &center).unwrap();
+        assert!(robust_error < 0.05);
+        assert!(robust_error < 0.5 * squared_error);
+    }
+
+    #[test]
+    fn test_chordal_initialization_is_exact_without_noise() {
+        let so3 = SO3::new();
+        let truth: Vec<Vec<f64>> = vec![
+            SO3::identity(),
+            SO3::from_axis_angle(&[1.0, 0.0, 0.0], 0.7),
+            SO3::from_axis_angle(&[0.0, 1.0, 1.0], 1.4),
+            SO3::from_axis_angle(&[1.0, -2.0, 0.5], 2.1),
+        ];
+        let measurements = relative_measurements(&truth, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+
+        let estimate = chordal_initialization(truth.len(), &measurements).unwrap();
+        for (e, t) in estimate.iter().zip(&truth) {
+            assert_relative_eq!(so3.distance(e, t).unwrap(), 0.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_multiple_rotation_averaging_with_noise() {
+        let so3 = SO3::new();
+        let mut rng = SeededRng::seed_from_u64(5);
+        let truth: Vec<Vec<f64>> = std::iter::once(SO3::identity())
+            .chain((1..5).map(|k| SO3::from_axis_angle(&[1.0, k as f64, -1.0], 0.5 * k as f64)))
+            .collect();
+        let edges: Vec<(usize, usize)> = (0..5)
+            .flat_map(|i| ((i + 1)..5).map(move |j| (i, j)))
+            .collect();
+        let measurements: Vec<RelativeRotation> = relative_measurements(&truth, &edges)
+            .into_iter()
+            .map(|m| {
+                let noisy = perturb(&so3, &m.rotation, 0.05, &mut rng);
+                RelativeRotation::new(m.from, m.to, noisy)
+            })
+            .collect();
+
+        let initial = chordal_initialization(truth.len(), &measurements).unwrap();
+        let refined = RotationAveraging::new()
+            .average_relative(truth.len(), &measurements, &optimizer())
+            .unwrap();
+
+        let total = |rotations: &[Vec<f64>]| -> f64 {
+            relative_residuals(rotations, &measurements)
+                .unwrap()
+                .iter()
+                .map(|r| r * r)
+                .sum()
+        };
+        assert!(total(&refined) <= total(&initial) + 1e-10);
+        assert_relative_eq!(
+            so3.distance(&refined[0], &SO3::identity()).unwrap(),
+            0.0,
+            epsilon = 1e-8
+        );
+        for (r, t) in refined.iter().zip(&truth) {
+            assert!(so3.check_point(r).is_ok());
+            assert!(so3.distance(r, t).unwrap() < 0.1);
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let identity = SO3::identity();
+        assert!(chordal_mean(&[]).is_err());
+        assert!(
+            chordal_initialization(3, &[RelativeRotation::new(0, 1, identity.clone())]).is_err()
+        );
+        assert!(
+            chordal_initialization(2, &[RelativeRotation::new(0, 2, identity.clone())]).is_err()
+        );
+        assert!(RotationAveraging::new()
+            .with_loss(RobustLoss::Huber(-1.0))
+            .average(&[identity], &optimizer())
+            .is_err());
+    }
+
+    fn relative_measurements(
+        truth: &[Vec<f64>],
+        edges: &[(usize, usize)],
+    ) -> Vec<RelativeRotation> {
+        edges
+            .iter()
+            .map(|&(i, j)| {
+                let relative = SO3::to_matrix(&truth[j]).unwrap()
+                    * SO3::to_matrix(&truth[i]).unwrap().transpose();
+                RelativeRotation::new(i, j, SO3::from_matrix(&relative))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,321 @@
+use crate::spd_random::{check_square, spectral_map};
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::{ManifoldError, Result};
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShrinkageTarget {
+    #[default]
+    ScaledIdentity,
+    Pooled(DMatrix<f64>),
+}
+
+impl ShrinkageTarget {
+    fn resolve(&self, sample_covariance: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+        let p = sample_covariance.nrows();
+        match self {
+            ShrinkageTarget::ScaledIdentity => {
+                let scale = sample_covariance.trace() / p as f64;
+                if scale.is_nan() || scale <= 0.0 {
+                    return Err(ManifoldError::NumericalError(
+                        "Sample covariance has zero trace".to_string(),
+                    ));
+                }
+                Ok(DMatrix::identity(p, p) * scale)
+            }
+            ShrinkageTarget::Pooled(pooled) => {
+                if check_square(pooled)? != p {
+                    return Err(ManifoldError::DimensionMismatch {
+                        expected: p,
+                        got: pooled.nrows(),
+                    });
+                }
+                if pooled.clone().cholesky().is_none() {
+                    return Err(ManifoldError::InvalidParameter(
+                        "Pooled target must be positive definite".to_string(),
+                    ));
+                }
+                Ok(pooled.clone())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShrinkageEstimate {
+    pub mean: DVector<f64>,
+    pub covariance: DMatrix<f64>,
+    pub intensity: f64,
+    pub scores: Vec<f64>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeodesicShrinkage {
+    pub target: ShrinkageTarget,
+    pub folds: usize,
+    pub intensities: Vec<f64>,
+}
+
+impl Default for GeodesicShrinkage {
+    fn default() -> Self {
+        Self::new(ShrinkageTarget::default())
+    }
+}
+
+impl GeodesicShrinkage {
+    pub fn new(target: ShrinkageTarget) -> Self {
+        Self {
+            target,
+            folds: 5,
+            intensities: (0..=20).map(|i| i as f64 / 20.0).collect(),
+        }
+    }
+
+    pub fn with_folds(mut self, folds: usize) -> Self {
+        self.folds = folds;
+        self
+    }
+
+    pub fn with_intensities(mut self, intensities: Vec<f64>) -> Self {
+        self.intensities = intensities;
+        self
+    }
+
+    pub fn fit(&self, samples: &[Vec<f64>]) -> Result<ShrinkageEstimate> {
+        if self.folds < 2 {
+            return Err(ManifoldError::InvalidParameter(
+                "Cross-validation requires at least two folds".to_string(),
+            ));
+        }
+        if self.intensities.is_empty() {
+            return Err(ManifoldError::InvalidParameter(
+                "At least one shrinkage intensity is required".to_string(),
+            ));
+        }
+        for intensity in &self.intensities {
+            check_intensity(*intensity)?;
+        }
+        if samples.len() < 2 * self.folds {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "{} folds require at least {} samples, got {}",
+                self.folds,
+                2 * self.folds,
+                samples.len()
+            )));
+        }
+
+        let mut scores = vec![0.0; self.intensities.len()];
+        for fold in 0..self.folds {
+            let (train, test): (Vec<_>, Vec<_>) = samples
+                .iter()
+                .enumerate()
+                .partition(|(i, _)| i % self.folds != fold);
+            let train: Vec<Vec<f64>> = train.into_iter().map(|(_, x)| x.clone()).collect();
+            let (mean, covariance) = sample_covariance(&train)?;
+            let target = self.target.resolve(&covariance)?;
+            for (score, intensity) in scores.iter_mut().zip(&self.intensities) {
+                let shrunk = geodesic_shrinkage(&covariance, &target, *intensity)?;
+                *score += test
+                    .iter()
+                    .map(|(_, x)| gaussian_log_likelihood(&mean, &shrunk, x))
+                    .sum::<f64>();
+            }
+        }
+        for score in &mut scores {
+            *score /= samples.len() as f64;
+        }
+
+        let mut best = 0;
+        for (i, score) in scores.iter().enumerate() {
+            if *score > scores[best] {
+                best = i;
+            }
+        }
+        let intensity = self.intensities[best];
+        let (mean, covariance) = sample_covariance(samples)?;
+        let target = self.target.resolve(&covariance)?;
+        Ok(ShrinkageEstimate {
+            mean,
+            covariance: geodesic_shrinkage(&covariance, &target, intensity)?,
+            intensity,
+            scores,
+        })
+    }
+}
+
+pub fn sample_covariance(samples: &[Vec<f64>]) -> Result<(DVector<f64>, DMatrix<f64>)> {
+    if samples.len() < 2 {
+        return Err(ManifoldError::InvalidParameter(
+            "Sample covariance requires at least two samples".to_string(),
+        ));
+    }
+    let p = samples[0].len();
+    let mut mean = DVector::zeros(p);
+    for sample in samples {
+        if sample.len() != p {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: p,
+                got: sample.len(),
+            });
+        }
+        mean += DVector::from_column_slice(sample);
+    }
+    mean /= samples.len() as f64;
+
+    let mut covariance = DMatrix::zeros(p, p);
+    for sample in samples {
+        let centered = DVector::from_column_slice(sample) - &mean;
+        covariance += &centered * centered.transpose();
+    }
+    covariance /= (samples.len() - 1) as f64;
+    Ok((mean, covariance))
+}
+
+pub fn geodesic_shrinkage(
+    sample_covariance: &DMatrix<f64>,
+    target: &DMatrix<f64>,
+    intensity: f64,
+) -> Result<DMatrix<f64>> {
+    check_intensity(intensity)?;
+    let p = check_square(sample_covariance)?;
+    if check_square(target)? != p {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: p,
+            got: target.nrows(),
+        });
+    }
+    let eigen = target.clone().symmetric_eigen();
+    if eigen.eigenvalues.iter().any(|l| *l <= 0.0) {
+        return Err(ManifoldError::InvalidParameter(
+            "Shrinkage target must be positive definite".to_string(),
+        ));
+    }
+    let sqrt_target = spectral_map(target, f64::sqrt);
+    let inv_sqrt_target = spectral_map(target, |l| 1.0 / l.sqrt());
+
+    let whitened = &inv_sqrt_target * sample_covariance * &inv_sqrt_target;
+    let whitened = (&whitened + whitened.transpose()) * 0.5;
+    let largest = whitened
+        .clone()
+        .symmetric_eigen()
+        .eigenvalues
+        .iter()
+        .fold(0.0_f64, |a, b| a.max(*b));
+    let floor = 1e-12 * largest.max(f64::MIN_POSITIVE);
+    let moved = spectral_map(&whitened, |l| l.max(floor).powf(1.0 - intensity));
+    let shrunk = &sqrt_target * moved * &sqrt_target;
+    Ok((&shrunk + shrunk.transpose()) * 0.5)
+}
+
+fn gaussian_log_likelihood(mean: &DVector<f64>, covariance: &DMatrix<f64>, x: &[f64]) -> f64 {
+    let Some(cholesky) = covariance.clone().cholesky() else {
+        return f64::NEG_INFINITY;
+    };
+    let centered = DVector::from_column_slice(x) - mean;
+    let whitened = cholesky.l().solve_lower_triangular(&centered);
+    let Some(whitened) = whitened else {
+        return f64::NEG_INFINITY;
+    };
+    let log_det: f64 = cholesky.l().diagonal().iter().map(|d| 2.0 * d.ln()).sum();
+    -0.5 * (mean.len() as f64 * (2.0 * PI).ln() + log_det + whitened.norm_squared())
+}
+
+fn check_intensity(intensity: f64) -> Result<()> {
+    if !(0.0..=1.0).contains(&intensity) {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Shrinkage intensity must lie in [0, 1], got {}",
+            intensity
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_core::{RandomSource, SeededRng};
+
+    fn gaussian_samples(covariance: &DMatrix<f64>, count: usize, seed: u64) -> Vec<Vec<f64>> {
+        let factor = covariance.clone().cholesky().unwrap().l();
+        let mut rng = SeededRng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| {
+                let z = DVector::from_fn(covariance.nrows(), |_, _| rng.next_gaussian());
+                (&factor * z).as_slice().to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_geodesic_endpoints_and_midpoint() {
+        let sample = DMatrix::from_diagonal(&DVector::from_vec(vec![4.0, 1.0]));
+        let target = DMatrix::from_diagonal(&DVector::from_vec(vec![1.0, 9.0]));
+
+        let start = geodesic_shrinkage(&sample, &target, 0.0).unwrap();
+        let end = geodesic_shrinkage(&sample, &target, 1.0).unwrap();
+        let middle = geodesic_shrinkage(&sample, &target, 0.5).unwrap();
+
+        assert_relative_eq!((start - &sample).norm(), 0.0, epsilon = 1e-10);
+        assert_relative_eq!((end - &target).norm(), 0.0, epsilon = 1e-10);
+        assert_relative_eq!(middle[(0, 0)], 2.0, epsilon = 1e-10);
+        assert_relative_eq!(middle[(1, 1)], 3.0, epsilon = 1e-10);
+        assert!(geodesic_shrinkage(&sample, &target, 1.5).is_err());
+    }
+
+    #[test]
+    fn test_high_dimensional_data_is_shrunk() {
+        let truth = DMatrix::identity(12, 12) * 2.0;
+        let samples = gaussian_samples(&truth, 10, 1);
+
+        let estimate = GeodesicShrinkage::default().fit(&samples).unwrap();
+        let (_, sample) = sample_covariance(&samples).unwrap();
+
+        assert!(estimate.intensity > 0.5);
+        assert!(estimate.covariance.clone().cholesky().is_some());
+        assert!((&estimate.covariance - &truth).norm() < (sample - &truth).norm());
+    }
+
+    #[test]
+    fn test_abundant_data_keeps_structure() {
+        let truth = DMatrix::from_row_slice(3, 3, &[4.0, 1.5, 0.0, 1.5, 1.0, 0.2, 0.0, 0.2, 0.3]);
+        let samples = gaussian_samples(&truth, 400, 2);
+
+        let estimate = GeodesicShrinkage::default().fit(&samples).unwrap();
+        assert!(estimate.intensity < 0.2);
+        assert_eq!(estimate.scores.len(), 21);
+    }
+
+    #[test]
+    fn test_pooled_target() {
+        let pooled = DMatrix::from_row_slice(2, 2, &[2.0, 0.5, 0.5, 1.0]);
+        let samples = gaussian_samples(&pooled, 6, 3);
+
+        let estimate = GeodesicShrinkage::new(ShrinkageTarget::Pooled(pooled.clone()))
+            .with_folds(3)
+            .with_intensities(vec![1.0])
+            .fit(&samples)
+            .unwrap();
+        assert_relative_eq!(estimate.intensity, 1.0);
+        assert_relative_eq!((estimate.covariance - pooled).norm(), 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_invalid_configuration() {
+        let samples = gaussian_samples(&DMatrix::identity(2, 2), 4, 4);
+        assert!(GeodesicShrinkage::default().fit(&samples).is_err());
+        assert!(GeodesicShrinkage::default()
+            .with_folds(1)
+            .fit(&samples)
+            .is_err());
+        assert!(
+            GeodesicShrinkage::new(ShrinkageTarget::Pooled(DMatrix::identity(3, 3)))
+                .with_folds(2)
+                .fit(&samples)
+                .is_err()
+        );
+    }
+}
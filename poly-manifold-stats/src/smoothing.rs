@@ -0,0 +1,322 @@
+use crate::mean::FrechetMean;
+use nalgebra::{DMatrix, DVector};
+use poly_manifold_core::validation::validate_entry_point;
+use poly_manifold_core::{LieGroup, Manifold, ManifoldError, Result, TangentVector};
+
+pub fn geodesic_moving_average<M>(
+    manifold: &M,
+    sequence: &[Vec<f64>],
+    window: usize,
+    estimator: &FrechetMean,
+) -> Result<Vec<Vec<f64>>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    if window == 0 {
+        return Err(ManifoldError::InvalidParameter(
+            "Moving-average window must be at least 1".to_string(),
+        ));
+    }
+    (0..sequence.len())
+        .map(|i| {
+            let start = (i + 1).saturating_sub(window);
+            let points = &sequence[start..=i];
+            estimator.blend(manifold, points, &vec![1.0; points.len()])
+        })
+        .collect()
+}
+
+pub fn exponential_smoothing<M>(
+    manifold: &M,
+    sequence: &[Vec<f64>],
+    alpha: f64,
+) -> Result<Vec<Vec<f64>>>
+where
+    M: Manifold<Scalar = f64> + ?Sized,
+{
+    if !(alpha > 0.0 && alpha <= 1.0) {
+        return Err(ManifoldError::InvalidParameter(format!(
+            "Smoothing factor must lie in (0, 1], got {}",
+            alpha
+        )));
+    }
+    let mut smoothed: Vec<Vec<f64>> = Vec::with_capacity(sequence.len());
+    for point in sequence {
+        validate_entry_point(manifold, point)?;
+        let next = match smoothed.last() {
+            None => point.clone(),
+            Some(previous) => {
+                let step = manifold.log(previous, point)?;
+                let step = TangentVector::new(step.components * alpha);
+                manifold.project_to_manifold(&manifold.exp(previous, &step)?)?
+            }
+        };
+        smoothed.push(next);
+    }
+    Ok(smoothed)
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LieKalmanFilter {
+    pub state: Vec<f64>,
+    pub covariance: DMatrix<f64>,
+}
+
+impl LieKalmanFilter {
+    pub fn new<G>(group: &G, state: Vec<f64>, covariance: DMatrix<f64>) -> Result<Self>
+    where
+        G: LieGroup<Scalar = f64>,
+    {
+        validate_entry_point(group, &state)?;
+        check_covariance(group.dim(), &covariance)?;
+        Ok(Self { state, covariance })
+    }
+
+    pub fn predict<G>(
+        &mut self,
+        group: &G,
+        velocity: &[f64],
+        dt: f64,
+        process_noise: &DMatrix<f64>,
+    ) -> Result<()>
+    where
+        G: LieGroup<Scalar = f64>,
+    {
+        if !(dt >= 0.0 && dt.is_finite()) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Time step must be non-negative and finite, got {}",
+                dt
+            )));
+        }
+        let d = group.dim();
+        if velocity.len() != d {
+            return Err(ManifoldError::DimensionMismatch {
+                expected: d,
+                got: velocity.len(),
+            });
+        }
+        check_covariance(d, process_noise)?;
+
+        let increment: Vec<f64> = velocity.iter().map(|v| v * dt).collect();
+        let step = group.group_exp(&increment)?;
+        let transition = adjoint(group, &group.inverse(&step)?)?;
+        self.state = group.project_to_manifold(&group.compose(&self.state, &step)?)?;
+        self.covariance = symmetrize(
+            &transition * &self.covariance * transition.transpose() + process_noise * dt,
+        );
+        Ok(())
+    }
+
+    pub fn update<G>(
+        &mut self,
+        group: &G,
+        measurement: &[f64],
+        measurement_noise: &DMatrix<f64>,
+    ) -> Result<f64>
+    where
+        G: LieGroup<Scalar = f64>,
+    {
+        let d = group.dim();
+        validate_entry_point(group, measurement)?;
+        check_covariance(d, measurement_noise)?;
+
+        let innovation = DVector::from_vec(group.between(&self.state, measurement)?);
+        let innovation_covariance = &self.covariance + measurement_noise;
+        let cholesky = innovation_covariance.clone().cholesky().ok_or_else(|| {
+            ManifoldError::NumericalError(
+                "Innovation covariance is not positive definite".to_string(),
+            )
+        })?;
+        let gain = cholesky.solve(&self.covariance).transpose();
+        let correction = &gain * &innovation;
+
+        self.state =
+            group.project_to_manifold(&group.perturb(&self.state, correction.as_slice())?)?;
+        let identity = DMatrix::<f64>::identity(d, d);
+        self.covariance = symmetrize((identity - gain) * &self.covariance);
+        Ok(innovation.dot(&cholesky.solve(&innovation)))
+    }
+}
+
+fn adjoint<G>(group: &G, element: &[f64]) -> Result<DMatrix<f64>>
+where
+    G: LieGroup<Scalar = f64>,
+{
+    let d = group.dim();
+    let h = 1e-6;
+    let inverse = group.inverse(element)?;
+    let mut adjoint = DMatrix::zeros(d, d);
+    for j in 0..d {
+        let mut generator = vec![0.0; d];
+        generator[j] = h;
+        let minus: Vec<f64> = generator.iter().map(|g| -g).collect();
+        let conjugate = |g: &[f64]| -> Result<Vec<f64>> {
+            group.group_log(
+                &group.compose(&group.compose(element, &group.group_exp(g)?)?, &inverse)?,
+            )
+        };
+        let forward = conjugate(&generator)?;
+        let backward = conjugate(&minus)?;
+        for i in 0..d {
+            adjoint[(i, j)] = (forward[i] - backward[i]) / (2.0 * h);
+        }
+    }
+    Ok(adjoint)
+}
+
+fn check_covariance(dim: usize, covariance: &DMatrix<f64>) -> Result<()> {
+    if covariance.nrows() != dim || covariance.ncols() != dim {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: dim,
+            got: covariance.nrows(),
+        });
+    }
+    if covariance.iter().any(|c| !c.is_finite()) {
+        return Err(ManifoldError::InvalidParameter(
+            "Covariance entries must be finite".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn symmetrize(matrix: DMatrix<f64>) -> DMatrix<f64> {
+    (&matrix + matrix.transpose()) * 0.5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use poly_manifold_core::{RandomSource, SeededRng};
+    use poly_manifold_spaces::{Euclidean, Sphere, SE3, SO3};
+
+    fn noisy_rotations(
+        count: usize,
+        omega: [f64; 3],
+        noise: f64,
+        seed: u64,
+    ) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+        let so3 = SO3::new();
+        let mut rng = SeededRng::seed_from_u64(seed);
+        let mut truth = Vec::with_capacity(count);
+        let mut observed = Vec::with_capacity(count);
+        for k in 0..count {
+            let algebra: Vec<f64> = omega.iter().map(|w| w * k as f64).collect();
+            let rotation = so3.group_exp(&algebra).unwrap();
+            let jitter: Vec<f64> = (0..3).map(|_| noise * rng.next_gaussian()).collect();
+            observed.push(so3.perturb(&rotation, &jitter).unwrap());
+            truth.push(rotation);
+        }
+        (truth, observed)
+    }
+
+    fn mean_error(so3: &SO3, estimates: &[Vec<f64>], truth: &[Vec<f64>]) -> f64 {
+        let errors: Vec<f64> = estimates
+            .iter()
+            .zip(truth)
+            .map(|(e, t)| so3.distance(e, t).unwrap())
+            .collect();
+        errors.iter().sum::<f64>() / errors.len() as f64
+    }
+
+    #[test]
+    fn test_moving_average_on_euclidean_matches_arithmetic_mean() {
+        let euclidean = Euclidean::new(1);
+        let sequence: Vec<Vec<f64>> = (0..6).map(|k| vec![(k * k) as f64]).collect();
+        let averaged =
+            geodesic_moving_average(&euclidean, &sequence, 3, &FrechetMean::default()).unwrap();
+        assert_relative_eq!(averaged[0][0], 0.0, epsilon = 1e-8);
+        assert_relative_eq!(averaged[1][0], 0.5, epsilon = 1e-8);
+        assert_relative_eq!(averaged[5][0], (9.0 + 16.0 + 25.0) / 3.0, epsilon = 1e-8);
+        assert!(
+            geodesic_moving_average(&euclidean, &sequence, 0, &FrechetMean::default()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_exponential_smoothing_denoises_rotations() {
+        let so3 = SO3::new();
+        let (truth, observed) = noisy_rotations(200, [0.0; 3], 0.1, 1);
+        let smoothed = exponential_smoothing(&so3, &observed, 0.1).unwrap();
+        assert!(
+            mean_error(&so3, &smoothed[50..], &truth[50..])
+                < 0.5 * mean_error(&so3, &observed, &truth)
+        );
+
+        let sphere = Sphere::new(2);
+        let sequence = vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]];
+        let halfway = exponential_smoothing(&sphere, &sequence, 0.5).unwrap();
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        assert_relative_eq!(halfway[1][0], s, epsilon = 1e-10);
+        assert_relative_eq!(halfway[1][1], s, epsilon = 1e-10);
+        assert!(exponential_smoothing(&sphere, &sequence, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_kalman_filter_tracks_rotating_attitude() {
+        let so3 = SO3::new();
+        let omega = [0.02, -0.01, 0.03];
+        let (truth, observed) = noisy_rotations(150, omega, 0.1, 2);
+        let mut filter =
+            LieKalmanFilter::new(&so3, SO3::identity(), DMatrix::identity(3, 3) * 0.1).unwrap();
+        let process = DMatrix::identity(3, 3) * 1e-5;
+        let measurement = DMatrix::identity(3, 3) * 0.01;
+
+        let mut estimates = Vec::new();
+        for (k, z) in observed.iter().enumerate() {
+            if k > 0 {
+                filter.predict(&so3, &omega, 1.0, &process).unwrap();
+            }
+            filter.update(&so3, z, &measurement).unwrap();
+            estimates.push(filter.state.clone());
+        }
+        assert!(
+            mean_error(&so3, &estimates[30..], &truth[30..])
+                < 0.4 * mean_error(&so3, &observed, &truth)
+        );
+        assert!(filter.covariance.trace() < 0.03);
+    }
+
+    #[test]
+    fn test_kalman_covariance_behaviour_on_se3() {
+        let se3 = SE3::new();
+        let mut filter =
+            LieKalmanFilter::new(&se3, se3.group_identity(), DMatrix::identity(6, 6)).unwrap();
+        let process = DMatrix::identity(6, 6) * 0.01;
+        filter
+            .predict(&se3, &[0.0, 0.0, 0.5, 1.0, 0.0, 0.0], 1.0, &process)
+            .unwrap();
+        let prior_trace = filter.covariance.trace();
+        assert!(prior_trace > 6.0);
+
+        let target = se3.group_exp(&[0.0, 0.0, 0.5, 1.0, 0.0, 0.0]).unwrap();
+        let mahalanobis = filter
+            .update(&se3, &target, &(DMatrix::identity(6, 6) * 0.01))
+            .unwrap();
+        assert!(mahalanobis < 1e-6);
+        assert!(filter.covariance.trace() < 0.1);
+        assert!(se3.distance(&filter.state, &target).unwrap() < 1e-6);
+    }
+
+    #[test]
+    fn test_kalman_filter_rejects_invalid_inputs() {
+        let so3 = SO3::new();
+        assert!(LieKalmanFilter::new(&so3, vec![1.0; 9], DMatrix::identity(3, 3)).is_err());
+        assert!(LieKalmanFilter::new(&so3, SO3::identity(), DMatrix::identity(2, 2)).is_err());
+        let mut filter =
+            LieKalmanFilter::new(&so3, SO3::identity(), DMatrix::identity(3, 3)).unwrap();
+        assert!(filter
+            .predict(&so3, &[0.0; 2], 1.0, &DMatrix::identity(3, 3))
+            .is_err());
+        for dt in [-0.1, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                filter.predict(&so3, &[0.0; 3], dt, &DMatrix::identity(3, 3)),
+                Err(ManifoldError::InvalidParameter(_))
+            ));
+        }
+        assert!(filter
+            .update(&so3, &SO3::identity(), &DMatrix::identity(4, 4))
+            .is_err());
+    }
+}
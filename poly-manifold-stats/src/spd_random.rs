@@ -0,0 +1,242 @@
+use nalgebra::DMatrix;
+use poly_manifold_core::{ManifoldError, RandomSource, Result};
+
+#[derive(Debug, Clone)]
+pub struct Wishart {
+    pub scale: DMatrix<f64>,
+    pub degrees_of_freedom: f64,
+    cholesky: DMatrix<f64>,
+}
+
+impl Wishart {
+    pub fn new(scale: DMatrix<f64>, degrees_of_freedom: f64) -> Result<Self> {
+        let n = check_square(&scale)?;
+        if !(degrees_of_freedom > n as f64 - 1.0 && degrees_of_freedom.is_finite()) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Degrees of freedom must exceed {}, got {}",
+                n - 1,
+                degrees_of_freedom
+            )));
+        }
+        let cholesky = scale
+            .clone()
+            .cholesky()
+            .ok_or_else(|| {
+                ManifoldError::InvalidParameter(
+                    "Scale matrix must be positive definite".to_string(),
+                )
+            })?
+            .l();
+        Ok(Self {
+            scale,
+            degrees_of_freedom,
+            cholesky,
+        })
+    }
+
+    pub fn mean(&self) -> DMatrix<f64> {
+        &self.scale * self.degrees_of_freedom
+    }
+
+    pub fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let n = self.scale.nrows();
+        let mut bartlett = DMatrix::zeros(n, n);
+        for i in 0..n {
+            let shape = 0.5 * (self.degrees_of_freedom - i as f64);
+            bartlett[(i, i)] = (2.0 * sample_gamma(shape, rng)).sqrt();
+            for j in 0..i {
+                bartlett[(i, j)] = rng.next_gaussian();
+            }
+        }
+        let factor = &self.cholesky * bartlett;
+        (&factor * factor.transpose()).as_slice().to_vec()
+    }
+
+    pub fn sample_n<R: RandomSource + ?Sized>(&self, count: usize, rng: &mut R) -> Vec<Vec<f64>> {
+        (0..count).map(|_| self.sample(rng)).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SpdLogNormal {
+    pub mean: DMatrix<f64>,
+    pub dispersion: f64,
+    sqrt_mean: DMatrix<f64>,
+}
+
+impl SpdLogNormal {
+    pub fn new(mean: DMatrix<f64>, dispersion: f64) -> Result<Self> {
+        check_square(&mean)?;
+        if !(dispersion >= 0.0 && dispersion.is_finite()) {
+            return Err(ManifoldError::InvalidParameter(format!(
+                "Dispersion must be non-negative and finite, got {}",
+                dispersion
+            )));
+        }
+        let eigen = mean.clone().symmetric_eigen();
+        if eigen.eigenvalues.iter().any(|l| *l <= 0.0) {
+            return Err(ManifoldError::InvalidParameter(
+                "Mean matrix must be positive definite".to_string(),
+            ));
+        }
+        let sqrt_mean = spectral_map(&mean, f64::sqrt);
+        Ok(Self {
+            mean,
+            dispersion,
+            sqrt_mean,
+        })
+    }
+
+    pub fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let n = self.mean.nrows();
+        let mut tangent = DMatrix::zeros(n, n);
+        for i in 0..n {
+            tangent[(i, i)] = rng.next_gaussian();
+            for j in 0..i {
+                let value = rng.next_gaussian() * std::f64::consts::FRAC_1_SQRT_2;
+                tangent[(i, j)] = value;
+                tangent[(j, i)] = value;
+            }
+        }
+        let whitened = spectral_map(&(tangent * self.dispersion), f64::exp);
+        (&self.sqrt_mean * whitened * &self.sqrt_mean)
+            .as_slice()
+            .to_vec()
+    }
+
+    pub fn sample_n<R: RandomSource + ?Sized>(&self, count: usize, rng: &mut R) -> Vec<Vec<f64>> {
+        (0..count).map(|_| self.sample(rng)).collect()
+    }
+}
+
+pub(crate) fn check_square(matrix: &DMatrix<f64>) -> Result<usize> {
+    if matrix.nrows() != matrix.ncols() || matrix.nrows() == 0 {
+        return Err(ManifoldError::DimensionMismatch {
+            expected: matrix.nrows(),
+            got: matrix.ncols(),
+        });
+    }
+    Ok(matrix.nrows())
+}
+
+pub(crate) fn spectral_map<F: Fn(f64) -> f64>(matrix: &DMatrix<f64>, f: F) -> DMatrix<f64> {
+    let eigen = matrix.clone().symmetric_eigen();
+    let mapped = eigen.eigenvalues.map(f);
+    &eigen.eigenvectors * DMatrix::from_diagonal(&mapped) * eigen.eigenvectors.transpose()
+}
+
+pub(crate) fn sample_gamma<R: RandomSource + ?Sized>(shape: f64, rng: &mut R) -> f64 {
+    if shape < 1.0 {
+        let u = 1.0 - rng.next_f64();
+        return sample_gamma(shape + 1.0, rng) * u.powf(1.0 / shape);
+    }
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+    loop {
+        let x = rng.next_gaussian();
+        let v = (1.0 + c * x).powi(3);
+        if v <= 0.0 {
+            continue;
+        }
+        let u = 1.0 - rng.next_f64();
+        if u.ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+            return d * v;
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+mod distributions {
+    use super::{SpdLogNormal, Wishart};
+    use poly_manifold_core::RandSource;
+    use rand::distr::Distribution;
+    use rand::Rng;
+
+    impl Distribution<Vec<f64>> for Wishart {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+            Wishart::sample(self, &mut RandSource(rng))
+        }
+    }
+
+    impl Distribution<Vec<f64>> for SpdLogNormal {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+            SpdLogNormal::sample(self, &mut RandSource(rng))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mean::FrechetMean;
+    use approx::assert_relative_eq;
+    use poly_manifold_core::{Manifold, SeededRng};
+    use poly_manifold_spaces::SPD;
+
+    #[test]
+    fn test_gamma_sampler_moments() {
+        let mut rng = SeededRng::seed_from_u64(5);
+        for shape in [0.5, 2.5] {
+            let samples: Vec<f64> = (0..20000).map(|_| sample_gamma(shape, &mut rng)).collect();
+            let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+            assert_relative_eq!(mean, shape, epsilon = 0.05 * shape);
+        }
+    }
+
+    #[test]
+    fn test_wishart_samples_are_spd_with_expected_mean() {
+        let spd = SPD::new(2);
+        let scale = DMatrix::from_row_slice(2, 2, &[1.0, 0.3, 0.3, 0.5]);
+        let wishart = Wishart::new(scale, 5.0).unwrap();
+
+        let mut rng = SeededRng::seed_from_u64(9);
+        let samples = wishart.sample_n(5000, &mut rng);
+        let mut average = DMatrix::zeros(2, 2);
+        for sample in &samples {
+            assert!(spd.check_point(sample).is_ok());
+            average += DMatrix::from_row_slice(2, 2, sample);
+        }
+        average /= samples.len() as f64;
+
+        let expected = wishart.mean();
+        for (a, e) in average.iter().zip(expected.iter()) {
+            assert_relative_eq!(a, e, epsilon = 0.1);
+        }
+    }
+
+    #[test]
+    fn test_log_normal_concentrates_at_frechet_mean() {
+        let spd = SPD::new(2);
+        let mean = DMatrix::from_row_slice(2, 2, &[1.2, 0.1, 0.1, 0.9]);
+        let distribution = SpdLogNormal::new(mean.clone(), 0.05).unwrap();
+
+        let mut rng = SeededRng::seed_from_u64(4);
+        let samples = distribution.sample_n(500, &mut rng);
+        for sample in &samples {
+            assert!(spd.check_point(sample).is_ok());
+        }
+        let estimate = FrechetMean::default().compute(&spd, &samples).unwrap();
+        for (a, e) in estimate.iter().zip(mean.iter()) {
+            assert_relative_eq!(a, e, epsilon = 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_zero_dispersion_returns_mean() {
+        let mean = DMatrix::from_row_slice(2, 2, &[2.0, 0.5, 0.5, 1.0]);
+        let distribution = SpdLogNormal::new(mean.clone(), 0.0).unwrap();
+        let sample = distribution.sample(&mut SeededRng::seed_from_u64(1));
+        for (s, m) in sample.iter().zip(mean.iter()) {
+            assert_relative_eq!(s, m, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let identity = DMatrix::<f64>::identity(3, 3);
+        assert!(Wishart::new(identity.clone(), 1.5).is_err());
+        assert!(Wishart::new(-identity.clone(), 4.0).is_err());
+        assert!(SpdLogNormal::new(identity.clone(), -1.0).is_err());
+        assert!(SpdLogNormal::new(DMatrix::zeros(2, 3), 0.1).is_err());
+    }
+}
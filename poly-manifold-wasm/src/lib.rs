@@ -0,0 +1,177 @@
+use js_sys::{Float64Array, Function};
+use nalgebra::DVector;
+use poly_manifold_autodiff::{GradientDescent, RiemannianOptimizer};
+use poly_manifold_core::{Manifold, ManifoldError, TangentVector};
+use poly_manifold_spaces::{Euclidean, PoincareBall, Sphere, SO3, SPD};
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+fn to_js_error(error: ManifoldError) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+fn tangent(components: &[f64]) -> TangentVector<f64> {
+    TangentVector::new(DVector::from_column_slice(components))
+}
+
+#[wasm_bindgen(js_name = Manifold)]
+pub struct JsManifold {
+    inner: Box<dyn Manifold<Scalar = f64>>,
+}
+
+impl JsManifold {
+    fn new(manifold: impl Manifold<Scalar = f64> + 'static) -> Self {
+        Self {
+            inner: Box::new(manifold),
+        }
+    }
+}
+
+#[wasm_bindgen(js_class = Manifold)]
+impl JsManifold {
+    pub fn euclidean(dimension: usize) -> Result<JsManifold, JsError> {
+        Ok(Self::new(
+            Euclidean::try_new(dimension).map_err(to_js_error)?,
+        ))
+    }
+
+    pub fn sphere(dimension: usize) -> Result<JsManifold, JsError> {
+        Ok(Self::new(Sphere::try_new(dimension).map_err(to_js_error)?))
+    }
+
+    pub fn spd(dimension: usize) -> Result<JsManifold, JsError> {
+        Ok(Self::new(SPD::try_new(dimension).map_err(to_js_error)?))
+    }
+
+    #[wasm_bindgen(js_name = poincareBall)]
+    pub fn poincare_ball(dimension: usize) -> Result<JsManifold, JsError> {
+        Ok(Self::new(
+            PoincareBall::try_new(dimension).map_err(to_js_error)?,
+        ))
+    }
+
+    pub fn so3() -> JsManifold {
+        Self::new(SO3::new())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn dim(&self) -> usize {
+        self.inner.dim()
+    }
+
+    #[wasm_bindgen(getter, js_name = ambientDim)]
+    pub fn ambient_dim(&self) -> usize {
+        self.inner.ambient_dim()
+    }
+
+    pub fn project(&self, point: &[f64]) -> Result<Vec<f64>, JsError> {
+        self.inner.project_to_manifold(point).map_err(to_js_error)
+    }
+
+    pub fn exp(&self, point: &[f64], tangent_vector: &[f64]) -> Result<Vec<f64>, JsError> {
+        self.inner
+            .exp(point, &tangent(tangent_vector))
+            .map_err(to_js_error)
+    }
+
+    pub fn log(&self, point: &[f64], other: &[f64]) -> Result<Vec<f64>, JsError> {
+        self.inner
+            .log(point, other)
+            .map(|v| v.components.as_slice().to_vec())
+            .map_err(to_js_error)
+    }
+
+    pub fn distance(&self, point: &[f64], other: &[f64]) -> Result<f64, JsError> {
+        self.inner.distance(point, other).map_err(to_js_error)
+    }
+
+    pub fn geodesic(&self, point: &[f64], other: &[f64], t: f64) -> Result<Vec<f64>, JsError> {
+        let direction = self.inner.log(point, other).map_err(to_js_error)?;
+        self.inner
+            .geodesic(point, &direction, t)
+            .map_err(to_js_error)
+    }
+}
+
+#[wasm_bindgen]
+pub fn optimize(
+    manifold: &JsManifold,
+    initial_point: &[f64],
+    cost: &Function,
+    learning_rate: f64,
+    max_iterations: usize,
+    tolerance: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let failure = RefCell::new(None);
+    let cost_function = |point: &[f64]| {
+        let value = cost.call1(&JsValue::NULL, &Float64Array::from(point).into());
+        match value.map(|v| v.as_f64()) {
+            Ok(Some(value)) => value,
+            Ok(None) => {
+                failure
+                    .borrow_mut()
+                    .get_or_insert_with(|| JsValue::from_str("Cost function must return a number"));
+                f64::NAN
+            }
+            Err(error) => {
+                failure.borrow_mut().get_or_insert(error);
+                f64::NAN
+            }
+        }
+    };
+    let result = GradientDescent::new(learning_rate, max_iterations, tolerance).minimize(
+        &manifold.inner.as_ref(),
+        initial_point,
+        cost_function,
+    );
+    if let Some(error) = failure.into_inner() {
+        return Err(error);
+    }
+    result
+        .map(|result| result.point)
+        .map_err(|error| to_js_error(error).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_sphere_geometry() {
+        let sphere = JsManifold::sphere(2).unwrap();
+        assert_eq!(sphere.dim(), 2);
+        assert_eq!(sphere.ambient_dim(), 3);
+
+        let north = [0.0, 0.0, 1.0];
+        let east = [1.0, 0.0, 0.0];
+        assert_relative_eq!(
+            sphere.distance(&north, &east).unwrap(),
+            FRAC_PI_2,
+            epsilon = 1e-12
+        );
+
+        let midpoint = sphere.geodesic(&north, &east, 0.5).unwrap();
+        let h = std::f64::consts::FRAC_1_SQRT_2;
+        assert_relative_eq!(midpoint[0], h, epsilon = 1e-12);
+        assert_relative_eq!(midpoint[2], h, epsilon = 1e-12);
+
+        let log = sphere.log(&north, &east).unwrap();
+        let back = sphere.exp(&north, &log).unwrap();
+        for (a, b) in back.iter().zip(east.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_spd_dimensions() {
+        let spd = JsManifold::spd(3).unwrap();
+        assert_eq!(spd.dim(), 6);
+        assert_eq!(spd.ambient_dim(), 9);
+        let projected = spd
+            .project(&[2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0])
+            .unwrap();
+        assert_relative_eq!(projected[0], 2.0, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod prelude;
+
+pub use poly_manifold_core::*;
+
+#[cfg(feature = "autodiff")]
+pub use poly_manifold_autodiff as autodiff;
+#[cfg(feature = "spaces")]
+pub use poly_manifold_spaces as spaces;
+#[cfg(feature = "stats")]
+pub use poly_manifold_stats as stats;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_core_is_reexported_at_the_root() {
+        let tangent: TangentVector<f64> = [3.0, 4.0].into_iter().collect();
+        assert_eq!(tangent.norm(), 5.0);
+        let error: Result<()> = Err(ManifoldError::DimensionMismatch {
+            expected: 2,
+            got: 3,
+        });
+        assert!(error.is_err());
+    }
+
+    #[cfg(feature = "spaces-spd")]
+    #[test]
+    fn test_spd_is_available_through_spaces() {
+        let spd = spaces::SPD::new(2);
+        assert_eq!(spd.dim(), 3);
+        assert!(spd.check_point(&[2.0, 0.5, 0.5, 1.0]).is_ok());
+    }
+}
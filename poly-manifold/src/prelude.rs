@@ -0,0 +1,27 @@
+#[cfg(feature = "autodiff")]
+pub use poly_manifold_autodiff::prelude::*;
+#[cfg(not(feature = "spaces"))]
+pub use poly_manifold_core::prelude::*;
+#[cfg(all(feature = "spaces", not(feature = "autodiff")))]
+pub use poly_manifold_spaces::prelude::*;
+
+#[cfg(all(test, feature = "autodiff"))]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_one_import_drives_an_optimization() {
+        let sphere = Sphere::new(2);
+        let start = sphere.point(vec![1.0, 0.0, 0.0]).unwrap();
+        let result = GradientDescent::new(0.2, 1000, 1e-10)
+            .minimize(&sphere, &start, |p| -p[2])
+            .unwrap();
+        assert_eq!(result.termination, Termination::Converged);
+        assert_relative_eq!(result.point[2], 1.0, epsilon = 1e-4);
+
+        let spd = SPD::new(2);
+        let identity = [1.0, 0.0, 0.0, 1.0];
+        assert_eq!(spd.distance(&identity, &identity).unwrap(), 0.0);
+    }
+}